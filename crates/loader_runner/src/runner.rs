@@ -61,7 +61,7 @@ pub struct LoaderContext<'a, 'context, T, U> {
   pub diagnostic: Vec<Diagnostic>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LoaderResult {
   pub cacheable: bool,
   pub file_dependencies: HashSet<PathBuf>,
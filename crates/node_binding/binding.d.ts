@@ -13,6 +13,15 @@ export interface ThreadsafeNodeFS {
   mkdir: (...args: any[]) => any
   mkdirp: (...args: any[]) => any
 }
+export interface RawBannerConfig {
+  banner: string
+  entryOnly?: boolean
+  footer?: boolean
+  raw?: boolean
+  test?: RawRuleSetCondition
+  include?: RawRuleSetCondition
+  exclude?: RawRuleSetCondition
+}
 export interface RawPattern {
   from: string
   to?: string
@@ -64,6 +73,40 @@ export interface RawHtmlPluginConfig {
   title?: string
   favicon?: string
   meta?: Record<string, Record<string, string>>
+  /** emit `<link rel="preload">` for the async chunks preloaded by the initial chunks */
+  preload?: boolean
+}
+export interface RawExposeConfig {
+  /** The exposed name, e.g. `./Button` */
+  name: string
+  import: Array<string>
+}
+export interface RawRemoteConfig {
+  /** The request prefix of the remote, e.g. `app` of `app/Button` */
+  key: string
+  external: string
+  shareScope?: string
+}
+export interface RawSharedConfig {
+  key: string
+  import?: string
+  shareKey?: string
+  shareScope?: string
+  requiredVersion?: string
+  version?: string
+  singleton?: boolean
+  strictVersion?: boolean
+  eager?: boolean
+}
+export interface RawModuleFederationConfig {
+  /** Name of the container, a container is built only if `name` and `exposes` are both set */
+  name?: string
+  exposes?: Array<RawExposeConfig>
+  libraryType?: string
+  remotes?: Array<RawRemoteConfig>
+  remoteType?: string
+  shared?: Array<RawSharedConfig>
+  shareScope?: string
 }
 export interface RawStyleConfig {
   styleLibraryDirectory?: string
@@ -116,6 +159,7 @@ export interface RawMinification {
   passes: number
   dropConsole: boolean
   pureFuncs: Array<string>
+  extractComments?: string
 }
 export interface RawPresetEnv {
   targets: Array<string>
@@ -140,6 +184,8 @@ export interface RawBuiltins {
   copy?: RawCopyConfig
   pluginImport?: Array<RawPluginImportConfig>
   relay?: RawRelayConfig
+  moduleFederation?: RawModuleFederationConfig
+  banner?: Array<RawBannerConfig>
 }
 export interface RawCacheOptions {
   type: string
@@ -165,15 +211,27 @@ export interface RawExperiments {
   asyncWebAssembly: boolean
 }
 export interface RawExternalItem {
-  type: "string" | "regexp" | "object"
+  type: "string" | "regexp" | "object" | "function"
   stringPayload?: string
   regexpPayload?: string
   objectPayload?: Record<string, RawExternalItemValue>
+  fnPayload?: (value: RawExternalItemFnCtx) => RawExternalItemFnResult
+}
+export interface RawExternalItemFnCtx {
+  request: string
+  context: string
+  dependencyType: string
+}
+export interface RawExternalItemFnResult {
+  externalType?: string
+  result?: RawExternalItemValue
 }
 export interface RawExternalItemValue {
-  type: "string" | "bool"
+  type: "string" | "bool" | "array" | "object"
   stringPayload?: string
   boolPayload?: boolean
+  arrayPayload?: Array<string>
+  objectPayload?: Record<string, Array<string>>
 }
 export interface RawExternalsPresets {
   node: boolean
@@ -281,9 +339,12 @@ export interface RawNodeOption {
 }
 export interface RawOptimizationOptions {
   splitChunks?: RawSplitChunksOptions
+  chunkIds: string
   moduleIds: string
   removeAvailableModules: boolean
   sideEffects: string
+  concatenateModules: boolean
+  realContentHash: boolean
 }
 export interface RawLibraryName {
   amd?: string
@@ -322,6 +383,7 @@ export interface RawOutputOptions {
   importFunctionName: string
   iife: boolean
   module: boolean
+  workerChunkLoading: string
 }
 export interface RawResolveOptions {
   preferRelative?: boolean
@@ -369,6 +431,8 @@ export interface RawCacheGroupOptions {
 export interface RawStatsOptions {
   colors: boolean
   reasons: boolean
+  providedExports: boolean
+  usedExports: boolean
 }
 export interface RawOptions {
   entry: Record<string, RawEntryItem>
@@ -390,6 +454,8 @@ export interface RawOptions {
   cache: RawCacheOptions
   experiments: RawExperiments
   node: RawNodeOption
+  recordsInputPath?: string
+  recordsOutputPath?: string
 }
 export interface JsAssetInfoRelated {
   sourceMap?: string
@@ -405,6 +471,9 @@ export interface JsAssetInfo {
    * the value(s) of the chunk hash used for this asset
    * the value(s) of the module hash used for this asset
    * the value(s) of the content hash used for this asset
+   */
+  contentHash?: Array<string>
+  /**
    * when asset was created from a source file (potentially transformed), the original filename relative to compilation context
    * size in bytes, only set after asset has been emitted
    * when asset is only used for development and doesn't count towards user-facing assets
@@ -424,8 +493,19 @@ export interface JsAsset {
   info: JsAssetInfo
 }
 export interface JsChunk {
+  id?: string
+  name?: string
   files: Array<string>
 }
+export interface JsContentHash {
+  /** One of `javascript`, `css`, `wasm` and `asset` */
+  sourceType: string
+  hash: string
+}
+export interface JsRenderManifestEntry {
+  filename: string
+  source: JsCompatSource
+}
 export interface JsChunkGroup {
   chunks: Array<JsChunk>
 }
@@ -444,6 +524,18 @@ export interface JsHooks {
   optimizeChunkModule: (...args: any[]) => any
   finishModules: (...args: any[]) => any
   normalModuleFactoryResolveForScheme: (...args: any[]) => any
+  beforeResolve: (...args: any[]) => any
+  afterResolve: (...args: any[]) => any
+  /** Modules are only created on the Rust side, requests can be rewritten in `before_resolve` */
+  factorize: (...args: any[]) => any
+  /** Returns the module, the rewritten `resource` of it is built instead */
+  buildModule: (...args: any[]) => any
+  /** Called after the module is built, so changes of it aren't written back */
+  succeedModule: (...args: any[]) => any
+  optimizeChunks: (...args: any[]) => any
+  chunkHash: (...args: any[]) => any
+  contentHash: (...args: any[]) => any
+  renderManifest: (...args: any[]) => any
 }
 export interface JsModule {
   originalSource?: JsCompatSource
@@ -464,6 +556,21 @@ export interface JsResourceData {
   /** Resource fragment with `#` prefix */
   fragment?: string
 }
+export interface JsResolveData {
+  /** Request to be resolved, could be rewritten in `beforeResolve` */
+  request: string
+  context?: string
+}
+export interface JsBeforeResolveOutput {
+  /** Returning `false` ignores the request */
+  result?: boolean
+  resolveData: JsResolveData
+}
+export interface JsAfterResolveData {
+  request: string
+  context?: string
+  resourceData: JsResourceData
+}
 export interface JsCompatSource {
   /** Whether the underlying data structure is a `RawSource` */
   isRaw: boolean
@@ -506,6 +613,8 @@ export interface JsStatsModule {
   issuerId?: string
   issuerPath: Array<JsStatsModuleIssuer>
   reasons?: Array<JsStatsModuleReason>
+  providedExports?: Array<string>
+  usedExports?: Array<string>
 }
 export interface JsStatsModuleIssuer {
   identifier: string
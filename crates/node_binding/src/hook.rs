@@ -16,6 +16,15 @@ pub enum Hook {
   AfterEmit,
   OptimizeChunkModules,
   FinishModules,
+  BeforeResolve,
+  AfterResolve,
+  Factorize,
+  BuildModule,
+  SucceedModule,
+  OptimizeChunks,
+  ChunkHash,
+  ContentHash,
+  RenderManifest,
 }
 
 impl From<String> for Hook {
@@ -34,6 +43,15 @@ impl From<String> for Hook {
       "afterEmit" => Hook::AfterEmit,
       "optimizeChunkModules" => Hook::OptimizeChunkModules,
       "finishModules" => Hook::FinishModules,
+      "beforeResolve" => Hook::BeforeResolve,
+      "afterResolve" => Hook::AfterResolve,
+      "factorize" => Hook::Factorize,
+      "buildModule" => Hook::BuildModule,
+      "succeedModule" => Hook::SucceedModule,
+      "optimizeChunks" => Hook::OptimizeChunks,
+      "chunkHash" => Hook::ChunkHash,
+      "contentHash" => Hook::ContentHash,
+      "renderManifest" => Hook::RenderManifest,
       hook_name => panic!("{hook_name} is an invalid hook name"),
     }
   }
@@ -25,7 +25,7 @@ pub struct JsAssetInfo {
   /// the value(s) of the module hash used for this asset
   // pub module_hash:
   /// the value(s) of the content hash used for this asset
-  // pub content_hash:
+  pub content_hash: Option<Vec<String>>,
   /// when asset was created from a source file (potentially transformed), the original filename relative to compilation context
   // pub source_filename:
   /// size in bytes, only set after asset has been emitted
@@ -44,6 +44,7 @@ impl From<JsAssetInfo> for rspack_core::AssetInfo {
   fn from(i: JsAssetInfo) -> Self {
     Self {
       minimized: i.minimized,
+      content_hash: i.content_hash.unwrap_or_default().into_iter().collect(),
       development: i.development,
       hot_module_replacement: i.hot_module_replacement,
       related: i.related.into(),
@@ -70,6 +71,7 @@ impl From<rspack_core::AssetInfo> for JsAssetInfo {
   fn from(info: rspack_core::AssetInfo) -> Self {
     Self {
       minimized: info.minimized,
+      content_hash: Some(info.content_hash.into_iter().collect()),
       development: info.development,
       hot_module_replacement: info.hot_module_replacement,
       related: info.related.into(),
@@ -1,5 +1,9 @@
+use super::JsCompatSource;
+
 #[napi(object)]
 pub struct JsChunk {
+  pub id: Option<String>,
+  pub name: Option<String>,
   pub files: Vec<String>,
 }
 
@@ -7,6 +11,23 @@ impl JsChunk {
   pub fn from(chunk: &rspack_core::Chunk) -> Self {
     let mut files = Vec::from_iter(chunk.files.iter().cloned());
     files.sort_unstable();
-    Self { files }
+    Self {
+      id: chunk.id.clone(),
+      name: chunk.name.clone(),
+      files,
+    }
   }
 }
+
+#[napi(object)]
+pub struct JsContentHash {
+  /// One of `javascript`, `css`, `wasm` and `asset`
+  pub source_type: String,
+  pub hash: String,
+}
+
+#[napi(object)]
+pub struct JsRenderManifestEntry {
+  pub filename: String,
+  pub source: JsCompatSource,
+}
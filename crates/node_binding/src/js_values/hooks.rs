@@ -16,4 +16,16 @@ pub struct JsHooks {
   pub optimize_chunk_module: JsFunction,
   pub finish_modules: JsFunction,
   pub normal_module_factory_resolve_for_scheme: JsFunction,
+  pub before_resolve: JsFunction,
+  pub after_resolve: JsFunction,
+  /// Modules are only created on the Rust side, requests can be rewritten in `before_resolve`
+  pub factorize: JsFunction,
+  /// Returns the module, the rewritten `resource` of it is built instead
+  pub build_module: JsFunction,
+  /// Called after the module is built, so changes of it aren't written back
+  pub succeed_module: JsFunction,
+  pub optimize_chunks: JsFunction,
+  pub chunk_hash: JsFunction,
+  pub content_hash: JsFunction,
+  pub render_manifest: JsFunction,
 }
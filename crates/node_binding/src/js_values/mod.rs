@@ -13,6 +13,7 @@ pub use chunk::*;
 pub use chunk_group::*;
 pub use compilation::*;
 pub use hooks::*;
+pub use module::*;
 pub use normal_module_factory::*;
 pub use source::*;
 pub use stats::*;
@@ -1,4 +1,7 @@
-use rspack_core::{NormalModuleFactoryResolveForSchemeArgs, ResourceData};
+use rspack_core::{
+  NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs,
+  NormalModuleFactoryResolveForSchemeArgs, ResourceData,
+};
 
 #[napi(object)]
 pub struct SchemeAndJsResourceData {
@@ -37,3 +40,43 @@ impl From<NormalModuleFactoryResolveForSchemeArgs> for SchemeAndJsResourceData {
     }
   }
 }
+
+#[napi(object)]
+pub struct JsResolveData {
+  /// Request to be resolved, could be rewritten in `beforeResolve`
+  pub request: String,
+  pub context: Option<String>,
+}
+
+#[napi(object)]
+pub struct JsBeforeResolveOutput {
+  /// Returning `false` ignores the request
+  pub result: Option<bool>,
+  pub resolve_data: JsResolveData,
+}
+
+#[napi(object)]
+pub struct JsAfterResolveData {
+  pub request: String,
+  pub context: Option<String>,
+  pub resource_data: JsResourceData,
+}
+
+impl From<NormalModuleBeforeResolveArgs> for JsResolveData {
+  fn from(value: NormalModuleBeforeResolveArgs) -> Self {
+    Self {
+      request: value.request,
+      context: value.context,
+    }
+  }
+}
+
+impl From<NormalModuleAfterResolveArgs<'_>> for JsAfterResolveData {
+  fn from(value: NormalModuleAfterResolveArgs<'_>) -> Self {
+    Self {
+      request: value.request.to_string(),
+      context: value.context.map(ToString::to_string),
+      resource_data: value.resource_data.clone().into(),
+    }
+  }
+}
@@ -88,6 +88,8 @@ pub struct JsStatsModule {
   pub issuer_id: Option<String>,
   pub issuer_path: Vec<JsStatsModuleIssuer>,
   pub reasons: Option<Vec<JsStatsModuleReason>>,
+  pub provided_exports: Option<Vec<String>>,
+  pub used_exports: Option<Vec<String>>,
 }
 
 impl From<rspack_core::StatsModule> for JsStatsModule {
@@ -107,6 +109,8 @@ impl From<rspack_core::StatsModule> for JsStatsModule {
       reasons: stats
         .reasons
         .map(|i| i.into_iter().map(Into::into).collect()),
+      provided_exports: stats.provided_exports,
+      used_exports: stats.used_exports,
     }
   }
 }
@@ -4,16 +4,26 @@ use std::path::PathBuf;
 use async_trait::async_trait;
 use napi::{Env, Result};
 use rspack_binding_macros::js_fn_into_theadsafe_fn;
+use rspack_core::rspack_sources::SourceExt;
 use rspack_core::{
-  NormalModuleFactoryResolveForSchemeArgs, PluginNormalModuleFactoryResolveForSchemeOutput,
-  ResourceData,
+  AssetInfo, ChunkHashArgs, ContentHashArgs, FactorizeArgs, NormalModuleAfterResolveArgs,
+  NormalModuleBeforeResolveArgs, NormalModuleFactoryContext,
+  NormalModuleFactoryResolveForSchemeArgs, PathData, PluginChunkHashHookOutput,
+  PluginContentHashHookOutput, PluginFactorizeHookOutput,
+  PluginNormalModuleFactoryAfterResolveOutput, PluginNormalModuleFactoryBeforeResolveOutput,
+  PluginNormalModuleFactoryResolveForSchemeOutput, PluginRenderManifestHookOutput,
+  RenderManifestArgs, RenderManifestEntry, ResourceData, SourceType,
 };
 use rspack_error::internal_error;
 use rspack_napi_shared::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use rspack_napi_shared::NapiResultExt;
+use xxhash_rust::xxh3::xxh3_64;
 
-use crate::js_values::{JsResourceData, SchemeAndJsResourceData};
-use crate::{DisabledHooks, Hook, JsCompilation, JsHooks};
+use crate::js_values::{
+  JsAfterResolveData, JsBeforeResolveOutput, JsChunk, JsContentHash, JsModule,
+  JsRenderManifestEntry, JsResolveData, JsResourceData, SchemeAndJsResourceData, ToJsModule,
+};
+use crate::{CompatSource, DisabledHooks, Hook, JsCompilation, JsHooks};
 
 pub struct JsHooksAdapter {
   disabled_hooks: DisabledHooks,
@@ -32,6 +42,15 @@ pub struct JsHooksAdapter {
   pub finish_modules_tsfn: ThreadsafeFunction<JsCompilation, ()>,
   pub normal_module_factory_resolve_for_scheme:
     ThreadsafeFunction<SchemeAndJsResourceData, JsResourceData>,
+  pub before_resolve_tsfn: ThreadsafeFunction<JsResolveData, JsBeforeResolveOutput>,
+  pub after_resolve_tsfn: ThreadsafeFunction<JsAfterResolveData, Option<bool>>,
+  pub factorize_tsfn: ThreadsafeFunction<JsResolveData, ()>,
+  pub build_module_tsfn: ThreadsafeFunction<JsModule, JsModule>,
+  pub succeed_module_tsfn: ThreadsafeFunction<JsModule, ()>,
+  pub optimize_chunks_tsfn: ThreadsafeFunction<JsCompilation, ()>,
+  pub chunk_hash_tsfn: ThreadsafeFunction<JsChunk, Option<String>>,
+  pub content_hash_tsfn: ThreadsafeFunction<JsChunk, Option<JsContentHash>>,
+  pub render_manifest_tsfn: ThreadsafeFunction<JsChunk, Option<Vec<JsRenderManifestEntry>>>,
 }
 
 impl Debug for JsHooksAdapter {
@@ -131,6 +150,217 @@ impl rspack_core::Plugin for JsHooksAdapter {
     })
   }
 
+  async fn before_resolve(
+    &self,
+    _ctx: rspack_core::PluginContext,
+    args: &mut NormalModuleBeforeResolveArgs,
+  ) -> PluginNormalModuleFactoryBeforeResolveOutput {
+    if self.is_hook_disabled(&Hook::BeforeResolve) {
+      return Ok(None);
+    }
+
+    let JsBeforeResolveOutput {
+      result,
+      resolve_data,
+    } = self
+      .before_resolve_tsfn
+      .call(args.clone().into(), ThreadsafeFunctionCallMode::NonBlocking)
+      .into_rspack_result()?
+      .await
+      .map_err(|err| internal_error!("Failed to call before resolve: {err}"))??;
+
+    // Write back the possibly rewritten request
+    args.request = resolve_data.request;
+    args.context = resolve_data.context;
+    Ok(result)
+  }
+
+  async fn after_resolve(
+    &self,
+    _ctx: rspack_core::PluginContext,
+    args: &NormalModuleAfterResolveArgs<'_>,
+  ) -> PluginNormalModuleFactoryAfterResolveOutput {
+    if self.is_hook_disabled(&Hook::AfterResolve) {
+      return Ok(None);
+    }
+
+    self
+      .after_resolve_tsfn
+      .call(args.clone().into(), ThreadsafeFunctionCallMode::NonBlocking)
+      .into_rspack_result()?
+      .await
+      .map_err(|err| internal_error!("Failed to call after resolve: {err}"))?
+  }
+
+  async fn factorize(
+    &self,
+    _ctx: rspack_core::PluginContext,
+    args: FactorizeArgs<'_>,
+    _job_ctx: &mut NormalModuleFactoryContext,
+  ) -> PluginFactorizeHookOutput {
+    if self.is_hook_disabled(&Hook::Factorize) {
+      return Ok(None);
+    }
+
+    // Modules are only created on the Rust side, so the factorization always falls through
+    self
+      .factorize_tsfn
+      .call(
+        JsResolveData {
+          request: args.dependency.request().to_string(),
+          context: args.dependency.get_context().map(ToString::to_string),
+        },
+        ThreadsafeFunctionCallMode::NonBlocking,
+      )
+      .into_rspack_result()?
+      .await
+      .map_err(|err| internal_error!("Failed to call factorize: {err}"))??;
+    Ok(None)
+  }
+
+  async fn build_module(&self, module: &mut dyn rspack_core::Module) -> rspack_error::Result<()> {
+    if self.is_hook_disabled(&Hook::BuildModule) {
+      return Ok(());
+    }
+
+    // Only normal modules are exposed to the Node side
+    if let Ok(js_module) = module.to_js_module() {
+      let JsModule { resource, .. } = self
+        .build_module_tsfn
+        .call(js_module, ThreadsafeFunctionCallMode::NonBlocking)
+        .into_rspack_result()?
+        .await
+        .map_err(|err| internal_error!("Failed to call build module: {err}"))??;
+
+      // Write back the possibly rewritten resource, which is built instead
+      let resource_data = module.try_as_normal_module_mut()?.resource_resolved_data_mut();
+      let resource_path = PathBuf::from(resource);
+      if resource_data.resource_path != resource_path {
+        resource_data.resource = format!(
+          "{}{}{}",
+          resource_path.to_string_lossy(),
+          resource_data.resource_query.as_deref().unwrap_or_default(),
+          resource_data.resource_fragment.as_deref().unwrap_or_default()
+        );
+        resource_data.resource_path = resource_path;
+      }
+    }
+    Ok(())
+  }
+
+  async fn succeed_module(&self, module: &dyn rspack_core::Module) -> rspack_error::Result<()> {
+    if self.is_hook_disabled(&Hook::SucceedModule) {
+      return Ok(());
+    }
+
+    if let Ok(module) = module.to_js_module() {
+      self
+        .succeed_module_tsfn
+        .call(module, ThreadsafeFunctionCallMode::NonBlocking)
+        .into_rspack_result()?
+        .await
+        .map_err(|err| internal_error!("Failed to call succeed module: {err}"))??;
+    }
+    Ok(())
+  }
+
+  async fn content_hash(
+    &self,
+    _ctx: rspack_core::PluginContext,
+    args: &ContentHashArgs<'_>,
+  ) -> PluginContentHashHookOutput {
+    if self.is_hook_disabled(&Hook::ContentHash) {
+      return Ok(None);
+    }
+
+    let content_hash = self
+      .content_hash_tsfn
+      .call(
+        JsChunk::from(args.chunk()),
+        ThreadsafeFunctionCallMode::NonBlocking,
+      )
+      .into_rspack_result()?
+      .await
+      .map_err(|err| internal_error!("Failed to call content hash: {err}"))??;
+
+    content_hash
+      .map(|JsContentHash { source_type, hash }| {
+        let source_type = match source_type.as_str() {
+          "javascript" => SourceType::JavaScript,
+          "css" => SourceType::Css,
+          "wasm" => SourceType::Wasm,
+          "asset" => SourceType::Asset,
+          _ => {
+            return Err(internal_error!(
+              "Unknown source type {source_type} of content hash"
+            ))
+          }
+        };
+        Ok((source_type, hash))
+      })
+      .transpose()
+  }
+
+  async fn chunk_hash(
+    &self,
+    _ctx: rspack_core::PluginContext,
+    args: &ChunkHashArgs<'_>,
+  ) -> PluginChunkHashHookOutput {
+    if self.is_hook_disabled(&Hook::ChunkHash) {
+      return Ok(None);
+    }
+
+    let digest = self
+      .chunk_hash_tsfn
+      .call(
+        JsChunk::from(args.chunk()),
+        ThreadsafeFunctionCallMode::NonBlocking,
+      )
+      .into_rspack_result()?
+      .await
+      .map_err(|err| internal_error!("Failed to call chunk hash: {err}"))??;
+
+    // `DefaultHasher` isn't stable across Rust releases, which would change the chunk hash
+    Ok(digest.map(|digest| xxh3_64(digest.as_bytes())))
+  }
+
+  async fn render_manifest(
+    &self,
+    _ctx: rspack_core::PluginContext,
+    args: RenderManifestArgs<'_>,
+  ) -> PluginRenderManifestHookOutput {
+    if self.is_hook_disabled(&Hook::RenderManifest) {
+      return Ok(vec![]);
+    }
+
+    let entries = self
+      .render_manifest_tsfn
+      .call(
+        JsChunk::from(args.chunk()),
+        ThreadsafeFunctionCallMode::NonBlocking,
+      )
+      .into_rspack_result()?
+      .await
+      .map_err(|err| internal_error!("Failed to call render manifest: {err}"))??;
+
+    Ok(
+      entries
+        .unwrap_or_default()
+        .into_iter()
+        .map(|entry| {
+          RenderManifestEntry::new(
+            CompatSource::from(entry.source).boxed(),
+            entry.filename,
+            PathData {
+              chunk_ukey: args.chunk_ukey,
+            },
+            AssetInfo::default(),
+          )
+        })
+        .collect(),
+    )
+  }
+
   async fn process_assets_stage_additional(
     &mut self,
     _ctx: rspack_core::PluginContext,
@@ -258,6 +488,29 @@ impl rspack_core::Plugin for JsHooksAdapter {
       .map_err(|err| internal_error!("Failed to compilation: {err}"))?
   }
 
+  async fn optimize_chunks(
+    &mut self,
+    _ctx: rspack_core::PluginContext,
+    args: rspack_core::OptimizeChunksArgs<'_>,
+  ) -> rspack_core::PluginOptimizeChunksOutput {
+    if self.is_hook_disabled(&Hook::OptimizeChunks) {
+      return Ok(());
+    }
+
+    let compilation = JsCompilation::from_compilation(unsafe {
+      std::mem::transmute::<&'_ mut rspack_core::Compilation, &'static mut rspack_core::Compilation>(
+        args.compilation,
+      )
+    });
+
+    self
+      .optimize_chunks_tsfn
+      .call(compilation, ThreadsafeFunctionCallMode::NonBlocking)
+      .into_rspack_result()?
+      .await
+      .map_err(|err| internal_error!("Failed to call optimize chunks: {err}"))?
+  }
+
   async fn finish_modules(
     &mut self,
     compilation: &mut rspack_core::Compilation,
@@ -324,6 +577,15 @@ impl JsHooksAdapter {
       optimize_chunk_module,
       normal_module_factory_resolve_for_scheme,
       finish_modules,
+      before_resolve,
+      after_resolve,
+      factorize,
+      build_module,
+      succeed_module,
+      optimize_chunks,
+      chunk_hash,
+      content_hash,
+      render_manifest,
     } = js_hooks;
 
     let process_assets_stage_additional_tsfn: ThreadsafeFunction<(), ()> =
@@ -353,6 +615,24 @@ impl JsHooksAdapter {
       SchemeAndJsResourceData,
       JsResourceData,
     > = js_fn_into_theadsafe_fn!(normal_module_factory_resolve_for_scheme, env);
+    let before_resolve_tsfn: ThreadsafeFunction<JsResolveData, JsBeforeResolveOutput> =
+      js_fn_into_theadsafe_fn!(before_resolve, env);
+    let after_resolve_tsfn: ThreadsafeFunction<JsAfterResolveData, Option<bool>> =
+      js_fn_into_theadsafe_fn!(after_resolve, env);
+    let factorize_tsfn: ThreadsafeFunction<JsResolveData, ()> =
+      js_fn_into_theadsafe_fn!(factorize, env);
+    let build_module_tsfn: ThreadsafeFunction<JsModule, JsModule> =
+      js_fn_into_theadsafe_fn!(build_module, env);
+    let succeed_module_tsfn: ThreadsafeFunction<JsModule, ()> =
+      js_fn_into_theadsafe_fn!(succeed_module, env);
+    let optimize_chunks_tsfn: ThreadsafeFunction<JsCompilation, ()> =
+      js_fn_into_theadsafe_fn!(optimize_chunks, env);
+    let chunk_hash_tsfn: ThreadsafeFunction<JsChunk, Option<String>> =
+      js_fn_into_theadsafe_fn!(chunk_hash, env);
+    let content_hash_tsfn: ThreadsafeFunction<JsChunk, Option<JsContentHash>> =
+      js_fn_into_theadsafe_fn!(content_hash, env);
+    let render_manifest_tsfn: ThreadsafeFunction<JsChunk, Option<Vec<JsRenderManifestEntry>>> =
+      js_fn_into_theadsafe_fn!(render_manifest, env);
 
     Ok(JsHooksAdapter {
       disabled_hooks,
@@ -370,6 +650,15 @@ impl JsHooksAdapter {
       optimize_chunk_modules_tsfn,
       normal_module_factory_resolve_for_scheme,
       finish_modules_tsfn,
+      before_resolve_tsfn,
+      after_resolve_tsfn,
+      factorize_tsfn,
+      build_module_tsfn,
+      succeed_module_tsfn,
+      optimize_chunks_tsfn,
+      chunk_hash_tsfn,
+      content_hash_tsfn,
+      render_manifest_tsfn,
     })
   }
 
@@ -0,0 +1,2 @@
+import './b'
+console.log('a')
\ No newline at end of file
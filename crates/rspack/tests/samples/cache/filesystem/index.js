@@ -0,0 +1,2 @@
+import './a'
+console.log('hello, world')
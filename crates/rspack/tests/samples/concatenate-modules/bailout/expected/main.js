@@ -0,0 +1,47 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./eval.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "evaluated", {
+    enumerable: true,
+    get: function() {
+        return evaluated;
+    }
+});
+const evaluated = eval('1');
+},
+"./index.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+// CONCATENATED MODULE: ./lib.js
+const lib = 'lib';
+
+// CONCATENATED MODULE: ./index.js
+var _eval = __webpack_require__("./eval.js");
+var _module = __webpack_require__("./module.js");
+console.log(_eval.evaluated, (0, _module.moduleId)(), lib);
+
+},
+"./module.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "moduleId", {
+    enumerable: true,
+    get: function() {
+        return moduleId;
+    }
+});
+function moduleId() {
+    return module.id;
+}
+},
+
+},function(__webpack_require__) {
+var __webpack_exports__ = __webpack_require__('./index.js');
+
+}
+]);
\ No newline at end of file
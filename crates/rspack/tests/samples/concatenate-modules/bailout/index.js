@@ -0,0 +1,4 @@
+import { evaluated } from './eval';
+import { moduleId } from './module';
+import { lib } from './lib';
+console.log(evaluated, moduleId(), lib);
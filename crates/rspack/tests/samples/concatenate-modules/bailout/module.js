@@ -0,0 +1,3 @@
+export function moduleId() {
+  return module.id;
+}
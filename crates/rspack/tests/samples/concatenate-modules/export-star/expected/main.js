@@ -0,0 +1,32 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./index.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+function _export(target, all) {
+  for (var name in all) Object.defineProperty(target, name, { enumerable: true, get: all[name] });
+}
+_export(exports, {
+  "a": function() {
+    return a;
+  },
+  "b": function() {
+    return b;
+  }
+});
+// CONCATENATED MODULE: ./lib.js
+const a = 1;
+var __WEBPACK_DEFAULT_EXPORT__ = 'lib';
+
+// CONCATENATED MODULE: ./star.js
+const b = 2;
+
+// CONCATENATED MODULE: ./index.js
+console.log(a);
+
+},
+
+},function(__webpack_require__) {
+var __webpack_exports__ = __webpack_require__('./index.js');
+
+}
+]);
\ No newline at end of file
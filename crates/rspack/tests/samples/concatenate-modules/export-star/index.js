@@ -0,0 +1,3 @@
+import { a } from './star';
+export * from './star';
+console.log(a);
@@ -0,0 +1,2 @@
+export * from './lib';
+export const b = 2;
@@ -0,0 +1,4 @@
+const value = 'a';
+export function getA() {
+  return value;
+}
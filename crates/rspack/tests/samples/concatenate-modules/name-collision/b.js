@@ -0,0 +1,4 @@
+const value = 'b';
+export function getB() {
+  return value;
+}
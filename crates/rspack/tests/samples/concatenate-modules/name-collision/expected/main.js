@@ -0,0 +1,27 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./index.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+// CONCATENATED MODULE: ./a.js
+const value_1 = 'a';
+function getA() {
+    return value_1;
+}
+
+// CONCATENATED MODULE: ./b.js
+const value_2 = 'b';
+function getB() {
+    return value_2;
+}
+
+// CONCATENATED MODULE: ./index.js
+const value_3 = getA() + getB();
+console.log(value_3);
+
+},
+
+},function(__webpack_require__) {
+var __webpack_exports__ = __webpack_require__('./index.js');
+
+}
+]);
\ No newline at end of file
@@ -0,0 +1,4 @@
+import { getA } from './a';
+import { getB } from './b';
+const value = getA() + getB();
+console.log(value);
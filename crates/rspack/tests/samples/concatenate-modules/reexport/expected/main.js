@@ -0,0 +1,20 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./index.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+// CONCATENATED MODULE: ./lib.js
+const a = 1;
+const b = 2;
+
+// CONCATENATED MODULE: ./reexport.js
+
+// CONCATENATED MODULE: ./index.js
+console.log(a, b);
+
+},
+
+},function(__webpack_require__) {
+var __webpack_exports__ = __webpack_require__('./index.js');
+
+}
+]);
\ No newline at end of file
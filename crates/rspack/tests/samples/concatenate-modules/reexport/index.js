@@ -0,0 +1,2 @@
+import { a, c } from './reexport';
+console.log(a, c);
@@ -0,0 +1 @@
+export { a, b as c } from './lib';
@@ -0,0 +1,32 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./index.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+// CONCATENATED MODULE: ./lib.js
+const lib = 'lib';
+
+// CONCATENATED MODULE: ./index.js
+var _shared = __webpack_require__("./shared.js");
+console.log(_shared.shared, lib);
+__webpack_require__.el("./async.js").then(__webpack_require__.bind(__webpack_require__, "./async.js")).then(__webpack_require__.ir);
+
+},
+"./shared.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "shared", {
+    enumerable: true,
+    get: function() {
+        return shared;
+    }
+});
+const shared = 'shared';
+},
+
+},function(__webpack_require__) {
+var __webpack_exports__ = __webpack_require__('./index.js');
+
+}
+]);
\ No newline at end of file
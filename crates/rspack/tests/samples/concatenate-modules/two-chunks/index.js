@@ -0,0 +1,4 @@
+import { shared } from './shared';
+import { lib } from './lib';
+console.log(shared, lib);
+import('./async');
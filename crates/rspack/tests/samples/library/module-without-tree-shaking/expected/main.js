@@ -0,0 +1,33 @@
+export const id = "main";
+export const ids = ["main"];
+export const modules = {
+"./index.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+function _export(target, all) {
+    for(var name in all)Object.defineProperty(target, name, {
+        enumerable: true,
+        get: all[name]
+    });
+}
+_export(exports, {
+    value: function() {
+        return value;
+    },
+    default: function() {
+        return main;
+    }
+});
+const value = "value";
+function main() {
+    return value;
+}
+},
+
+};
+import __webpack_require__ from './runtime.js';
+__webpack_require__.C({ ids: ids, modules: modules });
+var __webpack_exports__ = __webpack_require__('./index.js');
+export default __webpack_exports__;
@@ -0,0 +1,35 @@
+export const id = "main";
+export const ids = ["main"];
+export const modules = {
+"./index.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+function _export(target, all) {
+    for(var name in all)Object.defineProperty(target, name, {
+        enumerable: true,
+        get: all[name]
+    });
+}
+_export(exports, {
+    value: function() {
+        return value;
+    },
+    default: function() {
+        return main;
+    }
+});
+const value = "value";
+function main() {
+    return value;
+}
+},
+
+};
+import __webpack_require__ from './runtime.js';
+__webpack_require__.C({ ids: ids, modules: modules });
+var __webpack_exports__ = __webpack_require__('./index.js');
+var __webpack_exports__default = __webpack_exports__["default"];
+var __webpack_exports__value = __webpack_exports__["value"];
+export { __webpack_exports__default as "default", __webpack_exports__value as "value" };
@@ -0,0 +1,4 @@
+export const value = "value";
+export default function main() {
+	return value;
+}
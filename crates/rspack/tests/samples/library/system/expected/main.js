@@ -0,0 +1,13 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./index.js": function (module, exports, __webpack_require__) {
+module.exports = __webpack_require__("external-lib");
+},
+"external-lib": function (module, exports, __webpack_require__) {
+module.exports = __WEBPACK_EXTERNAL_MODULE_external_lib__},
+
+},function(__webpack_require__) {
+var __webpack_exports__ = __webpack_require__('./index.js');
+return __webpack_exports__;
+
+}
+]);
\ No newline at end of file
@@ -0,0 +1,3 @@
+import { lib } from './lib'
+/*! preserved by default */
+console.log(lib)
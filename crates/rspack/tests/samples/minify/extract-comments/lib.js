@@ -0,0 +1,2 @@
+/*! @license MIT extract-comments */
+export const lib = 'lib'
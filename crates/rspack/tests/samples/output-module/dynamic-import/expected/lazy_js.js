@@ -0,0 +1,8 @@
+export const id = "lazy_js";
+export const ids = ["lazy_js"];
+export const modules = {
+"./lazy.js": function (module, exports, __webpack_require__) {
+console.log('lazy');
+},
+
+};
@@ -0,0 +1,2 @@
+console.log('hello, world')
+import('./lazy')
\ No newline at end of file
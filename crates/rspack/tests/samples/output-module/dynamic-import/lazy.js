@@ -0,0 +1 @@
+console.log('lazy')
\ No newline at end of file
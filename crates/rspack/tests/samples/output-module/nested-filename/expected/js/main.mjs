@@ -0,0 +1,11 @@
+export const id = "main";
+export const ids = ["main"];
+export const modules = {
+"./index.js": function (module, exports, __webpack_require__) {
+console.log('hello, world');
+},
+
+};
+import __webpack_require__ from '../js/runtime.mjs';
+__webpack_require__.C({ ids: ids, modules: modules });
+var __webpack_exports__ = __webpack_require__('./index.js');
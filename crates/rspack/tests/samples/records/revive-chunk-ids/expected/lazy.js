@@ -0,0 +1,6 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["recorded-lazy"], {
+"./lazy.js": function (module, exports, __webpack_require__) {
+console.log('lazy');
+},
+
+}]);
\ No newline at end of file
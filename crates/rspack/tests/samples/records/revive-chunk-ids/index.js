@@ -0,0 +1 @@
+import(/* webpackChunkName: "lazy" */ './lazy')
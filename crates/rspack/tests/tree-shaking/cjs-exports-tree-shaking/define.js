@@ -0,0 +1,3 @@
+Object.defineProperty(exports, "__esModule", { value: true });
+Object.defineProperty(exports, "defined", { enumerable: true, value: "defined" });
+Object.defineProperty(exports, "unusedDefined", { enumerable: true, value: "unused" });
@@ -0,0 +1,79 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./define.js": function (module, exports, __webpack_require__) {
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "defined", {
+    enumerable: true,
+    value: "defined"
+});
+},
+"./flag.js": function (module, exports, __webpack_require__) {
+exports.__esModule = true;
+exports.default = function() {
+    return "default";
+};
+},
+"./index.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+var _libJs = __webpack_require__("./lib.js");
+var _objJs = __webpack_require__.ir(__webpack_require__("./obj.js"));
+var _objectJs = __webpack_require__("./object.js");
+var _defineJs = __webpack_require__("./define.js");
+var _flagJs = __webpack_require__.ir(__webpack_require__("./flag.js"));
+var _keyJs = __webpack_require__("./key.js");
+var _scopeJs = __webpack_require__("./scope.js");
+(0, _libJs.used)();
+console.log(_objJs.default.a);
+(0, _objectJs.foo)();
+console.log(_defineJs.defined);
+(0, _flagJs.default)();
+(0, _keyJs.named)();
+console.log(_scopeJs.outer);
+},
+"./key.js": function (module, exports, __webpack_require__) {
+const key = "dynamic";
+exports[key] = function() {
+    return "dynamic";
+};
+exports.named = function() {
+    return "named";
+};
+exports.unused = function() {
+    return "unused";
+};
+},
+"./lib.js": function (module, exports, __webpack_require__) {
+exports.used = function() {
+    return "used";
+};
+},
+"./obj.js": function (module, exports, __webpack_require__) {
+module.exports = {
+    a: 1,
+    b: 2
+};
+},
+"./object.js": function (module, exports, __webpack_require__) {
+function foo() {}
+module.exports = {
+    foo
+};
+},
+"./scope.js": function (module, exports, __webpack_require__) {
+function setExport() {
+    exports.inner = "inner";
+}
+setExport();
+exports.outer = "outer";
+exports.unused = "unused";
+},
+
+},function(__webpack_require__) {
+var __webpack_exports__ = __webpack_require__('./index.js');
+
+}
+]);
\ No newline at end of file
@@ -0,0 +1,8 @@
+// with `__esModule`, the default import refers to `exports.default` rather than `module.exports`
+exports.__esModule = true;
+exports.default = function () {
+	return "default";
+};
+exports.unusedNamed = function () {
+	return "unused";
+};
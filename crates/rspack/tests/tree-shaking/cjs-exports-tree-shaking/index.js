@@ -0,0 +1,15 @@
+import { used } from "./lib";
+import obj from "./obj";
+import { foo } from "./object";
+import { defined } from "./define";
+import flagDefault from "./flag";
+import { named } from "./key";
+import { outer } from "./scope";
+
+used();
+console.log(obj.a);
+foo();
+console.log(defined);
+flagDefault();
+named();
+console.log(outer);
@@ -0,0 +1,11 @@
+// computed keys can't be analyzed, so nothing is removed
+const key = "dynamic";
+exports[key] = function () {
+	return "dynamic";
+};
+exports.named = function () {
+	return "named";
+};
+exports.unused = function () {
+	return "unused";
+};
@@ -0,0 +1,6 @@
+exports.used = function () {
+	return "used";
+};
+exports.unused = function () {
+	return "unused";
+};
@@ -0,0 +1,4 @@
+function foo() {}
+function bar() {}
+
+module.exports = { foo, bar };
@@ -0,0 +1,7 @@
+// `exports` used out of top level statements can't be analyzed, so nothing is removed
+function setExport() {
+	exports.inner = "inner";
+}
+setExport();
+exports.outer = "outer";
+exports.unused = "unused";
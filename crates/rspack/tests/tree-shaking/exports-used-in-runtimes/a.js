@@ -0,0 +1,2 @@
+import { a } from './lib.js';
+console.log(a);
@@ -0,0 +1,2 @@
+import { b } from './lib.js';
+console.log(b);
@@ -0,0 +1,37 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["a"], {
+"./a.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+var _libJs = __webpack_require__("./lib.js");
+console.log(_libJs.a);
+},
+"./lib.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+function _export(target, all) {
+    for(var name in all)Object.defineProperty(target, name, {
+        enumerable: true,
+        get: all[name]
+    });
+}
+_export(exports, {
+    a: function() {
+        return a;
+    },
+    b: function() {
+        return b;
+    }
+});
+const a = 1;
+const b = 2;
+},
+
+},function(__webpack_require__) {
+var __webpack_exports__ = __webpack_require__('./a.js');
+
+}
+]);
\ No newline at end of file
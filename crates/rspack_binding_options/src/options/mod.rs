@@ -2,8 +2,8 @@ use std::{collections::HashMap, fmt::Debug};
 
 use napi_derive::napi;
 use rspack_core::{
-  BoxPlugin, CompilerOptions, DevServerOptions, Devtool, EntryItem, Experiments, ModuleOptions,
-  OutputOptions, PluginExt, TargetPlatform,
+  BoxPlugin, ChunkLoadingType, CompilerOptions, DevServerOptions, Devtool, EntryItem, Experiments,
+  ModuleOptions, OutputOptions, PluginExt, TargetPlatform,
 };
 use serde::Deserialize;
 
@@ -77,6 +77,8 @@ pub struct RawOptions {
   pub cache: RawCacheOptions,
   pub experiments: RawExperiments,
   pub node: RawNodeOption,
+  pub records_input_path: Option<String>,
+  pub records_output_path: Option<String>,
 }
 
 impl RawOptionsApply for RawOptions {
@@ -89,6 +91,11 @@ impl RawOptionsApply for RawOptions {
       .into_iter()
       .map(|(name, item)| (name, item.into()))
       .collect::<HashMap<String, EntryItem>>();
+    let mut builtins = self.builtins;
+    // Container plugins render the container entry, so they are applied before library plugins
+    if let Some(module_federation) = builtins.module_federation.take() {
+      module_federation.apply(plugins)?;
+    }
     let output: OutputOptions = self.output.apply(plugins)?;
     let resolve = self.resolve.try_into()?;
     let devtool: Devtool = self.devtool.into();
@@ -102,7 +109,7 @@ impl RawOptionsApply for RawOptions {
     let optimization = self.optimization.apply(plugins)?;
     let node = self.node.into();
     let dev_server: DevServerOptions = self.dev_server.into();
-    let builtins = self.builtins.apply(plugins)?;
+    let builtins = builtins.apply(plugins)?;
 
     plugins.push(
       rspack_plugin_asset::AssetPlugin::new(rspack_plugin_asset::AssetConfig {
@@ -111,26 +118,61 @@ impl RawOptionsApply for RawOptions {
       .boxed(),
     );
     plugins.push(rspack_plugin_json::JsonPlugin {}.boxed());
-    match &target.platform {
-      TargetPlatform::Web => {
-        plugins.push(rspack_plugin_runtime::ArrayPushCallbackChunkFormatPlugin {}.boxed());
-        plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+    if output.module {
+      plugins.push(rspack_plugin_runtime::ModuleChunkFormatPlugin {}.boxed());
+      plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+      if target.platform.is_web() {
         plugins.push(rspack_plugin_runtime::CssModulesPlugin {}.boxed());
-        plugins.push(rspack_plugin_runtime::JsonpChunkLoadingPlugin {}.boxed());
-      }
-      TargetPlatform::Node(_) => {
-        plugins.push(rspack_plugin_runtime::CommonJsChunkFormatPlugin {}.boxed());
-        plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
-        plugins.push(rspack_plugin_runtime::CommonJsChunkLoadingPlugin {}.boxed());
       }
-      _ => {
-        plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+      plugins.push(rspack_plugin_runtime::ModuleChunkLoadingPlugin {}.boxed());
+    } else {
+      match &target.platform {
+        TargetPlatform::Web => {
+          plugins.push(rspack_plugin_runtime::ArrayPushCallbackChunkFormatPlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::CssModulesPlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::JsonpChunkLoadingPlugin {}.boxed());
+        }
+        TargetPlatform::WebWorker => {
+          plugins.push(rspack_plugin_runtime::ArrayPushCallbackChunkFormatPlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::ImportScriptsChunkLoadingPlugin {}.boxed());
+        }
+        platform if platform.is_node() => {
+          plugins.push(rspack_plugin_runtime::CommonJsChunkFormatPlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+          // `require()` for node and `fs.readFile` for async-node, decided by each plugin
+          plugins.push(rspack_plugin_runtime::CommonJsChunkLoadingPlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::ReadFileChunkLoadingPlugin {}.boxed());
+        }
+        _ => {
+          plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+        }
+      };
+      // chunks of workers may be loaded differently from chunks of the target
+      let platform = &target.platform;
+      match output
+        .worker_chunk_loading
+        .or_else(|| platform.worker_chunk_loading())
+      {
+        Some(ChunkLoadingType::ImportScripts) if !matches!(platform, TargetPlatform::WebWorker) => {
+          plugins.push(rspack_plugin_runtime::ImportScriptsChunkLoadingPlugin {}.boxed());
+        }
+        Some(ChunkLoadingType::Jsonp) if !platform.is_web() => {
+          plugins.push(rspack_plugin_runtime::JsonpChunkLoadingPlugin {}.boxed());
+        }
+        Some(ChunkLoadingType::Require | ChunkLoadingType::AsyncNode) if !platform.is_node() => {
+          plugins.push(rspack_plugin_runtime::CommonJsChunkLoadingPlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::ReadFileChunkLoadingPlugin {}.boxed());
+        }
+        _ => {}
       }
-    };
+    }
     if dev_server.hot {
       plugins.push(rspack_plugin_runtime::HotModuleReplacementPlugin {}.boxed());
     }
     plugins.push(rspack_plugin_runtime::BasicRuntimeRequirementPlugin {}.boxed());
+    plugins.push(rspack_plugin_runtime::ChunkPrefetchPreloadPlugin {}.boxed());
     if experiments.lazy_compilation {
       plugins.push(rspack_plugin_runtime::LazyCompilationPlugin {}.boxed());
     }
@@ -138,7 +180,10 @@ impl RawOptionsApply for RawOptions {
       plugins.push(
         rspack_plugin_externals::ExternalPlugin::new(
           self.externals_type,
-          externals.into_iter().map(Into::into).collect(),
+          externals
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<rspack_error::Result<Vec<_>>>()?,
         )
         .boxed(),
       );
@@ -163,7 +208,7 @@ impl RawOptionsApply for RawOptions {
       .boxed(),
     );
 
-    plugins.push(rspack_ids::StableNamedChunkIdsPlugin::new(None, None).boxed());
+    // Chunk ids plugin is applied by `optimization.chunkIds`, see `RawOptimizationOptions`
 
     // Notice the plugin need to be placed after SplitChunksPlugin
     plugins.push(rspack_plugin_remove_empty_chunks::RemoveEmptyChunksPlugin.boxed());
@@ -185,6 +230,8 @@ impl RawOptionsApply for RawOptions {
       node,
       dev_server,
       builtins,
+      records_input_path: self.records_input_path.map(Into::into),
+      records_output_path: self.records_output_path.map(Into::into),
     })
   }
 }
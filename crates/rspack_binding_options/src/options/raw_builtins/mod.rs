@@ -1,6 +1,7 @@
 use napi_derive::napi;
 use rspack_core::{Builtins, Define, Minification, PluginExt, PresetEnv, Provide};
 use rspack_error::internal_error;
+use rspack_plugin_banner::BannerPlugin;
 use rspack_plugin_copy::CopyPlugin;
 use rspack_plugin_css::{plugin::CssConfig, CssPlugin};
 use rspack_plugin_dev_friendly_split_chunks::DevFriendlySplitChunksPlugin;
@@ -8,19 +9,23 @@ use rspack_plugin_html::HtmlPlugin;
 use rspack_plugin_progress::ProgressPlugin;
 use serde::Deserialize;
 
+mod raw_banner;
 mod raw_copy;
 mod raw_css;
 mod raw_decorator;
 mod raw_html;
+mod raw_module_federation;
 mod raw_plugin_import;
 mod raw_postcss;
 mod raw_progress;
 mod raw_react;
 mod raw_relay;
 
+pub use raw_banner::*;
 pub use raw_css::*;
 pub use raw_decorator::*;
 pub use raw_html::*;
+pub use raw_module_federation::*;
 pub use raw_postcss::*;
 pub use raw_progress::*;
 pub use raw_react::*;
@@ -37,6 +42,7 @@ pub struct RawMinification {
   pub passes: u32,
   pub drop_console: bool,
   pub pure_funcs: Vec<String>,
+  pub extract_comments: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -55,6 +61,7 @@ impl From<RawMinification> for Minification {
       passes: value.passes as usize,
       drop_console: value.drop_console,
       pure_funcs: value.pure_funcs,
+      extract_comments: value.extract_comments,
     }
   }
 }
@@ -96,6 +103,8 @@ pub struct RawBuiltins {
   pub copy: Option<RawCopyConfig>,
   pub plugin_import: Option<Vec<RawPluginImportConfig>>,
   pub relay: Option<RawRelayConfig>,
+  pub module_federation: Option<RawModuleFederationConfig>,
+  pub banner: Option<Vec<RawBannerConfig>>,
 }
 
 impl RawOptionsApply for RawBuiltins {
@@ -131,6 +140,11 @@ impl RawOptionsApply for RawBuiltins {
     if let Some(copy) = self.copy {
       plugins.push(CopyPlugin::new(copy.patterns.into_iter().map(Into::into).collect()).boxed());
     }
+    if let Some(banners) = self.banner {
+      for banner in banners {
+        plugins.push(BannerPlugin::new(banner.try_into()?).boxed());
+      }
+    }
 
     Ok(Builtins {
       minify_options: self.minify_options.map(Into::into),
@@ -0,0 +1,35 @@
+use napi_derive::napi;
+use rspack_error::Result;
+use rspack_plugin_banner::BannerConfig;
+use serde::Deserialize;
+
+use crate::RawRuleSetCondition;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawBannerConfig {
+  pub banner: String,
+  pub entry_only: Option<bool>,
+  pub footer: Option<bool>,
+  pub raw: Option<bool>,
+  pub test: Option<RawRuleSetCondition>,
+  pub include: Option<RawRuleSetCondition>,
+  pub exclude: Option<RawRuleSetCondition>,
+}
+
+impl TryFrom<RawBannerConfig> for BannerConfig {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawBannerConfig) -> Result<Self> {
+    Ok(Self {
+      banner: value.banner,
+      entry_only: value.entry_only,
+      footer: value.footer,
+      raw: value.raw,
+      test: value.test.map(TryFrom::try_from).transpose()?,
+      include: value.include.map(TryFrom::try_from).transpose()?,
+      exclude: value.exclude.map(TryFrom::try_from).transpose()?,
+    })
+  }
+}
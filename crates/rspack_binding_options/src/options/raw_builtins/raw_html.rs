@@ -43,6 +43,8 @@ pub struct RawHtmlPluginConfig {
   pub title: Option<String>,
   pub favicon: Option<String>,
   pub meta: Option<HashMap<String, HashMap<String, String>>>,
+  /// emit `<link rel="preload">` for the async chunks preloaded by the initial chunks
+  pub preload: Option<bool>,
 }
 
 impl From<RawHtmlPluginConfig> for HtmlPluginConfig {
@@ -77,6 +79,7 @@ impl From<RawHtmlPluginConfig> for HtmlPluginConfig {
       title: value.title,
       favicon: value.favicon,
       meta: value.meta,
+      preload: value.preload.unwrap_or_default(),
     }
   }
 }
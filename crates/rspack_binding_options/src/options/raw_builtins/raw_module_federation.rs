@@ -0,0 +1,136 @@
+use napi_derive::napi;
+use rspack_core::{BoxPlugin, PluginExt};
+use rspack_plugin_mf::{
+  ContainerPlugin, ContainerPluginOptions, ContainerReferencePlugin,
+  ContainerReferencePluginOptions, ExposeOptions, RemoteOptions, SharePlugin, ShareRuntimePlugin,
+  SharedOptions,
+};
+use serde::Deserialize;
+
+use crate::RawOptionsApply;
+
+const DEFAULT_SHARE_SCOPE: &str = "default";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawExposeConfig {
+  /// The exposed name, e.g. `./Button`
+  pub name: String,
+  pub import: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawRemoteConfig {
+  /// The request prefix of the remote, e.g. `app` of `app/Button`
+  pub key: String,
+  pub external: String,
+  pub share_scope: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawSharedConfig {
+  pub key: String,
+  pub import: Option<String>,
+  pub share_key: Option<String>,
+  pub share_scope: Option<String>,
+  pub required_version: Option<String>,
+  pub version: Option<String>,
+  pub singleton: Option<bool>,
+  pub strict_version: Option<bool>,
+  pub eager: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawModuleFederationConfig {
+  /// Name of the container, a container is built only if `name` and `exposes` are both set
+  pub name: Option<String>,
+  pub exposes: Option<Vec<RawExposeConfig>>,
+  pub library_type: Option<String>,
+  pub remotes: Option<Vec<RawRemoteConfig>>,
+  pub remote_type: Option<String>,
+  pub shared: Option<Vec<RawSharedConfig>>,
+  pub share_scope: Option<String>,
+}
+
+impl RawOptionsApply for RawModuleFederationConfig {
+  type Options = ();
+
+  fn apply(self, plugins: &mut Vec<BoxPlugin>) -> Result<Self::Options, rspack_error::Error> {
+    let share_scope = self
+      .share_scope
+      .unwrap_or_else(|| DEFAULT_SHARE_SCOPE.to_string());
+    if let (Some(name), Some(exposes)) = (self.name, self.exposes) {
+      plugins.push(
+        ContainerPlugin::new(ContainerPluginOptions {
+          name,
+          share_scope: share_scope.clone(),
+          library_type: self.library_type.unwrap_or_else(|| "var".to_string()),
+          exposes: exposes
+            .into_iter()
+            .map(|expose| {
+              (
+                expose.name,
+                ExposeOptions {
+                  import: expose.import,
+                },
+              )
+            })
+            .collect(),
+        })
+        .boxed(),
+      );
+    }
+    if let Some(remotes) = self.remotes.filter(|remotes| !remotes.is_empty()) {
+      plugins.push(
+        ContainerReferencePlugin::new(ContainerReferencePluginOptions {
+          remote_type: self.remote_type.unwrap_or_else(|| "script".to_string()),
+          remotes: remotes
+            .into_iter()
+            .map(|remote| {
+              (
+                remote.key,
+                RemoteOptions {
+                  external: remote.external,
+                  share_scope: remote.share_scope.unwrap_or_else(|| share_scope.clone()),
+                },
+              )
+            })
+            .collect(),
+        })
+        .boxed(),
+      );
+    }
+    if let Some(shared) = self.shared.filter(|shared| !shared.is_empty()) {
+      plugins.push(
+        SharePlugin::new(
+          shared
+            .into_iter()
+            .map(|shared| {
+              let options = SharedOptions {
+                import: shared.import.unwrap_or_else(|| shared.key.clone()),
+                share_key: shared.share_key.unwrap_or_else(|| shared.key.clone()),
+                share_scope: shared.share_scope.unwrap_or_else(|| share_scope.clone()),
+                required_version: shared.required_version,
+                version: shared.version,
+                singleton: shared.singleton.unwrap_or_default(),
+                strict_version: shared.strict_version.unwrap_or_default(),
+                eager: shared.eager.unwrap_or_default(),
+              };
+              (shared.key, options)
+            })
+            .collect(),
+        )
+        .boxed(),
+      );
+    }
+    plugins.push(ShareRuntimePlugin::default().boxed());
+    Ok(())
+  }
+}
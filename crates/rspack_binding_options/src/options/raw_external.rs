@@ -1,29 +1,93 @@
 use std::collections::HashMap;
+use std::fmt::Debug;
 
+use napi::bindgen_prelude::*;
 use napi_derive::napi;
-use rspack_core::{ExternalItem, ExternalItemObject, ExternalItemValue};
+use rspack_core::{
+  ExternalItem, ExternalItemFnCtx, ExternalItemFnResult, ExternalItemObject, ExternalItemValue,
+};
+use rspack_error::internal_error;
 use rspack_regex::RspackRegex;
 use serde::Deserialize;
+#[cfg(feature = "node-api")]
+use {
+  rspack_napi_shared::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  rspack_napi_shared::{NapiResultExt, NAPI_ENV},
+  std::sync::Arc,
+};
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawExternalItem {
-  #[napi(ts_type = r#""string" | "regexp" | "object""#)]
+  #[napi(ts_type = r#""string" | "regexp" | "object" | "function""#)]
   pub r#type: String,
   pub string_payload: Option<String>,
   pub regexp_payload: Option<String>,
   pub object_payload: Option<HashMap<String, RawExternalItemValue>>,
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = r#"(value: RawExternalItemFnCtx) => RawExternalItemFnResult"#)]
+  pub fn_payload: Option<JsFunction>,
+}
+
+impl Debug for RawExternalItem {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("RawExternalItem")
+      .field("r#type", &self.r#type)
+      .field("string_payload", &self.string_payload)
+      .field("regexp_payload", &self.regexp_payload)
+      .field("object_payload", &self.object_payload)
+      .field("fn_payload", &"...")
+      .finish()
+  }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawExternalItemFnCtx {
+  pub request: String,
+  pub context: String,
+  pub dependency_type: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawExternalItemFnResult {
+  pub external_type: Option<String>,
+  pub result: Option<RawExternalItemValue>,
+}
+
+impl From<ExternalItemFnCtx> for RawExternalItemFnCtx {
+  fn from(value: ExternalItemFnCtx) -> Self {
+    Self {
+      request: value.request,
+      context: value.context,
+      dependency_type: value.dependency_type,
+    }
+  }
+}
+
+impl From<RawExternalItemFnResult> for ExternalItemFnResult {
+  fn from(value: RawExternalItemFnResult) -> Self {
+    Self {
+      external_type: value.external_type,
+      result: value.result.map(Into::into),
+    }
+  }
 }
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawExternalItemValue {
-  #[napi(ts_type = r#""string" | "bool""#)]
+  #[napi(ts_type = r#""string" | "bool" | "array" | "object""#)]
   pub r#type: String,
   pub string_payload: Option<String>,
   pub bool_payload: Option<bool>,
+  pub array_payload: Option<Vec<String>>,
+  pub object_payload: Option<HashMap<String, Vec<String>>>,
 }
 
 impl From<RawExternalItemValue> for ExternalItemValue {
@@ -39,37 +103,82 @@ impl From<RawExternalItemValue> for ExternalItemValue {
           .bool_payload
           .expect("should have a bool_payload when RawExternalItemValue.type is \"bool\""),
       ),
+      "array" => Self::Array(
+        value
+          .array_payload
+          .expect("should have a array_payload when RawExternalItemValue.type is \"array\""),
+      ),
+      "object" => Self::Object(
+        value
+          .object_payload
+          .expect("should have a object_payload when RawExternalItemValue.type is \"object\"")
+          .into_iter()
+          .collect(),
+      ),
       _ => unreachable!(),
     }
   }
 }
 
-impl From<RawExternalItem> for ExternalItem {
-  fn from(value: RawExternalItem) -> Self {
+impl TryFrom<RawExternalItem> for ExternalItem {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawExternalItem) -> rspack_error::Result<Self> {
     match value.r#type.as_str() {
-      "string" => Self::from(
-        value
-          .string_payload
-          .expect("should have a string_payload when RawExternalItem.type is \"string\""),
-      ),
+      "string" => Ok(Self::from(value.string_payload.ok_or_else(|| {
+        internal_error!("should have a string_payload when RawExternalItem.type is \"string\"")
+      })?)),
       "regexp" => {
-        let payload = value
-          .regexp_payload
-          .expect("should have a regexp_payload when RawExternalItem.type is \"regexp\"");
+        let payload = value.regexp_payload.ok_or_else(|| {
+          internal_error!("should have a regexp_payload when RawExternalItem.type is \"regexp\"")
+        })?;
         let reg =
           RspackRegex::new(&payload).expect("regex_payload is not a legal regex in rust side");
-        Self::from(reg)
+        Ok(Self::from(reg))
       }
       "object" => {
         let payload: ExternalItemObject = value
           .object_payload
-          .expect("should have a object_payload when RawExternalItem.type is \"object\"")
+          .ok_or_else(|| {
+            internal_error!("should have a object_payload when RawExternalItem.type is \"object\"")
+          })?
           .into_iter()
           .map(|(k, v)| (k, v.into()))
           .collect();
-        payload.into()
+        Ok(payload.into())
       }
-      _ => unreachable!(),
+      #[cfg(feature = "node-api")]
+      "function" => {
+        let fn_payload = value.fn_payload.ok_or_else(|| {
+          internal_error!("should have a fn_payload when RawExternalItem.type is \"function\"")
+        })?;
+        let fn_payload: ThreadsafeFunction<RawExternalItemFnCtx, RawExternalItemFnResult> =
+          NAPI_ENV.with(|env| -> anyhow::Result<_> {
+            let env = env
+              .borrow()
+              .expect("Failed to get env, did you forget to call it from node?");
+            let fn_payload =
+              rspack_binding_macros::js_fn_into_theadsafe_fn!(fn_payload, &Env::from(env));
+            Ok(fn_payload)
+          })?;
+        let fn_payload = Arc::new(fn_payload);
+
+        Ok(Self::Fn(Box::new(move |ctx: ExternalItemFnCtx| {
+          let fn_payload = fn_payload.clone();
+          Box::pin(async move {
+            fn_payload
+              .call(ctx.into(), ThreadsafeFunctionCallMode::NonBlocking)
+              .into_rspack_result()?
+              .await
+              .map_err(|err| internal_error!("Failed to call external function: {err}"))?
+              .map(Into::into)
+          })
+        })))
+      }
+      _ => Err(internal_error!(
+        "Failed to resolve the external item type {}. Expected type is `string`, `regexp`, `object` or `function`.",
+        value.r#type
+      )),
     }
   }
 }
@@ -1,7 +1,12 @@
 use napi_derive::napi;
 use rspack_core::{Optimization, PluginExt, SideEffectOption};
 use rspack_error::internal_error;
-use rspack_ids::{DeterministicModuleIdsPlugin, NamedModuleIdsPlugin};
+use rspack_ids::{
+  DeterministicChunkIdsPlugin, DeterministicModuleIdsPlugin, NamedModuleIdsPlugin,
+  NaturalChunkIdsPlugin, OccurrenceChunkIdsPlugin, StableNamedChunkIdsPlugin,
+};
+use rspack_plugin_javascript::ModuleConcatenationPlugin;
+use rspack_plugin_real_content_hash::RealContentHashPlugin;
 use rspack_plugin_split_chunks::SplitChunksPlugin;
 use serde::Deserialize;
 
@@ -12,9 +17,12 @@ use crate::{RawOptionsApply, RawSplitChunksOptions};
 #[napi(object)]
 pub struct RawOptimizationOptions {
   pub split_chunks: Option<RawSplitChunksOptions>,
+  pub chunk_ids: String,
   pub module_ids: String,
   pub remove_available_modules: bool,
   pub side_effects: String,
+  pub concatenate_modules: bool,
+  pub real_content_hash: bool,
 }
 
 impl RawOptionsApply for RawOptimizationOptions {
@@ -28,6 +36,18 @@ impl RawOptionsApply for RawOptimizationOptions {
       let split_chunks_plugin = SplitChunksPlugin::new(options.into()).boxed();
       plugins.push(split_chunks_plugin);
     }
+    let chunk_ids_plugin = match self.chunk_ids.as_ref() {
+      "named" => StableNamedChunkIdsPlugin::new(None, None).boxed(),
+      "deterministic" => DeterministicChunkIdsPlugin::default().boxed(),
+      "natural" => NaturalChunkIdsPlugin::default().boxed(),
+      "size" => OccurrenceChunkIdsPlugin::new(true).boxed(),
+      _ => {
+        return Err(internal_error!(
+          "'chunk_ids' should be 'named', 'deterministic', 'natural' or 'size'."
+        ))
+      }
+    };
+    plugins.push(chunk_ids_plugin);
     let module_ids_plugin = match self.module_ids.as_ref() {
       "named" => NamedModuleIdsPlugin::default().boxed(),
       "deterministic" => DeterministicModuleIdsPlugin::default().boxed(),
@@ -38,6 +58,12 @@ impl RawOptionsApply for RawOptimizationOptions {
       }
     };
     plugins.push(module_ids_plugin);
+    if self.concatenate_modules {
+      plugins.push(ModuleConcatenationPlugin::default().boxed());
+    }
+    if self.real_content_hash {
+      plugins.push(RealContentHashPlugin.boxed());
+    }
     Ok(Optimization {
       remove_available_modules: self.remove_available_modules,
       side_effects: SideEffectOption::from(self.side_effects.as_str()),
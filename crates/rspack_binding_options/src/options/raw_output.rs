@@ -93,6 +93,7 @@ pub struct RawOutputOptions {
   pub import_function_name: String,
   pub iife: bool,
   pub module: bool,
+  pub worker_chunk_loading: String,
 }
 
 impl RawOptionsApply for RawOutputOptions {
@@ -126,6 +127,10 @@ impl RawOptionsApply for RawOutputOptions {
       import_function_name: self.import_function_name,
       iife: self.iife,
       module: self.module,
+      worker_chunk_loading: match self.worker_chunk_loading.as_str() {
+        "false" => None,
+        i => Some(i.into()),
+      },
     })
   }
 }
@@ -255,6 +260,12 @@ impl RawOutputOptions {
               rspack_plugin_library::AmdLibraryPlugin::new("amd-require".eq(library)).boxed(),
             );
           }
+          "system" => {
+            plugins.push(rspack_plugin_library::SystemLibraryPlugin::default().boxed());
+          }
+          "module" => {
+            plugins.push(rspack_plugin_library::ModuleLibraryPlugin::default().boxed());
+          }
           _ => {}
         }
       }
@@ -8,6 +8,8 @@ use serde::Deserialize;
 pub struct RawStatsOptions {
   pub colors: bool,
   pub reasons: bool,
+  pub provided_exports: bool,
+  pub used_exports: bool,
 }
 
 impl From<RawStatsOptions> for StatsOptions {
@@ -15,6 +17,8 @@ impl From<RawStatsOptions> for StatsOptions {
     Self {
       colors: value.colors,
       reasons: value.reasons,
+      provided_exports: value.provided_exports,
+      used_exports: value.used_exports,
     }
   }
 }
@@ -82,6 +82,10 @@ impl Program {
   pub fn get_inner_program(&self) -> &SwcProgram {
     &self.program
   }
+
+  pub fn comments(&self) -> Option<&SwcComments> {
+    self.comments.as_ref()
+  }
 }
 
 /// Swc transform context
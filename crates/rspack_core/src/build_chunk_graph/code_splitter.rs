@@ -1,10 +1,13 @@
 use anyhow::anyhow;
-use rspack_error::Result;
+use rspack_error::{Diagnostic, Result};
 use rspack_identifier::IdentifierSet;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use super::remove_parent_modules::RemoveParentModulesContext;
-use crate::{ChunkGroup, ChunkGroupKind, ChunkGroupUkey, ChunkUkey, Compilation, ModuleIdentifier};
+use crate::{
+  ChunkGroup, ChunkGroupKind, ChunkGroupOptions, ChunkGroupUkey, ChunkUkey, Compilation,
+  ContextMode, DependencyType, ModuleIdentifier,
+};
 
 pub(super) struct CodeSplitter<'me> {
   pub(super) compilation: &'me mut Compilation,
@@ -72,7 +75,7 @@ impl<'me> CodeSplitter<'me> {
       let mut entrypoint = ChunkGroup::new(
         ChunkGroupKind::Entrypoint,
         HashSet::from_iter([name.to_string()]),
-        Some(name.to_string()),
+        ChunkGroupOptions::with_name(name),
       );
       if options.runtime.is_none() {
         entrypoint.set_runtime_chunk(chunk.ukey);
@@ -192,6 +195,11 @@ impl<'me> CodeSplitter<'me> {
     }
     tracing::trace!("--- process_queue end ---");
 
+    // Modules only referenced by weak dependencies are not in any chunk, but still need to be known by the chunk graph
+    for module_identifier in self.compilation.module_graph.modules().keys() {
+      self.compilation.chunk_graph.add_module(*module_identifier);
+    }
+
     for chunk_group in self.compilation.chunk_group_by_ukey.values() {
       for chunk_ukey in chunk_group.chunks.iter() {
         self
@@ -341,82 +349,300 @@ impl<'me> CodeSplitter<'me> {
       });
     }
 
-    for module_identifier in mgm
-      .dynamic_depended_modules(&self.compilation.module_graph)
+    // All modules of a lazy-once context are put into the same chunk group
+    let mut lazy_once_chunk_group: Option<ChunkGroupUkey> = None;
+    let dynamic_dependencies = mgm
+      .dynamic_dependencies(&self.compilation.module_graph)
       .into_iter()
-      .rev()
+      .map(|(dependency, module_identifier)| {
+        let is_lazy_once = dependency
+          .options()
+          .map_or(false, |options| options.mode == ContextMode::LazyOnce);
+        (
+          *module_identifier,
+          *dependency.dependency_type(),
+          dependency.group_options().cloned().unwrap_or_default(),
+          is_lazy_once,
+        )
+      })
+      .collect::<Vec<_>>();
+    for (module_identifier, dependency_type, group_options, is_lazy_once) in
+      dynamic_dependencies.into_iter().rev()
     {
-      let is_already_split_module = self.split_point_modules.contains(module_identifier);
+      if dependency_type == DependencyType::NewWorker {
+        self.process_async_entrypoint(item, module_identifier, group_options);
+        continue;
+      }
+
+      let initial_chunk_group = group_options
+        .name
+        .as_ref()
+        .and_then(|name| self.compilation.named_chunk_groups.get(name))
+        .filter(|ukey| {
+          self
+            .compilation
+            .chunk_group_by_ukey
+            .get(ukey)
+            .map_or(false, |chunk_group| chunk_group.is_initial())
+        });
+      if initial_chunk_group.is_some() {
+        self.compilation.push_diagnostic(Diagnostic::error(
+          "AsyncDependencyToInitialChunkError".to_string(),
+          format!(
+            "It's not allowed to load an initial chunk on demand. The chunk name \"{}\" is already used by an entrypoint.",
+            group_options.name.as_deref().unwrap_or_default()
+          ),
+          0,
+          0,
+        ));
+        // Same as webpack, the module is put into the current chunk group instead
+        self
+          .compilation
+          .chunk_graph
+          .connect_block_and_chunk_group(module_identifier, item.chunk_group);
+        self.queue_delayed.push(QueueItem {
+          action: QueueAction::AddAndEnter,
+          chunk: item.chunk,
+          chunk_group: item.chunk_group,
+          module_identifier,
+        });
+        continue;
+      }
+
+      let is_already_split_module = self.split_point_modules.contains(&module_identifier);
 
       if is_already_split_module {
         let chunk = self
           .compilation
           .chunk_graph
           .split_point_module_identifier_to_chunk_ukey
-          .get(module_identifier)
+          .get(&module_identifier)
           .expect("split point module not found");
         self
           .remove_parent_modules_context
           .add_chunk_relation(item.chunk, *chunk);
         continue;
       } else {
-        self.split_point_modules.insert(*module_identifier);
+        self.split_point_modules.insert(module_identifier);
       }
 
-      let chunk = Compilation::add_chunk(&mut self.compilation.chunk_by_ukey);
-      chunk
+      let existing_chunk_group = group_options
+        .name
+        .as_ref()
+        .and_then(|name| self.compilation.named_chunk_groups.get(name))
+        .copied()
+        .or(lazy_once_chunk_group.filter(|_| is_lazy_once));
+
+      let chunk_group_ukey = match existing_chunk_group {
+        Some(ukey) => ukey,
+        None => self.add_async_chunk_group(item.chunk_group, group_options),
+      };
+      if is_lazy_once {
+        lazy_once_chunk_group = Some(chunk_group_ukey);
+      }
+
+      let item_chunk_group = self
+        .compilation
+        .chunk_group_by_ukey
+        .get_mut(&item.chunk_group)
+        .expect("chunk group not found");
+      item_chunk_group.children.insert(chunk_group_ukey);
+      let chunk_group = self
+        .compilation
+        .chunk_group_by_ukey
+        .get_mut(&chunk_group_ukey)
+        .expect("chunk group not found");
+      chunk_group.parents.insert(item.chunk_group);
+      let chunk_ukey = chunk_group.chunks[0];
+
+      self
+        .compilation
+        .chunk_by_ukey
+        .get_mut(&chunk_ukey)
+        .expect("chunk not found")
         .chunk_reasons
         .push(format!("DynamicImport({module_identifier})"));
       self
         .remove_parent_modules_context
-        .add_chunk_relation(item.chunk, chunk.ukey);
-      self.compilation.chunk_graph.add_chunk(chunk.ukey);
+        .add_chunk_relation(item.chunk, chunk_ukey);
 
       self
         .compilation
         .chunk_graph
         .split_point_module_identifier_to_chunk_ukey
-        .insert(*module_identifier, chunk.ukey);
-
-      let item_chunk_group = self
-        .compilation
-        .chunk_group_by_ukey
-        .get_mut(&item.chunk_group)
-        .expect("chunk group not found");
-      let mut chunk_group = ChunkGroup::new(
-        ChunkGroupKind::Normal,
-        item_chunk_group.runtime.clone(),
-        None,
-      );
+        .insert(module_identifier, chunk_ukey);
 
       self
         .compilation
         .chunk_graph
-        .connect_block_and_chunk_group(*module_identifier, chunk_group.ukey);
-
-      item_chunk_group.children.insert(chunk_group.ukey);
-      chunk_group.parents.insert(item_chunk_group.ukey);
+        .connect_block_and_chunk_group(module_identifier, chunk_group_ukey);
 
-      chunk_group.connect_chunk(chunk);
-
-      let chunk_group = {
-        let ukey = chunk_group.ukey;
-        self.compilation.chunk_group_by_ukey.add(chunk_group);
+      self.queue_delayed.push(QueueItem {
+        action: QueueAction::AddAndEnter,
+        chunk: chunk_ukey,
+        chunk_group: chunk_group_ukey,
+        module_identifier,
+      });
+    }
+  }
 
+  /// Workers are started from a new entrypoint with its own runtime, which isn't a child of the
+  /// chunk group creating it. Chunks of workers are loaded by `output.workerChunkLoading` because
+  /// there is no `document` in workers, except for `output.module` which uses `import()`.
+  fn process_async_entrypoint(
+    &mut self,
+    item: &QueueItem,
+    module_identifier: ModuleIdentifier,
+    mut options: ChunkGroupOptions,
+  ) {
+    let output = &self.compilation.options.output;
+    if !output.module {
+      options.chunk_loading = output.worker_chunk_loading.or_else(|| {
         self
           .compilation
-          .chunk_group_by_ukey
-          .get(&ukey)
-          .unwrap_or_else(|| panic!("chunk group not found: {ukey:?}"))
-      };
-
+          .options
+          .target
+          .platform
+          .worker_chunk_loading()
+      });
+    }
+    let entrypoint_ukey = if self.split_point_modules.contains(&module_identifier) {
+      self
+        .compilation
+        .chunk_graph
+        .get_block_chunk_group(&module_identifier, &self.compilation.chunk_group_by_ukey)
+        .ukey
+    } else {
+      self.split_point_modules.insert(module_identifier);
+      let entrypoint_ukey = self.add_async_entrypoint(module_identifier, options);
+      let chunk_ukey = self
+        .compilation
+        .chunk_group_by_ukey
+        .get(&entrypoint_ukey)
+        .expect("chunk group not found")
+        .get_entry_point_chunk();
       self.queue_delayed.push(QueueItem {
         action: QueueAction::AddAndEnter,
-        chunk: chunk.ukey,
-        chunk_group: chunk_group.ukey,
-        module_identifier: *module_identifier,
+        chunk: chunk_ukey,
+        chunk_group: entrypoint_ukey,
+        module_identifier,
       });
+      entrypoint_ukey
+    };
+
+    self
+      .compilation
+      .chunk_group_by_ukey
+      .get_mut(&item.chunk_group)
+      .expect("chunk group not found")
+      .async_entrypoints
+      .insert(entrypoint_ukey);
+  }
+
+  fn add_async_entrypoint(
+    &mut self,
+    module_identifier: ModuleIdentifier,
+    options: ChunkGroupOptions,
+  ) -> ChunkGroupUkey {
+    let runtime = match &options.name {
+      Some(name) => name.clone(),
+      None => self
+        .compilation
+        .module_graph
+        .module_by_identifier(&module_identifier)
+        .expect("module not found")
+        .readable_identifier(&self.compilation.options.context)
+        .to_string(),
+    };
+
+    let chunk = match &options.name {
+      Some(name) => Compilation::add_named_chunk(
+        name.clone(),
+        &mut self.compilation.chunk_by_ukey,
+        &mut self.compilation.named_chunks,
+      ),
+      None => Compilation::add_chunk(&mut self.compilation.chunk_by_ukey),
+    };
+    chunk
+      .chunk_reasons
+      .push(format!("AsyncEntrypoint({module_identifier})"));
+    self.compilation.chunk_graph.add_chunk(chunk.ukey);
+    self
+      .remove_parent_modules_context
+      .add_root_chunk(chunk.ukey);
+
+    let mut entrypoint = ChunkGroup::new(
+      ChunkGroupKind::Entrypoint,
+      HashSet::from_iter([runtime]),
+      options,
+    );
+    entrypoint.set_runtime_chunk(chunk.ukey);
+    entrypoint.set_entry_point_chunk(chunk.ukey);
+    entrypoint.connect_chunk(chunk);
+    let chunk_ukey = chunk.ukey;
+
+    self.compilation.chunk_graph.add_module(module_identifier);
+    self.compilation.chunk_graph.connect_chunk_and_entry_module(
+      chunk_ukey,
+      module_identifier,
+      entrypoint.ukey,
+    );
+    self
+      .compilation
+      .chunk_graph
+      .split_point_module_identifier_to_chunk_ukey
+      .insert(module_identifier, chunk_ukey);
+    self
+      .compilation
+      .chunk_graph
+      .connect_block_and_chunk_group(module_identifier, entrypoint.ukey);
+
+    if let Some(name) = entrypoint.name() {
+      self
+        .compilation
+        .named_chunk_groups
+        .insert(name.to_string(), entrypoint.ukey);
+    }
+    let ukey = entrypoint.ukey;
+    self.compilation.async_entrypoints.push(ukey);
+    self.compilation.chunk_group_by_ukey.add(entrypoint);
+    ukey
+  }
+
+  fn add_async_chunk_group(
+    &mut self,
+    parent: ChunkGroupUkey,
+    options: ChunkGroupOptions,
+  ) -> ChunkGroupUkey {
+    let chunk = match &options.name {
+      Some(name) => Compilation::add_named_chunk(
+        name.clone(),
+        &mut self.compilation.chunk_by_ukey,
+        &mut self.compilation.named_chunks,
+      ),
+      None => Compilation::add_chunk(&mut self.compilation.chunk_by_ukey),
+    };
+    self.compilation.chunk_graph.add_chunk(chunk.ukey);
+
+    let runtime = self
+      .compilation
+      .chunk_group_by_ukey
+      .get(&parent)
+      .expect("chunk group not found")
+      .runtime
+      .clone();
+    let mut chunk_group = ChunkGroup::new(ChunkGroupKind::Normal, runtime, options);
+    chunk_group.connect_chunk(chunk);
+
+    if let Some(name) = chunk_group.name() {
+      self
+        .compilation
+        .named_chunk_groups
+        .insert(name.to_string(), chunk_group.ukey);
     }
+    let ukey = chunk_group.ukey;
+    self.compilation.chunk_group_by_ukey.add(chunk_group);
+    ukey
   }
 }
 
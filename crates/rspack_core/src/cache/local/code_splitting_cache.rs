@@ -12,6 +12,7 @@ pub struct CodeSplittingCache {
   chunk_graph: ChunkGraph,
   chunk_group_by_ukey: Database<ChunkGroup>,
   entrypoints: HashMap<String, ChunkGroupUkey>,
+  async_entrypoints: Vec<ChunkGroupUkey>,
   named_chunk_groups: HashMap<String, ChunkGroupUkey>,
   named_chunks: HashMap<String, ChunkUkey>,
 }
@@ -38,6 +39,7 @@ where
       s.spawn(|_| compilation.chunk_graph = cache.chunk_graph.clone());
       s.spawn(|_| compilation.chunk_group_by_ukey = cache.chunk_group_by_ukey.clone());
       s.spawn(|_| compilation.entrypoints = cache.entrypoints.clone());
+      s.spawn(|_| compilation.async_entrypoints = cache.async_entrypoints.clone());
       s.spawn(|_| compilation.named_chunk_groups = cache.named_chunk_groups.clone());
       s.spawn(|_| compilation.named_chunks = cache.named_chunks.clone());
     });
@@ -52,6 +54,7 @@ where
     s.spawn(|_| cache.chunk_graph = compilation.chunk_graph.clone());
     s.spawn(|_| cache.chunk_group_by_ukey = compilation.chunk_group_by_ukey.clone());
     s.spawn(|_| cache.entrypoints = compilation.entrypoints.clone());
+    s.spawn(|_| cache.async_entrypoints = compilation.async_entrypoints.clone());
     s.spawn(|_| cache.named_chunk_groups = compilation.named_chunk_groups.clone());
     s.spawn(|_| cache.named_chunks = compilation.named_chunks.clone());
   });
@@ -36,15 +36,18 @@ impl Cache {
       is_idle: true.into(),
       snapshot_manager: snapshot_manager.clone(),
       resolve_module_occasion: ResolveModuleOccasion::new(
-        new_storage(&options.cache),
+        new_storage(&options, "resolve_module"),
         snapshot_manager.clone(),
       ),
       build_module_occasion: BuildModuleOccasion::new(
-        new_storage(&options.cache),
+        new_storage(&options, "build_module"),
         snapshot_manager,
       ),
-      code_generate_occasion: CodeGenerateOccasion::new(new_storage(&options.cache)),
-      create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(&options.cache)),
+      code_generate_occasion: CodeGenerateOccasion::new(new_storage(&options, "code_generate")),
+      create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(
+        &options,
+        "create_chunk_assets",
+      )),
     }
   }
 
@@ -59,6 +62,10 @@ impl Cache {
       .is_ok()
     {
       self.snapshot_manager.clear();
+      self.resolve_module_occasion.begin_idle();
+      self.build_module_occasion.begin_idle();
+      self.code_generate_occasion.begin_idle();
+      self.create_chunk_assets_occasion.begin_idle();
     }
   }
 
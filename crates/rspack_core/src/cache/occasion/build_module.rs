@@ -1,15 +1,89 @@
-use std::{path::Path, sync::Arc};
+use std::{path::PathBuf, sync::Arc};
 
 use futures::Future;
 use rspack_error::{Result, TWithDiagnosticArray};
+use rspack_loader_runner::Content;
+use rspack_sources::SourceMap;
+use rustc_hash::FxHashSet as HashSet;
+use serde::{Deserialize, Serialize};
 
 use crate::{
   cache::snapshot::{Snapshot, SnapshotManager},
-  cache::storage,
-  BoxModule, BuildResult,
+  cache::storage::{self, PersistentItem},
+  BoxModule, BuildResult, LoaderResult,
 };
 
-type Storage = dyn storage::Storage<(Snapshot, TWithDiagnosticArray<BuildResult>)>;
+/// Results of loaders are cached, a module restored from it skips loaders
+/// but is parsed again, so its ast and dependencies are always up to date.
+type Storage = dyn storage::Storage<(Snapshot, LoaderResult)>;
+
+#[derive(Serialize, Deserialize)]
+struct PersistentLoaderResult {
+  snapshot: Snapshot,
+  cacheable: bool,
+  file_dependencies: HashSet<PathBuf>,
+  context_dependencies: HashSet<PathBuf>,
+  missing_dependencies: HashSet<PathBuf>,
+  build_dependencies: HashSet<PathBuf>,
+  content: PersistentContent,
+  source_map: Option<String>,
+  additional_data: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+enum PersistentContent {
+  String(String),
+  Buffer(Vec<u8>),
+}
+
+impl PersistentItem for (Snapshot, LoaderResult) {
+  fn encode(&self) -> Option<serde_json::Value> {
+    let (snapshot, loader_result) = self;
+    let source_map = match &loader_result.source_map {
+      Some(source_map) => Some(source_map.to_json().ok()?),
+      None => None,
+    };
+    serde_json::to_value(PersistentLoaderResult {
+      snapshot: snapshot.clone(),
+      cacheable: loader_result.cacheable,
+      file_dependencies: loader_result.file_dependencies.clone(),
+      context_dependencies: loader_result.context_dependencies.clone(),
+      missing_dependencies: loader_result.missing_dependencies.clone(),
+      build_dependencies: loader_result.build_dependencies.clone(),
+      content: match &loader_result.content {
+        Content::String(s) => PersistentContent::String(s.clone()),
+        Content::Buffer(b) => PersistentContent::Buffer(b.clone()),
+      },
+      source_map,
+      additional_data: loader_result.additional_data.clone(),
+    })
+    .ok()
+  }
+
+  fn decode(value: serde_json::Value) -> Option<Self> {
+    let result: PersistentLoaderResult = serde_json::from_value(value).ok()?;
+    let source_map = match result.source_map {
+      Some(source_map) => Some(SourceMap::from_json(&source_map).ok()?),
+      None => None,
+    };
+    Some((
+      result.snapshot,
+      LoaderResult {
+        cacheable: result.cacheable,
+        file_dependencies: result.file_dependencies,
+        context_dependencies: result.context_dependencies,
+        missing_dependencies: result.missing_dependencies,
+        build_dependencies: result.build_dependencies,
+        content: match result.content {
+          PersistentContent::String(s) => Content::String(s),
+          PersistentContent::Buffer(b) => Content::Buffer(b),
+        },
+        source_map,
+        additional_data: result.additional_data,
+      },
+    ))
+  }
+}
 
 #[derive(Debug)]
 pub struct BuildModuleOccasion {
@@ -25,14 +99,20 @@ impl BuildModuleOccasion {
     }
   }
 
-  pub async fn use_cache<'a, G, F>(
+  pub fn begin_idle(&self) {
+    if let Some(storage) = &self.storage {
+      storage.begin_idle();
+    }
+  }
+
+  pub async fn use_cache<G, F>(
     &self,
-    module: &'a mut BoxModule,
+    mut module: BoxModule,
     generator: G,
-  ) -> Result<TWithDiagnosticArray<BuildResult>>
+  ) -> Result<(BoxModule, TWithDiagnosticArray<BuildResult>)>
   where
-    G: Fn(&'a mut BoxModule) -> F,
-    F: Future<Output = Result<TWithDiagnosticArray<BuildResult>>>,
+    G: FnOnce(BoxModule) -> F,
+    F: Future<Output = Result<(BoxModule, TWithDiagnosticArray<BuildResult>)>>,
   {
     let storage = match &self.storage {
       Some(s) => s,
@@ -41,66 +121,45 @@ impl BuildModuleOccasion {
     };
 
     let mut need_cache = false;
-    let id = module.identifier().to_owned();
-    if module.as_normal_module().is_some() {
+    let id = module.identifier();
+    if let Some(normal_module) = module.as_normal_module_mut() {
       // normal module
       // TODO cache all module type
-      if let Some((snapshot, data)) = storage.get(&id) {
+      need_cache = true;
+      if let Some((snapshot, loader_result)) = storage.get(&id) {
         let valid = self
           .snapshot_manager
           .check_snapshot_valid(&snapshot)
           .await
           .unwrap_or(false);
         if valid {
-          return Ok(data);
+          normal_module.set_loader_result(loader_result);
+          need_cache = false;
         }
       };
-      need_cache = true;
     }
 
     // run generator and save to cache
-    let data = generator(module).await?;
-    if need_cache && data.inner.build_info.cacheable {
-      let mut paths: Vec<&Path> = Vec::new();
-      paths.extend(
-        data
-          .inner
-          .build_info
-          .file_dependencies
-          .iter()
-          .map(|i| i.as_path()),
-      );
-      paths.extend(
-        data
-          .inner
-          .build_info
-          .context_dependencies
-          .iter()
-          .map(|i| i.as_path()),
-      );
-      paths.extend(
-        data
-          .inner
-          .build_info
-          .missing_dependencies
-          .iter()
-          .map(|i| i.as_path()),
-      );
-      paths.extend(
-        data
-          .inner
-          .build_info
-          .build_dependencies
-          .iter()
-          .map(|i| i.as_path()),
-      );
+    let (mut module, data) = generator(module).await?;
+    let loader_result = module
+      .as_normal_module_mut()
+      .and_then(|module| module.take_loader_result());
+    if need_cache && let Some(loader_result) = loader_result {
+      let paths = loader_result
+        .file_dependencies
+        .iter()
+        .chain(loader_result.context_dependencies.iter())
+        .chain(loader_result.missing_dependencies.iter())
+        .chain(loader_result.build_dependencies.iter())
+        .map(|i| i.as_path())
+        .collect::<Vec<_>>();
 
       let snapshot = self
         .snapshot_manager
         .create_snapshot(&paths, |option| &option.module)
         .await?;
-      storage.set(id, (snapshot, data.clone()));
+      storage.set(id, (snapshot, loader_result));
     }
-    Ok(data)
+    Ok((module, data))
   }
 }
@@ -1,8 +1,95 @@
+use std::hash::{Hash, Hasher};
+
 use rspack_error::Result;
+use rspack_sources::{
+  BoxSource, MapOptions, RawSource, SourceExt, SourceMap, SourceMapSource, WithoutOriginalOptions,
+};
+use rustc_hash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
+use swc_core::ecma::atoms::JsWord;
+use xxhash_rust::xxh3::Xxh3;
+
+use crate::{
+  cache::storage::{self, PersistentItem},
+  get_runtime_key, AstOrSource, BoxModule, CodeGenerationResult, Compilation, ModuleIdentifier,
+  NormalModuleAstOrSource, ProvidedExports, RuntimeGlobals, SourceType, UsedExports,
+};
+
+/// A code generation result and the hash of everything it was generated from.
+type Storage = dyn storage::Storage<(u64, CodeGenerationResult)>;
+
+#[derive(Serialize, Deserialize)]
+struct PersistentCodeGenerationResult {
+  hash: u64,
+  sources: Vec<(SourceType, PersistentSource)>,
+  data: HashMap<String, String>,
+  runtime_requirements: u64,
+}
+
+/// Generated code and its source map, the name of the source map is kept in the map itself.
+#[derive(Serialize, Deserialize)]
+struct PersistentSource {
+  code: String,
+  map: Option<String>,
+}
+
+impl PersistentSource {
+  /// Only string sources are persisted, an ast or a binary source is kept in memory.
+  fn from_ast_or_source(ast_or_source: &AstOrSource) -> Option<Self> {
+    let source = ast_or_source.as_source()?;
+    let code = String::from_utf8(source.buffer().to_vec()).ok()?;
+    let map = match source.map(&MapOptions::default()) {
+      Some(map) => Some(map.to_json().ok()?),
+      None => None,
+    };
+    Some(Self { code, map })
+  }
 
-use crate::{cache::storage, BoxModule, CodeGenerationResult, NormalModuleAstOrSource};
+  fn into_source(self) -> Option<BoxSource> {
+    Some(match self.map {
+      Some(map) => SourceMapSource::new(WithoutOriginalOptions {
+        value: self.code,
+        name: "",
+        source_map: SourceMap::from_json(&map).ok()?,
+      })
+      .boxed(),
+      None => RawSource::from(self.code).boxed(),
+    })
+  }
+}
 
-type Storage = dyn storage::Storage<CodeGenerationResult>;
+impl PersistentItem for (u64, CodeGenerationResult) {
+  fn encode(&self) -> Option<serde_json::Value> {
+    let (hash, result) = self;
+    let sources = result
+      .inner()
+      .iter()
+      .map(|(source_type, result)| {
+        PersistentSource::from_ast_or_source(&result.ast_or_source)
+          .map(|source| (*source_type, source))
+      })
+      .collect::<Option<Vec<_>>>()?;
+    serde_json::to_value(PersistentCodeGenerationResult {
+      hash: *hash,
+      sources,
+      data: result.data.clone(),
+      runtime_requirements: result.runtime_requirements.bits(),
+    })
+    .ok()
+  }
+
+  fn decode(value: serde_json::Value) -> Option<Self> {
+    let result: PersistentCodeGenerationResult = serde_json::from_value(value).ok()?;
+    let mut code_generation_result = CodeGenerationResult::default();
+    for (source_type, source) in result.sources {
+      code_generation_result.add(source_type, AstOrSource::from(source.into_source()?));
+    }
+    code_generation_result.data = result.data;
+    code_generation_result.runtime_requirements =
+      RuntimeGlobals::from_bits_truncate(result.runtime_requirements);
+    Some((result.hash, code_generation_result))
+  }
+}
 
 #[derive(Debug)]
 pub struct CodeGenerateOccasion {
@@ -14,9 +101,16 @@ impl CodeGenerateOccasion {
     Self { storage }
   }
 
+  pub fn begin_idle(&self) {
+    if let Some(storage) = &self.storage {
+      storage.begin_idle();
+    }
+  }
+
   #[allow(clippy::unwrap_in_result)]
   pub fn use_cache<'a, G>(
     &self,
+    compilation: &Compilation,
     module: &'a BoxModule,
     generator: G,
   ) -> Result<CodeGenerationResult>
@@ -29,26 +123,112 @@ impl CodeGenerateOccasion {
       None => return generator(module),
     };
 
-    let mut need_cache = false;
+    let mut need_cache = None;
     let id = module.identifier();
     if let Some(module) = module.as_normal_module() {
       // only cache normal module
       // TODO cache all module type
       if matches!(module.ast_or_source(), NormalModuleAstOrSource::Unbuild) {
-        if let Some(data) = storage.get(&id) {
+        // modules kept by an incremental rebuild are not built again,
+        // the last code generation result is all there is
+        if let Some((_, data)) = storage.get(&id) {
           return Ok(data);
         }
         // unbuild and no cache is unexpected
         panic!("unexpected unbuild module");
       }
-      need_cache = true;
+      let hash = code_generation_hash(compilation, &id);
+      if let Some((cached_hash, data)) = storage.get(&id) && cached_hash == hash {
+        return Ok(data);
+      }
+      need_cache = Some(hash);
     }
 
     // run generator and save to cache
     let data = generator(module)?;
-    if need_cache {
-      storage.set(id, data.clone());
+    if let Some(hash) = need_cache {
+      storage.set(id, (hash, data.clone()));
     }
     Ok(data)
   }
 }
+
+/// Hashes what the code generation of a module reads from the compilation besides the module itself,
+/// the module id, the ids of its dependencies, and the exports used in each runtime.
+fn code_generation_hash(compilation: &Compilation, module_identifier: &ModuleIdentifier) -> u64 {
+  let module_graph = &compilation.module_graph;
+  let chunk_graph = &compilation.chunk_graph;
+  let module_id = |identifier: &ModuleIdentifier| {
+    chunk_graph
+      .chunk_graph_module_by_module_identifier
+      .get(identifier)
+      .and_then(|cgm| cgm.id.as_deref())
+  };
+
+  let mut hasher = Xxh3::new();
+  module_graph
+    .get_module_hash(module_identifier)
+    .hash(&mut hasher);
+  module_id(module_identifier).hash(&mut hasher);
+  compilation
+    .side_effects_free_modules
+    .contains(module_identifier)
+    .hash(&mut hasher);
+  compilation
+    .concatenated_inner_modules
+    .contains(module_identifier)
+    .hash(&mut hasher);
+  if let Some(inner_modules) = compilation.concatenated_modules.get(module_identifier) {
+    for inner_module in inner_modules {
+      inner_module.as_str().hash(&mut hasher);
+    }
+  }
+
+  for dependency_id in module_graph
+    .dependencies_by_module_identifier(module_identifier)
+    .unwrap_or_default()
+  {
+    if let Some(dependency_module) = module_graph.module_identifier_by_dependency_id(dependency_id)
+    {
+      dependency_module.as_str().hash(&mut hasher);
+      module_id(dependency_module).hash(&mut hasher);
+      compilation
+        .side_effects_free_modules
+        .contains(dependency_module)
+        .hash(&mut hasher);
+    } else {
+      None::<&str>.hash(&mut hasher);
+    }
+  }
+
+  match module_graph.get_provided_exports(module_identifier) {
+    ProvidedExports::Unknown => None,
+    ProvidedExports::Names(names) => Some(sorted_names(names)),
+  }
+  .hash(&mut hasher);
+  let mut runtimes = chunk_graph
+    .get_module_runtimes(*module_identifier, &compilation.chunk_by_ukey)
+    .values()
+    .into_iter()
+    .map(|runtime| (get_runtime_key(runtime.clone()), runtime.clone()))
+    .collect::<Vec<_>>();
+  runtimes.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+  for (key, runtime) in runtimes {
+    key.hash(&mut hasher);
+    match module_graph.get_used_exports(module_identifier, Some(&runtime)) {
+      UsedExports::Unknown => None,
+      UsedExports::Names(names) => Some(sorted_names(names)),
+    }
+    .hash(&mut hasher);
+  }
+  hasher.finish()
+}
+
+fn sorted_names(names: Vec<JsWord>) -> Vec<String> {
+  let mut names = names
+    .into_iter()
+    .map(|name| name.to_string())
+    .collect::<Vec<_>>();
+  names.sort_unstable();
+  names
+}
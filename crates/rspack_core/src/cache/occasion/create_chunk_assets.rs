@@ -2,10 +2,17 @@ use futures::Future;
 use rspack_error::Result;
 use rspack_identifier::Identifier;
 
-use crate::{cache::storage, Chunk, Compilation, NormalModuleAstOrSource, RenderManifestEntry};
+use crate::{
+  cache::storage::{self, PersistentItem},
+  Chunk, Compilation, NormalModuleAstOrSource, RenderManifestEntry,
+};
 
 type Storage = dyn storage::Storage<Vec<RenderManifestEntry>>;
 
+// chunk assets are cheap to render from the cached code generation results,
+// and their filenames depend on the hashes of the current compilation, keep them in memory
+impl PersistentItem for Vec<RenderManifestEntry> {}
+
 #[derive(Debug)]
 pub struct CreateChunkAssetsOccasion {
   storage: Option<Box<Storage>>,
@@ -16,6 +23,12 @@ impl CreateChunkAssetsOccasion {
     Self { storage }
   }
 
+  pub fn begin_idle(&self) {
+    if let Some(storage) = &self.storage {
+      storage.begin_idle();
+    }
+  }
+
   pub async fn use_cache<'a, G, F>(
     &self,
     compilation: &Compilation,
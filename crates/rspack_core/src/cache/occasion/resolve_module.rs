@@ -1,14 +1,63 @@
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
 use futures::Future;
+use nodejs_resolver::{DescriptionData, Resource};
 
 use crate::{
   cache::snapshot::{Snapshot, SnapshotManager},
-  cache::storage,
+  cache::storage::{self, PersistentItem},
   ModuleIdentifier, ResolveArgs, ResolveError, ResolveResult,
 };
 
-type Storage = dyn storage::Storage<(Snapshot, ResolveResult)>;
+type Storage = dyn storage::Storage<ResolveCacheItem>;
+/// path, query, fragment of a resolved resource, and whether it has description data
+type PersistentResource = (PathBuf, Option<String>, Option<String>, bool);
+
+#[derive(Debug, Clone)]
+pub struct ResolveCacheItem {
+  snapshot: Snapshot,
+  result: ResolveResult,
+  /// Description data can only be created by the resolver, so it's not persisted,
+  /// a restored item loads it again before it's used.
+  description_missing: bool,
+}
+
+impl PersistentItem for ResolveCacheItem {
+  fn encode(&self) -> Option<serde_json::Value> {
+    let resource = match &self.result {
+      ResolveResult::Resource(resource) => Some((
+        &resource.path,
+        &resource.query,
+        &resource.fragment,
+        self.description_missing || resource.description.is_some(),
+      )),
+      ResolveResult::Ignored => None,
+    };
+    serde_json::to_value((&self.snapshot, resource)).ok()
+  }
+
+  fn decode(value: serde_json::Value) -> Option<Self> {
+    let (snapshot, resource): (Snapshot, Option<PersistentResource>) =
+      serde_json::from_value(value).ok()?;
+    let (result, description_missing) = match resource {
+      Some((path, query, fragment, has_description)) => (
+        ResolveResult::Resource(Resource {
+          path,
+          query,
+          fragment,
+          description: None,
+        }),
+        has_description,
+      ),
+      None => (ResolveResult::Ignored, false),
+    };
+    Some(Self {
+      snapshot,
+      result,
+      description_missing,
+    })
+  }
+}
 
 #[derive(Debug)]
 pub struct ResolveModuleOccasion {
@@ -24,14 +73,25 @@ impl ResolveModuleOccasion {
     }
   }
 
-  pub async fn use_cache<'a, G, F>(
+  pub fn begin_idle(&self) {
+    if let Some(storage) = &self.storage {
+      storage.begin_idle();
+    }
+  }
+
+  /// `load_description` loads the description data of a resolved file,
+  /// which is used by the items restored from a persistent storage.
+  pub async fn use_cache<'a, G, F, L, D>(
     &self,
     args: ResolveArgs<'a>,
     generator: G,
+    load_description: L,
   ) -> Result<ResolveResult, ResolveError>
   where
     G: Fn(ResolveArgs<'a>) -> F,
     F: Future<Output = Result<ResolveResult, ResolveError>>,
+    L: FnOnce(PathBuf) -> D,
+    D: Future<Output = Option<Arc<DescriptionData>>>,
   {
     let storage = match &self.storage {
       Some(s) => s,
@@ -51,15 +111,25 @@ impl ResolveModuleOccasion {
     ));
     {
       // read
-      if let Some((snapshot, data)) = storage.get(&id) {
+      if let Some(mut item) = storage.get(&id) {
         let valid = self
           .snapshot_manager
-          .check_snapshot_valid(&snapshot)
+          .check_snapshot_valid(&item.snapshot)
           .await
           .unwrap_or(false);
 
         if valid {
-          return Ok(data);
+          if !item.description_missing {
+            return Ok(item.result);
+          }
+          if let ResolveResult::Resource(resource) = &mut item.result
+            && let Some(description) = load_description(resource.path.clone()).await
+          {
+            resource.description = Some(description);
+            item.description_missing = false;
+            storage.set(id, item.clone());
+            return Ok(item.result);
+          }
         }
       };
     }
@@ -76,7 +146,14 @@ impl ResolveModuleOccasion {
       .create_snapshot(&paths, |option| &option.resolve)
       .await
       .map_err(|err| ResolveError(err.to_string(), err))?;
-    storage.set(id, (snapshot, data.clone()));
+    storage.set(
+      id,
+      ResolveCacheItem {
+        snapshot,
+        result: data.clone(),
+        description_missing: false,
+      },
+    );
     Ok(data)
   }
 }
@@ -0,0 +1,188 @@
+use std::{
+  hash::{BuildHasherDefault, Hasher},
+  path::{Path, PathBuf},
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use dashmap::DashMap;
+use rspack_identifier::{Identifier, IdentifierHasher};
+use rustc_hash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
+use xxhash_rust::xxh3::Xxh3;
+
+use super::{PersistentItem, Storage};
+use crate::FileSystemCacheOptions;
+
+const DEFAULT_CACHE_DIRECTORY: &str = "node_modules/.cache/rspack";
+const DEFAULT_CACHE_NAME: &str = "default";
+/// One month, same as webpack
+const DEFAULT_MAX_AGE: u64 = 1000 * 60 * 60 * 24 * 30;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Pack {
+  version: String,
+  entries: HashMap<String, PackEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PackEntry {
+  /// Last time in milliseconds this entry was read or written.
+  last_used: u64,
+  data: serde_json::Value,
+}
+
+/// FileSystemStorage keeps items in memory during compilation,
+/// and writes them to `<cache_location>/<name>.pack` when the compiler becomes idle.
+///
+/// The pack is dropped as a whole if `version` or the content of any `build_dependencies` changes,
+/// so the config file should be one of the `build_dependencies`, same as webpack.
+#[derive(Debug)]
+pub struct FileSystemStorage<Item> {
+  path: PathBuf,
+  version: String,
+  max_age: u64,
+  data: DashMap<Identifier, (u64, Item), BuildHasherDefault<IdentifierHasher>>,
+}
+
+impl<Item> FileSystemStorage<Item>
+where
+  Item: PersistentItem,
+{
+  pub fn new(options: &FileSystemCacheOptions, context: &Path, name: &str) -> Self {
+    let location = if options.cache_location.is_empty() {
+      let cache_directory = if options.cache_directory.is_empty() {
+        context.join(DEFAULT_CACHE_DIRECTORY)
+      } else {
+        context.join(&options.cache_directory)
+      };
+      if options.name.is_empty() {
+        cache_directory.join(DEFAULT_CACHE_NAME)
+      } else {
+        cache_directory.join(&options.name)
+      }
+    } else {
+      context.join(&options.cache_location)
+    };
+
+    let storage = Self {
+      path: location.join(format!("{name}.pack")),
+      version: calc_version(options, context),
+      max_age: if options.max_age == 0 {
+        DEFAULT_MAX_AGE
+      } else {
+        options.max_age as u64
+      },
+      data: Default::default(),
+    };
+    storage.restore();
+    storage
+  }
+
+  fn restore(&self) {
+    let Ok(content) = std::fs::read(&self.path) else {
+      return;
+    };
+    let pack: Pack = match serde_json::from_slice(&content) {
+      Ok(pack) => pack,
+      Err(err) => {
+        tracing::debug!("Ignore broken cache pack {}: {}", self.path.display(), err);
+        return;
+      }
+    };
+    if pack.version != self.version {
+      return;
+    }
+
+    let now = now();
+    for (id, entry) in pack.entries {
+      if now.saturating_sub(entry.last_used) > self.max_age {
+        continue;
+      }
+      if let Some(item) = Item::decode(entry.data) {
+        self
+          .data
+          .insert(Identifier::from(id), (entry.last_used, item));
+      }
+    }
+  }
+
+  fn store(&self) -> std::io::Result<()> {
+    let entries = self
+      .data
+      .iter()
+      .filter_map(|item| {
+        let (last_used, data) = item.value();
+        data.encode().map(|data| {
+          (
+            item.key().to_string(),
+            PackEntry {
+              last_used: *last_used,
+              data,
+            },
+          )
+        })
+      })
+      .collect();
+    let pack = Pack {
+      version: self.version.clone(),
+      entries,
+    };
+
+    if let Some(dir) = self.path.parent() {
+      std::fs::create_dir_all(dir)?;
+    }
+    // write to a temporary file first, so a killed process never leaves a half written pack
+    let temp_path = self.path.with_extension("pack.tmp");
+    std::fs::write(&temp_path, serde_json::to_vec(&pack)?)?;
+    std::fs::rename(temp_path, &self.path)
+  }
+}
+
+impl<Item> Storage<Item> for FileSystemStorage<Item>
+where
+  Item: PersistentItem + Clone + std::fmt::Debug + Send + Sync,
+{
+  fn get(&self, id: &Identifier) -> Option<Item> {
+    self.data.get_mut(id).map(|mut item| {
+      item.0 = now();
+      item.1.clone()
+    })
+  }
+
+  fn set(&self, id: Identifier, data: Item) {
+    self.data.insert(id, (now(), data));
+  }
+
+  fn begin_idle(&self) {
+    if let Err(err) = self.store() {
+      tracing::debug!(
+        "Failed to store cache pack {}: {}",
+        self.path.display(),
+        err
+      );
+    }
+  }
+}
+
+fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|d| d.as_millis() as u64)
+    .unwrap_or_default()
+}
+
+/// Cache version is made up of `version` and the content of `build_dependencies`,
+/// a missing build dependency is hashed as its path only.
+fn calc_version(options: &FileSystemCacheOptions, context: &Path) -> String {
+  let mut hasher = Xxh3::default();
+  hasher.write(env!("CARGO_PKG_VERSION").as_bytes());
+  hasher.write(options.version.as_bytes());
+  for dependency in &options.build_dependencies {
+    let path = context.join(dependency);
+    hasher.write(path.to_string_lossy().as_bytes());
+    if let Ok(content) = std::fs::read(&path) {
+      hasher.write(&content);
+    }
+  }
+  format!("{:016x}", hasher.finish())
+}
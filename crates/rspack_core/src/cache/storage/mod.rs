@@ -2,25 +2,46 @@ use std::fmt::Debug;
 
 use rspack_identifier::Identifier;
 
-use crate::CacheOptions;
+use crate::{CacheOptions, CompilerOptions};
 
+mod filesystem;
 mod memory;
+use filesystem::FileSystemStorage;
 use memory::MemoryStorage;
 
 pub trait Storage<Item>: Debug + Send + Sync {
   fn get(&self, id: &Identifier) -> Option<Item>;
   fn set(&self, id: Identifier, data: Item);
-  // fn begin_idle(&self);
+  /// Called when a compilation is done, persistent storage should write back here.
+  fn begin_idle(&self) {}
   // fn end_idle(&self);
   // fn clear(&self);
 }
 
-pub fn new_storage<Item>(options: &CacheOptions) -> Option<Box<dyn Storage<Item>>>
+/// Items which could be written to a persistent storage.
+///
+/// Returning `None` from `encode` means the item can only live in memory,
+/// e.g. a code generation result which holds an ast.
+pub trait PersistentItem: Sized {
+  fn encode(&self) -> Option<serde_json::Value> {
+    None
+  }
+  fn decode(_value: serde_json::Value) -> Option<Self> {
+    None
+  }
+}
+
+pub fn new_storage<Item>(options: &CompilerOptions, name: &str) -> Option<Box<dyn Storage<Item>>>
 where
-  Item: Debug + Clone + Send + Sync + 'static,
+  Item: PersistentItem + Debug + Clone + Send + Sync + 'static,
 {
-  match options {
+  match &options.cache {
     CacheOptions::Disabled => None,
+    CacheOptions::FileSystem(fs_options) => Some(Box::new(FileSystemStorage::new(
+      fs_options,
+      &options.context,
+      name,
+    ))),
     _ => Some(Box::new(MemoryStorage::new())),
   }
 }
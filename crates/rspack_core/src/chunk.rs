@@ -1,4 +1,5 @@
 use std::{
+  collections::BTreeMap,
   fmt::{Debug, Formatter, Result},
   hash::Hasher,
 };
@@ -8,8 +9,8 @@ use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use xxhash_rust::xxh3::Xxh3;
 
 use crate::{
-  ChunkGraph, ChunkGroupByUkey, ChunkGroupKind, ChunkGroupUkey, ChunkUkey, ModuleGraph,
-  RuntimeSpec, SourceType,
+  ChunkByUkey, ChunkGraph, ChunkGroupByUkey, ChunkGroupKind, ChunkGroupOrderKey, ChunkGroupUkey,
+  ChunkLoadingType, ChunkUkey, ModuleGraph, RuntimeSpec, SourceType,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -186,6 +187,20 @@ impl Chunk {
       })
   }
 
+  /// Chunk loading specified by the entrypoint of the runtime chunk, `None` means the default of
+  /// the target
+  pub fn get_chunk_loading(
+    &self,
+    chunk_group_by_ukey: &ChunkGroupByUkey,
+  ) -> Option<ChunkLoadingType> {
+    self
+      .groups
+      .iter()
+      .filter_map(|ukey| chunk_group_by_ukey.get(ukey))
+      .filter(|group| group.kind == ChunkGroupKind::Entrypoint)
+      .find_map(|group| group.options.chunk_loading)
+  }
+
   pub fn get_all_async_chunks(&self, chunk_group_by_ukey: &ChunkGroupByUkey) -> HashSet<ChunkUkey> {
     let mut queue = HashSet::default();
     let mut chunks = HashSet::default();
@@ -258,6 +273,121 @@ impl Chunk {
     chunks
   }
 
+  pub fn get_all_referenced_async_entrypoints(
+    &self,
+    chunk_group_by_ukey: &ChunkGroupByUkey,
+  ) -> HashSet<ChunkGroupUkey> {
+    let mut visited = HashSet::default();
+    let mut entrypoints = HashSet::default();
+    let mut queue = self.groups.iter().copied().collect::<Vec<_>>();
+
+    while let Some(chunk_group_ukey) = queue.pop() {
+      if !visited.insert(chunk_group_ukey) {
+        continue;
+      }
+      if let Some(chunk_group) = chunk_group_by_ukey.get(&chunk_group_ukey) {
+        entrypoints.extend(chunk_group.async_entrypoints.iter().copied());
+        queue.extend(chunk_group.children.iter().copied());
+      }
+    }
+
+    entrypoints
+  }
+
+  /// Chunks in the child groups which have an order of `order_key`,
+  /// sorted by the order from high to low
+  pub fn get_child_chunks_by_order(
+    &self,
+    order_key: &ChunkGroupOrderKey,
+    chunk_group_by_ukey: &ChunkGroupByUkey,
+  ) -> Vec<ChunkUkey> {
+    let mut list = vec![];
+    for group_ukey in &self.groups {
+      let Some(group) = chunk_group_by_ukey.get(group_ukey) else {
+        continue;
+      };
+      // only the last chunk of a group triggers the hints of its children
+      if group.chunks.last() != Some(&self.ukey) {
+        continue;
+      }
+      for child_group_ukey in group.children.iter() {
+        if let Some(child_group) = chunk_group_by_ukey.get(child_group_ukey)
+          && let Some(order) = child_group.options.order(order_key)
+        {
+          list.push((order, child_group));
+        }
+      }
+    }
+    // Higher order first, and fall back to the group name to keep the output deterministic
+    list.sort_by(|(order_a, group_a), (order_b, group_b)| {
+      order_b
+        .cmp(order_a)
+        .then_with(|| group_a.name().cmp(&group_b.name()))
+        .then_with(|| group_a.ukey.cmp(&group_b.ukey))
+    });
+
+    let mut chunks = vec![];
+    for (_, child_group) in list {
+      for chunk_ukey in &child_group.chunks {
+        if !chunks.contains(chunk_ukey) {
+          chunks.push(*chunk_ukey);
+        }
+      }
+    }
+    chunks
+  }
+
+  pub fn get_child_ids_by_order(
+    &self,
+    order_key: &ChunkGroupOrderKey,
+    chunk_group_by_ukey: &ChunkGroupByUkey,
+    chunk_by_ukey: &ChunkByUkey,
+  ) -> Option<Vec<String>> {
+    let chunk_ids = self
+      .get_child_chunks_by_order(order_key, chunk_group_by_ukey)
+      .iter()
+      .filter_map(|chunk_ukey| chunk_by_ukey.get(chunk_ukey).and_then(|c| c.id.clone()))
+      .collect::<Vec<_>>();
+    (!chunk_ids.is_empty()).then_some(chunk_ids)
+  }
+
+  /// Map from chunk id to the ids of its ordered children, for every async chunk of this chunk
+  pub fn get_child_ids_by_orders_map(
+    &self,
+    include_direct_children: bool,
+    chunk_group_by_ukey: &ChunkGroupByUkey,
+    chunk_by_ukey: &ChunkByUkey,
+  ) -> HashMap<ChunkGroupOrderKey, BTreeMap<String, Vec<String>>> {
+    let mut result: HashMap<ChunkGroupOrderKey, BTreeMap<String, Vec<String>>> = HashMap::default();
+
+    let mut add_child_ids_by_orders_to_map = |chunk: &Chunk| {
+      let Some(chunk_id) = &chunk.id else {
+        return;
+      };
+      for order_key in [ChunkGroupOrderKey::Preload, ChunkGroupOrderKey::Prefetch] {
+        if let Some(child_ids) =
+          chunk.get_child_ids_by_order(&order_key, chunk_group_by_ukey, chunk_by_ukey)
+        {
+          result
+            .entry(order_key)
+            .or_default()
+            .insert(chunk_id.clone(), child_ids);
+        }
+      }
+    };
+
+    if include_direct_children {
+      add_child_ids_by_orders_to_map(self);
+    }
+    for chunk_ukey in self.get_all_async_chunks(chunk_group_by_ukey) {
+      if let Some(chunk) = chunk_by_ukey.get(&chunk_ukey) {
+        add_child_ids_by_orders_to_map(chunk);
+      }
+    }
+
+    result
+  }
+
   pub fn get_render_hash(&self) -> String {
     format!("{:x}", self.hash.finish())
   }
@@ -3,7 +3,8 @@ use rspack_identifier::IdentifierMap;
 use rustc_hash::FxHashSet as HashSet;
 
 use crate::{
-  Chunk, ChunkByUkey, ChunkGroupByUkey, ChunkGroupUkey, ChunkUkey, ModuleIdentifier, RuntimeSpec,
+  Chunk, ChunkByUkey, ChunkGroupByUkey, ChunkGroupUkey, ChunkLoadingType, ChunkUkey,
+  ModuleIdentifier, RuntimeSpec,
 };
 
 impl DatabaseItem for ChunkGroup {
@@ -20,19 +21,21 @@ pub struct ChunkGroup {
   pub(crate) module_post_order_indices: IdentifierMap<usize>,
   pub(crate) parents: HashSet<ChunkGroupUkey>,
   pub(crate) children: HashSet<ChunkGroupUkey>,
+  /// Entrypoints started from this chunk group, e.g. by `new Worker()`
+  pub(crate) async_entrypoints: HashSet<ChunkGroupUkey>,
   pub(crate) kind: ChunkGroupKind,
   // ChunkGroupInfo
   pub(crate) next_pre_order_index: usize,
   pub(crate) next_post_order_index: usize,
   pub(crate) runtime: RuntimeSpec,
+  pub options: ChunkGroupOptions,
   // Entrypoint
-  // pub(crate) name: Option<String>,
   pub(crate) runtime_chunk: Option<ChunkUkey>,
   pub(crate) entry_point_chunk: Option<ChunkUkey>,
 }
 
 impl ChunkGroup {
-  pub fn new(kind: ChunkGroupKind, runtime: RuntimeSpec, _name: Option<String>) -> Self {
+  pub fn new(kind: ChunkGroupKind, runtime: RuntimeSpec, options: ChunkGroupOptions) -> Self {
     Self {
       ukey: ChunkGroupUkey::new(),
       chunks: vec![],
@@ -40,16 +43,21 @@ impl ChunkGroup {
       module_pre_order_indices: Default::default(),
       parents: Default::default(),
       children: Default::default(),
+      async_entrypoints: Default::default(),
       kind,
       next_pre_order_index: 0,
       next_post_order_index: 0,
       runtime,
-      // name,
+      options,
       runtime_chunk: None,
       entry_point_chunk: None,
     }
   }
 
+  pub fn name(&self) -> Option<&str> {
+    self.options.name.as_deref()
+  }
+
   pub fn module_post_order_index(&self, module_identifier: &ModuleIdentifier) -> Option<usize> {
     // A module could split into another ChunkGroup, which doesn't have the module_post_order_indices of the module
     self
@@ -87,6 +95,10 @@ impl ChunkGroup {
     matches!(self.kind, ChunkGroupKind::Entrypoint)
   }
 
+  pub fn parents_iterable(&self) -> impl Iterator<Item = &ChunkGroupUkey> {
+    self.parents.iter()
+  }
+
   pub fn set_runtime_chunk(&mut self, chunk_ukey: ChunkUkey) {
     self.runtime_chunk = Some(chunk_ukey);
   }
@@ -162,3 +174,36 @@ pub enum ChunkGroupKind {
   Entrypoint,
   Normal,
 }
+
+/// Options of a chunk group, e.g. from magic comments of `import()`
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct ChunkGroupOptions {
+  pub name: Option<String>,
+  pub prefetch_order: Option<i32>,
+  pub preload_order: Option<i32>,
+  /// Chunk loading of an entrypoint which differs from the target, e.g. `importScripts` in workers
+  pub chunk_loading: Option<ChunkLoadingType>,
+}
+
+impl ChunkGroupOptions {
+  pub fn with_name(name: impl Into<String>) -> Self {
+    Self {
+      name: Some(name.into()),
+      ..Default::default()
+    }
+  }
+
+  pub fn order(&self, order_key: &ChunkGroupOrderKey) -> Option<i32> {
+    match order_key {
+      ChunkGroupOrderKey::Preload => self.preload_order,
+      ChunkGroupOrderKey::Prefetch => self.prefetch_order,
+    }
+  }
+}
+
+/// Orders of child chunk groups which are loaded with resource hints, see [ChunkGroupOptions]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ChunkGroupOrderKey {
+  Preload,
+  Prefetch,
+}
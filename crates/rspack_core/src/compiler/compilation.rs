@@ -27,23 +27,26 @@ use tokio::sync::mpsc::error::TryRecvError;
 use tracing::instrument;
 use xxhash_rust::xxh3::Xxh3;
 
-#[cfg(debug_assertions)]
 use crate::tree_shaking::visitor::TreeShakingResult;
 use crate::{
   build_chunk_graph::build_chunk_graph,
   cache::{use_code_splitting_cache, Cache, CodeSplittingCache},
   is_source_equal,
-  tree_shaking::{optimizer, visitor::SymbolRef, BailoutFlag, OptimizeDependencyResult},
+  tree_shaking::{
+    optimizer, set_exports_used_in_runtimes, visitor::SymbolRef, BailoutFlag,
+    OptimizeDependencyResult,
+  },
   utils::fast_drop,
   AddQueue, AddTask, AddTaskResult, AdditionalChunkRuntimeRequirementsArgs, BoxModuleDependency,
   BuildQueue, BuildTask, BuildTaskResult, BundleEntries, Chunk, ChunkByUkey, ChunkGraph,
   ChunkGroup, ChunkGroupUkey, ChunkHashArgs, ChunkKind, ChunkUkey, CleanQueue, CleanTask,
-  CleanTaskResult, CodeGenerationResult, CodeGenerationResults, CompilerOptions, ContentHashArgs,
-  DependencyId, EntryDependency, EntryItem, EntryOptions, Entrypoint, FactorizeQueue,
-  FactorizeTask, FactorizeTaskResult, LoaderRunnerRunner, Module, ModuleGraph, ModuleIdentifier,
-  ModuleType, NormalModuleAstOrSource, ProcessAssetsArgs, ProcessDependenciesQueue,
-  ProcessDependenciesResult, ProcessDependenciesTask, RenderManifestArgs, Resolve, RuntimeGlobals,
-  RuntimeModule, SharedPluginDriver, Stats, TaskResult, WorkerTask,
+  CleanTaskResult, CodeGenerationResult, CodeGenerationResults, CompilationRecords,
+  CompilerOptions, ContentHashArgs, DependencyId, EntryDependency, EntryItem, EntryOptions,
+  Entrypoint, FactorizeQueue, FactorizeTask, FactorizeTaskResult, LoaderRunnerRunner, Module,
+  ModuleGraph, ModuleIdentifier, ModuleType, NormalModuleAstOrSource, ProcessAssetsArgs,
+  ProcessDependenciesQueue, ProcessDependenciesResult, ProcessDependenciesTask, RenderManifestArgs,
+  Resolve, RuntimeGlobals, RuntimeModule, SharedPluginDriver, SourceType, Stats, TaskResult,
+  WorkerTask,
 };
 
 #[derive(Debug)]
@@ -73,6 +76,8 @@ pub struct Compilation {
   pub chunk_by_ukey: Database<Chunk>,
   pub chunk_group_by_ukey: Database<ChunkGroup>,
   pub entrypoints: HashMap<String, ChunkGroupUkey>,
+  /// Entrypoints created on demand by modules, e.g. `new Worker()`
+  pub async_entrypoints: Vec<ChunkGroupUkey>,
   pub assets: CompilationAssets,
   pub emitted_assets: DashSet<String, BuildHasherDefault<FxHasher>>,
   diagnostics: IndexSet<Diagnostic, BuildHasherDefault<FxHasher>>,
@@ -103,6 +108,13 @@ pub struct Compilation {
   pub build_dependencies: IndexSet<PathBuf, BuildHasherDefault<FxHasher>>,
   pub side_effects_free_modules: IdentifierSet,
   pub module_item_map: IdentifierMap<Vec<ModuleItem>>,
+  /// Modules merged into the scope of a root module by module concatenation, keyed by the root,
+  /// in the order of evaluation with the root itself last
+  pub concatenated_modules: IdentifierMap<Vec<ModuleIdentifier>>,
+  /// Modules merged into another module, which are not rendered on their own
+  pub concatenated_inner_modules: IdentifierSet,
+  /// Records of the previous compilation, used to keep module and chunk ids stable
+  pub records: Option<Arc<CompilationRecords>>,
 }
 
 impl Compilation {
@@ -128,6 +140,7 @@ impl Compilation {
       entries,
       chunk_graph: Default::default(),
       entrypoints: Default::default(),
+      async_entrypoints: Default::default(),
       assets: Default::default(),
       emitted_assets: Default::default(),
       diagnostics: Default::default(),
@@ -156,6 +169,9 @@ impl Compilation {
       build_dependencies: Default::default(),
       side_effects_free_modules: IdentifierSet::default(),
       module_item_map: IdentifierMap::default(),
+      concatenated_modules: IdentifierMap::default(),
+      concatenated_inner_modules: IdentifierSet::default(),
+      records: None,
     }
   }
 
@@ -182,6 +198,17 @@ impl Compilation {
     }
   }
 
+  pub fn rename_asset(&mut self, filename: &str, new_name: String) {
+    if let Some(asset) = self.assets.remove(filename) {
+      self.assets.insert(new_name.clone(), asset);
+      self.chunk_by_ukey.values_mut().for_each(|chunk| {
+        if chunk.files.remove(filename) {
+          chunk.files.insert(new_name.clone());
+        }
+      });
+    }
+  }
+
   pub fn emit_asset(&mut self, filename: String, asset: CompilationAsset) {
     tracing::trace!("Emit asset {}", filename);
     if let Some(mut original) = self.assets.remove(&filename)
@@ -854,11 +881,20 @@ impl Compilation {
         .modules()
         .par_iter()
         .filter(filter_op)
+        // Modules not in any chunk, e.g. only referenced by weak dependencies, are never rendered
+        .filter(|(module_identifier, _)| {
+          compilation
+            .chunk_graph
+            .get_number_of_module_chunks(**module_identifier)
+            > 0
+        })
         .map(|(module_identifier, module)| {
           compilation
             .cache
             .code_generate_occasion
-            .use_cache(module, |module| module.code_generation(compilation))
+            .use_cache(compilation, module, |module| {
+              module.code_generation(compilation)
+            })
             .map(|result| (*module_identifier, result))
         })
         .collect::<Result<Vec<(ModuleIdentifier, CodeGenerationResult)>>>()?;
@@ -897,7 +933,7 @@ impl Compilation {
   }
 
   #[instrument(skip_all)]
-  async fn create_chunk_assets(&mut self, plugin_driver: SharedPluginDriver) {
+  async fn create_chunk_assets(&mut self, plugin_driver: SharedPluginDriver) -> Result<()> {
     let results = self
       .chunk_by_ukey
       .values()
@@ -928,27 +964,26 @@ impl Compilation {
       .collect::<std::result::Result<Vec<_>, _>>()
       .expect("Failed to resolve render_manifest results");
 
-    chunk_ukey_and_manifest
-      .into_iter()
-      .for_each(|(chunk_ukey, manifest)| {
-        manifest
-          .expect("TODO: we should return this error rathen expect")
-          .into_iter()
-          .for_each(|file_manifest| {
-            let current_chunk = self
-              .chunk_by_ukey
-              .get_mut(&chunk_ukey)
-              .unwrap_or_else(|| panic!("chunk({chunk_ukey:?}) should be in chunk_by_ukey",));
-            current_chunk
-              .files
-              .insert(file_manifest.filename().to_string());
-
-            self.emit_asset(
-              file_manifest.filename().to_string(),
-              CompilationAsset::with_source(CachedSource::new(file_manifest.source).boxed()),
-            );
-          });
-      })
+    for (chunk_ukey, manifest) in chunk_ukey_and_manifest {
+      for file_manifest in manifest? {
+        let current_chunk = self
+          .chunk_by_ukey
+          .get_mut(&chunk_ukey)
+          .unwrap_or_else(|| panic!("chunk({chunk_ukey:?}) should be in chunk_by_ukey",));
+        current_chunk
+          .files
+          .insert(file_manifest.filename().to_string());
+
+        self.emit_asset(
+          file_manifest.filename().to_string(),
+          CompilationAsset::new(
+            Some(CachedSource::new(file_manifest.source).boxed()),
+            file_manifest.info,
+          ),
+        );
+      }
+    }
+    Ok(())
   }
   #[instrument(name = "compilation:process_asssets", skip_all)]
   async fn process_assets(&mut self, plugin_driver: SharedPluginDriver) -> Result<()> {
@@ -993,17 +1028,30 @@ impl Compilation {
   pub async fn seal(&mut self, plugin_driver: SharedPluginDriver) -> Result<()> {
     use_code_splitting_cache(self, |compilation| async {
       build_chunk_graph(compilation)?;
-      plugin_driver.write().await.optimize_chunks(compilation)?;
+      plugin_driver
+        .write()
+        .await
+        .optimize_chunks(compilation)
+        .await?;
       Ok(compilation)
     })
     .await?;
+    if self.options.builtins.tree_shaking {
+      set_exports_used_in_runtimes(self);
+    }
     plugin_driver
       .write()
       .await
       .optimize_chunk_modules(self)
       .await?;
 
+    if let Some(records) = self.records.clone() {
+      records.revive_modules(self);
+    }
     plugin_driver.write().await.module_ids(self)?;
+    if let Some(records) = self.records.clone() {
+      records.revive_chunks(self);
+    }
     plugin_driver.write().await.chunk_ids(self)?;
 
     self.code_generation().await?;
@@ -1014,20 +1062,24 @@ impl Compilation {
 
     self.create_hash(plugin_driver.clone()).await?;
 
-    self.create_chunk_assets(plugin_driver.clone()).await;
+    self.create_chunk_assets(plugin_driver.clone()).await?;
 
     self.process_assets(plugin_driver).await?;
     Ok(())
   }
 
   pub fn get_chunk_graph_entries(&self) -> HashSet<ChunkUkey> {
-    let entries = self.entrypoints.values().map(|entrypoint_ukey| {
-      let entrypoint = self
-        .chunk_group_by_ukey
-        .get(entrypoint_ukey)
-        .expect("chunk group not found");
-      entrypoint.get_runtime_chunk()
-    });
+    let entries = self
+      .entrypoints
+      .values()
+      .chain(self.async_entrypoints.iter())
+      .map(|entrypoint_ukey| {
+        let entrypoint = self
+          .chunk_group_by_ukey
+          .get(entrypoint_ukey)
+          .expect("chunk group not found");
+        entrypoint.get_runtime_chunk()
+      });
     HashSet::from_iter(entries)
   }
 
@@ -1223,11 +1275,26 @@ impl Compilation {
 
     for item in hash_results {
       let (chunk_ukey, hashes) = item.expect("Failed to resolve content_hash results");
-      hashes?.into_iter().for_each(|hash| {
-        if let Some(chunk) = self.chunk_by_ukey.get_mut(&chunk_ukey) && let Some((source_type, hash)) = hash {
-          chunk.content_hash.insert(source_type, hash);
-        }
-      });
+      let mut content_hashes: HashMap<SourceType, String> = HashMap::default();
+      hashes?
+        .into_iter()
+        .flatten()
+        .for_each(|(source_type, hash)| {
+          // Combine hashes if more than one plugin contributes to the same source type
+          let hash = match content_hashes.remove(&source_type) {
+            Some(prev) => {
+              let mut hasher = Xxh3::new();
+              prev.hash(&mut hasher);
+              hash.hash(&mut hasher);
+              format!("{:x}", hasher.finish())
+            }
+            None => hash,
+          };
+          content_hashes.insert(source_type, hash);
+        });
+      if let Some(chunk) = self.chunk_by_ukey.get_mut(&chunk_ukey) {
+        chunk.content_hash.extend(content_hashes);
+      }
     }
 
     tracing::trace!("calculate chunks content hash");
@@ -1384,7 +1451,7 @@ pub struct AssetInfo {
   /// the value(s) of the module hash used for this asset
   // pub module_hash:
   /// the value(s) of the content hash used for this asset
-  // pub content_hash:
+  pub content_hash: HashSet<String>,
   /// when asset was created from a source file (potentially transformed), the original filename relative to compilation context
   // pub source_filename:
   /// size in bytes, only set after asset has been emitted
@@ -1419,6 +1486,11 @@ impl AssetInfo {
     self.related = v;
     self
   }
+
+  pub fn with_content_hash(mut self, v: HashSet<String>) -> Self {
+    self.content_hash = v;
+    self
+  }
 }
 
 #[derive(Debug, Default, Clone)]
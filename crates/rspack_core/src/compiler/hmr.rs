@@ -2,14 +2,16 @@ use std::{
   hash::{Hash, Hasher},
   ops::Sub,
   path::PathBuf,
+  sync::Arc,
 };
 
 use rspack_error::Result;
 use rspack_fs::AsyncWritableFileSystem;
-use rspack_identifier::{IdentifierMap, IdentifierSet};
+use rspack_identifier::IdentifierSet;
 use rspack_sources::{RawSource, SourceExt};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
+use super::CompilationRecords;
 use crate::{
   fast_set, AssetInfo, Chunk, ChunkKind, Compilation, CompilationAsset, Compiler, ModuleIdentifier,
   RenderManifestArgs, RuntimeSpec, SetupMakeParam,
@@ -43,89 +45,13 @@ impl<T> Compiler<T>
 where
   T: AsyncWritableFileSystem + Send + Sync,
 {
-  // TODO: remove this function when we had `record` in compiler.
   pub async fn rebuild(
     &mut self,
     changed_files: std::collections::HashSet<String>,
     removed_files: std::collections::HashSet<String>,
   ) -> Result<()> {
     assert!(!changed_files.is_empty() || !removed_files.is_empty());
-    let old = self.compilation.get_stats();
-    fn collect_changed_modules(
-      compilation: &Compilation,
-    ) -> (IdentifierMap<(u64, String)>, IdentifierMap<String>) {
-      let modules_map = compilation
-        .module_graph
-        .modules()
-        .values()
-        .map(|module| {
-          let identifier = module.identifier();
-          (
-            identifier,
-            (
-              compilation
-                .module_graph
-                .get_module_hash(&identifier)
-                .expect("Module hash expected"),
-              compilation
-                .chunk_graph
-                .get_module_id(identifier)
-                .as_deref()
-                .expect("should has module id")
-                .to_string(),
-            ),
-          )
-        })
-        .collect::<IdentifierMap<_>>();
-
-      let old_runtime_modules = compilation
-        .runtime_modules
-        .iter()
-        .map(|(identifier, module)| {
-          (
-            *identifier,
-            module.generate(compilation).source().to_string(),
-          )
-        })
-        .collect();
-
-      (modules_map, old_runtime_modules)
-    }
-
-    let (old_all_modules, old_runtime_modules) = collect_changed_modules(old.compilation);
-    // TODO: should use `records`
-
-    let mut all_old_runtime: RuntimeSpec = Default::default();
-    for entrypoint_ukey in old.compilation.entrypoints.values() {
-      if let Some(runtime) = old
-        .compilation
-        .chunk_group_by_ukey
-        .get(entrypoint_ukey)
-        .map(|entrypoint| entrypoint.runtime.clone())
-      {
-        all_old_runtime.extend(runtime);
-      }
-    }
-
-    let mut hot_update_main_content_by_runtime = all_old_runtime
-      .iter()
-      .map(|id| (id.clone(), HotUpdateContent::new(id)))
-      .collect::<HashMap<String, HotUpdateContent>>();
-
-    let mut old_chunks: Vec<(String, IdentifierSet, RuntimeSpec)> = vec![];
-    for (ukey, chunk) in old.compilation.chunk_by_ukey.iter() {
-      let modules = old
-        .compilation
-        .chunk_graph
-        .get_chunk_graph_chunk(ukey)
-        .modules
-        .clone();
-      old_chunks.push((
-        chunk.expect_id().to_string(),
-        modules,
-        chunk.runtime.clone(),
-      ));
-    }
+    let old_records = Arc::new(CompilationRecords::new(&self.compilation));
 
     // build without stats
     {
@@ -181,6 +107,7 @@ where
       }
 
       fast_set(&mut self.compilation, new_compilation);
+      self.compilation.records = Some(old_records.clone());
 
       self.compilation.lazy_visit_modules = changed_files.clone();
 
@@ -214,60 +141,70 @@ where
       self.cache.begin_idle();
     }
 
-    // ----
+    self.create_hot_update_assets(&old_records).await?;
+    self.compile_done().await?;
+
+    Ok(())
+  }
+
+  pub(super) async fn create_hot_update_assets(
+    &mut self,
+    old_records: &CompilationRecords,
+  ) -> Result<()> {
+    let all_old_runtime: RuntimeSpec = old_records.runtime.iter().cloned().collect();
+    let mut hot_update_main_content_by_runtime = all_old_runtime
+      .iter()
+      .map(|id| (id.clone(), HotUpdateContent::new(id)))
+      .collect::<HashMap<String, HotUpdateContent>>();
+
     if hot_update_main_content_by_runtime.is_empty() {
-      self.compile_done().await?;
       return Ok(());
     }
 
-    let (now_all_modules, now_runtime_modules) = collect_changed_modules(&self.compilation);
+    let now_records = CompilationRecords::new(&self.compilation);
 
     let mut updated_modules: IdentifierSet = Default::default();
     let mut updated_runtime_modules: IdentifierSet = Default::default();
     let mut completely_removed_modules: HashSet<String> = Default::default();
 
-    for (old_uri, (old_hash, old_module_id)) in &old_all_modules {
-      if let Some((now_hash, _)) = now_all_modules.get(old_uri) {
+    for (old_uri, old_hash) in &old_records.module_hashes {
+      if let Some(now_hash) = now_records.module_hashes.get(old_uri) {
         // updated
         if now_hash != old_hash {
-          updated_modules.insert(*old_uri);
+          updated_modules.insert(ModuleIdentifier::from(old_uri.as_str()));
         }
-      } else {
+      } else if let Some(old_module_id) = old_records.modules.by_identifier.get(old_uri) {
         // deleted
         completely_removed_modules.insert(old_module_id.to_string());
       }
     }
-    for identifier in now_all_modules.keys() {
-      if !old_all_modules.contains_key(identifier) {
+    for identifier in now_records.module_hashes.keys() {
+      if !old_records.module_hashes.contains_key(identifier) {
         // added
-        updated_modules.insert(*identifier);
+        updated_modules.insert(ModuleIdentifier::from(identifier.as_str()));
       }
     }
 
-    // println!(
-    //   "updated_modules: {:?}\n, remove modules {:?}",
-    //   updated_modules, completely_removed_modules
-    // );
-
-    for (identifier, old_runtime_module_content) in &old_runtime_modules {
-      if let Some(new_runtime_module_content) = now_runtime_modules.get(identifier) {
+    for (identifier, old_runtime_module_hash) in &old_records.runtime_module_hashes {
+      if let Some(new_runtime_module_hash) = now_records.runtime_module_hashes.get(identifier) {
         // updated
-        if new_runtime_module_content != old_runtime_module_content {
-          updated_runtime_modules.insert(*identifier);
+        if new_runtime_module_hash != old_runtime_module_hash {
+          updated_runtime_modules.insert(ModuleIdentifier::from(identifier.as_str()));
         }
       }
     }
-    for identifier in now_runtime_modules.keys() {
-      if !old_runtime_modules.contains_key(identifier) {
+    for identifier in now_records.runtime_module_hashes.keys() {
+      if !old_records.runtime_module_hashes.contains_key(identifier) {
         // added
-        updated_runtime_modules.insert(*identifier);
+        updated_runtime_modules.insert(ModuleIdentifier::from(identifier.as_str()));
       }
     }
 
     // TODO: hash
     // if old.hash == now.hash { return  } else { // xxxx}
 
-    for (chunk_id, _old_chunk_modules, old_runtime) in &old_chunks {
+    for (chunk_id, old_runtime) in &old_records.chunk_runtime {
+      let old_runtime: RuntimeSpec = old_runtime.iter().cloned().collect();
       let mut new_modules = vec![];
       let mut new_runtime_modules = vec![];
       let mut chunk_id = chunk_id.to_string();
@@ -318,7 +255,7 @@ where
         // subtractRuntime
         removed_from_runtime = removed_from_runtime.sub(&new_runtime);
       } else {
-        removed_from_runtime = old_runtime.clone();
+        removed_from_runtime = old_runtime;
         // new_runtime = old_runtime.clone();
       }
 
@@ -443,8 +380,6 @@ where
       );
     }
 
-    self.compile_done().await?;
-
     Ok(())
   }
 }
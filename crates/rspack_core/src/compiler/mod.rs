@@ -1,12 +1,17 @@
 mod compilation;
 mod hmr;
 mod queue;
+mod records;
 mod resolver;
 
-use std::{path::Path, sync::Arc};
+use std::{
+  path::{Path, PathBuf},
+  sync::Arc,
+};
 
 pub use compilation::*;
 pub use queue::*;
+pub use records::*;
 pub use resolver::*;
 use rspack_error::Result;
 use rspack_fs::AsyncWritableFileSystem;
@@ -31,6 +36,8 @@ where
   pub plugin_driver: SharedPluginDriver,
   pub loader_runner_runner: Arc<LoaderRunnerRunner>,
   pub cache: Arc<Cache>,
+  /// Records read from `records_input_path`
+  pub records: Option<Arc<CompilationRecords>>,
 }
 
 impl<T> Compiler<T>
@@ -57,6 +64,17 @@ where
       plugin_driver.clone(),
     ));
     let cache = Arc::new(Cache::new(options.clone()));
+    let records =
+      options
+        .records_input_path
+        .as_ref()
+        .and_then(|path| match CompilationRecords::read(path) {
+          Ok(records) => records.map(Arc::new),
+          Err(err) => {
+            tracing::warn!("{}", err);
+            None
+          }
+        });
 
     Self {
       options: options.clone(),
@@ -72,6 +90,7 @@ where
       plugin_driver,
       loader_runner_runner,
       cache,
+      records,
     }
   }
 
@@ -104,6 +123,7 @@ where
         self.cache.clone(),
       ),
     );
+    self.compilation.records = self.records.clone();
 
     // Fake this compilation as *currently* rebuilding does not create a new compilation
     self
@@ -130,6 +150,11 @@ where
       .collect::<HashSet<_>>();
     self.compile(SetupMakeParam::ForceBuildDeps(deps)).await?;
     self.cache.begin_idle();
+    // Records read from disk belong to a previous process, emit hot update against them
+    // so clients alive across restarts could still apply updates.
+    if self.options.dev_server.hot && let Some(records) = self.records.clone() {
+      self.create_hot_update_assets(&records).await?;
+    }
     self.compile_done().await?;
     Ok(())
   }
@@ -148,6 +173,10 @@ where
       if !diagnostics.is_empty() {
         self.compilation.push_batch_diagnostic(diagnostics);
       }
+      analyze_result.set_exports_info(
+        &mut self.compilation.module_graph,
+        &self.compilation.entry_module_identifiers,
+      );
       self.compilation.used_symbol_ref = analyze_result.used_symbol_ref;
       self.compilation.bailout_module_identifiers = analyze_result.bail_out_module_identifiers;
       self.compilation.side_effects_free_modules = analyze_result.side_effects_free_modules;
@@ -176,10 +205,27 @@ where
       self.emit_assets().await?;
     }
 
+    if let Some(path) = &self.options.records_output_path {
+      self.store_records(path.clone()).await?;
+    }
+
     self.compilation.done(self.plugin_driver.clone()).await?;
     Ok(())
   }
 
+  #[instrument(name = "store_records", skip_all)]
+  async fn store_records(&mut self, path: PathBuf) -> Result<()> {
+    let records = CompilationRecords::new(&self.compilation);
+    if let Some(dir) = path.parent() {
+      self.output_filesystem.create_dir_all(dir).await?;
+    }
+    self
+      .output_filesystem
+      .write(&path, records.to_json_string()?.as_bytes())
+      .await?;
+    Ok(())
+  }
+
   #[instrument(name = "emit_assets", skip_all)]
   pub async fn emit_assets(&mut self) -> Result<()> {
     self
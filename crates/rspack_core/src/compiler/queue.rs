@@ -75,7 +75,8 @@ impl WorkerTask for FactorizeTask {
     let (result, diagnostics) = match *dependency.dependency_type() {
       DependencyType::ImportContext
       | DependencyType::CommonJSRequireContext
-      | DependencyType::RequireContext => {
+      | DependencyType::RequireContext
+      | DependencyType::ImportMetaContext => {
         let factory = ContextModuleFactory::new(self.plugin_driver, self.cache);
         factory
           .create(ModuleFactoryCreateData {
@@ -237,7 +238,6 @@ pub struct BuildTaskResult {
 #[async_trait::async_trait]
 impl WorkerTask for BuildTask {
   async fn run(self) -> Result<TaskResult> {
-    let mut module = self.module;
     let compiler_options = self.compiler_options;
     let loader_runner_runner = self.loader_runner_runner;
     let cache = self.cache;
@@ -245,7 +245,7 @@ impl WorkerTask for BuildTask {
 
     let build_result = cache
       .build_module_occasion
-      .use_cache(&mut module, |module| async {
+      .use_cache(self.module, |mut module| async move {
         plugin_driver
           .read()
           .await
@@ -259,13 +259,13 @@ impl WorkerTask for BuildTask {
           })
           .await;
 
-        plugin_driver.read().await.succeed_module(module).await?;
+        plugin_driver.read().await.succeed_module(&module).await?;
 
-        result
+        result.map(|result| (module, result))
       })
       .await;
 
-    build_result.map(|build_result| {
+    build_result.map(|(module, build_result)| {
       let (build_result, diagnostics) = build_result.split_into_parts();
 
       TaskResult::Build(BuildTaskResult {
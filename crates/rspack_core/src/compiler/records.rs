@@ -0,0 +1,200 @@
+use std::path::Path;
+
+use rspack_error::Result;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use serde::{Deserialize, Serialize};
+
+use crate::{ChunkKind, Compilation, ModuleIdentifier, RuntimeSpec};
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleRecords {
+  /// module identifier -> module id
+  pub by_identifier: HashMap<String, String>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkRecords {
+  /// chunk name -> chunk id
+  pub by_name: HashMap<String, String>,
+  /// identifier of the module which splits out the chunk -> chunk id
+  pub by_source: HashMap<String, String>,
+}
+
+/// Records are used to keep ids and hashes stable across compiler restarts,
+/// which is the same as webpack's `recordsPath`.
+///
+/// Besides ids, records carry everything HMR needs to diff against the previous compilation.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompilationRecords {
+  pub modules: ModuleRecords,
+  pub chunks: ChunkRecords,
+  /// module identifier -> module hash
+  pub module_hashes: HashMap<String, String>,
+  /// runtime module identifier -> hash of the generated code
+  pub runtime_module_hashes: HashMap<String, String>,
+  /// chunk id -> runtime of the chunk
+  pub chunk_runtime: HashMap<String, Vec<String>>,
+  /// all runtime of entrypoints
+  pub runtime: Vec<String>,
+}
+
+impl CompilationRecords {
+  pub fn new(compilation: &Compilation) -> Self {
+    let mut records = Self::default();
+
+    for identifier in compilation.module_graph.modules().keys() {
+      if compilation
+        .chunk_graph
+        .get_number_of_module_chunks(*identifier)
+        == 0
+      {
+        continue;
+      }
+      if let Some(id) = compilation.chunk_graph.get_module_id(*identifier) {
+        records
+          .modules
+          .by_identifier
+          .insert(identifier.to_string(), id.clone());
+      }
+      if let Some(hash) = compilation.module_graph.get_module_hash(identifier) {
+        records
+          .module_hashes
+          .insert(identifier.to_string(), format!("{hash:x}"));
+      }
+    }
+
+    for (identifier, module) in &compilation.runtime_modules {
+      let content = module.generate(compilation).source().to_string();
+      records.runtime_module_hashes.insert(
+        identifier.to_string(),
+        format!("{:x}", crate::calc_hash(&content)),
+      );
+    }
+
+    let source_by_chunk = compilation
+      .chunk_graph
+      .split_point_module_identifier_to_chunk_ukey
+      .iter()
+      .map(|(module, chunk)| (*chunk, module))
+      .collect::<HashMap<_, _>>();
+    for (ukey, chunk) in compilation.chunk_by_ukey.iter() {
+      let Some(id) = &chunk.id else {
+        continue;
+      };
+      // only ids of normal chunks are revived, but HMR diffs the runtime of all chunks
+      if matches!(chunk.kind, ChunkKind::Normal) {
+        if let Some(name) = &chunk.name {
+          records.chunks.by_name.insert(name.clone(), id.clone());
+        } else if let Some(module) = source_by_chunk.get(ukey) {
+          records
+            .chunks
+            .by_source
+            .insert(module.to_string(), id.clone());
+        }
+      }
+      let mut runtime = chunk.runtime.iter().cloned().collect::<Vec<_>>();
+      runtime.sort_unstable();
+      records.chunk_runtime.insert(id.clone(), runtime);
+    }
+
+    let mut all_runtime: RuntimeSpec = Default::default();
+    for entrypoint_ukey in compilation.entrypoints.values() {
+      if let Some(entrypoint) = compilation.chunk_group_by_ukey.get(entrypoint_ukey) {
+        all_runtime.extend(entrypoint.runtime.clone());
+      }
+    }
+    records.runtime = all_runtime.into_iter().collect();
+    records.runtime.sort_unstable();
+
+    records
+  }
+
+  pub fn read(path: &Path) -> Result<Option<Self>> {
+    if !path.exists() {
+      return Ok(None);
+    }
+    let content = std::fs::read(path)?;
+    Ok(Some(serde_json::from_slice(&content).map_err(|e| {
+      rspack_error::internal_error!("Failed to parse records {}: {}", path.display(), e)
+    })?))
+  }
+
+  pub fn to_json_string(&self) -> Result<String> {
+    serde_json::to_string_pretty(self)
+      .map_err(|e| rspack_error::internal_error!("Failed to serialize records: {}", e))
+  }
+
+  pub fn module_id(&self, identifier: &ModuleIdentifier) -> Option<&str> {
+    self
+      .modules
+      .by_identifier
+      .get(identifier.as_str())
+      .map(|id| id.as_str())
+  }
+
+  /// Assign recorded ids to modules, modules with an id will be skipped by ids plugins.
+  pub fn revive_modules(&self, compilation: &mut Compilation) {
+    let mut used_ids = compilation
+      .module_graph
+      .modules()
+      .keys()
+      .filter_map(|identifier| compilation.chunk_graph.get_module_id(*identifier).clone())
+      .collect::<HashSet<_>>();
+    let identifiers = compilation
+      .module_graph
+      .modules()
+      .keys()
+      .copied()
+      .collect::<Vec<_>>();
+    for identifier in identifiers {
+      if compilation
+        .chunk_graph
+        .get_number_of_module_chunks(identifier)
+        == 0
+        || compilation.chunk_graph.get_module_id(identifier).is_some()
+      {
+        continue;
+      }
+      if let Some(id) = self.module_id(&identifier) && !used_ids.contains(id) {
+        used_ids.insert(id.to_string());
+        compilation
+          .chunk_graph
+          .set_module_id(identifier, id.to_string());
+      }
+    }
+  }
+
+  /// Assign recorded ids to chunks, named chunks will keep their names as ids.
+  pub fn revive_chunks(&self, compilation: &mut Compilation) {
+    let mut used_ids = compilation
+      .chunk_by_ukey
+      .values()
+      .filter_map(|chunk| chunk.id.clone().or_else(|| chunk.name.clone()))
+      .collect::<HashSet<_>>();
+    let source_by_chunk = compilation
+      .chunk_graph
+      .split_point_module_identifier_to_chunk_ukey
+      .iter()
+      .map(|(module, chunk)| (*chunk, *module))
+      .collect::<HashMap<_, _>>();
+    for chunk in compilation.chunk_by_ukey.values_mut() {
+      if chunk.id.is_some() {
+        continue;
+      }
+      let id = match &chunk.name {
+        Some(name) => self.chunks.by_name.get(name),
+        None => source_by_chunk
+          .get(&chunk.ukey)
+          .and_then(|module| self.chunks.by_source.get(module.as_str())),
+      };
+      if let Some(id) = id && (chunk.name.as_ref() == Some(id) || !used_ids.contains(id)) {
+        used_ids.insert(id.clone());
+        chunk.id = Some(id.clone());
+        chunk.ids = vec![id.clone()];
+      }
+    }
+  }
+}
@@ -16,10 +16,11 @@ use rustc_hash::FxHashMap as HashMap;
 use xxhash_rust::xxh3::Xxh3;
 
 use crate::{
-  contextify, stringify_map, AstOrSource, BoxModuleDependency, BuildContext, BuildInfo,
-  BuildResult, ChunkGraph, CodeGenerationResult, Compilation, ContextElementDependency,
-  DependencyCategory, DependencyType, GenerationResult, LibIdentOptions, Module, ModuleType,
-  Resolve, ResolveOptionsWithDependencyType, ResolverFactory, RuntimeGlobals, SourceType,
+  contextify, stringify_map, to_path, AstOrSource, BoxModuleDependency, BuildContext, BuildInfo,
+  BuildResult, ChunkGraph, ChunkGroupOptions, CodeGenerationResult, Compilation,
+  ContextElementDependency, DependencyCategory, DependencyType, GenerationResult, LibIdentOptions,
+  Module, ModuleType, Resolve, ResolveOptionsWithDependencyType, ResolverFactory, RuntimeGlobals,
+  SourceType,
 };
 
 #[derive(Debug, Clone)]
@@ -50,10 +51,14 @@ pub struct ContextOptions {
   pub recursive: bool,
   pub reg_exp: RspackRegex,
   pub reg_str: String, // generate context module id
-  pub include: Option<String>,
-  pub exclude: Option<String>,
+  /// Only the requests matching it are included
+  pub include: Option<RspackRegex>,
+  /// The requests matching it are excluded
+  pub exclude: Option<RspackRegex>,
   pub category: DependencyCategory,
   pub request: String,
+  /// Options of chunk groups created for lazy loaded modules of the context
+  pub group_options: Option<ChunkGroupOptions>,
 }
 
 impl Display for ContextOptions {
@@ -64,11 +69,15 @@ impl Display for ContextOptions {
       self.mode,
       self.recursive,
       self.reg_str,
-      self.include,
-      self.exclude,
+      self.include.as_ref().map(|r| r.to_source_string()),
+      self.exclude.as_ref().map(|r| r.to_source_string()),
       self.category,
       self.request
-    )
+    )?;
+    if let Some(name) = self.group_options.as_ref().and_then(|o| o.name.as_ref()) {
+      write!(f, " {name}")?;
+    }
+    Ok(())
   }
 }
 
@@ -77,9 +86,12 @@ impl PartialEq for ContextOptions {
     self.mode == other.mode
       && self.recursive == other.recursive
       && self.reg_str == other.reg_str
-      && self.include == other.include
-      && self.exclude == other.exclude
+      && self.include.as_ref().map(|r| r.to_source_string())
+        == other.include.as_ref().map(|r| r.to_source_string())
+      && self.exclude.as_ref().map(|r| r.to_source_string())
+        == other.exclude.as_ref().map(|r| r.to_source_string())
       && self.category == other.category
+      && self.group_options == other.group_options
   }
 }
 
@@ -90,9 +102,18 @@ impl Hash for ContextOptions {
     self.mode.hash(state);
     self.recursive.hash(state);
     self.reg_str.hash(state);
-    self.include.hash(state);
-    self.exclude.hash(state);
+    self
+      .include
+      .as_ref()
+      .map(|r| r.to_source_string())
+      .hash(state);
+    self
+      .exclude
+      .as_ref()
+      .map(|r| r.to_source_string())
+      .hash(state);
     self.category.hash(state);
+    self.group_options.hash(state);
   }
 }
 
@@ -320,6 +341,12 @@ impl Module for ContextModule {
       id.push_str(" recursive ");
     }
     id.push_str(&self.options.context_options.reg_str);
+    if let Some(include) = &self.options.context_options.include {
+      id.push_str(format!("|include: {}", include.to_source_string()).as_str());
+    }
+    if let Some(exclude) = &self.options.context_options.exclude {
+      id.push_str(format!("|exclude: {}", exclude.to_source_string()).as_str());
+    }
     Some(Cow::Owned(id))
   }
 
@@ -437,7 +464,27 @@ impl ContextModule {
             );
 
             requests.iter().for_each(|r| {
-              if options.context_options.reg_exp.test(&r.request) {
+              let context_options = &options.context_options;
+              if context_options.reg_exp.test(&r.request)
+                && context_options
+                  .include
+                  .as_ref()
+                  .map_or(true, |include| include.test(&r.request))
+                && !context_options
+                  .exclude
+                  .as_ref()
+                  .map_or(false, |exclude| exclude.test(&r.request))
+              {
+                let mut context_options = options.context_options.clone();
+                if let Some(name) = context_options
+                  .group_options
+                  .as_mut()
+                  .and_then(|o| o.name.as_mut())
+                {
+                  *name = name
+                    .replace("[index]", &dependencies.len().to_string())
+                    .replace("[request]", &to_path(&r.request));
+                }
                 dependencies.push(Box::new(ContextElementDependency {
                   id: None,
                   request: format!(
@@ -449,7 +496,7 @@ impl ContextModule {
                   user_request: r.request.to_string(),
                   category: options.context_options.category,
                   context: options.resource.clone(),
-                  options: options.context_options.clone(),
+                  options: context_options,
                 }));
               }
             })
@@ -1,8 +1,8 @@
 use rspack_error::Result;
 
 use crate::{
-  CodeGeneratable, CodeGeneratableResult, ContextOptions, Dependency, DependencyCategory,
-  DependencyId, DependencyType, ModuleDependency,
+  ChunkGroupOptions, CodeGeneratable, CodeGeneratableResult, ContextOptions, Dependency,
+  DependencyCategory, DependencyId, DependencyType, ModuleDependency,
 };
 
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
@@ -58,6 +58,10 @@ impl ModuleDependency for ContextElementDependency {
   fn options(&self) -> Option<&ContextOptions> {
     Some(&self.options)
   }
+
+  fn group_options(&self) -> Option<&ChunkGroupOptions> {
+    self.options.group_options.as_ref()
+  }
 }
 
 impl CodeGeneratable for ContextElementDependency {
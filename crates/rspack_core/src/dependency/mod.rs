@@ -7,9 +7,11 @@ pub use context_element_dependency::*;
 mod common_js_require_context_dependency;
 mod const_dependency;
 mod import_context_dependency;
+mod import_meta_context_dependency;
 pub use common_js_require_context_dependency::*;
 pub use const_dependency::ConstDependency;
 pub use import_context_dependency::*;
+pub use import_meta_context_dependency::ImportMetaContextDependency;
 mod require_context_dependency;
 use std::{
   any::Any,
@@ -23,7 +25,8 @@ mod static_exports_dependency;
 pub use static_exports_dependency::*;
 
 use crate::{
-  AsAny, ContextMode, ContextOptions, DynEq, DynHash, ErrorSpan, ModuleGraph, ModuleIdentifier,
+  AsAny, ChunkGroupOptions, ContextMode, ContextOptions, DynEq, DynHash, ErrorSpan, ModuleGraph,
+  ModuleIdentifier,
 };
 
 // Used to describe dependencies' types, see webpack's `type` getter in `Dependency`
@@ -41,8 +44,20 @@ pub enum DependencyType {
   DynamicImport,
   // cjs require
   CjsRequire,
+  // require.resolve and require.resolveWeak
+  RequireResolve,
+  // an item of `require.ensure([...], callback)`, or a require call in the callback
+  RequireEnsureItem,
+  // define([...], factory)
+  AmdDefine,
+  // require([...], callback)
+  AmdRequireArray,
+  // an item of the array in `define([...])` or `require([...])`
+  AmdRequireItem,
   // new URL("./foo", import.meta.url)
   NewUrl,
+  // new Worker(new URL("./foo", import.meta.url))
+  NewWorker,
   // import.meta.webpackHot.accept
   ImportMetaHotAccept,
   // import.meta.webpackHot.decline
@@ -65,12 +80,20 @@ pub enum DependencyType {
   CommonJSRequireContext,
   // require.context
   RequireContext,
+  // import.meta.webpackContext
+  ImportMetaContext,
   /// wasm import
   WasmImport,
   /// wasm export import
   WasmExportImported,
   /// static exports
   StaticExports,
+  /// module exposed by a container
+  ContainerExposed,
+  /// remote module to the external of its container
+  RemoteToExternal,
+  /// fallback of a consumed shared module
+  ConsumeSharedFallback,
 }
 
 #[derive(Default, Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -79,7 +102,9 @@ pub enum DependencyCategory {
   Unknown,
   Esm,
   CommonJS,
+  Amd,
   Url,
+  Worker,
   CssImport,
   CssCompose,
   Wasm,
@@ -90,7 +115,9 @@ impl From<&str> for DependencyCategory {
     match value {
       "esm" => Self::Esm,
       "commonjs" => Self::CommonJS,
+      "amd" => Self::Amd,
       "url" => Self::Url,
+      "worker" => Self::Worker,
       "wasm" => Self::Wasm,
       "css-import" => Self::CssImport,
       "css-compose" => Self::CssCompose,
@@ -105,7 +132,9 @@ impl Display for DependencyCategory {
       DependencyCategory::Unknown => write!(f, "unknown"),
       DependencyCategory::Esm => write!(f, "esm"),
       DependencyCategory::CommonJS => write!(f, "commonjs"),
+      DependencyCategory::Amd => write!(f, "amd"),
       DependencyCategory::Url => write!(f, "url"),
+      DependencyCategory::Worker => write!(f, "worker"),
       DependencyCategory::CssImport => write!(f, "css-import"),
       DependencyCategory::CssCompose => write!(f, "css-compose"),
       DependencyCategory::Wasm => write!(f, "wasm"),
@@ -243,6 +272,15 @@ pub trait ModuleDependency: Dependency {
   fn options(&self) -> Option<&ContextOptions> {
     None
   }
+  /// Options of the chunk group the referenced module is split into,
+  /// `None` if the dependency doesn't split the referenced module
+  fn group_options(&self) -> Option<&ChunkGroupOptions> {
+    None
+  }
+  /// A weak dependency doesn't add the referenced module to any chunk
+  fn weak(&self) -> bool {
+    false
+  }
 }
 
 impl ModuleDependency for Box<dyn ModuleDependency> {
@@ -261,6 +299,14 @@ impl ModuleDependency for Box<dyn ModuleDependency> {
   fn options(&self) -> Option<&ContextOptions> {
     (**self).options()
   }
+
+  fn group_options(&self) -> Option<&ChunkGroupOptions> {
+    (**self).group_options()
+  }
+
+  fn weak(&self) -> bool {
+    (**self).weak()
+  }
 }
 
 impl Dependency for Box<dyn ModuleDependency> {
@@ -332,7 +378,22 @@ pub type BoxModuleDependency = Box<dyn ModuleDependency>;
 pub type BoxDependency = Box<dyn Dependency>;
 
 pub fn is_async_dependency(dep: &BoxModuleDependency) -> bool {
-  if matches!(dep.dependency_type(), DependencyType::DynamicImport) {
+  if matches!(
+    dep.dependency_type(),
+    DependencyType::DynamicImport
+      | DependencyType::AmdRequireItem
+      | DependencyType::RequireEnsureItem
+      | DependencyType::ConsumeSharedFallback
+  ) {
+    // `import()` in eager or weak mode doesn't split the imported module,
+    // only the items of `require([...])` and `require.ensure` are loaded on demand,
+    // and fallbacks of shared modules consumed eagerly are in the same chunk
+    return dep.group_options().is_some();
+  }
+  if matches!(
+    dep.dependency_type(),
+    DependencyType::NewWorker | DependencyType::ContainerExposed
+  ) {
     return true;
   }
   if matches!(dep.dependency_type(), DependencyType::ContextElement) {
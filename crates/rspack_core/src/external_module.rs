@@ -1,28 +1,105 @@
 use std::borrow::Cow;
+use std::fmt;
 use std::hash::Hash;
 
-use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_error::{internal_error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_identifier::{Identifiable, Identifier};
+use rustc_hash::FxHashMap as HashMap;
 
 use crate::{
+  json_string,
   rspack_sources::{BoxSource, RawSource, Source, SourceExt},
-  to_identifier, AstOrSource, BuildContext, BuildResult, CodeGenerationResult, Compilation,
-  Context, ExternalType, GenerationResult, LibIdentOptions, Module, ModuleType, SourceType,
+  to_identifier, AstOrSource, BuildContext, BuildMeta, BuildResult, CodeGenerationResult,
+  Compilation, Context, ExternalType, GenerationResult, LibIdentOptions, Module, ModuleType,
+  RuntimeGlobals, SourceType,
 };
 
 static EXTERNAL_MODULE_SOURCE_TYPES: &[SourceType] = &[SourceType::JavaScript];
 
+/// Key of [CodeGenerationResult::data] holding the static import statement of a "module" external,
+/// which should be hoisted to the top level of the chunk when `output.module` is enabled.
+pub const EXTERNAL_MODULE_IMPORT_DATA_KEY: &str = "external_module_import";
+
+/// Split `global@url` of "script" externals into `[url, global]`
+fn extract_url_and_global(url_and_global: &str) -> Result<Vec<String>> {
+  match url_and_global.find('@') {
+    Some(index) if index > 0 && index < url_and_global.len() - 1 => Ok(vec![
+      url_and_global[index + 1..].to_string(),
+      url_and_global[..index].to_string(),
+    ]),
+    _ => Err(internal_error!(
+      "Invalid request \"{url_and_global}\" of script external, expected \"global@url\""
+    )),
+  }
+}
+
+/// `["a"]["b"]` of `["a", "b"]`
+pub fn property_access(properties: &[String]) -> String {
+  properties
+    .iter()
+    .map(|p| format!("[{}]", json_string(p)))
+    .collect()
+}
+
+/// Request of an external module, the first item of each request is the module
+/// and the rest are property paths accessed from it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExternalRequest {
+  /// `"lodash"` or `["lodash", "merge"]`
+  Single(Vec<String>),
+  /// `{ root: "_", commonjs: "lodash" }`, request of each library type
+  Map(HashMap<String, Vec<String>>),
+}
+
+impl ExternalRequest {
+  /// Request of the given library type, [ExternalRequest::Single] is shared by all types.
+  pub fn for_type(&self, r#type: &str) -> Option<&[String]> {
+    match self {
+      Self::Single(request) => Some(request),
+      Self::Map(map) => map.get(r#type).map(|r| r.as_slice()),
+    }
+  }
+
+  /// The module part of the request for the given library type
+  pub fn primary(&self, r#type: &str) -> Option<&str> {
+    self
+      .for_type(r#type)
+      .and_then(|r| r.first())
+      .map(|r| r.as_str())
+  }
+}
+
+impl From<String> for ExternalRequest {
+  fn from(value: String) -> Self {
+    Self::Single(vec![value])
+  }
+}
+
+impl fmt::Display for ExternalRequest {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Single(request) if request.len() == 1 => write!(f, "{}", request[0]),
+      Self::Single(request) => write!(f, "{}", serde_json::json!(request)),
+      Self::Map(map) => {
+        // sort keys to keep the identifier stable
+        let map = map.iter().collect::<std::collections::BTreeMap<_, _>>();
+        write!(f, "{}", serde_json::json!(map))
+      }
+    }
+  }
+}
+
 #[derive(Debug)]
 pub struct ExternalModule {
   id: Identifier,
-  pub request: String,
+  pub request: ExternalRequest,
   external_type: ExternalType,
   /// Request intended by user (without loaders from config)
   user_request: String,
 }
 
 impl ExternalModule {
-  pub fn new(request: String, external_type: ExternalType, user_request: String) -> Self {
+  pub fn new(request: ExternalRequest, external_type: ExternalType, user_request: String) -> Self {
     Self {
       id: Identifier::from(format!("external {external_type} {request}")),
       request,
@@ -31,47 +108,147 @@ impl ExternalModule {
     }
   }
 
-  pub fn get_source(&self, compilation: &Compilation) -> BoxSource {
+  pub fn external_type(&self) -> &ExternalType {
+    &self.external_type
+  }
+
+  pub fn get_source(&self, compilation: &Compilation) -> Result<(BoxSource, RuntimeGlobals)> {
+    let mut runtime_requirements = RuntimeGlobals::default();
+    if matches!(
+      self.external_type.as_str(),
+      "amd" | "amd-require" | "umd" | "umd2" | "system" | "jsonp"
+    ) {
+      // these externals are passed in by the library wrapper, which picks the request by itself
+      let id = compilation
+        .module_graph
+        .module_graph_module_by_identifier(&self.identifier())
+        .map(|m| m.id(&compilation.chunk_graph))
+        .unwrap_or_default();
+      let source = format!(
+        "module.exports = __WEBPACK_EXTERNAL_MODULE_{}__",
+        to_identifier(id)
+      );
+      return Ok((RawSource::from(source).boxed(), runtime_requirements));
+    }
+
+    let request = self.request.for_type(&self.external_type).ok_or_else(|| {
+      internal_error!(
+        "Missing external configuration for type \"{}\" of external {}",
+        self.external_type,
+        self.request
+      )
+    })?;
+    let (module, properties) = request
+      .split_first()
+      .ok_or_else(|| internal_error!("Empty request of external {}", self.user_request))?;
     let source = match self.external_type.as_str() {
       "this" => format!(
-        "module.exports = (function() {{ return this['{}']; }}())",
-        self.request
+        "module.exports = (function() {{ return this{}; }}())",
+        property_access(request)
       ),
       "window" | "self" => format!(
-        "module.exports = {}['{}']",
-        self.external_type, self.request
+        "module.exports = {}{}",
+        self.external_type,
+        property_access(request)
       ),
       "global" => format!(
-        "module.exports = {}['{}']",
-        compilation.options.output.global_object, self.request
+        "module.exports = {}{}",
+        compilation.options.output.global_object,
+        property_access(request)
       ),
       "commonjs" | "commonjs2" | "commonjs-module" | "commonjs-static" => {
-        format!("module.exports = require('{}')", self.request)
-      }
-      "amd" | "amd-require" | "umd" | "umd2" | "system" | "jsonp" => {
-        let id = compilation
-          .module_graph
-          .module_graph_module_by_identifier(&self.identifier())
-          .map(|m| m.id(&compilation.chunk_graph))
-          .unwrap_or_default();
         format!(
-          "module.exports = __WEBPACK_EXTERNAL_MODULE_{}__",
-          to_identifier(id)
+          "module.exports = require('{module}'){}",
+          property_access(properties)
         )
       }
-      "import" => {
+      "import" => self.get_import_source(compilation, module, properties)?,
+      "var" | "promise" | "const" | "let" | "assign" => {
+        format!("module.exports = {module}{}", property_access(properties))
+      }
+      "script" => {
+        let url_and_global = if properties.is_empty() {
+          extract_url_and_global(module)?
+        } else {
+          request.to_vec()
+        };
+        let (url, global) = (&url_and_global[0], &url_and_global[1]);
+        runtime_requirements.insert(RuntimeGlobals::LOAD_SCRIPT);
         format!(
-          "module.exports = {}('{}')",
-          compilation.options.output.import_function_name, self.request
+          r#"var __webpack_error__ = new Error();
+module.exports = new Promise(function(resolve, reject) {{
+  if(typeof {global} !== "undefined") return resolve();
+  {load_script}({url}, function(event) {{
+    if(typeof {global} !== "undefined") return resolve();
+    var errorType = event && (event.type === 'load' ? 'missing' : event.type);
+    var realSrc = event && event.target && event.target.src;
+    __webpack_error__.message = 'Loading script failed.\n(' + errorType + ': ' + realSrc + ')';
+    __webpack_error__.name = 'ScriptExternalLoadError';
+    __webpack_error__.type = errorType;
+    __webpack_error__.request = realSrc;
+    reject(__webpack_error__);
+  }}, {key});
+}}).then(function() {{ return {global}{properties}; }})"#,
+          load_script = RuntimeGlobals::LOAD_SCRIPT,
+          url = json_string(url),
+          key = json_string(global),
+          properties = property_access(&url_and_global[2..]),
         )
       }
-      "var" | "promise" | "const" | "let" | "assign" => {
-        format!("module.exports = {}", self.request)
+      "module" => {
+        if compilation.options.output.module {
+          format!(
+            "module.exports = __WEBPACK_EXTERNAL_MODULE_{}__{}",
+            to_identifier(module),
+            property_access(properties)
+          )
+        } else {
+          self.get_import_source(compilation, module, properties)?
+        }
+      }
+      r#type => {
+        return Err(internal_error!(
+          "Unsupported external type \"{type}\" of external {}",
+          self.request
+        ))
       }
-      // TODO "script" "module"
-      _ => "".to_string(),
     };
-    RawSource::from(source).boxed()
+    Ok((RawSource::from(source).boxed(), runtime_requirements))
+  }
+
+  /// `import("xxx")` for "import" externals and "module" externals without esm output
+  fn get_import_source(
+    &self,
+    compilation: &Compilation,
+    module: &str,
+    properties: &[String],
+  ) -> Result<String> {
+    let import = format!(
+      "{}({})",
+      compilation.options.output.import_function_name,
+      json_string(module)
+    );
+    Ok(if properties.is_empty() {
+      format!("module.exports = {import}")
+    } else {
+      format!(
+        "module.exports = {import}.then(function(module) {{ return module{}; }})",
+        property_access(properties)
+      )
+    })
+  }
+
+  /// `import * as __WEBPACK_EXTERNAL_MODULE_xxx__ from "xxx"` for "module" externals in esm output
+  fn get_module_import(&self, compilation: &Compilation) -> Option<String> {
+    if self.external_type != "module" || !compilation.options.output.module {
+      return None;
+    }
+    let module = self.request.primary(&self.external_type)?;
+    Some(format!(
+      "import * as __WEBPACK_EXTERNAL_MODULE_{}__ from {};\n",
+      to_identifier(module),
+      json_string(module)
+    ))
   }
 }
 
@@ -107,18 +284,40 @@ impl Module for ExternalModule {
 
   async fn build(
     &mut self,
-    _build_context: BuildContext<'_>,
+    build_context: BuildContext<'_>,
   ) -> Result<TWithDiagnosticArray<BuildResult>> {
-    Ok(BuildResult::default().with_empty_diagnostic())
+    // script externals and module externals without esm output are loaded asynchronously
+    let is_async = match self.external_type.as_str() {
+      "script" => true,
+      "module" => !build_context.compiler_options.output.module,
+      _ => false,
+    };
+    Ok(
+      BuildResult {
+        build_meta: BuildMeta {
+          is_async,
+          ..Default::default()
+        },
+        ..Default::default()
+      }
+      .with_empty_diagnostic(),
+    )
   }
 
   fn code_generation(&self, compilation: &Compilation) -> Result<CodeGenerationResult> {
     let mut cgr = CodeGenerationResult::default();
+    let (source, runtime_requirements) = self.get_source(compilation)?;
 
     cgr.add(
       SourceType::JavaScript,
-      GenerationResult::from(AstOrSource::from(self.get_source(compilation))),
+      GenerationResult::from(AstOrSource::from(source)),
     );
+    cgr.runtime_requirements.insert(runtime_requirements);
+    if let Some(module_import) = self.get_module_import(compilation) {
+      cgr
+        .data
+        .insert(EXTERNAL_MODULE_IMPORT_DATA_KEY.to_string(), module_import);
+    }
 
     Ok(cgr)
   }
@@ -7,6 +7,7 @@
 use std::{fmt, sync::Arc};
 
 use rspack_database::Database;
+use serde::{Deserialize, Serialize};
 pub mod external_module;
 pub use external_module::*;
 pub mod ast;
@@ -73,12 +74,14 @@ pub mod tree_shaking;
 
 pub use rspack_sources;
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SourceType {
   JavaScript,
   Css,
   Wasm,
   Asset,
+  Remote,
+  ConsumeShared,
   #[default]
   Unknown,
 }
@@ -0,0 +1,250 @@
+use std::collections::BTreeMap;
+
+use rspack_identifier::IdentifierSet;
+use swc_core::ecma::atoms::JsWord;
+
+use crate::{RuntimeSpec, RuntimeSpecMap};
+
+/// How an export is used, same as `UsageState` of webpack.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UsageState {
+  Unused,
+  /// No usage information is collected, the export should be treated as used
+  #[default]
+  Unknown,
+  Used,
+}
+
+/// Exports provided by a module
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProvidedExports {
+  /// The module may provide exports which are unknown statically, e.g. CommonJS modules
+  Unknown,
+  Names(Vec<JsWord>),
+}
+
+/// Exports of a module used by other modules
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UsedExports {
+  /// Any export of the module may be used
+  Unknown,
+  Names(Vec<JsWord>),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ExportInfo {
+  /// `Some(true)` if the export is provided, `Some(false)` if it's not, `None` if unknown
+  pub provided: Option<bool>,
+  /// Whether the name of the export can be changed, from the side of the module providing it
+  pub can_mangle_provide: Option<bool>,
+  /// Whether the name of the export can be changed, from the side of modules using it
+  pub can_mangle_use: Option<bool>,
+  used: UsageState,
+  used_in_runtime: RuntimeSpecMap<UsageState>,
+  /// Modules using the export, from which usage in each runtime is computed after chunks are created,
+  /// `None` if the export may be used by any module
+  pub(crate) used_by: Option<IdentifierSet>,
+}
+
+impl ExportInfo {
+  /// Usage of the export in `runtime`, or in any runtime if `runtime` is `None`
+  pub fn get_used(&self, runtime: Option<&RuntimeSpec>) -> UsageState {
+    runtime
+      .and_then(|runtime| self.used_in_runtime.get(runtime))
+      .copied()
+      .unwrap_or(self.used)
+  }
+
+  /// Sets usage of the export in `runtime`, or in all runtimes if `runtime` is `None`
+  pub fn set_used(&mut self, used: UsageState, runtime: Option<&RuntimeSpec>) {
+    match runtime {
+      Some(runtime) => {
+        self.used_in_runtime.set(runtime.clone(), used);
+        self.used = self
+          .used_in_runtime
+          .get_values()
+          .into_iter()
+          .copied()
+          .max()
+          .unwrap_or(used);
+      }
+      None => {
+        self.used = used;
+        self.used_in_runtime = Default::default();
+      }
+    }
+  }
+
+  pub fn can_mangle(&self) -> bool {
+    self.can_mangle_provide == Some(true) && self.can_mangle_use == Some(true)
+  }
+}
+
+/// Exports of a module and how they are used, same as `ExportsInfo` of webpack.
+#[derive(Debug, Clone, Default)]
+pub struct ExportsInfo {
+  exports: BTreeMap<JsWord, ExportInfo>,
+  /// Info of exports which are not listed in `exports`
+  other_exports_info: ExportInfo,
+}
+
+impl ExportsInfo {
+  pub fn exports(&self) -> impl Iterator<Item = (&JsWord, &ExportInfo)> {
+    self.exports.iter()
+  }
+
+  pub fn exports_mut(&mut self) -> impl Iterator<Item = (&JsWord, &mut ExportInfo)> {
+    self.exports.iter_mut()
+  }
+
+  pub fn other_exports_info(&self) -> &ExportInfo {
+    &self.other_exports_info
+  }
+
+  pub fn other_exports_info_mut(&mut self) -> &mut ExportInfo {
+    &mut self.other_exports_info
+  }
+
+  /// Info of the export, falls back to info of other exports if it's not listed
+  pub fn get_export_info(&self, name: &JsWord) -> &ExportInfo {
+    self.exports.get(name).unwrap_or(&self.other_exports_info)
+  }
+
+  pub fn get_export_info_mut(&mut self, name: &JsWord) -> &mut ExportInfo {
+    let other_exports_info = &self.other_exports_info;
+    self
+      .exports
+      .entry(name.clone())
+      .or_insert_with(|| other_exports_info.clone())
+  }
+
+  pub fn get_provided_exports(&self) -> ProvidedExports {
+    if self.other_exports_info.provided != Some(false) {
+      return ProvidedExports::Unknown;
+    }
+    ProvidedExports::Names(
+      self
+        .exports
+        .iter()
+        .filter(|(_, info)| info.provided != Some(false))
+        .map(|(name, _)| name.clone())
+        .collect(),
+    )
+  }
+
+  /// Exports known to be provided, which are listed even if the module may provide other exports
+  pub fn get_known_provided_exports(&self) -> Vec<JsWord> {
+    self
+      .exports
+      .iter()
+      .filter(|(_, info)| info.provided == Some(true))
+      .map(|(name, _)| name.clone())
+      .collect()
+  }
+
+  pub fn get_used_exports(&self, runtime: Option<&RuntimeSpec>) -> UsedExports {
+    if self.other_exports_info.get_used(runtime) != UsageState::Unused {
+      return UsedExports::Unknown;
+    }
+    UsedExports::Names(
+      self
+        .exports
+        .iter()
+        .filter(|(_, info)| info.get_used(runtime) != UsageState::Unused)
+        .map(|(name, _)| name.clone())
+        .collect(),
+    )
+  }
+
+  pub fn is_export_provided(&self, name: &JsWord) -> Option<bool> {
+    self.get_export_info(name).provided
+  }
+
+  pub fn get_used(&self, name: &JsWord, runtime: Option<&RuntimeSpec>) -> UsageState {
+    self.get_export_info(name).get_used(runtime)
+  }
+
+  /// Whether any export of the module is used
+  pub fn is_used(&self, runtime: Option<&RuntimeSpec>) -> bool {
+    self.other_exports_info.get_used(runtime) != UsageState::Unused
+      || self
+        .exports
+        .values()
+        .any(|info| info.get_used(runtime) != UsageState::Unused)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn runtime(name: &str) -> RuntimeSpec {
+    RuntimeSpec::from_iter([name.to_string()])
+  }
+
+  #[test]
+  fn provided_and_used_exports() {
+    let mut exports_info = ExportsInfo::default();
+    assert_eq!(
+      exports_info.get_provided_exports(),
+      ProvidedExports::Unknown
+    );
+    assert_eq!(exports_info.get_used_exports(None), UsedExports::Unknown);
+
+    exports_info.other_exports_info_mut().provided = Some(false);
+    exports_info
+      .other_exports_info_mut()
+      .set_used(UsageState::Unused, None);
+    for (name, used) in [("a", UsageState::Used), ("b", UsageState::Unused)] {
+      let export_info = exports_info.get_export_info_mut(&name.into());
+      export_info.provided = Some(true);
+      export_info.set_used(used, None);
+    }
+    assert_eq!(
+      exports_info.get_provided_exports(),
+      ProvidedExports::Names(vec!["a".into(), "b".into()])
+    );
+    assert_eq!(
+      exports_info.get_used_exports(None),
+      UsedExports::Names(vec!["a".into()])
+    );
+    assert_eq!(exports_info.is_export_provided(&"c".into()), Some(false));
+  }
+
+  #[test]
+  fn known_provided_exports_of_unknown_exports() {
+    let mut exports_info = ExportsInfo::default();
+    exports_info.get_export_info_mut(&"a".into()).provided = Some(true);
+    assert_eq!(
+      exports_info.get_provided_exports(),
+      ProvidedExports::Unknown
+    );
+    assert_eq!(
+      exports_info.get_known_provided_exports(),
+      vec![JsWord::from("a")]
+    );
+    assert_eq!(exports_info.is_export_provided(&"b".into()), None);
+  }
+
+  #[test]
+  fn used_in_runtime() {
+    let mut export_info = ExportInfo::default();
+    export_info.set_used(UsageState::Unused, Some(&runtime("main")));
+    export_info.set_used(UsageState::Used, Some(&runtime("worker")));
+    assert_eq!(
+      export_info.get_used(Some(&runtime("main"))),
+      UsageState::Unused
+    );
+    assert_eq!(
+      export_info.get_used(Some(&runtime("worker"))),
+      UsageState::Used
+    );
+    assert_eq!(export_info.get_used(None), UsageState::Used);
+
+    export_info.set_used(UsageState::Unused, None);
+    assert_eq!(
+      export_info.get_used(Some(&runtime("worker"))),
+      UsageState::Unused
+    );
+  }
+}
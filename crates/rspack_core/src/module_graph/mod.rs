@@ -8,10 +8,12 @@ use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 mod connection;
 pub use connection::{ConnectionId, ModuleGraphConnection};
+mod exports_info;
+pub use exports_info::*;
 
 use crate::{
   BoxModule, BoxModuleDependency, BuildInfo, BuildMeta, DependencyId, Module, ModuleGraphModule,
-  ModuleIdentifier,
+  ModuleIdentifier, RuntimeSpec,
 };
 
 #[derive(Debug, Default)]
@@ -37,6 +39,9 @@ pub struct ModuleGraph {
 
   /// Module graph connections table index for `ConnectionId`
   connections_map: HashMap<ModuleGraphConnection, ConnectionId>,
+
+  /// Exports info of modules, modules without it provide and use unknown exports
+  exports_info_map: IdentifierMap<ExportsInfo>,
 }
 
 impl ModuleGraph {
@@ -340,6 +345,40 @@ impl ModuleGraph {
       .unwrap_or_default()
   }
 
+  pub fn get_exports_info(&self, module_identifier: &ModuleIdentifier) -> Option<&ExportsInfo> {
+    self.exports_info_map.get(module_identifier)
+  }
+
+  pub fn get_exports_info_mut(&mut self, module_identifier: &ModuleIdentifier) -> &mut ExportsInfo {
+    self.exports_info_map.entry(*module_identifier).or_default()
+  }
+
+  pub fn get_provided_exports(&self, module_identifier: &ModuleIdentifier) -> ProvidedExports {
+    self
+      .get_exports_info(module_identifier)
+      .map_or(ProvidedExports::Unknown, |info| info.get_provided_exports())
+  }
+
+  pub fn get_used_exports(
+    &self,
+    module_identifier: &ModuleIdentifier,
+    runtime: Option<&RuntimeSpec>,
+  ) -> UsedExports {
+    self
+      .get_exports_info(module_identifier)
+      .map_or(UsedExports::Unknown, |info| info.get_used_exports(runtime))
+  }
+
+  pub fn exports_info_iter_mut(
+    &mut self,
+  ) -> impl Iterator<Item = (&ModuleIdentifier, &mut ExportsInfo)> {
+    self.exports_info_map.iter_mut()
+  }
+
+  pub fn clear_exports_info(&mut self) {
+    self.exports_info_map.clear();
+  }
+
   /// Remove a connection and return connection origin module identifier and dependency
   fn revoke_connection(&mut self, connection_id: ConnectionId) -> Option<DependencyId> {
     let connection = match self.connections[*connection_id].take() {
@@ -386,6 +425,7 @@ impl ModuleGraph {
   /// Remove module from module graph and return parent module identifier and dependency pair
   pub fn revoke_module(&mut self, module_identifier: &ModuleIdentifier) -> Vec<DependencyId> {
     self.module_identifier_to_module.remove(module_identifier);
+    self.exports_info_map.remove(module_identifier);
     let mgm = self
       .module_identifier_to_module_graph_module
       .remove(module_identifier);
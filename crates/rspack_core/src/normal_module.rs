@@ -15,7 +15,7 @@ use rspack_error::{
   internal_error, Diagnostic, IntoTWithDiagnosticArray, Result, Severity, TWithDiagnosticArray,
 };
 use rspack_identifier::Identifiable;
-use rspack_loader_runner::{Content, ResourceData};
+use rspack_loader_runner::{Content, LoaderResult, ResourceData};
 use rspack_sources::{
   BoxSource, CachedSource, OriginalSource, RawSource, Source, SourceExt, SourceMap,
   SourceMapSource, WithoutOriginalOptions,
@@ -26,11 +26,12 @@ use xxhash_rust::xxh3::Xxh3;
 
 use crate::{
   contextify, is_async_dependency, module_graph::ConnectionId, AssetGeneratorOptions,
-  AssetParserOptions, BoxLoader, BoxModule, BuildContext, BuildInfo, BuildMeta, BuildResult,
-  ChunkGraph, CodeGenerationResult, Compilation, CompilerOptions, Context, Dependency,
-  DependencyId, FactoryMeta, GenerateContext, LibIdentOptions, Module, ModuleAst, ModuleDependency,
-  ModuleGraph, ModuleGraphConnection, ModuleIdentifier, ModuleType, ParseContext, ParseResult,
-  ParserAndGenerator, Resolve, RuntimeGlobals, SourceType,
+  AssetParserOptions, BoxLoader, BoxModule, BoxModuleDependency, BuildContext, BuildInfo,
+  BuildMeta, BuildResult, CacheOptions, ChunkGraph, CodeGenerationResult, Compilation,
+  CompilerOptions, Context, Dependency, DependencyId, FactoryMeta, GenerateContext,
+  LibIdentOptions, Module, ModuleAst, ModuleDependency, ModuleGraph, ModuleGraphConnection,
+  ModuleIdentifier, ModuleType, ParseContext, ParseResult, ParserAndGenerator, Resolve,
+  RuntimeGlobals, SourceType,
 };
 
 bitflags! {
@@ -186,7 +187,10 @@ impl ModuleGraphModule {
     self
       .dependencies
       .iter()
-      .filter(|id| !is_async_dependency(module_graph.dependency_by_id(id).expect("should have id")))
+      .filter(|id| {
+        let dependency = module_graph.dependency_by_id(id).expect("should have id");
+        !is_async_dependency(dependency) && !dependency.weak()
+      })
       .filter_map(|id| module_graph.module_identifier_by_dependency_id(id))
       .collect()
   }
@@ -195,11 +199,30 @@ impl ModuleGraphModule {
     &self,
     module_graph: &'a ModuleGraph,
   ) -> Vec<&'a ModuleIdentifier> {
+    self
+      .dynamic_dependencies(module_graph)
+      .into_iter()
+      .map(|(_, module_identifier)| module_identifier)
+      .collect()
+  }
+
+  /// Async dependencies with the modules they reference
+  pub fn dynamic_dependencies<'a>(
+    &self,
+    module_graph: &'a ModuleGraph,
+  ) -> Vec<(&'a BoxModuleDependency, &'a ModuleIdentifier)> {
     self
       .dependencies
       .iter()
-      .filter(|id| is_async_dependency(module_graph.dependency_by_id(id).expect("should have id")))
-      .filter_map(|id| module_graph.module_identifier_by_dependency_id(id))
+      .filter_map(|id| {
+        let dependency = module_graph.dependency_by_id(id).expect("should have id");
+        if !is_async_dependency(dependency) || dependency.weak() {
+          return None;
+        }
+        module_graph
+          .module_identifier_by_dependency_id(id)
+          .map(|module_identifier| (dependency, module_identifier))
+      })
       .collect()
   }
 
@@ -335,6 +358,9 @@ pub struct NormalModule {
 
   code_generation_dependencies: Option<Vec<Box<dyn ModuleDependency>>>,
   presentational_dependencies: Option<Vec<Box<dyn Dependency>>>,
+
+  /// Result of loaders, restored from the cache to skip them or kept to be cached after build
+  loader_result: Option<LoaderResult>,
 }
 
 #[derive(Debug)]
@@ -399,6 +425,7 @@ impl NormalModule {
       cached_source_sizes: DashMap::default(),
       code_generation_dependencies: None,
       presentational_dependencies: None,
+      loader_result: None,
     }
   }
 
@@ -406,6 +433,10 @@ impl NormalModule {
     &self.resource_data
   }
 
+  pub fn resource_resolved_data_mut(&mut self) -> &mut ResourceData {
+    &mut self.resource_data
+  }
+
   pub fn request(&self) -> &str {
     &self.request
   }
@@ -441,6 +472,14 @@ impl NormalModule {
   pub fn ast_or_source_mut(&mut self) -> &mut NormalModuleAstOrSource {
     &mut self.ast_or_source
   }
+
+  pub fn set_loader_result(&mut self, loader_result: LoaderResult) {
+    self.loader_result = Some(loader_result);
+  }
+
+  pub fn take_loader_result(&mut self) -> Option<LoaderResult> {
+    self.loader_result.take()
+  }
 }
 
 impl Identifiable for NormalModule {
@@ -485,13 +524,19 @@ impl Module for NormalModule {
     let mut build_info = Default::default();
     let mut build_meta = Default::default();
     let mut diagnostics = Vec::new();
-    let loader_result = build_context
-      .loader_runner_runner
-      .run(
-        self.resource_data.clone(),
-        self.loaders.iter().map(|i| i.as_ref()).collect::<Vec<_>>(),
-      )
-      .await;
+    let loader_result = match self.loader_result.take() {
+      // restored from the cache, the content is parsed again without running loaders
+      Some(loader_result) => Ok(loader_result.with_empty_diagnostic()),
+      None => {
+        build_context
+          .loader_runner_runner
+          .run(
+            self.resource_data.clone(),
+            self.loaders.iter().map(|i| i.as_ref()).collect::<Vec<_>>(),
+          )
+          .await
+      }
+    };
     let (loader_result, ds) = match loader_result {
       Ok(r) => r.split_into_parts(),
       Err(e) => {
@@ -499,6 +544,13 @@ impl Module for NormalModule {
         return Ok(BuildResult::default().with_diagnostic(e.into()));
       }
     };
+    // diagnostics of loaders are not cached, keep the result only if there are none
+    if ds.is_empty()
+      && loader_result.cacheable
+      && !matches!(build_context.compiler_options.cache, CacheOptions::Disabled)
+    {
+      self.loader_result = Some(loader_result.clone());
+    }
     diagnostics.extend(ds);
 
     let original_source = self.create_source(loader_result.content, loader_result.source_map)?;
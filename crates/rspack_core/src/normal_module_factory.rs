@@ -5,13 +5,15 @@ use std::{
 
 use rspack_error::{internal_error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_identifier::Identifiable;
+use serde::{Deserialize, Serialize};
 use swc_core::common::Span;
 
 use crate::{
-  cache::Cache, module_rule_matcher, resolve, AssetGeneratorOptions, AssetParserOptions,
-  CompilerOptions, Dependency, DependencyCategory, FactorizeArgs, FactoryMeta, MissingModule,
-  ModuleArgs, ModuleDependency, ModuleExt, ModuleFactory, ModuleFactoryCreateData,
+  cache::Cache, module_rule_matcher, resolve, resolve_description, AssetGeneratorOptions,
+  AssetParserOptions, CompilerOptions, Dependency, DependencyCategory, FactorizeArgs, FactoryMeta,
+  MissingModule, ModuleArgs, ModuleDependency, ModuleExt, ModuleFactory, ModuleFactoryCreateData,
   ModuleFactoryResult, ModuleIdentifier, ModuleRule, ModuleType, NormalModule,
+  NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs,
   NormalModuleFactoryResolveForSchemeArgs, RawModule, Resolve, ResolveArgs, ResolveError,
   ResolveResult, ResourceData, SharedPluginDriver,
 };
@@ -59,7 +61,27 @@ impl NormalModuleFactory {
     } else {
       PathBuf::from(self.context.options.context.as_path())
     };
-    let specifier = data.dependency.request();
+    let mut before_resolve_args = NormalModuleBeforeResolveArgs {
+      request: data.dependency.request().to_owned(),
+      context: data.context,
+    };
+    let before_resolve_result = self
+      .plugin_driver
+      .read()
+      .await
+      .before_resolve(&mut before_resolve_args)
+      .await?;
+    if let Some(false) = before_resolve_result {
+      return Ok(Some(self.create_ignored_module(
+        &importer_with_context,
+        &before_resolve_args.request,
+      )));
+    }
+    let NormalModuleBeforeResolveArgs {
+      request: specifier,
+      context,
+    } = before_resolve_args;
+    let specifier = specifier.as_str();
     if should_skip_resolve(specifier) {
       return Ok(None);
     }
@@ -69,7 +91,7 @@ impl NormalModuleFactory {
 
     let resolve_args = ResolveArgs {
       importer,
-      context: data.context,
+      context: context.clone(),
       specifier,
       dependency_type: data.dependency.dependency_type(),
       dependency_category: data.dependency.category(),
@@ -122,10 +144,26 @@ impl NormalModuleFactory {
       }
     } else {
       // default resolve
+      let resolve_options = resolve_args.resolve_options.clone();
+      let dependency_type = *resolve_args.dependency_type;
+      let dependency_category = *resolve_args.dependency_category;
       let resource_data = self
         .cache
         .resolve_module_occasion
-        .use_cache(resolve_args, |args| resolve(args, plugin_driver))
+        .use_cache(
+          resolve_args,
+          |args| resolve(args, plugin_driver),
+          |path| async move {
+            resolve_description(
+              &path,
+              resolve_options,
+              dependency_type,
+              dependency_category,
+              plugin_driver,
+            )
+            .await
+          },
+        )
         .await;
       match resource_data {
         Ok(ResolveResult::Resource(resource)) => {
@@ -139,20 +177,8 @@ impl NormalModuleFactory {
           }
         }
         Ok(ResolveResult::Ignored) => {
-          let ident = format!("{}/{}", importer_with_context.display(), specifier);
-          let module_identifier = ModuleIdentifier::from(format!("ignored|{ident}"));
-
-          let raw_module = RawModule::new(
-            "/* (ignored) */".to_owned(),
-            module_identifier,
-            format!("{ident} (ignored)"),
-            Default::default(),
-          )
-          .boxed();
-          self.context.module_type = Some(*raw_module.module_type());
-
           return Ok(Some(
-            ModuleFactoryResult::new(raw_module).with_empty_diagnostic(),
+            self.create_ignored_module(&importer_with_context, specifier),
           ));
         }
         Err(ResolveError(runtime_error, internal_error)) => {
@@ -172,6 +198,22 @@ impl NormalModuleFactory {
         }
       }
     };
+    let after_resolve_result = self
+      .plugin_driver
+      .read()
+      .await
+      .after_resolve(NormalModuleAfterResolveArgs {
+        request: specifier,
+        context: context.as_deref(),
+        resource_data: &resource_data,
+      })
+      .await?;
+    if let Some(false) = after_resolve_result {
+      return Ok(Some(
+        self.create_ignored_module(&importer_with_context, specifier),
+      ));
+    }
+
     //TODO: with contextScheme
     let resolved_module_rules = self
       .calculate_module_rules(&resource_data, data.dependency.category())
@@ -223,7 +265,7 @@ impl NormalModuleFactory {
     let normal_module = NormalModule::new(
       request,
       user_request,
-      data.dependency.request().to_owned(),
+      specifier.to_owned(),
       resolved_module_type,
       resolved_parser_and_generator,
       resolved_parser_options,
@@ -260,6 +302,26 @@ impl NormalModuleFactory {
     ))
   }
 
+  fn create_ignored_module(
+    &mut self,
+    importer_with_context: &Path,
+    specifier: &str,
+  ) -> TWithDiagnosticArray<ModuleFactoryResult> {
+    let ident = format!("{}/{}", importer_with_context.display(), specifier);
+    let module_identifier = ModuleIdentifier::from(format!("ignored|{ident}"));
+
+    let raw_module = RawModule::new(
+      "/* (ignored) */".to_owned(),
+      module_identifier,
+      format!("{ident} (ignored)"),
+      Default::default(),
+    )
+    .boxed();
+    self.context.module_type = Some(*raw_module.module_type());
+
+    ModuleFactoryResult::new(raw_module).with_empty_diagnostic()
+  }
+
   async fn calculate_module_rules(
     &self,
     resource_data: &ResourceData,
@@ -389,7 +451,7 @@ pub struct NormalModuleFactoryContext {
 /// Rspan aka `Rspack span`, just avoiding conflict with span in other crate
 /// ## Warning
 /// RSpan is zero based, `Span` of `swc` is 1 based. see https://swc-css.netlify.app/?code=eJzLzC3ILypRSFRIK8rPVVAvSS0u0csqVgcAZaoIKg
-#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default)]
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
 pub struct ErrorSpan {
   pub start: u32,
   pub end: u32,
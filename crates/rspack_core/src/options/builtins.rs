@@ -51,6 +51,9 @@ pub struct Minification {
   pub passes: usize,
   pub drop_console: bool,
   pub pure_funcs: Vec<String>,
+  /// Regex condition of comments to be extracted into `<asset>.LICENSE.txt`,
+  /// license comments are kept inline if not set
+  pub extract_comments: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use crate::{
   Builtins, BundleEntries, CacheOptions, Context, DevServerOptions, Devtool, Experiments, Mode,
   ModuleOptions, NodeOption, Optimization, OutputOptions, Resolve, SnapshotOptions, StatsOptions,
@@ -22,6 +24,10 @@ pub struct CompilerOptions {
   pub experiments: Experiments,
   pub node: NodeOption,
   pub optimization: Optimization,
+  /// Where to read records of the previous run, like webpack's `recordsInputPath`
+  pub records_input_path: Option<PathBuf>,
+  /// Where to write records after assets emitted, like webpack's `recordsOutputPath`
+  pub records_output_path: Option<PathBuf>,
 }
 
 impl CompilerOptions {
@@ -1,3 +1,7 @@
+use std::fmt::Debug;
+
+use futures::future::BoxFuture;
+use rspack_error::Result;
 use rspack_regex::RspackRegex;
 use rustc_hash::FxHashMap as HashMap;
 
@@ -7,16 +11,48 @@ pub type Externals = Vec<ExternalItem>;
 pub enum ExternalItemValue {
   String(String),
   Bool(bool),
-  // TODO: string[] | Record<string, string|string[]>
+  /// `["lodash", "merge"]`, a module with its sub-property path
+  Array(Vec<String>),
+  /// `{ root: "_", commonjs: "lodash" }`, request of each library type
+  Object(HashMap<String, Vec<String>>),
 }
 
 pub type ExternalItemObject = HashMap<String, ExternalItemValue>;
 
-#[derive(Debug)]
+pub struct ExternalItemFnCtx {
+  pub request: String,
+  /// Directory of the issuer
+  pub context: String,
+  /// Category of the dependency, e.g. `esm`, `commonjs`
+  pub dependency_type: String,
+}
+
+pub struct ExternalItemFnResult {
+  /// Overrides `externalsType` for this request
+  pub external_type: Option<ExternalType>,
+  /// `None` means the request should not be externalized
+  pub result: Option<ExternalItemValue>,
+}
+
+pub type ExternalItemFn =
+  Box<dyn Fn(ExternalItemFnCtx) -> BoxFuture<'static, Result<ExternalItemFnResult>> + Sync + Send>;
+
 pub enum ExternalItem {
   Object(ExternalItemObject),
   String(String),
   RegExp(RspackRegex),
+  Fn(ExternalItemFn),
+}
+
+impl Debug for ExternalItem {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Object(i) => i.fmt(f),
+      Self::String(i) => i.fmt(f),
+      Self::RegExp(i) => i.fmt(f),
+      Self::Fn(_) => "Fn(...)".fmt(f),
+    }
+  }
 }
 
 impl From<ExternalItemObject> for ExternalItem {
@@ -6,9 +6,10 @@ use std::{
 
 use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
 use sugar_path::SugarPath;
 
-use crate::{Chunk, ChunkGroupByUkey, ChunkKind, Compilation, SourceType};
+use crate::{AssetInfo, Chunk, ChunkGroupByUkey, ChunkKind, Compilation, SourceType};
 
 #[derive(Debug)]
 pub struct OutputOptions {
@@ -29,6 +30,8 @@ pub struct OutputOptions {
   pub import_function_name: String,
   pub iife: bool,
   pub module: bool,
+  /// How chunks of workers are loaded, falls back to the default of the target if it's `None`
+  pub worker_chunk_loading: Option<ChunkLoadingType>,
 }
 
 #[derive(Debug)]
@@ -53,6 +56,29 @@ impl From<&str> for WasmLoadingType {
   }
 }
 
+/// How chunks are loaded by the runtime, the default is decided by the target
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChunkLoadingType {
+  Jsonp,
+  ImportScripts,
+  Require,
+  AsyncNode,
+  Import,
+}
+
+impl From<&str> for ChunkLoadingType {
+  fn from(value: &str) -> Self {
+    match value {
+      "jsonp" => Self::Jsonp,
+      "import-scripts" => Self::ImportScripts,
+      "require" => Self::Require,
+      "async-node" => Self::AsyncNode,
+      "import" => Self::Import,
+      _ => todo!(),
+    }
+  }
+}
+
 pub const NAME_PLACEHOLDER: &str = "[name]";
 pub const PATH_PLACEHOLDER: &str = "[path]";
 pub const EXT_PLACEHOLDER: &str = "[ext]";
@@ -104,8 +130,30 @@ impl Filename {
     extension: &str,
     source_type: &SourceType,
   ) -> String {
+    self.render(Self::chunk_render_options(chunk, extension, source_type))
+  }
+
+  /// Same as `render_with_chunk`, and records the content hash used in the filename to `asset_info`.
+  pub fn render_with_chunk_and_asset_info(
+    &self,
+    chunk: &Chunk,
+    extension: &str,
+    source_type: &SourceType,
+    asset_info: &mut AssetInfo,
+  ) -> String {
+    self.render_impl(
+      Self::chunk_render_options(chunk, extension, source_type),
+      Some(asset_info),
+    )
+  }
+
+  fn chunk_render_options(
+    chunk: &Chunk,
+    extension: &str,
+    source_type: &SourceType,
+  ) -> FilenameRenderOptions {
     let hash = Some(chunk.get_render_hash());
-    self.render(FilenameRenderOptions {
+    FilenameRenderOptions {
       // See https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/TemplatedPathPlugin.js#L214
       name: chunk.name_for_filename_template(),
       extension: Some(extension.to_owned()),
@@ -120,10 +168,18 @@ impl Filename {
       chunkhash: hash.clone(),
       hash,
       ..Default::default()
-    })
+    }
   }
 
   pub fn render(&self, options: FilenameRenderOptions) -> String {
+    self.render_impl(options, None)
+  }
+
+  fn render_impl(
+    &self,
+    options: FilenameRenderOptions,
+    asset_info: Option<&mut AssetInfo>,
+  ) -> String {
     let mut filename = self.template.clone();
     if let Some(name) = options.name {
       filename = filename.replace(NAME_PLACEHOLDER, &name);
@@ -142,6 +198,7 @@ impl Filename {
     }
 
     if let Some(contenthash) = options.contenthash {
+      let mut used_content_hashes = vec![];
       filename = CONTENT_HASH_PLACEHOLDER
         .replace_all(&filename, |caps: &Captures| {
           let hash_len = contenthash.len();
@@ -150,9 +207,14 @@ impl Filename {
             .and_then(|m| m.as_str().parse().ok())
             .unwrap_or(hash_len)
             .min(hash_len);
-          &contenthash[..hash_len.min(contenthash.len())]
+          let used_content_hash = &contenthash[..hash_len.min(contenthash.len())];
+          used_content_hashes.push(used_content_hash.to_string());
+          used_content_hash
         })
         .into_owned();
+      if let Some(asset_info) = asset_info {
+        asset_info.content_hash.extend(used_content_hashes);
+      }
     }
 
     if let Some(chunkhash) = options.chunkhash {
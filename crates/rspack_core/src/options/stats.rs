@@ -2,4 +2,6 @@
 pub struct StatsOptions {
   pub colors: bool,
   pub reasons: bool,
+  pub provided_exports: bool,
+  pub used_exports: bool,
 }
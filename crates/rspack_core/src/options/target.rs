@@ -1,11 +1,14 @@
 use anyhow::anyhow;
 pub use swc_core::ecma::ast::EsVersion;
 
+use crate::ChunkLoadingType;
+
 #[derive(Debug, Clone)]
 pub enum TargetPlatform {
   Web,
   WebWorker,
   Node(String),
+  AsyncNode(String),
   None,
 }
 
@@ -16,6 +19,21 @@ impl TargetPlatform {
   pub fn is_web(&self) -> bool {
     matches!(self, TargetPlatform::Web)
   }
+  /// Both `node` and `async-node`, which only differ in chunk loading
+  pub fn is_node(&self) -> bool {
+    matches!(self, TargetPlatform::Node(_) | TargetPlatform::AsyncNode(_))
+  }
+
+  /// How chunks of workers are loaded if `output.workerChunkLoading` isn't set,
+  /// node `worker_threads` have no `importScripts`
+  pub fn worker_chunk_loading(&self) -> Option<ChunkLoadingType> {
+    match self {
+      TargetPlatform::Web | TargetPlatform::WebWorker => Some(ChunkLoadingType::ImportScripts),
+      TargetPlatform::Node(_) => Some(ChunkLoadingType::Require),
+      TargetPlatform::AsyncNode(_) => Some(ChunkLoadingType::AsyncNode),
+      TargetPlatform::None => None,
+    }
+  }
 }
 
 #[derive(Debug, Clone)]
@@ -80,6 +98,7 @@ impl Target {
         "web" => TargetPlatform::Web,
         "webworker" => TargetPlatform::WebWorker,
         "node" => TargetPlatform::Node(String::new()),
+        "async-node" => TargetPlatform::AsyncNode(String::new()),
         _ => {
           return Err(anyhow!("Unknown target platform {}", item));
         }
@@ -6,9 +6,10 @@ use rspack_sources::BoxSource;
 use rustc_hash::FxHashMap as HashMap;
 
 use crate::{
-  AdditionalChunkRuntimeRequirementsArgs, BoxModule, ChunkHashArgs, ChunkUkey, Compilation,
-  CompilationArgs, ContentHashArgs, DoneArgs, FactorizeArgs, JsChunkHashArgs, Module, ModuleArgs,
-  ModuleFactoryResult, ModuleType, NormalModuleFactoryContext,
+  AdditionalChunkRuntimeRequirementsArgs, AssetInfo, BoxModule, ChunkHashArgs, ChunkUkey,
+  Compilation, CompilationArgs, ContentHashArgs, DoneArgs, FactorizeArgs, JsChunkHashArgs, Module,
+  ModuleArgs, ModuleFactoryResult, ModuleType, NormalModuleAfterResolveArgs,
+  NormalModuleBeforeResolveArgs, NormalModuleFactoryContext,
   NormalModuleFactoryResolveForSchemeArgs, OptimizeChunksArgs, ParserAndGenerator, PluginContext,
   ProcessAssetsArgs, RenderArgs, RenderChunkArgs, RenderManifestArgs, RenderModuleContentArgs,
   RenderStartupArgs, SourceType, ThisCompilationArgs,
@@ -24,6 +25,8 @@ pub type PluginReadResourceOutput = Result<Option<Content>>;
 pub type PluginFactorizeHookOutput = Result<Option<ModuleFactoryResult>>;
 pub type PluginModuleHookOutput = Result<Option<BoxModule>>;
 pub type PluginNormalModuleFactoryResolveForSchemeOutput = Result<Option<ResourceData>>;
+pub type PluginNormalModuleFactoryBeforeResolveOutput = Result<Option<bool>>;
+pub type PluginNormalModuleFactoryAfterResolveOutput = Result<Option<bool>>;
 pub type PluginContentHashHookOutput = Result<Option<(SourceType, String)>>;
 pub type PluginChunkHashHookOutput = Result<Option<u64>>;
 pub type PluginRenderManifestHookOutput = Result<Vec<RenderManifestEntry>>;
@@ -72,6 +75,26 @@ pub trait Plugin: Debug + Send + Sync {
   async fn read_resource(&self, _resource_data: &ResourceData) -> PluginReadResourceOutput {
     Ok(None)
   }
+
+  /// Called before a request is resolved. Plugins may rewrite `request` and `context` in place.
+  /// It behaves like a BailHook, returning `Some(false)` ignores the request.
+  async fn before_resolve(
+    &self,
+    _ctx: PluginContext,
+    _args: &mut NormalModuleBeforeResolveArgs,
+  ) -> PluginNormalModuleFactoryBeforeResolveOutput {
+    Ok(None)
+  }
+
+  /// Called after a request is resolved to a resource.
+  /// It behaves like a BailHook, returning `Some(false)` ignores the resolved module.
+  async fn after_resolve(
+    &self,
+    _ctx: PluginContext,
+    _args: &NormalModuleAfterResolveArgs<'_>,
+  ) -> PluginNormalModuleFactoryAfterResolveOutput {
+    Ok(None)
+  }
   /**
    * factorize hook will generate BoxModule which will be used to generate ModuleGraphModule.
    * It is used to handle the generation of those modules which are not normal, such as External Module
@@ -244,6 +267,14 @@ pub trait Plugin: Debug + Send + Sync {
     Ok(())
   }
 
+  async fn process_assets_stage_optimize_hash(
+    &mut self,
+    _ctx: PluginContext,
+    _args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
+    Ok(())
+  }
+
   async fn process_assets_stage_report(
     &mut self,
     _ctx: PluginContext,
@@ -252,10 +283,10 @@ pub trait Plugin: Debug + Send + Sync {
     Ok(())
   }
 
-  fn optimize_chunks(
+  async fn optimize_chunks(
     &mut self,
     _ctx: PluginContext,
-    _args: OptimizeChunksArgs,
+    _args: OptimizeChunksArgs<'_>,
   ) -> PluginOptimizeChunksOutput {
     Ok(())
   }
@@ -322,18 +353,19 @@ pub struct RenderManifestEntry {
   pub(crate) source: BoxSource,
   filename: String,
   pub(crate) path_options: PathData,
-  // info?: AssetInfo;
+  pub(crate) info: AssetInfo,
   // pub identifier: String,
   // hash?: string;
   // auxiliary?: boolean;
 }
 
 impl RenderManifestEntry {
-  pub fn new(source: BoxSource, filename: String, path_options: PathData) -> Self {
+  pub fn new(source: BoxSource, filename: String, path_options: PathData, info: AssetInfo) -> Self {
     Self {
       source,
       filename,
       path_options,
+      info,
     }
   }
 
@@ -94,6 +94,19 @@ pub struct NormalModuleFactoryResolveForSchemeArgs {
   pub scheme: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct NormalModuleBeforeResolveArgs {
+  pub request: String,
+  pub context: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NormalModuleAfterResolveArgs<'a> {
+  pub request: &'a str,
+  pub context: Option<&'a str>,
+  pub resource_data: &'a ResourceData,
+}
+
 #[derive(Debug)]
 pub struct ResolveArgs<'a> {
   pub importer: Option<&'a PathBuf>,
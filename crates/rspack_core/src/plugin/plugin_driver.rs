@@ -1,5 +1,6 @@
 use std::{
   collections::HashMap,
+  hash::{Hash, Hasher},
   sync::{Arc, Mutex},
 };
 
@@ -7,15 +8,18 @@ use rayon::prelude::*;
 use rspack_error::{Diagnostic, Result};
 use rspack_loader_runner::ResourceData;
 use tracing::instrument;
+use xxhash_rust::xxh3::Xxh3;
 
 use crate::{
   AdditionalChunkRuntimeRequirementsArgs, ApplyContext, BoxedParserAndGeneratorBuilder,
   ChunkHashArgs, Compilation, CompilationArgs, CompilerOptions, Content, ContentHashArgs, DoneArgs,
-  FactorizeArgs, JsChunkHashArgs, Module, ModuleArgs, ModuleType, NormalModuleFactoryContext,
+  FactorizeArgs, JsChunkHashArgs, Module, ModuleArgs, ModuleType, NormalModuleAfterResolveArgs,
+  NormalModuleBeforeResolveArgs, NormalModuleFactoryContext,
   NormalModuleFactoryResolveForSchemeArgs, OptimizeChunksArgs, Plugin,
   PluginAdditionalChunkRuntimeRequirementsOutput, PluginBuildEndHookOutput,
   PluginChunkHashHookOutput, PluginCompilationHookOutput, PluginContext, PluginFactorizeHookOutput,
   PluginJsChunkHashHookOutput, PluginMakeHookOutput, PluginModuleHookOutput,
+  PluginNormalModuleFactoryAfterResolveOutput, PluginNormalModuleFactoryBeforeResolveOutput,
   PluginNormalModuleFactoryResolveForSchemeOutput, PluginProcessAssetsOutput,
   PluginRenderChunkHookOutput, PluginRenderHookOutput, PluginRenderManifestHookOutput,
   PluginRenderModuleContentOutput, PluginRenderStartupHookOutput, PluginThisCompilationHookOutput,
@@ -177,12 +181,22 @@ impl PluginDriver {
   }
 
   pub async fn chunk_hash(&self, args: &ChunkHashArgs<'_>) -> PluginChunkHashHookOutput {
+    let mut result = None;
     for plugin in &self.plugins {
       if let Some(hash) = plugin.chunk_hash(PluginContext::new(), args).await? {
-        return Ok(Some(hash));
+        // Keep the hash untouched if only one plugin contributes, otherwise combine them
+        result = Some(match result {
+          Some(prev) => {
+            let mut hasher = Xxh3::default();
+            prev.hash(&mut hasher);
+            hash.hash(&mut hasher);
+            hasher.finish()
+          }
+          None => hash,
+        });
       }
     }
-    Ok(None)
+    Ok(result)
   }
 
   pub async fn render_manifest(
@@ -280,6 +294,32 @@ impl PluginDriver {
     Ok(None)
   }
 
+  pub async fn before_resolve(
+    &self,
+    args: &mut NormalModuleBeforeResolveArgs,
+  ) -> PluginNormalModuleFactoryBeforeResolveOutput {
+    for plugin in &self.plugins {
+      tracing::trace!("running before_resolve:{}", plugin.name());
+      if let Some(data) = plugin.before_resolve(PluginContext::new(), args).await? {
+        return Ok(Some(data));
+      }
+    }
+    Ok(None)
+  }
+
+  pub async fn after_resolve(
+    &self,
+    args: NormalModuleAfterResolveArgs<'_>,
+  ) -> PluginNormalModuleFactoryAfterResolveOutput {
+    for plugin in &self.plugins {
+      tracing::trace!("running after_resolve:{}", plugin.name());
+      if let Some(data) = plugin.after_resolve(PluginContext::new(), &args).await? {
+        return Ok(Some(data));
+      }
+    }
+    Ok(None)
+  }
+
   pub async fn normal_module_factory_resolve_for_scheme(
     &self,
     args: NormalModuleFactoryResolveForSchemeArgs,
@@ -352,6 +392,7 @@ impl PluginDriver {
     run_stage!(process_assets_stage_dev_tooling);
     run_stage!(process_assets_stage_optimize_inline);
     run_stage!(process_assets_stage_summarize);
+    run_stage!(process_assets_stage_optimize_hash);
     run_stage!(process_assets_stage_report);
     Ok(())
   }
@@ -374,9 +415,11 @@ impl PluginDriver {
     Ok(())
   }
   #[instrument(name = "plugin:optimize_chunks", skip_all)]
-  pub fn optimize_chunks(&mut self, compilation: &mut Compilation) -> Result<()> {
+  pub async fn optimize_chunks(&mut self, compilation: &mut Compilation) -> Result<()> {
     for plugin in &mut self.plugins {
-      plugin.optimize_chunks(PluginContext::new(), OptimizeChunksArgs { compilation })?;
+      plugin
+        .optimize_chunks(PluginContext::new(), OptimizeChunksArgs { compilation })
+        .await?;
     }
     Ok(())
   }
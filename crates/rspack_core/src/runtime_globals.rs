@@ -184,6 +184,41 @@ bitflags! {
      * the baseURI of current document
      */
     const BASE_URI = 1 << 32;
+
+    /**
+     * an object with all share scopes
+     */
+    const SHARE_SCOPE_MAP = 1 << 33;
+
+    /**
+     * initialize sharing, the function takes the name of a share scope
+     */
+    const INITIALIZE_SHARING = 1 << 34;
+
+    /**
+     * the scope of remote modules which are loaded by the current `get` of a container
+     */
+    const CURRENT_REMOTE_GET_SCOPE = 1 << 35;
+
+    /**
+     * the chunk prefetch function
+     */
+    const PREFETCH_CHUNK = 1 << 36;
+
+    /**
+     * an object with handlers to prefetch a chunk
+     */
+    const PREFETCH_CHUNK_HANDLERS = 1 << 37;
+
+    /**
+     * the chunk preload function
+     */
+    const PRELOAD_CHUNK = 1 << 38;
+
+    /**
+     * an object with handlers to preload a chunk
+     */
+    const PRELOAD_CHUNK_HANDLERS = 1 << 39;
   }
 }
 
@@ -243,6 +278,13 @@ impl RuntimeGlobals {
       R::INSTANTIATE_WASM => "__webpack_require__.v",
       R::ASYNC_MODULE => "__webpack_require__.a",
       R::BASE_URI => "__webpack_require__.b",
+      R::SHARE_SCOPE_MAP => "__webpack_require__.S",
+      R::INITIALIZE_SHARING => "__webpack_require__.I",
+      R::CURRENT_REMOTE_GET_SCOPE => "__webpack_require__.R",
+      R::PREFETCH_CHUNK => "__webpack_require__.E",
+      R::PREFETCH_CHUNK_HANDLERS => "__webpack_require__.F",
+      R::PRELOAD_CHUNK => "__webpack_require__.G",
+      R::PRELOAD_CHUNK_HANDLERS => "__webpack_require__.H",
       r => panic!(
         "Unexpected flag `{r:?}`. RuntimeGlobals should only be printed for one single flag."
       ),
@@ -21,6 +21,11 @@ pub trait RuntimeModule: Module {
  */
 pub const RUNTIME_MODULE_STAGE_ATTACH: u8 = 10;
 
+/**
+ * Runtime modules which trigger actions on bootstrap
+ */
+pub const RUNTIME_MODULE_STAGE_TRIGGER: u8 = 20;
+
 pub trait RuntimeModuleExt {
   fn boxed(self) -> Box<dyn RuntimeModule>;
 }
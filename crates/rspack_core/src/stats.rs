@@ -7,7 +7,8 @@ use rspack_error::{
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use crate::{
-  BoxModule, Chunk, ChunkGroupUkey, Compilation, ModuleIdentifier, ModuleType, SourceType,
+  BoxModule, Chunk, ChunkGroupUkey, Compilation, ModuleIdentifier, ModuleType, ProvidedExports,
+  SourceType, UsedExports,
 };
 
 #[derive(Debug, Clone)]
@@ -328,6 +329,34 @@ impl Stats<'_> {
       })
       .transpose()?;
 
+    let stats_options = &self.compilation.options.stats;
+    let provided_exports = stats_options
+      .provided_exports
+      .then(|| {
+        match self
+          .compilation
+          .module_graph
+          .get_provided_exports(&identifier)
+        {
+          ProvidedExports::Names(names) => Some(names.iter().map(|n| n.to_string()).collect()),
+          ProvidedExports::Unknown => None,
+        }
+      })
+      .flatten();
+    let used_exports = stats_options
+      .used_exports
+      .then(|| {
+        match self
+          .compilation
+          .module_graph
+          .get_used_exports(&identifier, None)
+        {
+          UsedExports::Names(names) => Some(names.iter().map(|n| n.to_string()).collect()),
+          UsedExports::Unknown => None,
+        }
+      })
+      .flatten();
+
     let mut chunks: Vec<String> = self
       .compilation
       .chunk_graph
@@ -361,6 +390,8 @@ impl Stats<'_> {
       issuer_id,
       issuer_path,
       reasons,
+      provided_exports,
+      used_exports,
     })
   }
 
@@ -471,6 +502,8 @@ pub struct StatsModule {
   pub issuer_id: Option<String>,
   pub issuer_path: Vec<StatsModuleIssuer>,
   pub reasons: Option<Vec<StatsModuleReason>>,
+  pub provided_exports: Option<Vec<String>>,
+  pub used_exports: Option<Vec<String>>,
 }
 
 #[derive(Debug)]
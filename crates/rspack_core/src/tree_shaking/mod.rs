@@ -1,12 +1,16 @@
+use std::collections::VecDeque;
+
 use bitflags;
 use once_cell::sync::Lazy;
 use rspack_identifier::{IdentifierMap, IdentifierSet};
 use rspack_symbol::{IndirectTopLevelSymbol, StarSymbol, Symbol};
-use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use swc_core::ecma::ast::ModuleItem;
+use swc_core::ecma::atoms::JsWord;
 
+use self::symbol_graph::SymbolGraph;
 use self::visitor::{SymbolRef, TreeShakingResult};
-use crate::ModuleGraph;
+use crate::{Compilation, ModuleGraph, ModuleIdentifier, ModuleSyntax, RuntimeSpec, UsageState};
 
 pub mod optimizer;
 pub mod symbol_graph;
@@ -21,7 +25,191 @@ pub struct OptimizeDependencyResult {
   pub bail_out_module_identifiers: IdentifierMap<BailoutFlag>,
   pub side_effects_free_modules: IdentifierSet,
   pub module_item_map: IdentifierMap<Vec<ModuleItem>>,
+  pub symbol_graph: SymbolGraph,
+}
+
+impl OptimizeDependencyResult {
+  /// Records provided and used exports of analyzed modules as exports info of the module graph.
+  ///
+  /// Usage in each runtime is unknown until chunks are created, which is set by
+  /// [set_exports_used_in_runtimes] from modules using each export.
+  pub fn set_exports_info(
+    &self,
+    module_graph: &mut ModuleGraph,
+    entry_module_identifiers: &IdentifierSet,
+  ) {
+    module_graph.clear_exports_info();
+    let mut export_users = self.collect_export_users(entry_module_identifiers);
+    for (module_identifier, result) in &self.analyze_results {
+      let bailout = self
+        .bail_out_module_identifiers
+        .contains_key(module_identifier);
+      let is_esm = result.module_syntax == ModuleSyntax::ESM;
+      // Exports reexported by `export *` from modules which are not analyzed are unknown,
+      // known exports of the module are still provided
+      let has_unknown_star_exports = result.inherit_export_maps.keys().any(|module| {
+        self
+          .analyze_results
+          .get(module)
+          .map_or(true, |result| result.module_syntax != ModuleSyntax::ESM)
+      });
+
+      let exports_info = module_graph.get_exports_info_mut(module_identifier);
+      let other_exports_info = exports_info.other_exports_info_mut();
+      let is_exports_known =
+        (is_esm || result.commonjs_exports_analyzed) && !has_unknown_star_exports;
+      other_exports_info.provided = is_exports_known.then_some(false);
+      other_exports_info.can_mangle_provide = Some(false);
+      other_exports_info.set_used(
+        if is_exports_known && !bailout {
+          UsageState::Unused
+        } else {
+          UsageState::Unknown
+        },
+        None,
+      );
+
+      for (name, symbol) in export_symbols(result) {
+        let export_info = exports_info.get_export_info_mut(name);
+        export_info.provided = Some(true);
+        export_info.can_mangle_provide = Some(is_esm);
+        export_info.can_mangle_use = Some(!bailout);
+        export_info.set_used(
+          if bailout || self.used_symbol_ref.contains(symbol) {
+            UsageState::Used
+          } else {
+            UsageState::Unused
+          },
+          None,
+        );
+        export_info.used_by = if bailout {
+          None
+        } else {
+          export_users.remove(&(*module_identifier, name.clone()))
+        };
+      }
+    }
+  }
+
+  /// Modules using each export, found by walking the symbol graph from symbols used by each module.
+  /// Exports of entry modules are used by the entry modules themselves.
+  fn collect_export_users(
+    &self,
+    entry_module_identifiers: &IdentifierSet,
+  ) -> HashMap<(ModuleIdentifier, JsWord), IdentifierSet> {
+    let mut exports_by_symbol: HashMap<&SymbolRef, Vec<(ModuleIdentifier, &JsWord)>> =
+      HashMap::default();
+    for (module_identifier, result) in &self.analyze_results {
+      for (name, symbol) in export_symbols(result) {
+        exports_by_symbol
+          .entry(symbol)
+          .or_default()
+          .push((*module_identifier, name));
+      }
+    }
+
+    let mut export_users: HashMap<(ModuleIdentifier, JsWord), IdentifierSet> = HashMap::default();
+    for (module_identifier, result) in &self.analyze_results {
+      let mut queue = result
+        .used_symbol_refs
+        .iter()
+        .cloned()
+        .collect::<VecDeque<_>>();
+      if entry_module_identifiers.contains(module_identifier) {
+        queue.extend(export_symbols(result).map(|(_, symbol)| symbol.clone()));
+      }
+      let mut visited = HashSet::default();
+      while let Some(symbol) = queue.pop_front() {
+        if visited.contains(&symbol) {
+          continue;
+        }
+        let mut used_exports = exports_by_symbol.get(&symbol).cloned().unwrap_or_default();
+        // All exports of a namespace object may be used
+        if let SymbolRef::Star(star) = &symbol
+          && let Some(result) = self.analyze_results.get(&star.src())
+        {
+          used_exports.extend(export_symbols(result).map(|(name, _)| (star.src(), name)));
+        }
+        for (module, name) in used_exports {
+          export_users
+            .entry((module, name.clone()))
+            .or_default()
+            .insert(*module_identifier);
+        }
+        if let Some(index) = self.symbol_graph.get_node_index(&symbol) {
+          queue.extend(
+            self
+              .symbol_graph
+              .graph
+              .neighbors(*index)
+              .filter_map(|index| self.symbol_graph.get_symbol(&index))
+              .cloned(),
+          );
+        }
+        visited.insert(symbol);
+      }
+    }
+    export_users
+  }
 }
+
+/// Exports of the module with their symbols, `export *` never reexports `default`
+fn export_symbols(result: &TreeShakingResult) -> impl Iterator<Item = (&JsWord, &SymbolRef)> {
+  result.export_map.iter().chain(
+    result
+      .inherit_export_maps
+      .values()
+      .flat_map(|export_map| export_map.iter())
+      .filter(|(name, _)| &***name != "default" && !result.export_map.contains_key(*name)),
+  )
+}
+
+/// Splits usage of exports into runtimes after chunks are created,
+/// an export is used in a runtime if any module using it is in a chunk of the runtime.
+pub fn set_exports_used_in_runtimes(compilation: &mut Compilation) {
+  let chunk_graph = &compilation.chunk_graph;
+  let chunk_by_ukey = &compilation.chunk_by_ukey;
+  let module_runtimes = |module: &ModuleIdentifier| -> Vec<&RuntimeSpec> {
+    chunk_graph
+      .chunk_graph_module_by_module_identifier
+      .get(module)
+      .map(|cgm| {
+        cgm
+          .chunks
+          .iter()
+          .filter_map(|chunk| chunk_by_ukey.get(chunk))
+          .map(|chunk| &chunk.runtime)
+          .collect()
+      })
+      .unwrap_or_default()
+  };
+
+  for (module_identifier, exports_info) in compilation.module_graph.exports_info_iter_mut() {
+    let runtimes = module_runtimes(module_identifier);
+    for (_, export_info) in exports_info.exports_mut() {
+      if export_info.get_used(None) != UsageState::Used {
+        continue;
+      }
+      let Some(used_by) = &export_info.used_by else {
+        continue;
+      };
+      let used_in = used_by
+        .iter()
+        .flat_map(&module_runtimes)
+        .flatten()
+        .collect::<HashSet<_>>();
+      for runtime in &runtimes {
+        let used = if runtime.iter().any(|name| used_in.contains(name)) {
+          UsageState::Used
+        } else {
+          UsageState::Unused
+        };
+        export_info.set_used(used, Some(runtime));
+      }
+    }
+  }
+}
+
 const ANALYZE_LOGGING: bool = true;
 static CARE_MODULE_ID_FROM_ENV: Lazy<Vec<String>> = Lazy::new(|| {
   let cwd = std::env::current_dir().expect("");
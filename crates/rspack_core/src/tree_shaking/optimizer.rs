@@ -162,6 +162,7 @@ impl<'a> CodeSizeOptimizer<'a> {
         bail_out_module_identifiers: std::mem::take(&mut self.bailout_modules),
         side_effects_free_modules: std::mem::take(&mut self.side_effects_free_modules),
         module_item_map: IdentifierMap::default(),
+        symbol_graph: std::mem::take(&mut self.symbol_graph),
       }
       .with_diagnostic(errors_to_diagnostics(errors)),
     )
@@ -869,6 +870,28 @@ impl<'a> CodeSizeOptimizer<'a> {
                   ModuleUsedType::INDIRECT,
                 );
 
+                // The default import of a CommonJS module without `__esModule` flag refers to
+                // `module.exports` itself, so all of its exports should be marked as used, e.g.
+                // ```js
+                // import lib from './cjs'
+                // lib.a
+                // ```
+                // With the flag, it refers to `exports.default` like an ES module.
+                if module_result.commonjs_exports_analyzed
+                  && !module_result.has_es_module_flag
+                  && indirect_symbol.indirect_id() == "default"
+                {
+                  let import_all = SymbolRef::Star(StarSymbol::new(
+                    module_result.module_identifier,
+                    Default::default(),
+                    indirect_symbol.importer(),
+                    StarSymbolKind::ImportAllAs,
+                  ));
+                  self.symbol_graph.add_edge(&current_symbol_ref, &import_all);
+                  symbol_queue.push_back(import_all);
+                  return;
+                }
+
                 // Only report diagnostic when following conditions are satisfied:
                 // 1. src module is not a bailout module and src module using ESM syntax to export some symbols.
                 // 2. src module has no reexport or any reexport src module is not bailouted
@@ -901,9 +924,8 @@ impl<'a> CodeSizeOptimizer<'a> {
                   }
                   return;
                 } else {
-                  // TODO: This branch should be remove after we analyze module.exports
-                  // If one of inherit module is a bailout module, that most probably means that module has some common js export
-                  // which we don't analyze yet, we just pass it. It is alright because we don't modified the ast of bailout module
+                  // If src module or one of inherit module is a bailout module, that most probably means that module has some common js export
+                  // which can't be analyzed statically, we just pass it. It is alright because we don't modified the ast of bailout module
                   return;
                 }
               }
@@ -1071,8 +1093,16 @@ impl<'a> CodeSizeOptimizer<'a> {
       }
     };
 
-    // by default webpack will not mark the `export *` as used in entry module
-    if !is_entry {
+    // by default webpack will not mark the `export *` as used in entry module,
+    // unless the entry is an ES module library, which exports all of them
+    let is_module_library = self
+      .compilation
+      .options
+      .output
+      .library
+      .as_ref()
+      .map_or(false, |library| library.library_type == "module");
+    if !is_entry || is_module_library {
       let inherit_export_symbols = get_inherit_export_symbol_ref(entry_module_result);
 
       q.extend(inherit_export_symbols);
@@ -8,7 +8,7 @@ use rustc_hash::FxHashMap;
 use super::{visitor::SymbolRef, ConvertModulePath};
 use crate::{contextify, ModuleGraph};
 
-#[derive(Default, Clone)]
+#[derive(Debug, Default, Clone)]
 pub struct SymbolGraph {
   pub(crate) graph: StableDiGraph<SymbolRef, ()>,
   pub(crate) symbol_to_index: FxHashMap<SymbolRef, NodeIndex>,
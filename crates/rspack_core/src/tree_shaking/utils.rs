@@ -1,5 +1,11 @@
-use swc_core::common::Mark;
-use swc_core::ecma::ast::{CallExpr, Callee, Expr, ExprOrSpread, Ident, Lit};
+use rspack_identifier::Identifier;
+use rspack_symbol::{BetterId, Symbol, SymbolType};
+use swc_core::common::{Mark, SyntaxContext};
+use swc_core::ecma::ast::{
+  AssignExpr, AssignOp, CallExpr, Callee, ComputedPropName, Expr, ExprOrSpread, ExprStmt, Ident,
+  Lit, MemberExpr, MemberProp, ModuleItem, ObjectLit, Pat, PatOrExpr, Prop, PropName, PropOrSpread,
+  Stmt, Str,
+};
 use swc_core::ecma::atoms::{js_word, JsWord};
 
 pub fn get_first_string_lit_arg(e: &CallExpr) -> Option<JsWord> {
@@ -53,3 +59,197 @@ pub fn get_path_of_module_identifier<T: AsRef<str>>(path: T) -> String {
   let (_, path) = t.split_once('|').expect("Expect have `|` delimiter ");
   path.to_string()
 }
+
+/// A statically analyzable CommonJS export statement at the top level of a module.
+#[derive(Debug)]
+pub enum CommonJsExport<'a> {
+  /// `exports.a = exports.b = value`, names are ordered from the outermost assignment
+  Assign(Vec<JsWord>, &'a Expr),
+  /// `module.exports = { a, b: value, c() {} }`
+  Object(Vec<(JsWord, &'a Prop)>),
+  /// `Object.defineProperty(exports, "a", descriptor)`
+  DefineProperty(JsWord, &'a Expr),
+}
+
+impl<'a> CommonJsExport<'a> {
+  pub fn names(&self) -> Vec<JsWord> {
+    match self {
+      CommonJsExport::Assign(names, _) => names.clone(),
+      CommonJsExport::Object(props) => props.iter().map(|(name, _)| name.clone()).collect(),
+      CommonJsExport::DefineProperty(name, _) => vec![name.clone()],
+    }
+  }
+}
+
+/// Check if the expression is `exports` or `module.exports`
+pub fn is_commonjs_exports_object(expr: &Expr, unresolved_ctxt: SyntaxContext) -> bool {
+  match expr {
+    Expr::Ident(Ident { sym, span, .. }) => span.ctxt == unresolved_ctxt && sym == "exports",
+    Expr::Member(MemberExpr {
+      obj:
+        box Expr::Ident(Ident {
+          sym: js_word!("module"),
+          span,
+          ..
+        }),
+      prop: MemberProp::Ident(Ident { sym, .. }),
+      ..
+    }) => span.ctxt == unresolved_ctxt && sym == "exports",
+    _ => false,
+  }
+}
+
+/// Get the export name of `exports.xxx`, `exports["xxx"]` or `module.exports.xxx`
+pub fn get_commonjs_export_member_name(
+  expr: &Expr,
+  unresolved_ctxt: SyntaxContext,
+) -> Option<JsWord> {
+  match expr {
+    Expr::Member(MemberExpr { obj, prop, .. })
+      if is_commonjs_exports_object(obj, unresolved_ctxt) =>
+    {
+      match prop {
+        MemberProp::Ident(ident) => Some(ident.sym.clone()),
+        MemberProp::Computed(ComputedPropName {
+          expr: box Expr::Lit(Lit::Str(Str { value, .. })),
+          ..
+        }) => Some(value.clone()),
+        _ => None,
+      }
+    }
+    _ => None,
+  }
+}
+
+fn get_commonjs_export_assign_target(
+  node: &AssignExpr,
+  unresolved_ctxt: SyntaxContext,
+) -> Option<JsWord> {
+  if node.op != AssignOp::Assign {
+    return None;
+  }
+  match &node.left {
+    PatOrExpr::Expr(box expr) => get_commonjs_export_member_name(expr, unresolved_ctxt),
+    PatOrExpr::Pat(box Pat::Expr(box expr)) => {
+      get_commonjs_export_member_name(expr, unresolved_ctxt)
+    }
+    PatOrExpr::Pat(_) => None,
+  }
+}
+
+/// Strip the `exports.xxx = ` part of an assignment chain, returns the export names and the assigned value
+fn get_commonjs_export_assign_chain(
+  mut expr: &Expr,
+  unresolved_ctxt: SyntaxContext,
+) -> (Vec<JsWord>, &Expr) {
+  let mut names = vec![];
+  while let Expr::Assign(assign) = expr {
+    match get_commonjs_export_assign_target(assign, unresolved_ctxt) {
+      Some(name) => {
+        names.push(name);
+        expr = &assign.right;
+      }
+      None => break,
+    }
+  }
+  (names, expr)
+}
+
+fn get_static_prop_name(prop_name: &PropName) -> Option<JsWord> {
+  match prop_name {
+    PropName::Ident(ident) => Some(ident.sym.clone()),
+    PropName::Str(str) => Some(str.value.clone()),
+    PropName::Num(_) | PropName::Computed(_) | PropName::BigInt(_) => None,
+  }
+}
+
+fn get_commonjs_export_object_props(object: &ObjectLit) -> Option<Vec<(JsWord, &Prop)>> {
+  object
+    .props
+    .iter()
+    .map(|prop| match prop {
+      PropOrSpread::Prop(box prop @ Prop::Shorthand(ident)) => Some((ident.sym.clone(), prop)),
+      PropOrSpread::Prop(box prop @ Prop::KeyValue(key_value)) => {
+        get_static_prop_name(&key_value.key).map(|name| (name, prop))
+      }
+      PropOrSpread::Prop(box prop @ Prop::Method(method)) => {
+        get_static_prop_name(&method.key).map(|name| (name, prop))
+      }
+      // getter, setter and spread may evaluate code when `module.exports` is accessed
+      PropOrSpread::Prop(_) | PropOrSpread::Spread(_) => None,
+    })
+    .collect()
+}
+
+/// Recognize the top level statement exporting something in a CommonJS way, including
+/// 1. `exports.a = value`, `module.exports.a = value` and chained assignments like `exports.a = exports.b = void 0`
+/// 2. `module.exports = { a, b: value }` whose keys are all static
+/// 3. `Object.defineProperty(exports, "a", descriptor)`
+pub fn get_commonjs_export(
+  module_item: &ModuleItem,
+  unresolved_ctxt: SyntaxContext,
+) -> Option<CommonJsExport<'_>> {
+  let expr = match module_item {
+    ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => &**expr,
+    _ => return None,
+  };
+  match expr {
+    Expr::Assign(assign) => {
+      let (names, value) = get_commonjs_export_assign_chain(expr, unresolved_ctxt);
+      if !names.is_empty() {
+        return Some(CommonJsExport::Assign(names, value));
+      }
+      match (&assign.left, &assign.right) {
+        (
+          PatOrExpr::Expr(box left) | PatOrExpr::Pat(box Pat::Expr(box left)),
+          box Expr::Object(object),
+        ) if assign.op == AssignOp::Assign
+          && matches!(left, Expr::Member(_))
+          && is_commonjs_exports_object(left, unresolved_ctxt) =>
+        {
+          get_commonjs_export_object_props(object).map(CommonJsExport::Object)
+        }
+        _ => None,
+      }
+    }
+    Expr::Call(call) if is_object_define_property_call(call, unresolved_ctxt) => {
+      match call.args.as_slice() {
+        [ExprOrSpread {
+          spread: None,
+          expr: box target,
+        }, ExprOrSpread {
+          spread: None,
+          expr: box Expr::Lit(Lit::Str(name)),
+        }, ExprOrSpread {
+          spread: None,
+          expr: box descriptor,
+        }] if is_commonjs_exports_object(target, unresolved_ctxt) => Some(
+          CommonJsExport::DefineProperty(name.value.clone(), descriptor),
+        ),
+        _ => None,
+      }
+    }
+    _ => None,
+  }
+}
+
+fn is_object_define_property_call(call: &CallExpr, unresolved_ctxt: SyntaxContext) -> bool {
+  matches!(&call.callee, Callee::Expr(box Expr::Member(MemberExpr {
+    obj: box Expr::Ident(Ident { sym: obj_sym, span, .. }),
+    prop: MemberProp::Ident(Ident { sym: prop_sym, .. }),
+    ..
+  })) if span.ctxt == unresolved_ctxt && obj_sym == "Object" && prop_sym == "defineProperty")
+}
+
+/// CommonJS exports don't create any binding, so we use a symbol with an empty syntax context
+/// to represent each of them.
+pub fn create_commonjs_export_symbol(module_identifier: Identifier, name: JsWord) -> Symbol {
+  Symbol::new(
+    module_identifier,
+    BetterId {
+      ctxt: SyntaxContext::empty(),
+      atom: name,
+    },
+    SymbolType::Define,
+  )
+}
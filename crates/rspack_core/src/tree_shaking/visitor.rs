@@ -25,7 +25,10 @@ use super::SideEffect;
 // use swc_ecma_ast::*;
 // use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
 use super::{
-  utils::{get_dynamic_import_string_literal, get_require_literal},
+  utils::{
+    create_commonjs_export_symbol, get_commonjs_export, get_dynamic_import_string_literal,
+    get_require_literal, CommonJsExport,
+  },
   BailoutFlag,
 };
 use crate::{
@@ -137,6 +140,17 @@ pub(crate) struct ModuleRefAnalyze<'a> {
   /// 1. `require()` -> CommonJs
   /// 2. `export ` -> ESM
   module_syntax: ModuleSyntax,
+  /// Names exported by top level CommonJS export statements, see [get_commonjs_export]
+  commonjs_export_names: HashSet<JsWord>,
+  /// Become `false` once `exports` or `module.exports` is used in a way we can't analyze, e.g.
+  /// ```js
+  /// exports[key] = value;
+  /// function setExport() { exports.a = 1 }
+  /// ```
+  is_commonjs_exports_analyzable: bool,
+  /// Whether the module has `exports.__esModule = true` or `Object.defineProperty(exports, "__esModule", ..)`
+  pub(crate) has_es_module_flag: bool,
+  pub(crate) commonjs_exports_analyzed: bool,
   pub(crate) bail_out_module_identifiers: IdentifierMap<BailoutFlag>,
   pub(crate) side_effects: SideEffect,
   pub(crate) options: &'a Arc<CompilerOptions>,
@@ -231,6 +245,10 @@ impl<'a> ModuleRefAnalyze<'a> {
       used_symbol_ref: HashSet::default(),
       export_default_name: None,
       module_syntax: ModuleSyntax::empty(),
+      commonjs_export_names: HashSet::default(),
+      is_commonjs_exports_analyzable: true,
+      has_es_module_flag: false,
+      commonjs_exports_analyzed: false,
       bail_out_module_identifiers: IdentifierMap::default(),
       side_effects: SideEffect::Analyze(true),
       immediate_evaluate_reference_map: HashMap::default(),
@@ -342,11 +360,124 @@ impl<'a> ModuleRefAnalyze<'a> {
     default_ident
   }
 
+  /// Any access of `exports` or `module.exports` reaching here is not a top level export statement
+  /// recognized by [get_commonjs_export], so the CommonJS exports of the module can't be analyzed.
   fn check_commonjs_feature(&mut self, obj: &Ident, prop: &str) {
-    if self.state.contains(AnalyzeState::ASSIGNMENT_LHS)
+    if obj.span.ctxt == self.unresolved_ctxt
       && ((&obj.sym == "module" && prop == "exports") || &obj.sym == "exports")
     {
-      self.module_syntax.insert(ModuleSyntax::COMMONJS);
+      self.is_commonjs_exports_analyzable = false;
+      if self.state.contains(AnalyzeState::ASSIGNMENT_LHS) {
+        self.module_syntax.insert(ModuleSyntax::COMMONJS);
+      }
+    }
+  }
+
+  fn analyze_commonjs_export(&mut self, commonjs_export: CommonJsExport<'_>) {
+    self.module_syntax.insert(ModuleSyntax::COMMONJS);
+    match commonjs_export {
+      CommonJsExport::Assign(names, value) => {
+        let flag = match value {
+          Expr::Fn(_) => SymbolFlag::FUNCTION_EXPR,
+          Expr::Arrow(_) => SymbolFlag::ARROW_EXPR,
+          Expr::Class(_) => SymbolFlag::CLASS_EXPR,
+          Expr::Ident(_) => SymbolFlag::ALIAS,
+          _ => SymbolFlag::empty(),
+        };
+        self.analyze_commonjs_export_value(names, value, flag);
+      }
+      CommonJsExport::Object(props) => {
+        for (name, prop) in props {
+          let flag = match prop {
+            Prop::Shorthand(_) => SymbolFlag::ALIAS,
+            Prop::Method(_) => SymbolFlag::FUNCTION_EXPR,
+            Prop::KeyValue(KeyValueProp { value, .. }) => match &**value {
+              Expr::Fn(_) => SymbolFlag::FUNCTION_EXPR,
+              Expr::Arrow(_) => SymbolFlag::ARROW_EXPR,
+              Expr::Class(_) => SymbolFlag::CLASS_EXPR,
+              Expr::Ident(_) => SymbolFlag::ALIAS,
+              _ => SymbolFlag::empty(),
+            },
+            _ => SymbolFlag::empty(),
+          };
+          self.analyze_commonjs_export_value(vec![name], prop, flag);
+        }
+      }
+      CommonJsExport::DefineProperty(name, descriptor) => {
+        self.analyze_commonjs_export_value(vec![name], descriptor, SymbolFlag::empty());
+      }
+    }
+  }
+
+  /// Visit the exported value with the first export name as the body owner, other names of
+  /// a chained assignment reference the first one.
+  fn analyze_commonjs_export_value<T: VisitWith<Self>>(
+    &mut self,
+    names: Vec<JsWord>,
+    value: &T,
+    flag: SymbolFlag,
+  ) {
+    let mut symbol_ext_list = vec![];
+    for name in names {
+      if &name == "__esModule" {
+        self.has_es_module_flag = true;
+        continue;
+      }
+      let symbol = create_commonjs_export_symbol(self.module_identifier, name.clone());
+      symbol_ext_list.push(SymbolExt::new(
+        symbol.id().clone(),
+        SymbolFlag::VAR_DECL | flag,
+      ));
+      self.commonjs_export_names.insert(name);
+    }
+    let body_owner_symbol_ext = symbol_ext_list.first().cloned();
+    if let Some(ref body_owner_symbol_ext) = body_owner_symbol_ext {
+      for symbol_ext in symbol_ext_list.iter().skip(1) {
+        self.add_reference(
+          symbol_ext.clone(),
+          IdOrMemExpr::Id(body_owner_symbol_ext.id().clone()),
+          false,
+        );
+      }
+    }
+    let before_owner_extend_symbol = self.current_body_owner_symbol_ext.clone();
+    self.current_body_owner_symbol_ext = body_owner_symbol_ext;
+    value.visit_with(self);
+    self.current_body_owner_symbol_ext = before_owner_extend_symbol;
+  }
+
+  /// A CommonJS module is tree-shakable only if all of its exports are recognized
+  /// and it doesn't mix with ESM syntax, otherwise we bailout the module.
+  fn finalize_commonjs_exports(&mut self, program: &Program) {
+    if !self.module_syntax.contains(ModuleSyntax::COMMONJS) {
+      return;
+    }
+    let has_module_decl = match program {
+      Program::Module(module) => module
+        .body
+        .iter()
+        .any(|item| matches!(item, ModuleItem::ModuleDecl(_))),
+      Program::Script(_) => false,
+    };
+    // Without `__esModule` flag, the default export of a CommonJS module is `module.exports` itself
+    // rather than `exports.default`
+    let has_ambiguous_default_export =
+      !self.has_es_module_flag && self.commonjs_export_names.contains(&js_word!("default"));
+    let names = std::mem::take(&mut self.commonjs_export_names);
+    if self.is_commonjs_exports_analyzable && !has_module_decl && !has_ambiguous_default_export {
+      self.commonjs_exports_analyzed = true;
+      for name in names {
+        let symbol = create_commonjs_export_symbol(self.module_identifier, name.clone());
+        self.add_export(name, SymbolRef::Direct(symbol));
+      }
+    } else {
+      // All the exported values are evaluated as is, so anything they reference is used
+      for name in names {
+        let symbol = create_commonjs_export_symbol(self.module_identifier, name);
+        self
+          .used_id_set
+          .insert(IdOrMemExpr::Id(symbol.id().clone()));
+      }
       match self
         .bail_out_module_identifiers
         .entry(self.module_identifier)
@@ -368,6 +499,7 @@ impl<'a> Visit for ModuleRefAnalyze<'a> {
     assert!(GLOBALS.is_set());
     self.unresolved_ctxt = self.unresolved_ctxt.apply_mark(self.unresolved_mark);
     node.visit_children_with(self);
+    self.finalize_commonjs_exports(node);
     // calc reachable imports for each export symbol defined in current module
     for (key, symbol) in self.export_map.iter() {
       match symbol {
@@ -518,7 +650,10 @@ impl<'a> Visit for ModuleRefAnalyze<'a> {
     for module_item in &node.body {
       if !is_import_decl(module_item) {
         self.analyze_stmt_side_effects(module_item);
-        module_item.visit_with(self);
+        match get_commonjs_export(module_item, self.unresolved_ctxt) {
+          Some(commonjs_export) => self.analyze_commonjs_export(commonjs_export),
+          None => module_item.visit_with(self),
+        }
       }
     }
   }
@@ -526,6 +661,10 @@ impl<'a> Visit for ModuleRefAnalyze<'a> {
   fn visit_ident(&mut self, node: &Ident) {
     let id: BetterId = node.to_id().into();
     let mark = id.ctxt.outer();
+    // e.g. `typeof exports`, `fn(module)`
+    if id.ctxt == self.unresolved_ctxt && (&id.atom == "exports" || &id.atom == "module") {
+      self.is_commonjs_exports_analyzable = false;
+    }
 
     if self.potential_top_mark.contains(&mark) {
       match self.current_body_owner_symbol_ext {
@@ -1435,6 +1574,15 @@ pub struct TreeShakingResult {
   pub(crate) bail_out_module_identifiers: IdentifierMap<BailoutFlag>,
   pub(crate) side_effects: SideEffect,
   pub(crate) module_syntax: ModuleSyntax,
+  pub(crate) commonjs_exports_analyzed: bool,
+  pub(crate) has_es_module_flag: bool,
+}
+
+impl TreeShakingResult {
+  /// Whether all the exports of the CommonJS module are statically analyzed, so they could be shaken
+  pub fn is_commonjs_exports_analyzed(&self) -> bool {
+    self.commonjs_exports_analyzed
+  }
 }
 
 impl From<ModuleRefAnalyze<'_>> for TreeShakingResult {
@@ -1454,6 +1602,8 @@ impl From<ModuleRefAnalyze<'_>> for TreeShakingResult {
       bail_out_module_identifiers: analyze.bail_out_module_identifiers,
       side_effects: analyze.side_effects,
       module_syntax: analyze.module_syntax,
+      commonjs_exports_analyzed: analyze.commonjs_exports_analyzed,
+      has_es_module_flag: analyze.has_es_module_flag,
     }
   }
 }
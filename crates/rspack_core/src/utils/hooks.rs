@@ -1,9 +1,13 @@
-use std::path::Path;
+use std::{path::Path, sync::Arc};
 
+use nodejs_resolver::DescriptionData;
 use rspack_error::{internal_error, Error, TraceableError};
 use sugar_path::{AsPath, SugarPath};
 
-use crate::{ResolveArgs, ResolveOptionsWithDependencyType, ResolveResult, SharedPluginDriver};
+use crate::{
+  DependencyCategory, DependencyType, Resolve, ResolveArgs, ResolveOptionsWithDependencyType,
+  ResolveResult, SharedPluginDriver,
+};
 
 /// Tuple used to represent a resolve error.
 /// The first element is the error message for runtime and the second element is the error used for stats and so on.
@@ -133,3 +137,27 @@ pub async fn resolve(
     }
   })
 }
+
+/// Loads the description data of a resolved file by resolving the absolute path of it,
+/// which doesn't look up `node_modules` and is much cheaper than the original resolving.
+pub async fn resolve_description(
+  path: &Path,
+  resolve_options: Option<Resolve>,
+  dependency_type: DependencyType,
+  dependency_category: DependencyCategory,
+  plugin_driver: &SharedPluginDriver,
+) -> Option<Arc<DescriptionData>> {
+  let plugin_driver = plugin_driver.read().await;
+  let resolver = plugin_driver
+    .resolver_factory
+    .get(ResolveOptionsWithDependencyType {
+      resolve_options,
+      resolve_to_context: false,
+      dependency_type,
+      dependency_category,
+    });
+  match resolver.resolve(path.parent()?, &path.to_string_lossy()) {
+    Ok(ResolveResult::Resource(resource)) => resource.description,
+    _ => None,
+  }
+}
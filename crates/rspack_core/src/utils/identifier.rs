@@ -20,3 +20,14 @@ static IDENTIFIER_REGEXP: Lazy<Regex> =
 pub fn to_identifier(v: &str) -> Cow<'_, str> {
   IDENTIFIER_REGEXP.replace_all(v, "_")
 }
+
+static PATH_NAME_NORMALIZE_REPLACE_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"[^a-zA-Z0-9_!§$()=\-^°]+").expect("should init regex"));
+
+/// Same as `Template.toPath` of webpack, e.g. `./locales/en.json` to `locales-en-json`
+pub fn to_path(v: &str) -> String {
+  PATH_NAME_NORMALIZE_REPLACE_REGEX
+    .replace_all(v, "-")
+    .trim_matches('-')
+    .to_string()
+}
@@ -157,3 +157,8 @@ pub fn stringify_value_vec_map(map: &HashMap<String, Vec<String>>) -> String {
 pub fn stringify_vec(vec: &[String]) -> String {
   format!("[{}]", vec.iter().map(|s| format!("'{s}'")).join(",  "))
 }
+
+/// A JavaScript string literal of the value, quotes and line breaks in it are escaped.
+pub fn json_string(value: &str) -> String {
+  serde_json::to_string(value).unwrap_or_else(|_| format!("\"{value}\""))
+}
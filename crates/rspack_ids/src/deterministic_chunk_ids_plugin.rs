@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use rspack_core::{Compilation, Plugin};
+use rspack_error::Result;
+
+use crate::id_helpers::{
+  assign_deterministic_ids, compare_chunks_natural, get_full_chunk_name, get_used_chunk_ids,
+};
+
+#[derive(Debug)]
+pub struct DeterministicChunkIdsPlugin {
+  pub context: Option<String>,
+  pub max_length: usize,
+}
+
+impl DeterministicChunkIdsPlugin {
+  pub fn new(context: Option<String>, max_length: Option<usize>) -> Self {
+    Self {
+      context,
+      max_length: max_length.unwrap_or(3),
+    }
+  }
+}
+
+impl Default for DeterministicChunkIdsPlugin {
+  fn default() -> Self {
+    Self::new(None, None)
+  }
+}
+
+impl Plugin for DeterministicChunkIdsPlugin {
+  fn chunk_ids(&mut self, compilation: &mut Compilation) -> Result<()> {
+    let mut used_ids = get_used_chunk_ids(compilation);
+    let chunk_graph = &compilation.chunk_graph;
+    let module_graph = &compilation.module_graph;
+    let context = self
+      .context
+      .clone()
+      .unwrap_or_else(|| compilation.options.context.to_string_lossy().to_string());
+
+    let chunks = compilation
+      .chunk_by_ukey
+      .values()
+      .filter(|chunk| chunk.id.is_none())
+      .collect::<Vec<_>>();
+    let mut chunk_ukey_to_id = HashMap::with_capacity(chunks.len());
+    let used_ids_len = used_ids.len();
+    assign_deterministic_ids(
+      chunks,
+      |chunk| get_full_chunk_name(chunk, chunk_graph, &context, module_graph),
+      |a, b| compare_chunks_natural(chunk_graph, module_graph, a, b),
+      |chunk, id| {
+        let size = used_ids.len();
+        used_ids.insert(id.clone());
+        if used_ids.len() == size {
+          return false;
+        }
+        chunk_ukey_to_id.insert(chunk.ukey, id);
+        true
+      },
+      &[usize::pow(10, self.max_length as u32)],
+      10,
+      used_ids_len,
+      0,
+    );
+
+    chunk_ukey_to_id.into_iter().for_each(|(chunk_ukey, id)| {
+      let chunk = compilation
+        .chunk_by_ukey
+        .get_mut(&chunk_ukey)
+        .expect("Chunk should exist");
+      chunk.id = Some(id.clone());
+      chunk.ids = vec![id];
+    });
+
+    Ok(())
+  }
+}
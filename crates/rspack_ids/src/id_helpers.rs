@@ -417,6 +417,50 @@ pub fn request_to_id(request: &str) -> String {
     .to_string()
 }
 
+pub fn get_full_chunk_name(
+  chunk: &Chunk,
+  chunk_graph: &ChunkGraph,
+  context: &str,
+  module_graph: &ModuleGraph,
+) -> String {
+  if let Some(name) = &chunk.name {
+    return name.clone();
+  }
+  chunk_graph
+    .get_chunk_root_modules(&chunk.ukey, module_graph)
+    .iter()
+    .filter_map(|id| module_graph.module_by_identifier(id))
+    .map(|module| get_full_module_name(module, context))
+    .collect::<Vec<_>>()
+    .join(",")
+}
+
+pub fn compare_chunks_natural(
+  chunk_graph: &ChunkGraph,
+  module_graph: &ModuleGraph,
+  a: &Chunk,
+  b: &Chunk,
+) -> Ordering {
+  let a_modules = chunk_graph.get_ordered_chunk_modules(&a.ukey, module_graph);
+  let b_modules = chunk_graph.get_ordered_chunk_modules(&b.ukey, module_graph);
+  for (a_module, b_module) in a_modules.iter().zip(b_modules.iter()) {
+    let cmp = compare_ids(
+      chunk_graph
+        .get_module_id(a_module.identifier())
+        .as_deref()
+        .unwrap_or_default(),
+      chunk_graph
+        .get_module_id(b_module.identifier())
+        .as_deref()
+        .unwrap_or_default(),
+    );
+    if cmp != Ordering::Equal {
+      return cmp;
+    }
+  }
+  compare_numbers(a_modules.len(), b_modules.len())
+}
+
 pub fn get_used_chunk_ids(compilation: &Compilation) -> HashSet<String> {
   let mut used_ids = compilation
     .used_chunk_ids
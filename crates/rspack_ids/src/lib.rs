@@ -7,3 +7,9 @@ mod named_chunk_ids_plugin;
 pub use named_chunk_ids_plugin::*;
 mod stable_named_chunk_ids_plugin;
 pub use stable_named_chunk_ids_plugin::StableNamedChunkIdsPlugin;
+mod deterministic_chunk_ids_plugin;
+pub use deterministic_chunk_ids_plugin::*;
+mod natural_chunk_ids_plugin;
+pub use natural_chunk_ids_plugin::*;
+mod occurrence_chunk_ids_plugin;
+pub use occurrence_chunk_ids_plugin::*;
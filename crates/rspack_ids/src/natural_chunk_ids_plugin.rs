@@ -0,0 +1,24 @@
+use rspack_core::{Compilation, Plugin};
+use rspack_error::Result;
+
+use crate::id_helpers::{assign_ascending_chunk_ids, compare_chunks_natural};
+
+#[derive(Debug, Default)]
+pub struct NaturalChunkIdsPlugin {}
+
+impl Plugin for NaturalChunkIdsPlugin {
+  fn chunk_ids(&mut self, compilation: &mut Compilation) -> Result<()> {
+    let chunk_graph = &compilation.chunk_graph;
+    let module_graph = &compilation.module_graph;
+
+    let mut chunks = compilation.chunk_by_ukey.values().collect::<Vec<_>>();
+    chunks.sort_unstable_by(|a, b| compare_chunks_natural(chunk_graph, module_graph, a, b));
+    let chunks = chunks.iter().map(|chunk| chunk.ukey).collect::<Vec<_>>();
+
+    if !chunks.is_empty() {
+      assign_ascending_chunk_ids(&chunks, compilation);
+    }
+
+    Ok(())
+  }
+}
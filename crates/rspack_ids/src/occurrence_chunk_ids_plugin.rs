@@ -0,0 +1,67 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use rspack_core::{Compilation, Plugin};
+use rspack_error::Result;
+
+use crate::id_helpers::{assign_ascending_chunk_ids, compare_chunks_natural};
+
+/// Assigns ascending ids to chunks, the chunks that are referenced most often get the shortest ids.
+#[derive(Debug, Default)]
+pub struct OccurrenceChunkIdsPlugin {
+  // Prioritise the chunks which are loaded by the initial chunks.
+  pub prioritise_initial: bool,
+}
+
+impl OccurrenceChunkIdsPlugin {
+  pub fn new(prioritise_initial: bool) -> Self {
+    Self { prioritise_initial }
+  }
+}
+
+impl Plugin for OccurrenceChunkIdsPlugin {
+  fn chunk_ids(&mut self, compilation: &mut Compilation) -> Result<()> {
+    let chunk_graph = &compilation.chunk_graph;
+    let module_graph = &compilation.module_graph;
+    let chunk_group_by_ukey = &compilation.chunk_group_by_ukey;
+
+    let occurs_in_initial_chunks = compilation
+      .chunk_by_ukey
+      .values()
+      .map(|chunk| {
+        let occurs = chunk
+          .groups
+          .iter()
+          .filter_map(|group| chunk_group_by_ukey.get(group))
+          .flat_map(|group| group.parents_iterable())
+          .filter_map(|parent| chunk_group_by_ukey.get(parent))
+          .filter(|parent| parent.is_initial())
+          .count();
+        (chunk.ukey, occurs)
+      })
+      .collect::<HashMap<_, _>>();
+
+    let mut chunks = compilation.chunk_by_ukey.values().collect::<Vec<_>>();
+    chunks.sort_unstable_by(|a, b| {
+      if self.prioritise_initial {
+        let a_entry_occurs = occurs_in_initial_chunks.get(&a.ukey);
+        let b_entry_occurs = occurs_in_initial_chunks.get(&b.ukey);
+        let cmp = b_entry_occurs.cmp(&a_entry_occurs);
+        if cmp != Ordering::Equal {
+          return cmp;
+        }
+      }
+      let cmp = b.groups.len().cmp(&a.groups.len());
+      if cmp != Ordering::Equal {
+        return cmp;
+      }
+      compare_chunks_natural(chunk_graph, module_graph, a, b)
+    });
+    let chunks = chunks.iter().map(|chunk| chunk.ukey).collect::<Vec<_>>();
+
+    if !chunks.is_empty() {
+      assign_ascending_chunk_ids(&chunks, compilation);
+    }
+
+    Ok(())
+  }
+}
@@ -58,6 +58,7 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
             import_function_name: "import".to_string(),
             iife: true,
             module: false,
+            worker_chunk_loading: None,
           },
           target: rspack_core::Target::new(&vec![String::from("web")]).expect("TODO:"),
           resolve: rspack_core::Resolve::default(),
@@ -72,6 +73,8 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
             remove_available_modules: false,
             side_effects: SideEffectOption::False,
           },
+          records_input_path: None,
+          records_output_path: None,
         }),
         resolver_factory: Default::default(),
       },
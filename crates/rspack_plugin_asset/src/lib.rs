@@ -13,7 +13,7 @@ use rayon::prelude::*;
 use rspack_core::{
   get_contenthash,
   rspack_sources::{RawSource, SourceExt},
-  AssetParserDataUrlOption, AssetParserOptions, AstOrSource, FilenameRenderOptions,
+  AssetInfo, AssetParserDataUrlOption, AssetParserOptions, AstOrSource, FilenameRenderOptions,
   GenerateContext, GenerationResult, Module, ModuleIdentifier, ParseContext, ParserAndGenerator,
   PathData, Plugin, PluginContext, PluginRenderManifestHookOutput, RenderManifestArgs,
   RenderManifestEntry, RuntimeGlobals, SourceType,
@@ -528,6 +528,7 @@ impl Plugin for AssetPlugin {
               PathData {
                 chunk_ukey: args.chunk_ukey,
               },
+              AssetInfo::default(),
             )
           });
 
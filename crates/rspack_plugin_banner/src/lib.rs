@@ -0,0 +1,125 @@
+#![feature(let_chains)]
+
+use async_trait::async_trait;
+use rspack_core::{
+  rspack_sources::{ConcatSource, RawSource, SourceExt},
+  Chunk, Filename, FilenameRenderOptions, Plugin, PluginContext, PluginProcessAssetsOutput,
+  ProcessAssetsArgs, RuleSetCondition,
+};
+use rspack_error::Result;
+
+#[derive(Debug)]
+pub struct BannerConfig {
+  /// Specifies the banner, placeholders such as `[name]`, `[hash]` and `[chunkhash]` are rendered.
+  pub banner: String,
+  /// If true, the banner will only be added to the entry chunks.
+  pub entry_only: Option<bool>,
+  /// If true, the banner will be placed at the end of the output.
+  pub footer: Option<bool>,
+  /// If true, the banner will not be wrapped in a comment.
+  pub raw: Option<bool>,
+  /// Include all modules that pass test assertion.
+  pub test: Option<RuleSetCondition>,
+  /// Include all modules matching any of these conditions.
+  pub include: Option<RuleSetCondition>,
+  /// Exclude all modules matching any of these conditions.
+  pub exclude: Option<RuleSetCondition>,
+}
+
+fn wrap_comment(str: &str) -> String {
+  let str = str.replace("*/", "* /");
+  if !str.contains('\n') {
+    return format!("/*! {str} */");
+  }
+  let lines = str
+    .split('\n')
+    .map(|line| format!(" * {line}").trim_end().to_string())
+    .collect::<Vec<_>>()
+    .join("\n");
+  format!("/*!\n{lines}\n */")
+}
+
+#[derive(Debug)]
+pub struct BannerPlugin {
+  config: BannerConfig,
+  comment: String,
+}
+
+impl BannerPlugin {
+  pub fn new(config: BannerConfig) -> Self {
+    let comment = if config.raw.unwrap_or(false) {
+      config.banner.clone()
+    } else {
+      wrap_comment(&config.banner)
+    };
+    Self { config, comment }
+  }
+
+  async fn match_object(&self, filename: &str) -> Result<bool> {
+    if let Some(test) = &self.config.test && !test.try_match(filename).await? {
+      return Ok(false);
+    }
+    if let Some(include) = &self.config.include && !include.try_match(filename).await? {
+      return Ok(false);
+    }
+    if let Some(exclude) = &self.config.exclude && exclude.try_match(filename).await? {
+      return Ok(false);
+    }
+    Ok(true)
+  }
+
+  fn render_comment(&self, chunk: &Chunk, hash: &str) -> String {
+    let chunkhash = chunk.get_render_hash();
+    Filename::from(self.comment.clone()).render(FilenameRenderOptions {
+      name: chunk.name_for_filename_template(),
+      chunkhash: Some(chunkhash),
+      hash: Some(hash.to_string()),
+      ..Default::default()
+    })
+  }
+}
+
+#[async_trait]
+impl Plugin for BannerPlugin {
+  fn name(&self) -> &'static str {
+    "BannerPlugin"
+  }
+
+  async fn process_assets_stage_additional(
+    &mut self,
+    _ctx: PluginContext,
+    args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
+    let compilation = args.compilation;
+    let entry_only = self.config.entry_only.unwrap_or(false);
+
+    let mut updates = vec![];
+    for chunk in compilation.chunk_by_ukey.values() {
+      if entry_only && !chunk.can_be_initial(&compilation.chunk_group_by_ukey) {
+        continue;
+      }
+      for file in &chunk.files {
+        if !self.match_object(file).await? {
+          continue;
+        }
+        updates.push((file.clone(), self.render_comment(chunk, &compilation.hash)));
+      }
+    }
+
+    let footer = self.config.footer.unwrap_or(false);
+    for (file, comment) in updates {
+      compilation.update_asset(&file, |source, _| {
+        let comment = RawSource::from(comment).boxed();
+        let separator = RawSource::from("\n").boxed();
+        *source = if footer {
+          ConcatSource::new([source.clone(), separator, comment]).boxed()
+        } else {
+          ConcatSource::new([comment, separator, source.clone()]).boxed()
+        };
+        Ok(())
+      })?;
+    }
+
+    Ok(())
+  }
+}
@@ -20,7 +20,7 @@ use rspack_core::{
     BoxSource, ConcatSource, MapOptions, RawSource, Source, SourceExt, SourceMap, SourceMapSource,
     SourceMapSourceOptions,
   },
-  Chunk, ChunkGraph, ChunkKind, Compilation, FilenameRenderOptions, GenerateContext,
+  AssetInfo, Chunk, ChunkGraph, ChunkKind, Compilation, FilenameRenderOptions, GenerateContext,
   GenerationResult, Module, ModuleGraph, ModuleType, NormalModuleAstOrSource, ParseContext,
   ParseResult, ParserAndGenerator, PathData, Plugin, RenderManifestEntry, SourceType,
 };
@@ -710,7 +710,13 @@ impl Plugin for CssPlugin {
         &args.compilation.chunk_group_by_ukey,
       );
 
-      let output_path = filename_template.render_with_chunk(chunk, ".css", &SourceType::Css);
+      let mut asset_info = AssetInfo::default();
+      let output_path = filename_template.render_with_chunk_and_asset_info(
+        chunk,
+        ".css",
+        &SourceType::Css,
+        &mut asset_info,
+      );
 
       let path_data = PathData {
         chunk_ukey: args.chunk_ukey,
@@ -719,6 +725,7 @@ impl Plugin for CssPlugin {
         source.boxed(),
         output_path,
         path_data,
+        asset_info,
       )])
     }
   }
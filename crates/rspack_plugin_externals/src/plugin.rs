@@ -1,9 +1,9 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_core::{
-  ApplyContext, ExternalItem, ExternalItemValue, ExternalModule, ExternalType, FactorizeArgs,
-  ModuleDependency, ModuleExt, ModuleFactoryResult, NormalModuleFactoryContext, Plugin,
-  PluginContext, PluginFactorizeHookOutput,
+  ApplyContext, ExternalItem, ExternalItemFnCtx, ExternalItemValue, ExternalModule,
+  ExternalRequest, ExternalType, FactorizeArgs, ModuleDependency, ModuleExt, ModuleFactoryResult,
+  NormalModuleFactoryContext, Plugin, PluginContext, PluginFactorizeHookOutput,
 };
 use rspack_error::Result;
 
@@ -27,32 +27,56 @@ impl ExternalPlugin {
     r#type: Option<String>,
     dependency: &dyn ModuleDependency,
   ) -> Option<ExternalModule> {
-    let mut external_module_config = match config {
-      ExternalItemValue::String(config) => config,
+    // The type prefix of the request is only respected when the type is not specified explicitly
+    let split: fn(&str) -> (Option<ExternalType>, &str) = if r#type.is_none() {
+      split_external_type
+    } else {
+      |config| (None, config)
+    };
+    let (external_module_config, external_module_type) = match config {
+      ExternalItemValue::String(config) => {
+        let (r#type, config) = split(config);
+        (ExternalRequest::from(config.to_owned()), r#type)
+      }
       ExternalItemValue::Bool(config) => {
         if *config {
-          dependency.request()
+          let (r#type, config) = split(dependency.request());
+          (ExternalRequest::from(config.to_owned()), r#type)
         } else {
           return None;
         }
       }
-    };
-    let external_module_type = r#type.unwrap_or_else(|| {
-      if UNSPECIFIED_EXTERNAL_TYPE_REGEXP.is_match(external_module_config)
-        && let Some((t, c)) = external_module_config.split_once(' ') {
-        external_module_config = c;
-        return t.to_owned();
+      ExternalItemValue::Array(config) => {
+        let mut config = config.clone();
+        let r#type = config.first_mut().and_then(|first| {
+          let (r#type, request) = split(first);
+          let r#type = r#type?;
+          *first = request.to_owned();
+          Some(r#type)
+        });
+        (ExternalRequest::Single(config), r#type)
       }
-      self.r#type.clone()
-    });
+      ExternalItemValue::Object(config) => (ExternalRequest::Map(config.clone()), None),
+    };
     Some(ExternalModule::new(
-      external_module_config.to_owned(),
-      external_module_type,
+      external_module_config,
+      r#type
+        .or(external_module_type)
+        .unwrap_or_else(|| self.r#type.clone()),
       dependency.request().to_owned(),
     ))
   }
 }
 
+/// Split `"commonjs lodash"` into the external type and the request
+fn split_external_type(config: &str) -> (Option<ExternalType>, &str) {
+  if UNSPECIFIED_EXTERNAL_TYPE_REGEXP.is_match(config)
+    && let Some((t, c)) = config.split_once(' ') {
+    return (Some(t.to_owned()), c);
+  }
+  (None, config)
+}
+
 #[async_trait::async_trait]
 impl Plugin for ExternalPlugin {
   fn name(&self) -> &'static str {
@@ -67,7 +91,7 @@ impl Plugin for ExternalPlugin {
     &self,
     _ctx: PluginContext,
     args: FactorizeArgs<'_>,
-    _job_ctx: &mut NormalModuleFactoryContext,
+    job_ctx: &mut NormalModuleFactoryContext,
   ) -> PluginFactorizeHookOutput {
     for external_item in &self.externals {
       match external_item {
@@ -101,6 +125,25 @@ impl Plugin for ExternalPlugin {
             return Ok(maybe_module.map(|i| ModuleFactoryResult::new(i.boxed())));
           }
         }
+        ExternalItem::Fn(f) => {
+          let context = job_ctx
+            .original_resource_path
+            .as_ref()
+            .and_then(|p| p.parent())
+            .unwrap_or(&job_ctx.options.context)
+            .to_string_lossy()
+            .to_string();
+          let result = f(ExternalItemFnCtx {
+            request: args.dependency.request().to_string(),
+            context,
+            dependency_type: args.dependency.category().to_string(),
+          })
+          .await?;
+          if let Some(value) = result.result {
+            let maybe_module = self.handle_external(&value, result.external_type, args.dependency);
+            return Ok(maybe_module.map(|i| ModuleFactoryResult::new(i.boxed())));
+          }
+        }
       }
     }
     Ok(None)
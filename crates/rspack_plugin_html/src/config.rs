@@ -89,6 +89,9 @@ pub struct HtmlPluginConfig {
   pub title: Option<String>,
   pub favicon: Option<String>,
   pub meta: Option<HashMap<String, HashMap<String, String>>>,
+  /// emit `<link rel="preload">` for the async chunks preloaded by the initial chunks
+  #[serde(default)]
+  pub preload: bool,
 }
 
 fn default_filename() -> String {
@@ -120,6 +123,7 @@ impl Default for HtmlPluginConfig {
       title: None,
       favicon: None,
       meta: None,
+      preload: false,
     }
   }
 }
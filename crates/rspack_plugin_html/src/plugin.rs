@@ -3,11 +3,12 @@ use std::{fs, path::Path};
 use anyhow::Context;
 use async_trait::async_trait;
 use dojang::dojang::Dojang;
+use itertools::Itertools;
 use rayon::prelude::{IntoParallelRefMutIterator, ParallelIterator};
 use rspack_core::{
   parse_to_url,
   rspack_sources::{RawSource, SourceExt},
-  CompilationAsset, Plugin,
+  ChunkGroupOrderKey, CompilationAsset, Plugin,
 };
 use serde::Deserialize;
 use swc_html::visit::VisitMutWith;
@@ -97,7 +98,7 @@ impl Plugin for HtmlPlugin {
     if !diagnostic.is_empty() {
       compilation.push_batch_diagnostic(diagnostic);
     }
-    let included_assets = compilation
+    let included_entrypoints = compilation
       .entrypoints
       .keys()
       .filter(|&entry_name| {
@@ -111,6 +112,9 @@ impl Plugin for HtmlPlugin {
         included
       })
       .map(|entry_name| compilation.entrypoint_by_name(entry_name))
+      .collect::<Vec<_>>();
+    let included_assets = included_entrypoints
+      .iter()
       .flat_map(|entry| entry.get_files(&compilation.chunk_by_ukey))
       .map(|asset_name| {
         (
@@ -155,6 +159,46 @@ impl Plugin for HtmlPlugin {
       }
     }
 
+    if config.preload {
+      // async chunks which are preloaded by the initial chunks, e.g. `import(/* webpackPreload: true */ "./foo")`
+      let preload_assets = included_entrypoints
+        .iter()
+        .flat_map(|entry| entry.chunks.iter())
+        .filter_map(|chunk_ukey| compilation.chunk_by_ukey.get(chunk_ukey))
+        .flat_map(|chunk| {
+          chunk.get_child_chunks_by_order(
+            &ChunkGroupOrderKey::Preload,
+            &compilation.chunk_group_by_ukey,
+          )
+        })
+        .filter_map(|chunk_ukey| compilation.chunk_by_ukey.get(&chunk_ukey))
+        .flat_map(|chunk| chunk.files.iter().sorted())
+        .unique()
+        .filter_map(|asset_name| {
+          compilation
+            .assets
+            .get(asset_name)
+            .map(|asset| (asset_name, asset))
+        })
+        .collect::<Vec<_>>();
+      for (asset_name, asset) in preload_assets {
+        if let Some(extension) = Path::new(asset_name).extension() {
+          let asset_uri = format!(
+            "{}{asset_name}",
+            config.get_public_path(compilation, asset_name),
+          );
+          let as_type = if extension.eq_ignore_ascii_case("css") {
+            "style"
+          } else if extension.eq_ignore_ascii_case("js") || extension.eq_ignore_ascii_case("mjs") {
+            "script"
+          } else {
+            continue;
+          };
+          tags.push((HTMLPluginTag::create_preload(&asset_uri, as_type), asset));
+        }
+      }
+    }
+
     // if some plugin changes assets in the same stage after this plugin
     // both the name and the integrity may be inaccurate
     if let Some(hash_func) = &config.sri {
@@ -0,0 +1 @@
+import(/* webpackPreload: true */ "./b").then(m => console.log(m.default)); import("./c");
@@ -1,5 +1,7 @@
+use std::collections::HashSet;
 use std::sync::{mpsc, Arc};
 
+use regex::Regex;
 use rspack_core::ModuleType;
 use rspack_error::{internal_error, DiagnosticKind, Error, Result, TraceableError};
 use swc_core::{
@@ -9,7 +11,7 @@ use swc_core::{
   },
   common::{
     collections::AHashMap,
-    comments::{Comment, Comments, SingleThreadedComments},
+    comments::{Comment, CommentKind, Comments, SingleThreadedComments},
     errors::{Emitter, Handler, HANDLER},
     BytePos, FileName, Mark, SourceMap, GLOBALS,
   },
@@ -35,9 +37,19 @@ use super::stringify::print;
 use super::{parse::parse_js, stringify::SourceMapConfig};
 use crate::utils::ecma_parse_error_to_rspack_error;
 
-pub fn minify(opts: &JsMinifyOptions, input: String, filename: &str) -> Result<TransformOutput> {
+/// The minified output and the comments extracted from it
+type MinifyResult = Result<(TransformOutput, Vec<String>)>;
+
+/// Minifies `input`, comments matching `extract_comments` are removed from the output and returned
+/// in the order they appear.
+pub fn minify(
+  opts: &JsMinifyOptions,
+  input: String,
+  filename: &str,
+  extract_comments: Option<&Regex>,
+) -> MinifyResult {
   let cm: Arc<SourceMap> = Default::default();
-  GLOBALS.set(&Default::default(), || -> Result<TransformOutput> {
+  GLOBALS.set(&Default::default(), || -> MinifyResult {
     with_rspack_error_handler(
       "Minify Error".to_string(),
       DiagnosticKind::JavaScript,
@@ -167,6 +179,10 @@ pub fn minify(opts: &JsMinifyOptions, input: String, filename: &str) -> Result<T
           })
         });
 
+        let extracted_comments = extract_comments
+          .map(|condition| extract_file_comments(&comments, condition))
+          .unwrap_or_default();
+
         let preserve_comments = opts
           .format
           .comments
@@ -189,6 +205,7 @@ pub fn minify(opts: &JsMinifyOptions, input: String, filename: &str) -> Result<T
           Some(&comments),
           opts.format.ascii_only,
         )
+        .map(|output| (output, extracted_comments))
       },
     )
   })
@@ -216,7 +233,7 @@ fn minify_file_comments(
     BoolOr::Data(JsMinifyCommentOption::PreserveSomeComments) => {
       let preserve_excl = |_: &BytePos, vc: &mut Vec<Comment>| -> bool {
         // Preserve license comments.
-        vc.retain(|c: &Comment| c.text.contains("@license") || c.text.starts_with('!'));
+        vc.retain(is_license_comment);
         !vc.is_empty()
       };
       let (mut l, mut t) = comments.borrow_all_mut();
@@ -233,6 +250,39 @@ fn minify_file_comments(
   }
 }
 
+pub(crate) fn is_license_comment(comment: &Comment) -> bool {
+  comment.text.contains("@license") || comment.text.starts_with('!')
+}
+
+fn extract_file_comments(comments: &SingleThreadedComments, condition: &Regex) -> Vec<String> {
+  let mut extracted = vec![];
+  let (mut l, mut t) = comments.borrow_all_mut();
+  for comments_by_pos in [&mut *l, &mut *t] {
+    comments_by_pos.retain(|pos, vc| {
+      vc.retain(|c| {
+        if condition.is_match(&c.text) {
+          extracted.push((*pos, c.clone()));
+          false
+        } else {
+          true
+        }
+      });
+      !vc.is_empty()
+    });
+  }
+  extracted.sort_by_key(|(pos, _)| *pos);
+
+  let mut visited = HashSet::new();
+  extracted
+    .into_iter()
+    .map(|(_, c)| match c.kind {
+      CommentKind::Line => format!("//{}", c.text),
+      CommentKind::Block => format!("/*{}*/", c.text),
+    })
+    .filter(|c| visited.insert(c.clone()))
+    .collect()
+}
+
 // keep this private to make sure with_rspack_error_handler is safety
 struct RspackErrorEmitter {
   tx: mpsc::Sender<rspack_error::Error>,
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use rspack_core::{ast::javascript::Ast, Devtool};
 use rspack_error::{internal_error, Result};
 use swc_core::{
-  base::TransformOutput,
+  base::{SwcComments, TransformOutput},
   common::{
     collections::AHashMap, comments::Comments, source_map::SourceMapGenConfig, BytePos, FileName,
     SourceMap,
@@ -19,8 +19,19 @@ use swc_core::{
   },
 };
 
-pub fn stringify(ast: &Ast, devtool: &Devtool) -> Result<TransformOutput> {
+use super::minify::is_license_comment;
+
+/// Prints the ast, license comments are kept when `keep_license_comments` is set so that they
+/// could be carried through to the minifier.
+pub fn stringify(
+  ast: &Ast,
+  devtool: &Devtool,
+  keep_license_comments: bool,
+) -> Result<TransformOutput> {
   ast.visit(|program, context| {
+    let comments = keep_license_comments
+      .then(|| program.comments().map(license_comments))
+      .flatten();
     print(
       program.get_inner_program(),
       context.source_map.clone(),
@@ -32,12 +43,33 @@ pub fn stringify(ast: &Ast, devtool: &Devtool) -> Result<TransformOutput> {
         names: Default::default(),
       },
       false,
-      None,
+      comments.as_ref().map(|c| c as &dyn Comments),
       false,
     )
   })
 }
 
+fn license_comments(comments: &SwcComments) -> SwcComments {
+  let license_comments = SwcComments::default();
+  for (from, to) in [
+    (&comments.leading, &license_comments.leading),
+    (&comments.trailing, &license_comments.trailing),
+  ] {
+    from.iter().for_each(|entry| {
+      let vc = entry
+        .value()
+        .iter()
+        .filter(|c| is_license_comment(c))
+        .cloned()
+        .collect::<Vec<_>>();
+      if !vc.is_empty() {
+        to.insert(*entry.key(), vc);
+      }
+    });
+  }
+  license_comments
+}
+
 pub fn print(
   node: &SwcProgram,
   source_map: Arc<SourceMap>,
@@ -0,0 +1,81 @@
+use rspack_core::{
+  create_javascript_visitor, CodeGeneratable, CodeGeneratableContext, CodeGeneratableResult,
+  Dependency, JsAstPath, ModuleIdentifier, RuntimeGlobals,
+};
+use swc_core::ecma::{
+  ast::*,
+  utils::{quote_ident, ExprFactory},
+};
+use swc_core::quote;
+
+/// `define([...], factory)`, the value returned by the factory becomes `module.exports`
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub struct AMDDefineDependency {
+  #[allow(unused)]
+  ast_path: JsAstPath,
+}
+
+impl AMDDefineDependency {
+  pub fn new(ast_path: JsAstPath) -> Self {
+    Self { ast_path }
+  }
+}
+
+impl Dependency for AMDDefineDependency {
+  fn parent_module_identifier(&self) -> Option<&ModuleIdentifier> {
+    None
+  }
+}
+
+impl CodeGeneratable for AMDDefineDependency {
+  fn generate(
+    &self,
+    code_generatable_context: &mut CodeGeneratableContext,
+  ) -> rspack_error::Result<CodeGeneratableResult> {
+    let mut code_gen = CodeGeneratableResult::default();
+    code_generatable_context
+      .runtime_requirements
+      .insert(RuntimeGlobals::REQUIRE);
+    code_generatable_context
+      .runtime_requirements
+      .insert(RuntimeGlobals::MODULE);
+
+    code_gen.visitors.push(
+      create_javascript_visitor!(exact &self.ast_path, visit_mut_expr(n: &mut Expr) {
+        let Expr::Call(call_expr) = n else { return };
+        let mut args = call_expr.args.iter().filter(|arg| arg.spread.is_none()).map(|arg| *arg.expr.clone()).collect::<Vec<_>>();
+        // The module name of `define("name", [...], factory)` is ignored
+        if let Some(Expr::Lit(Lit::Str(_))) = args.first() {
+          args.remove(0);
+        }
+        let (array, factory) = match args.as_slice() {
+          [array @ Expr::Array(_), factory] => (array.clone(), factory.clone()),
+          [factory] => (
+            quote!(
+              "[$require, exports, module]" as Expr,
+              require = quote_ident!(RuntimeGlobals::REQUIRE),
+            ),
+            factory.clone(),
+          ),
+          _ => return,
+        };
+        *n = quote!(
+          "(function() {
+            var __WEBPACK_AMD_DEFINE_ARRAY__ = $array;
+            var __WEBPACK_AMD_DEFINE_FACTORY__ = $factory;
+            var __WEBPACK_AMD_DEFINE_RESULT__ = typeof __WEBPACK_AMD_DEFINE_FACTORY__ === 'function'
+              ? __WEBPACK_AMD_DEFINE_FACTORY__.apply(exports, __WEBPACK_AMD_DEFINE_ARRAY__)
+              : __WEBPACK_AMD_DEFINE_FACTORY__;
+            if (__WEBPACK_AMD_DEFINE_RESULT__ !== undefined) {
+              module.exports = __WEBPACK_AMD_DEFINE_RESULT__;
+            }
+          })()" as Expr,
+          array: Expr = array,
+          factory: Expr = factory.wrap_with_paren(),
+        );
+      }),
+    );
+
+    Ok(code_gen)
+  }
+}
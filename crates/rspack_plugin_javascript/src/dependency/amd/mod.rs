@@ -0,0 +1,6 @@
+mod define;
+pub use define::*;
+mod require_array;
+pub use require_array::*;
+mod require_item;
+pub use require_item::*;
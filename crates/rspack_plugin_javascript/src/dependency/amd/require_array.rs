@@ -0,0 +1,120 @@
+use rspack_core::{
+  create_javascript_visitor, CodeGeneratable, CodeGeneratableContext, CodeGeneratableResult,
+  Dependency, JsAstPath, ModuleIdentifier, RuntimeGlobals,
+};
+use swc_core::common::DUMMY_SP;
+use swc_core::ecma::{
+  ast::*,
+  utils::{quote_ident, quote_str, ExprFactory},
+};
+use swc_core::quote;
+
+/// `require([...], callback, errorCallback)`, the items of the array are loaded on demand and
+/// passed to the callback
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub struct AMDRequireArrayDependency {
+  #[allow(unused)]
+  ast_path: JsAstPath,
+}
+
+impl AMDRequireArrayDependency {
+  pub fn new(ast_path: JsAstPath) -> Self {
+    Self { ast_path }
+  }
+}
+
+impl Dependency for AMDRequireArrayDependency {
+  fn parent_module_identifier(&self) -> Option<&ModuleIdentifier> {
+    None
+  }
+}
+
+impl CodeGeneratable for AMDRequireArrayDependency {
+  fn generate(
+    &self,
+    code_generatable_context: &mut CodeGeneratableContext,
+  ) -> rspack_error::Result<CodeGeneratableResult> {
+    let mut code_gen = CodeGeneratableResult::default();
+    code_generatable_context
+      .runtime_requirements
+      .insert(RuntimeGlobals::ENSURE_CHUNK);
+    code_generatable_context
+      .runtime_requirements
+      .insert(RuntimeGlobals::LOAD_CHUNK_WITH_MODULE);
+
+    // The items have been replaced with `__webpack_require__(id)` by `AMDRequireItemDependency`
+    // before this visitor is applied
+    code_gen.visitors.push(
+      create_javascript_visitor!(exact &self.ast_path, visit_mut_expr(n: &mut Expr) {
+        let Expr::Call(call_expr) = n else { return };
+        let mut args = call_expr.args.iter().filter(|arg| arg.spread.is_none()).map(|arg| *arg.expr.clone());
+        let Some(Expr::Array(array)) = args.next() else { return };
+        let callback = args.next();
+        let error_callback = args.next();
+
+        let load_chunks = ArrayLit {
+          span: DUMMY_SP,
+          elems: array
+            .elems
+            .iter()
+            .flatten()
+            .filter_map(|item| required_module_id(&item.expr))
+            .map(|module_id| {
+              Some(
+                quote!(
+                  "$load_chunk($id)" as Expr,
+                  load_chunk = quote_ident!(RuntimeGlobals::LOAD_CHUNK_WITH_MODULE),
+                  id: Expr = module_id.into(),
+                )
+                .as_arg(),
+              )
+            })
+            .collect(),
+        };
+        let body = match callback {
+          Some(callback) => quote!(
+            "(function() {
+              var __WEBPACK_AMD_REQUIRE_ARRAY__ = $array;
+              $callback.apply(null, __WEBPACK_AMD_REQUIRE_ARRAY__);
+            })" as Expr,
+            array: Expr = Expr::Array(array),
+            callback: Expr = callback.wrap_with_paren(),
+          ),
+          None => quote!("(function() { $array; })" as Expr, array: Expr = Expr::Array(array)),
+        };
+        let mut expr = quote!(
+          "Promise.all($load_chunks).then($body)" as Expr,
+          load_chunks: Expr = load_chunks.into(),
+          body: Expr = body,
+        );
+        if let Some(error_callback) = error_callback {
+          expr = quote!(
+            "$expr['catch']($error_callback)" as Expr,
+            expr: Expr = expr,
+            error_callback: Expr = error_callback,
+          );
+        }
+        *n = expr;
+      }),
+    );
+
+    Ok(code_gen)
+  }
+}
+
+/// Matches `__webpack_require__(id)` and returns the module id
+fn required_module_id(expr: &Expr) -> Option<Str> {
+  let Expr::Call(CallExpr { callee: Callee::Expr(box Expr::Ident(callee)), args, .. }) = expr else {
+    return None;
+  };
+  if &*callee.sym != RuntimeGlobals::REQUIRE.name() {
+    return None;
+  }
+  match args.as_slice() {
+    [ExprOrSpread {
+      spread: None,
+      expr: box Expr::Lit(Lit::Str(module_id)),
+    }] => Some(quote_str!(module_id.value.clone())),
+    _ => None,
+  }
+}
@@ -0,0 +1,136 @@
+use rspack_core::{
+  create_javascript_visitor, ChunkGroupOptions, CodeGeneratable, CodeGeneratableContext,
+  CodeGeneratableResult, Dependency, DependencyCategory, DependencyId, DependencyType, ErrorSpan,
+  JsAstPath, ModuleDependency, ModuleIdentifier, RuntimeGlobals,
+};
+use swc_core::ecma::{
+  atoms::JsWord,
+  utils::{quote_ident, quote_str},
+};
+use swc_core::quote;
+
+/// An item of the array in `define(["./foo"], factory)` or `require(["./foo"], callback)`,
+/// the item is replaced with `__webpack_require__("./foo")`
+#[derive(Debug, Eq, Clone)]
+pub struct AMDRequireItemDependency {
+  id: Option<DependencyId>,
+  parent_module_identifier: Option<ModuleIdentifier>,
+  request: JsWord,
+  span: Option<ErrorSpan>,
+  /// Only the items of `require([...])` are loaded on demand
+  group_options: Option<ChunkGroupOptions>,
+  #[allow(unused)]
+  ast_path: JsAstPath,
+}
+
+// Do not edit this, as it is used to uniquely identify the dependency.
+impl PartialEq for AMDRequireItemDependency {
+  fn eq(&self, other: &Self) -> bool {
+    self.parent_module_identifier == other.parent_module_identifier && self.request == other.request
+  }
+}
+
+// Do not edit this, as it is used to uniquely identify the dependency.
+impl std::hash::Hash for AMDRequireItemDependency {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    self.parent_module_identifier.hash(state);
+    self.request.hash(state);
+    self.category().hash(state);
+    self.dependency_type().hash(state);
+  }
+}
+
+impl AMDRequireItemDependency {
+  pub fn new(
+    request: JsWord,
+    span: Option<ErrorSpan>,
+    ast_path: JsAstPath,
+    group_options: Option<ChunkGroupOptions>,
+  ) -> Self {
+    Self {
+      id: None,
+      parent_module_identifier: None,
+      request,
+      span,
+      group_options,
+      ast_path,
+    }
+  }
+}
+
+impl Dependency for AMDRequireItemDependency {
+  fn id(&self) -> Option<DependencyId> {
+    self.id
+  }
+  fn set_id(&mut self, id: Option<DependencyId>) {
+    self.id = id;
+  }
+  fn parent_module_identifier(&self) -> Option<&ModuleIdentifier> {
+    self.parent_module_identifier.as_ref()
+  }
+
+  fn set_parent_module_identifier(&mut self, module_identifier: Option<ModuleIdentifier>) {
+    self.parent_module_identifier = module_identifier;
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Amd
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::AmdRequireItem
+  }
+}
+
+impl ModuleDependency for AMDRequireItemDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    self.span.as_ref()
+  }
+
+  fn group_options(&self) -> Option<&ChunkGroupOptions> {
+    self.group_options.as_ref()
+  }
+}
+
+impl CodeGeneratable for AMDRequireItemDependency {
+  fn generate(
+    &self,
+    code_generatable_context: &mut CodeGeneratableContext,
+  ) -> rspack_error::Result<CodeGeneratableResult> {
+    let CodeGeneratableContext {
+      compilation,
+      runtime_requirements,
+      ..
+    } = code_generatable_context;
+    let mut code_gen = CodeGeneratableResult::default();
+
+    if let Some(id) = self.id() {
+      if let Some(module_id) = compilation
+        .module_graph
+        .module_graph_module_by_dependency_id(&id)
+        .map(|m| m.id(&compilation.chunk_graph).to_string())
+      {
+        runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+        code_gen.visitors.push(
+          create_javascript_visitor!(exact &self.ast_path, visit_mut_expr(n: &mut Expr) {
+            *n = quote!(
+              "$require($id)" as Expr,
+              require = quote_ident!(RuntimeGlobals::REQUIRE),
+              id: Expr = quote_str!(&*module_id).into(),
+            );
+          }),
+        );
+      }
+    }
+
+    Ok(code_gen)
+  }
+}
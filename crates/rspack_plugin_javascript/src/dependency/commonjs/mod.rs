@@ -2,3 +2,9 @@ mod require;
 pub use require::*;
 mod export;
 pub use export::*;
+mod require_resolve;
+pub use require_resolve::*;
+mod require_ensure;
+pub use require_ensure::*;
+mod require_ensure_item;
+pub use require_ensure_item::*;
@@ -0,0 +1,127 @@
+use rspack_core::{
+  create_javascript_visitor, CodeGeneratable, CodeGeneratableContext, CodeGeneratableResult,
+  Dependency, DependencyType, ErrorSpan, JsAstPath, ModuleIdentifier, RuntimeGlobals,
+};
+use swc_core::common::DUMMY_SP;
+use swc_core::ecma::{
+  ast::*,
+  utils::{quote_ident, quote_str, ExprFactory},
+};
+use swc_core::quote;
+
+/// `require.ensure(["./foo"], callback, errorCallback, "name")`, the callback is called once the
+/// chunks of the items and of the `require` calls in the callback are loaded
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub struct RequireEnsureDependency {
+  span: ErrorSpan,
+  #[allow(unused)]
+  ast_path: JsAstPath,
+}
+
+impl RequireEnsureDependency {
+  pub fn new(span: ErrorSpan, ast_path: JsAstPath) -> Self {
+    Self { span, ast_path }
+  }
+}
+
+impl Dependency for RequireEnsureDependency {
+  fn parent_module_identifier(&self) -> Option<&ModuleIdentifier> {
+    None
+  }
+}
+
+impl CodeGeneratable for RequireEnsureDependency {
+  fn generate(
+    &self,
+    code_generatable_context: &mut CodeGeneratableContext,
+  ) -> rspack_error::Result<CodeGeneratableResult> {
+    let CodeGeneratableContext {
+      compilation,
+      module,
+      runtime_requirements,
+    } = code_generatable_context;
+    let mut code_gen = CodeGeneratableResult::default();
+
+    let Some(mgm) = compilation
+      .module_graph
+      .module_graph_module_by_identifier(&module.identifier()) else {
+      return Ok(code_gen);
+    };
+    // The items of this `require.ensure` are the ones within its span
+    let module_ids = mgm
+      .dependencies
+      .iter()
+      .filter(|id| {
+        compilation
+          .module_graph
+          .dependency_by_id(id)
+          .map_or(false, |dependency| {
+            matches!(
+              dependency.dependency_type(),
+              DependencyType::RequireEnsureItem
+            ) && dependency.span().map_or(false, |span| {
+              span.start >= self.span.start && span.end <= self.span.end
+            })
+          })
+      })
+      .filter_map(|id| {
+        compilation
+          .module_graph
+          .module_graph_module_by_dependency_id(id)
+          .map(|m| m.id(&compilation.chunk_graph).to_string())
+      })
+      .fold(Vec::new(), |mut module_ids, module_id| {
+        if !module_ids.contains(&module_id) {
+          module_ids.push(module_id);
+        }
+        module_ids
+      });
+
+    runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+    runtime_requirements.insert(RuntimeGlobals::ENSURE_CHUNK);
+    runtime_requirements.insert(RuntimeGlobals::LOAD_CHUNK_WITH_MODULE);
+
+    code_gen.visitors.push(
+      create_javascript_visitor!(exact &self.ast_path, visit_mut_call_expr(n: &mut CallExpr) {
+        let mut args = n.args.iter().filter(|arg| arg.spread.is_none()).map(|arg| *arg.expr.clone()).skip(1);
+        let Some(callback) = args.next() else { return };
+        let error_callback = args.find(|arg| !matches!(arg, Expr::Lit(Lit::Str(_))));
+
+        let load_chunks = ArrayLit {
+          span: DUMMY_SP,
+          elems: module_ids
+            .iter()
+            .map(|module_id| {
+              Some(
+                quote!(
+                  "$load_chunk($id)" as Expr,
+                  load_chunk = quote_ident!(RuntimeGlobals::LOAD_CHUNK_WITH_MODULE),
+                  id: Expr = quote_str!(&**module_id).into(),
+                )
+                .as_arg(),
+              )
+            })
+            .collect(),
+        };
+        let mut expr = quote!(
+          "Promise.all($load_chunks).then($callback.bind(null, $require))" as Expr,
+          load_chunks: Expr = load_chunks.into(),
+          callback: Expr = callback.wrap_with_paren(),
+          require = quote_ident!(RuntimeGlobals::REQUIRE),
+        );
+        if let Some(error_callback) = error_callback {
+          expr = quote!(
+            "$expr['catch']($error_callback)" as Expr,
+            expr: Expr = expr,
+            error_callback: Expr = error_callback,
+          );
+        }
+        if let Expr::Call(call_expr) = expr {
+          *n = call_expr;
+        }
+      }),
+    );
+
+    Ok(code_gen)
+  }
+}
@@ -0,0 +1,137 @@
+use rspack_core::{
+  create_javascript_visitor, ChunkGroupOptions, CodeGeneratable, CodeGeneratableContext,
+  CodeGeneratableResult, Dependency, DependencyCategory, DependencyId, DependencyType, ErrorSpan,
+  JsAstPath, ModuleDependency, ModuleIdentifier, RuntimeGlobals,
+};
+use swc_core::ecma::{
+  ast::*,
+  atoms::JsWord,
+  utils::{quote_ident, quote_str, ExprFactory},
+};
+
+/// An item of `require.ensure(["./foo"], callback)` or a `require("./foo")` call in the callback,
+/// the module is put into the chunk group of the `require.ensure`
+#[derive(Debug, Eq, Clone)]
+pub struct RequireEnsureItemDependency {
+  id: Option<DependencyId>,
+  parent_module_identifier: Option<ModuleIdentifier>,
+  request: JsWord,
+  span: Option<ErrorSpan>,
+  /// From the chunk name argument of `require.ensure`
+  group_options: ChunkGroupOptions,
+  #[allow(unused)]
+  ast_path: JsAstPath,
+}
+
+// Do not edit this, as it is used to uniquely identify the dependency.
+impl PartialEq for RequireEnsureItemDependency {
+  fn eq(&self, other: &Self) -> bool {
+    self.parent_module_identifier == other.parent_module_identifier && self.request == other.request
+  }
+}
+
+// Do not edit this, as it is used to uniquely identify the dependency.
+impl std::hash::Hash for RequireEnsureItemDependency {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    self.parent_module_identifier.hash(state);
+    self.request.hash(state);
+    self.category().hash(state);
+    self.dependency_type().hash(state);
+  }
+}
+
+impl RequireEnsureItemDependency {
+  pub fn new(
+    request: JsWord,
+    span: Option<ErrorSpan>,
+    ast_path: JsAstPath,
+    group_options: ChunkGroupOptions,
+  ) -> Self {
+    Self {
+      id: None,
+      parent_module_identifier: None,
+      request,
+      span,
+      group_options,
+      ast_path,
+    }
+  }
+}
+
+impl Dependency for RequireEnsureItemDependency {
+  fn id(&self) -> Option<DependencyId> {
+    self.id
+  }
+  fn set_id(&mut self, id: Option<DependencyId>) {
+    self.id = id;
+  }
+  fn parent_module_identifier(&self) -> Option<&ModuleIdentifier> {
+    self.parent_module_identifier.as_ref()
+  }
+
+  fn set_parent_module_identifier(&mut self, module_identifier: Option<ModuleIdentifier>) {
+    self.parent_module_identifier = module_identifier;
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CommonJS
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::RequireEnsureItem
+  }
+}
+
+impl ModuleDependency for RequireEnsureItemDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    self.span.as_ref()
+  }
+
+  fn group_options(&self) -> Option<&ChunkGroupOptions> {
+    Some(&self.group_options)
+  }
+}
+
+impl CodeGeneratable for RequireEnsureItemDependency {
+  fn generate(
+    &self,
+    code_generatable_context: &mut CodeGeneratableContext,
+  ) -> rspack_error::Result<CodeGeneratableResult> {
+    let CodeGeneratableContext {
+      compilation,
+      runtime_requirements,
+      ..
+    } = code_generatable_context;
+    let mut code_gen = CodeGeneratableResult::default();
+
+    if let Some(id) = self.id() {
+      if let Some(module_id) = compilation
+        .module_graph
+        .module_graph_module_by_dependency_id(&id)
+        .map(|m| m.id(&compilation.chunk_graph).to_string())
+      {
+        runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+        // The path of an item of the array points to the `require.ensure` call, which is
+        // rewritten by `RequireEnsureDependency`, so only `require("./foo")` calls are matched
+        code_gen.visitors.push(
+          create_javascript_visitor!(exact &self.ast_path, visit_mut_call_expr(n: &mut CallExpr) {
+            if let Callee::Expr(box Expr::Ident(_)) = &n.callee {
+              n.callee = quote_ident!(RuntimeGlobals::REQUIRE).as_callee();
+              n.args = vec![quote_str!(&*module_id).as_arg()];
+            }
+          }),
+        );
+      }
+    }
+
+    Ok(code_gen)
+  }
+}
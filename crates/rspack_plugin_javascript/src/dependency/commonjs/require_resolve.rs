@@ -0,0 +1,136 @@
+use rspack_core::{
+  create_javascript_visitor, CodeGeneratable, CodeGeneratableContext, CodeGeneratableResult,
+  Dependency, DependencyCategory, DependencyId, DependencyType, ErrorSpan, JsAstPath,
+  ModuleDependency, ModuleIdentifier,
+};
+use swc_core::ecma::{
+  ast::Expr,
+  atoms::JsWord,
+  utils::{quote_ident, quote_str},
+};
+
+/// `require.resolve("./foo")` and `require.resolveWeak("./foo")`, replaced with the module id.
+/// A weakly resolved module is not added to any chunk, it's `null` when the module has no id.
+#[derive(Debug, Eq, Clone)]
+pub struct RequireResolveDependency {
+  id: Option<DependencyId>,
+  parent_module_identifier: Option<ModuleIdentifier>,
+  request: JsWord,
+  weak: bool,
+  span: Option<ErrorSpan>,
+  #[allow(unused)]
+  ast_path: JsAstPath,
+}
+
+// Do not edit this, as it is used to uniquely identify the dependency.
+impl PartialEq for RequireResolveDependency {
+  fn eq(&self, other: &Self) -> bool {
+    self.parent_module_identifier == other.parent_module_identifier
+      && self.request == other.request
+      && self.weak == other.weak
+  }
+}
+
+// Do not edit this, as it is used to uniquely identify the dependency.
+impl std::hash::Hash for RequireResolveDependency {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    self.parent_module_identifier.hash(state);
+    self.request.hash(state);
+    self.weak.hash(state);
+    self.category().hash(state);
+    self.dependency_type().hash(state);
+  }
+}
+
+impl RequireResolveDependency {
+  pub fn new(request: JsWord, weak: bool, span: Option<ErrorSpan>, ast_path: JsAstPath) -> Self {
+    Self {
+      id: None,
+      parent_module_identifier: None,
+      request,
+      weak,
+      span,
+      ast_path,
+    }
+  }
+}
+
+impl Dependency for RequireResolveDependency {
+  fn id(&self) -> Option<DependencyId> {
+    self.id
+  }
+  fn set_id(&mut self, id: Option<DependencyId>) {
+    self.id = id;
+  }
+  fn parent_module_identifier(&self) -> Option<&ModuleIdentifier> {
+    self.parent_module_identifier.as_ref()
+  }
+
+  fn set_parent_module_identifier(&mut self, module_identifier: Option<ModuleIdentifier>) {
+    self.parent_module_identifier = module_identifier;
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CommonJS
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::RequireResolve
+  }
+}
+
+impl ModuleDependency for RequireResolveDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    self.span.as_ref()
+  }
+
+  fn weak(&self) -> bool {
+    self.weak
+  }
+}
+
+impl CodeGeneratable for RequireResolveDependency {
+  fn generate(
+    &self,
+    code_generatable_context: &mut CodeGeneratableContext,
+  ) -> rspack_error::Result<CodeGeneratableResult> {
+    let CodeGeneratableContext { compilation, .. } = code_generatable_context;
+    let mut code_gen = CodeGeneratableResult::default();
+
+    if let Some(id) = self.id() {
+      if let Some(referenced_module) = compilation
+        .module_graph
+        .module_graph_module_by_dependency_id(&id)
+      {
+        // A weakly resolved module may not be in any chunk, so it may not have an id
+        let module_id = if self.weak {
+          compilation
+            .chunk_graph
+            .get_module_id(referenced_module.module_identifier)
+            .clone()
+        } else {
+          Some(referenced_module.id(&compilation.chunk_graph).to_string())
+        };
+        let expr = match module_id {
+          Some(module_id) => quote_str!(module_id).into(),
+          None => Expr::Ident(quote_ident!("null /* weak dependency, without id */")),
+        };
+        code_gen.visitors.push(
+          create_javascript_visitor!(exact &self.ast_path, visit_mut_expr(n: &mut Expr) {
+            *n = expr.clone();
+          }),
+        );
+      }
+    }
+
+    Ok(code_gen)
+  }
+}
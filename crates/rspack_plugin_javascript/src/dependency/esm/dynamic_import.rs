@@ -1,15 +1,17 @@
 use rspack_core::{
-  create_javascript_visitor, CodeGeneratable, CodeGeneratableContext, CodeGeneratableDeclMappings,
-  CodeGeneratableResult, Dependency, DependencyCategory, DependencyId, DependencyType, ErrorSpan,
-  JsAstPath, ModuleDependency, ModuleDependencyExt, ModuleIdentifier, RuntimeGlobals,
+  create_javascript_visitor, ChunkGroupOptions, CodeGeneratable, CodeGeneratableContext,
+  CodeGeneratableDeclMappings, CodeGeneratableResult, ContextMode, Dependency, DependencyCategory,
+  DependencyId, DependencyType, ErrorSpan, JsAstPath, ModuleDependency, ModuleDependencyExt,
+  ModuleIdentifier, RuntimeGlobals,
 };
 use swc_core::{
   common::DUMMY_SP,
   ecma::{
     ast::*,
     atoms::{Atom, JsWord},
-    utils::ExprFactory,
+    utils::{quote_ident, quote_str, ExprFactory},
   },
+  quote,
 };
 
 #[derive(Debug, Eq, Clone)]
@@ -20,6 +22,10 @@ pub struct EsmDynamicImportDependency {
   category: &'static DependencyCategory,
   dependency_type: &'static DependencyType,
   span: Option<ErrorSpan>,
+  /// From the `webpackMode` magic comment
+  mode: ContextMode,
+  /// From the `webpackChunkName`, `webpackPrefetch` and `webpackPreload` magic comments
+  group_options: ChunkGroupOptions,
 
   #[allow(unused)]
   ast_path: JsAstPath,
@@ -46,13 +52,21 @@ impl std::hash::Hash for EsmDynamicImportDependency {
 }
 
 impl EsmDynamicImportDependency {
-  pub fn new(request: JsWord, span: Option<ErrorSpan>, ast_path: JsAstPath) -> Self {
+  pub fn new(
+    request: JsWord,
+    span: Option<ErrorSpan>,
+    ast_path: JsAstPath,
+    mode: ContextMode,
+    group_options: ChunkGroupOptions,
+  ) -> Self {
     Self {
       parent_module_identifier: None,
       request,
       category: &DependencyCategory::Esm,
       dependency_type: &DependencyType::DynamicImport,
       span,
+      mode,
+      group_options,
       ast_path,
       id: None,
     }
@@ -95,6 +109,14 @@ impl ModuleDependency for EsmDynamicImportDependency {
   fn span(&self) -> Option<&ErrorSpan> {
     self.span.as_ref()
   }
+
+  fn group_options(&self) -> Option<&ChunkGroupOptions> {
+    matches!(self.mode, ContextMode::Lazy | ContextMode::LazyOnce).then_some(&self.group_options)
+  }
+
+  fn weak(&self) -> bool {
+    matches!(self.mode, ContextMode::Weak | ContextMode::AsyncWeak)
+  }
 }
 
 impl CodeGeneratable for EsmDynamicImportDependency {
@@ -115,15 +137,45 @@ impl CodeGeneratable for EsmDynamicImportDependency {
         .module_graph
         .module_graph_module_by_dependency_id(&dependency_id)
       {
-        let module_id = referenced_module.id(&compilation.chunk_graph).to_string();
+        // A weakly imported module may not be in any chunk, so it may not have an id
+        let module_id = if self.weak() {
+          compilation
+            .chunk_graph
+            .get_module_id(referenced_module.module_identifier)
+            .clone()
+        } else {
+          Some(referenced_module.id(&compilation.chunk_graph).to_string())
+        };
 
-        {
+        if let Some(module_id) = &module_id {
           let (id, val) = self.decl_mapping(&compilation.module_graph, module_id.clone());
           decl_mappings.insert(id, val);
         }
 
         // Add interop require to runtime requirements, as dynamic imports have been transformed so `inject_runtime_helper` will not be able to detect this.
         runtime_requirements.insert(RuntimeGlobals::INTEROP_REQUIRE);
+
+        // Eager and weak imports don't load any chunk
+        let call_expr = match self.mode {
+          ContextMode::Eager => module_id.as_deref().map(eager_import_call),
+          ContextMode::Weak | ContextMode::AsyncWeak => {
+            runtime_requirements.insert(RuntimeGlobals::MODULE_FACTORIES);
+            Some(weak_import_call(module_id.as_deref()))
+          }
+          _ => None,
+        };
+        if let Some(call_expr) = call_expr {
+          code_gen.visitors.push(
+            create_javascript_visitor!(exact &self.ast_path, visit_mut_call_expr(n: &mut CallExpr) {
+              if let Some(import) = n.args.get(0) && import.spread.is_none() && let Expr::Lit(Lit::Str(_)) = import.expr.as_ref() {
+                *n = call_expr.clone();
+              }
+            }),
+          );
+          return Ok(code_gen.with_decl_mappings(decl_mappings));
+        }
+
+        let module_id = module_id.unwrap_or_default();
         runtime_requirements.insert(RuntimeGlobals::ENSURE_CHUNK);
         runtime_requirements.insert(RuntimeGlobals::LOAD_CHUNK_WITH_MODULE);
 
@@ -192,3 +244,58 @@ impl CodeGeneratable for EsmDynamicImportDependency {
     Ok(code_gen.with_decl_mappings(decl_mappings))
   }
 }
+
+/// `Promise.resolve().then(__webpack_require__.bind(__webpack_require__, id)).then(__webpack_require__.ir)`
+fn eager_import_call(module_id: &str) -> CallExpr {
+  let expr = quote!(
+    "Promise.resolve().then($require.bind($require, $id)).then($interop)" as Expr,
+    require = quote_ident!(RuntimeGlobals::REQUIRE),
+    id: Expr = quote_str!(module_id).into(),
+    interop: Expr = interop_require_expr(),
+  );
+  expect_call(expr)
+}
+
+/// Rejects if the module is not available, as it's not loaded by any chunk
+fn weak_import_call(module_id: Option<&str>) -> CallExpr {
+  let expr = match module_id {
+    Some(module_id) => quote!(
+      "Promise.resolve().then(function() {
+        if (!$modules[$id]) {
+          var e = new Error(\"Module '\" + $id + \"' is not available (weak dependency)\");
+          e.code = 'MODULE_NOT_FOUND';
+          throw e;
+        }
+        return $require($id);
+      }).then($interop)" as Expr,
+      modules = quote_ident!(RuntimeGlobals::MODULE_FACTORIES),
+      require = quote_ident!(RuntimeGlobals::REQUIRE),
+      id: Expr = quote_str!(module_id).into(),
+      interop: Expr = interop_require_expr(),
+    ),
+    None => quote!(
+      "Promise.resolve().then(function() {
+        var e = new Error(\"Module is not available (weak dependency)\");
+        e.code = 'MODULE_NOT_FOUND';
+        throw e;
+      })" as Expr
+    ),
+  };
+  expect_call(expr)
+}
+
+fn interop_require_expr() -> Expr {
+  MemberExpr {
+    span: DUMMY_SP,
+    obj: Box::new(Expr::Ident(quote_ident!(RuntimeGlobals::REQUIRE))),
+    prop: MemberProp::Ident(quote_ident!(RuntimeGlobals::INTEROP_REQUIRE)),
+  }
+  .into()
+}
+
+fn expect_call(expr: Expr) -> CallExpr {
+  match expr {
+    Expr::Call(call_expr) => call_expr,
+    _ => unreachable!("should be a call expression"),
+  }
+}
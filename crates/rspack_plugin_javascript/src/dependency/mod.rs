@@ -1,9 +1,13 @@
+mod amd;
 mod commonjs;
 mod esm;
 mod hmr;
 mod url;
+mod worker;
 
+pub use amd::*;
 pub use commonjs::*;
 pub use esm::*;
 pub use hmr::*;
 pub use url::*;
+pub use worker::*;
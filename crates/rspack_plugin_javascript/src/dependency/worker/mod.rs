@@ -0,0 +1,151 @@
+use rspack_core::{
+  create_javascript_visitor, ChunkGroupOptions, CodeGeneratable, CodeGeneratableContext,
+  CodeGeneratableResult, Dependency, DependencyCategory, DependencyId, DependencyType, ErrorSpan,
+  JsAstPath, ModuleDependency, ModuleIdentifier, RuntimeGlobals,
+};
+use swc_core::ecma::atoms::JsWord;
+use swc_core::ecma::utils::{quote_ident, quote_str};
+use swc_core::quote;
+
+/// `new Worker(new URL("./foo", import.meta.url))`, the worker is bundled as a new entrypoint
+#[derive(Debug, Eq, Clone)]
+pub struct WorkerDependency {
+  id: Option<DependencyId>,
+  parent_module_identifier: Option<ModuleIdentifier>,
+  request: JsWord,
+  span: Option<ErrorSpan>,
+  /// From the `webpackChunkName` magic comment
+  group_options: ChunkGroupOptions,
+  #[allow(unused)]
+  ast_path: JsAstPath,
+}
+
+// Do not edit this, as it is used to uniquely identify the dependency.
+impl PartialEq for WorkerDependency {
+  fn eq(&self, other: &Self) -> bool {
+    self.parent_module_identifier == other.parent_module_identifier && self.request == other.request
+  }
+}
+
+// Do not edit this, as it is used to uniquely identify the dependency.
+impl std::hash::Hash for WorkerDependency {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    self.parent_module_identifier.hash(state);
+    self.request.hash(state);
+    self.category().hash(state);
+    self.dependency_type().hash(state);
+  }
+}
+
+impl WorkerDependency {
+  pub fn new(
+    request: JsWord,
+    span: Option<ErrorSpan>,
+    ast_path: JsAstPath,
+    group_options: ChunkGroupOptions,
+  ) -> Self {
+    Self {
+      id: None,
+      parent_module_identifier: None,
+      request,
+      span,
+      group_options,
+      ast_path,
+    }
+  }
+}
+
+impl Dependency for WorkerDependency {
+  fn id(&self) -> Option<DependencyId> {
+    self.id
+  }
+  fn set_id(&mut self, id: Option<DependencyId>) {
+    self.id = id;
+  }
+  fn parent_module_identifier(&self) -> Option<&ModuleIdentifier> {
+    self.parent_module_identifier.as_ref()
+  }
+
+  fn set_parent_module_identifier(&mut self, module_identifier: Option<ModuleIdentifier>) {
+    self.parent_module_identifier = module_identifier;
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Worker
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::NewWorker
+  }
+}
+
+impl ModuleDependency for WorkerDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    self.span.as_ref()
+  }
+
+  fn group_options(&self) -> Option<&ChunkGroupOptions> {
+    Some(&self.group_options)
+  }
+}
+
+impl CodeGeneratable for WorkerDependency {
+  fn generate(
+    &self,
+    code_generatable_context: &mut CodeGeneratableContext,
+  ) -> rspack_error::Result<CodeGeneratableResult> {
+    let CodeGeneratableContext {
+      compilation,
+      runtime_requirements,
+      ..
+    } = code_generatable_context;
+    let mut code_gen = CodeGeneratableResult::default();
+
+    let Some(module_identifier) = self
+      .id()
+      .and_then(|id| compilation.module_graph.module_identifier_by_dependency_id(&id))
+    else {
+      return Ok(code_gen);
+    };
+    let entrypoint = compilation
+      .chunk_graph
+      .get_block_chunk_group(module_identifier, &compilation.chunk_group_by_ukey);
+    let Some(chunk_id) = compilation
+      .chunk_by_ukey
+      .get(&entrypoint.get_entry_point_chunk())
+      .and_then(|chunk| chunk.id.clone())
+    else {
+      return Ok(code_gen);
+    };
+
+    runtime_requirements.insert(RuntimeGlobals::PUBLIC_PATH);
+    runtime_requirements.insert(RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME);
+    runtime_requirements.insert(RuntimeGlobals::BASE_URI);
+
+    code_gen.visitors.push(
+      create_javascript_visitor!(exact &self.ast_path, visit_mut_new_expr(n: &mut NewExpr) {
+        let Some(args) = &mut n.args else { return };
+        if let Some(first) = args.first_mut() {
+          // new URL(__webpack_require__.p + __webpack_require__.u(chunkId), __webpack_require__.b)
+          first.expr = box quote!(
+            "new URL($public_path + $get_chunk_filename($chunk_id), $base_uri)" as Expr,
+            public_path = quote_ident!(RuntimeGlobals::PUBLIC_PATH),
+            get_chunk_filename = quote_ident!(RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME),
+            chunk_id: Expr = quote_str!(&*chunk_id).into(),
+            base_uri = quote_ident!(RuntimeGlobals::BASE_URI),
+          );
+        }
+      }),
+    );
+
+    Ok(code_gen)
+  }
+}
@@ -6,6 +6,8 @@
 // use once_cell::sync::Lazy;
 
 mod dependency;
+mod module_concatenation;
+pub use module_concatenation::ModuleConcatenationPlugin;
 mod plugin;
 pub use plugin::*;
 mod ast;
@@ -0,0 +1,378 @@
+use rspack_core::{ast::javascript::Ast, Compilation, Module, ModuleIdentifier};
+use rspack_error::{internal_error, Result};
+use rspack_identifier::IdentifierSet;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use swc_core::common::{util::take::Take, SyntaxContext, DUMMY_SP};
+use swc_core::ecma::ast::*;
+use swc_core::ecma::atoms::JsWord;
+use swc_core::ecma::utils::find_pat_ids;
+use swc_core::ecma::visit::{noop_visit_mut_type, noop_visit_type, Visit, VisitMut, VisitWith};
+
+/// Name of the binding declared for `export default <expr>` and anonymous default exports
+const DEFAULT_EXPORT: &str = "__WEBPACK_DEFAULT_EXPORT__";
+
+/// Module referenced by an import or export declaration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(super) enum ModuleReference {
+  /// Module in the same scope
+  Concatenated(ModuleIdentifier),
+  /// Module required by `__webpack_require__`
+  External(ModuleIdentifier),
+}
+
+/// Imported export of a module, `None` for the namespace object
+pub(super) type ImportedName = (ModuleReference, Option<JsWord>);
+
+#[derive(Debug)]
+pub(super) enum ExportSource {
+  /// Top level binding of the module, which may also be an imported binding
+  Local(Id),
+  /// `export { a as b } from "x"`, or `export * as b from "x"`
+  Reexport(ImportedName),
+}
+
+/// Records of a module to be concatenated, whose import and export declarations are removed.
+#[derive(Debug)]
+pub(super) struct ConcatenatedModuleInfo {
+  pub top_level_ctxt: SyntaxContext,
+  pub imports: HashMap<Id, ImportedName>,
+  pub exports: Vec<(JsWord, ExportSource)>,
+  pub star_exports: Vec<ModuleReference>,
+  /// Modules required by the module, in the order of declarations
+  pub requests: Vec<ModuleIdentifier>,
+  /// Top level bindings, except imported bindings
+  pub top_level_bindings: Vec<Id>,
+  /// Names of all identifiers, except references to imported bindings
+  pub names: HashSet<JsWord>,
+  /// Properties read from namespace imports, e.g. `ns.a`, which are linked directly
+  pub namespace_properties: HashSet<(Id, JsWord)>,
+  /// Namespace imports used other than reading properties
+  pub namespace_objects: HashSet<Id>,
+}
+
+/// Replaces import and export declarations of the module by records,
+/// the ast should be prepared by `run_before_concatenation_pass`.
+pub(super) fn analyze_module(
+  ast: &mut Ast,
+  module: &dyn Module,
+  compilation: &Compilation,
+  group: &IdentifierSet,
+) -> Result<ConcatenatedModuleInfo> {
+  let module_graph = &compilation.module_graph;
+  // Requests are replaced by module ids in code generation of dependencies
+  let module_by_id = module_graph
+    .module_graph_module_by_identifier(&module.identifier())
+    .map(|mgm| mgm.dependencies.as_slice())
+    .unwrap_or_default()
+    .iter()
+    .filter_map(|dependency| module_graph.module_identifier_by_dependency_id(dependency))
+    .filter_map(|module| {
+      compilation
+        .chunk_graph
+        .get_module_id(*module)
+        .clone()
+        .map(|id| (id, *module))
+    })
+    .collect();
+
+  ast.transform(|program, context| {
+    if matches!(program.get_inner_program(), Program::Script(_)) {
+      return Err(internal_error!(
+        "Failed to concatenate {}, which is not an esm module",
+        module.identifier()
+      ));
+    }
+    let top_level_ctxt = SyntaxContext::empty().apply_mark(context.top_level_mark);
+    let mut linker = DeclarationLinker {
+      compilation,
+      group,
+      module_by_id,
+      default_export_ident: Ident::new(DEFAULT_EXPORT.into(), DUMMY_SP.with_ctxt(top_level_ctxt)),
+      info: ConcatenatedModuleInfo {
+        top_level_ctxt,
+        imports: Default::default(),
+        exports: Default::default(),
+        star_exports: Default::default(),
+        requests: Default::default(),
+        top_level_bindings: Default::default(),
+        names: Default::default(),
+        namespace_properties: Default::default(),
+        namespace_objects: Default::default(),
+      },
+      error: None,
+    };
+    program.visit_mut_with(&mut linker);
+    if let Some(error) = linker.error {
+      return Err(error);
+    }
+
+    let mut info = linker.info;
+    program.visit_with(&mut NamesCollector {
+      info: &mut info,
+      top_level_bindings: HashSet::default(),
+    });
+    Ok(info)
+  })
+}
+
+/// Removes import and export declarations of a module, and records them
+struct DeclarationLinker<'a> {
+  compilation: &'a Compilation,
+  group: &'a IdentifierSet,
+  module_by_id: HashMap<String, ModuleIdentifier>,
+  default_export_ident: Ident,
+  info: ConcatenatedModuleInfo,
+  error: Option<rspack_error::Error>,
+}
+
+impl<'a> DeclarationLinker<'a> {
+  fn resolve(&mut self, request: &str) -> Result<ModuleReference> {
+    let chunk_graph = &self.compilation.chunk_graph;
+    let module = match self.module_by_id.get(request) {
+      Some(module) => *module,
+      // Tree shaking may link imports to the module declaring the symbol
+      None => {
+        let module = chunk_graph
+          .chunk_graph_module_by_module_identifier
+          .iter()
+          .find(|(_, cgm)| cgm.id.as_deref() == Some(request))
+          .map(|(module, _)| *module)
+          .ok_or_else(|| internal_error!("Failed to resolve module {request} in concatenation"))?;
+        self.module_by_id.insert(request.to_string(), module);
+        module
+      }
+    };
+    if !self.info.requests.contains(&module) {
+      self.info.requests.push(module);
+    }
+    Ok(if self.group.contains(&module) {
+      ModuleReference::Concatenated(module)
+    } else {
+      ModuleReference::External(module)
+    })
+  }
+
+  /// Records the declaration, and returns the statement to replace it with
+  fn link(&mut self, decl: ModuleDecl) -> Result<Option<Stmt>> {
+    match decl {
+      ModuleDecl::Import(import) => {
+        if import.type_only {
+          return Ok(None);
+        }
+        let reference = self.resolve(&import.src.value)?;
+        for specifier in import.specifiers {
+          let (local, name) = match specifier {
+            ImportSpecifier::Named(named) => {
+              let name = named
+                .imported
+                .as_ref()
+                .map(module_export_name)
+                .unwrap_or_else(|| named.local.sym.clone());
+              (named.local, Some(name))
+            }
+            ImportSpecifier::Default(default) => (default.local, Some("default".into())),
+            ImportSpecifier::Namespace(namespace) => (namespace.local, None),
+          };
+          self.info.imports.insert(local.to_id(), (reference, name));
+        }
+        Ok(None)
+      }
+      ModuleDecl::ExportDecl(export) => {
+        for id in declared_ids(&export.decl) {
+          self
+            .info
+            .exports
+            .push((id.0.clone(), ExportSource::Local(id)));
+        }
+        Ok(Some(Stmt::Decl(export.decl)))
+      }
+      ModuleDecl::ExportNamed(export) => {
+        if export.type_only {
+          return Ok(None);
+        }
+        let reference = match &export.src {
+          Some(src) => Some(self.resolve(&src.value)?),
+          None => None,
+        };
+        for specifier in export.specifiers {
+          let (exported, orig) = match specifier {
+            ExportSpecifier::Named(named) => (
+              module_export_name(named.exported.as_ref().unwrap_or(&named.orig)),
+              Some(named.orig),
+            ),
+            ExportSpecifier::Namespace(namespace) => (module_export_name(&namespace.name), None),
+            ExportSpecifier::Default(default) => (
+              default.exported.sym,
+              Some(ModuleExportName::Ident(Ident::new(
+                "default".into(),
+                DUMMY_SP,
+              ))),
+            ),
+          };
+          let source = match (reference, orig) {
+            (Some(reference), orig) => {
+              ExportSource::Reexport((reference, orig.as_ref().map(module_export_name)))
+            }
+            (None, Some(ModuleExportName::Ident(orig))) => ExportSource::Local(orig.to_id()),
+            (None, _) => continue,
+          };
+          self.info.exports.push((exported, source));
+        }
+        Ok(None)
+      }
+      ModuleDecl::ExportDefaultDecl(export) => {
+        let decl = match export.decl {
+          DefaultDecl::Fn(FnExpr { ident, function }) => Decl::Fn(FnDecl {
+            ident: ident.unwrap_or_else(|| self.default_export_ident.clone()),
+            declare: false,
+            function,
+          }),
+          DefaultDecl::Class(ClassExpr { ident, class }) => Decl::Class(ClassDecl {
+            ident: ident.unwrap_or_else(|| self.default_export_ident.clone()),
+            declare: false,
+            class,
+          }),
+          DefaultDecl::TsInterfaceDecl(_) => return Ok(None),
+        };
+        for id in declared_ids(&decl) {
+          self
+            .info
+            .exports
+            .push(("default".into(), ExportSource::Local(id)));
+        }
+        Ok(Some(Stmt::Decl(decl)))
+      }
+      ModuleDecl::ExportDefaultExpr(export) => {
+        let ident = self.default_export_ident.clone();
+        self
+          .info
+          .exports
+          .push(("default".into(), ExportSource::Local(ident.to_id())));
+        Ok(Some(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+          span: DUMMY_SP,
+          kind: VarDeclKind::Var,
+          declare: false,
+          decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(ident.into()),
+            init: Some(export.expr),
+            definite: false,
+          }],
+        })))))
+      }
+      ModuleDecl::ExportAll(export) => {
+        if !export.type_only {
+          let reference = self.resolve(&export.src.value)?;
+          self.info.star_exports.push(reference);
+        }
+        Ok(None)
+      }
+      ModuleDecl::TsImportEquals(_)
+      | ModuleDecl::TsExportAssignment(_)
+      | ModuleDecl::TsNamespaceExport(_) => Ok(None),
+    }
+  }
+}
+
+impl<'a> VisitMut for DeclarationLinker<'a> {
+  noop_visit_mut_type!();
+
+  fn visit_mut_module(&mut self, module: &mut swc_core::ecma::ast::Module) {
+    let mut body = Vec::with_capacity(module.body.len());
+    for item in module.body.take() {
+      match item {
+        ModuleItem::ModuleDecl(decl) => match self.link(decl) {
+          Ok(Some(stmt)) => body.push(ModuleItem::Stmt(stmt)),
+          Ok(None) => {}
+          Err(error) => {
+            self.error.get_or_insert(error);
+          }
+        },
+        ModuleItem::Stmt(stmt) => body.push(ModuleItem::Stmt(stmt)),
+      }
+    }
+    module.body = body;
+  }
+}
+
+pub(super) fn module_export_name(name: &ModuleExportName) -> JsWord {
+  match name {
+    ModuleExportName::Ident(ident) => ident.sym.clone(),
+    ModuleExportName::Str(str) => str.value.clone(),
+  }
+}
+
+fn declared_ids(decl: &Decl) -> Vec<Id> {
+  match decl {
+    Decl::Class(class) => vec![class.ident.to_id()],
+    Decl::Fn(function) => vec![function.ident.to_id()],
+    Decl::Var(var) => find_pat_ids(&var.decls),
+    Decl::TsEnum(ts_enum) => vec![ts_enum.id.to_id()],
+    Decl::TsModule(ts_module) => match &ts_module.id {
+      TsModuleName::Ident(ident) => vec![ident.to_id()],
+      TsModuleName::Str(_) => vec![],
+    },
+    Decl::TsInterface(_) | Decl::TsTypeAlias(_) => vec![],
+  }
+}
+
+struct NamesCollector<'a> {
+  info: &'a mut ConcatenatedModuleInfo,
+  top_level_bindings: HashSet<Id>,
+}
+
+impl<'a> Visit for NamesCollector<'a> {
+  noop_visit_type!();
+
+  fn visit_ident(&mut self, ident: &Ident) {
+    let id = ident.to_id();
+    if self.info.imports.contains_key(&id) {
+      if matches!(self.info.imports.get(&id), Some((_, None))) {
+        self.info.namespace_objects.insert(id);
+      }
+      return;
+    }
+    if ident.span.ctxt == self.info.top_level_ctxt && self.top_level_bindings.insert(id.clone()) {
+      self.info.top_level_bindings.push(id);
+    }
+    self.info.names.insert(ident.sym.clone());
+  }
+
+  fn visit_member_expr(&mut self, member: &MemberExpr) {
+    if let Expr::Ident(obj) = &*member.obj
+      && let Some((_, None)) = self.info.imports.get(&obj.to_id())
+      && let Some(property) = static_property(&member.prop)
+    {
+      self.info.namespace_properties.insert((obj.to_id(), property));
+      return;
+    }
+    member.obj.visit_with(self);
+    if let MemberProp::Computed(computed) = &member.prop {
+      computed.visit_with(self);
+    }
+  }
+
+  fn visit_prop_name(&mut self, name: &PropName) {
+    if let PropName::Computed(computed) = name {
+      computed.visit_with(self);
+    }
+  }
+
+  fn visit_super_prop_expr(&mut self, expr: &SuperPropExpr) {
+    if let SuperProp::Computed(computed) = &expr.prop {
+      computed.visit_with(self);
+    }
+  }
+}
+
+/// Name of the property read by `obj.a` or `obj["a"]`
+pub(super) fn static_property(prop: &MemberProp) -> Option<JsWord> {
+  match prop {
+    MemberProp::Ident(ident) => Some(ident.sym.clone()),
+    MemberProp::Computed(ComputedPropName {
+      expr: box Expr::Lit(Lit::Str(str)),
+      ..
+    }) => Some(str.value.clone()),
+    _ => None,
+  }
+}
@@ -0,0 +1,735 @@
+use rspack_core::rspack_sources::{ConcatSource, RawSource, SourceExt};
+use rspack_core::{
+  ast::javascript::Ast, AstOrSource, Compilation, GenerateContext, GenerationResult, Module,
+  ModuleIdentifier, NormalModuleAstOrSource, RuntimeGlobals, SourceType,
+};
+use rspack_error::{internal_error, Result};
+use rspack_identifier::{IdentifierMap, IdentifierSet};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use swc_core::common::{util::take::Take, SyntaxContext, DUMMY_SP};
+use swc_core::ecma::ast::*;
+use swc_core::ecma::atoms::JsWord;
+use swc_core::ecma::utils::undefined;
+use swc_core::ecma::visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
+
+use super::analyze::{
+  analyze_module, static_property, ConcatenatedModuleInfo, ExportSource, ImportedName,
+  ModuleReference,
+};
+use crate::plugin::stringify_to_source;
+use crate::visitors::{run_after_concatenation_pass, run_before_concatenation_pass};
+
+/// Names which can't be used by top level bindings of concatenated modules
+const RESERVED_NAMES: &[&str] = &["__webpack_require__", "exports", "module", "require"];
+
+/// What an imported or exported name refers to after concatenation
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Binding {
+  /// Top level binding of a concatenated module, with its final name
+  Local(JsWord),
+  /// Export of a module out of the scope, `None` for the exports object
+  External(ModuleIdentifier, Option<JsWord>),
+  /// Namespace object of a concatenated module
+  Namespace(usize),
+  /// The export is not found, which is `undefined`
+  Missing,
+}
+
+struct ConcatenatedMember<'a> {
+  module: &'a dyn Module,
+  ast: Ast,
+  info: ConcatenatedModuleInfo,
+  /// Final names of top level bindings
+  names: HashMap<Id, JsWord>,
+}
+
+/// Generates the code of `modules` concatenated into one scope, `modules` are in the order
+/// of evaluation, and the last one is the root whose exports are exposed.
+pub(crate) fn generate_concatenated_module(
+  modules: &[ModuleIdentifier],
+  generate_context: &mut GenerateContext,
+) -> Result<GenerationResult> {
+  let compilation = generate_context.compilation;
+  let group = modules.iter().copied().collect::<IdentifierSet>();
+
+  let mut members = Vec::with_capacity(modules.len());
+  for identifier in modules {
+    let module = compilation
+      .module_graph
+      .module_by_identifier(identifier)
+      .ok_or_else(|| internal_error!("Failed to get module {identifier} for concatenation"))?;
+    let normal_module = module.try_as_normal_module()?;
+    let NormalModuleAstOrSource::BuiltSucceed(ast_or_source) = normal_module.ast_or_source() else {
+      return Err(internal_error!("Failed to concatenate {identifier}, which is not built"));
+    };
+    let mut ast = ast_or_source
+      .to_owned()
+      .try_into_ast()?
+      .try_into_javascript()?;
+    run_before_concatenation_pass(
+      &mut ast,
+      module.as_ref(),
+      &mut GenerateContext {
+        compilation,
+        module_generator_options: generate_context.module_generator_options,
+        runtime_requirements: generate_context.runtime_requirements,
+        data: generate_context.data,
+        requested_source_type: SourceType::JavaScript,
+      },
+    )?;
+    let info = analyze_module(&mut ast, module.as_ref(), compilation, &group)?;
+    members.push(ConcatenatedMember {
+      module: module.as_ref(),
+      ast,
+      info,
+      names: Default::default(),
+    });
+  }
+  let index_by_module = modules
+    .iter()
+    .enumerate()
+    .map(|(index, module)| (*module, index))
+    .collect::<IdentifierMap<_>>();
+
+  let mut names = NameAllocator::new(&members);
+  for member in &mut members {
+    for id in &member.info.top_level_bindings {
+      let name = names.allocate_binding(&id.0);
+      member.names.insert(id.clone(), name);
+    }
+  }
+
+  let mut linker = Linker {
+    members: &members,
+    index_by_module: &index_by_module,
+    used_namespaces: Default::default(),
+    interop_externals: Default::default(),
+  };
+  let root = members.len() - 1;
+  let root_exports = linker.exports(root);
+  let mut member_bindings = Vec::with_capacity(members.len());
+  for member in &members {
+    let mut imports = HashMap::default();
+    for (id, imported) in &member.info.imports {
+      let binding = linker.resolve_import(imported, &mut HashSet::default());
+      if member.info.namespace_objects.contains(id) {
+        linker.use_object(&binding);
+      }
+      imports.insert(id.clone(), binding);
+    }
+    let mut namespace_properties = HashMap::default();
+    for (id, property) in &member.info.namespace_properties {
+      if let Some((reference, None)) = member.info.imports.get(id) {
+        let binding = linker.resolve_import(
+          &(*reference, Some(property.clone())),
+          &mut HashSet::default(),
+        );
+        namespace_properties.insert((id.clone(), property.clone()), binding);
+      }
+    }
+    member_bindings.push((imports, namespace_properties));
+  }
+  for (_, binding) in &root_exports {
+    linker.use_object(binding);
+  }
+  // Namespace objects may expose other namespace objects
+  let mut namespace_exports = vec![];
+  let mut rendered_namespaces = HashSet::default();
+  while let Some(index) = linker
+    .used_namespaces
+    .iter()
+    .find(|index| !rendered_namespaces.contains(*index))
+    .copied()
+  {
+    rendered_namespaces.insert(index);
+    let exports = linker.exports(index);
+    for (_, binding) in &exports {
+      linker.use_object(binding);
+    }
+    namespace_exports.push((index, exports));
+  }
+  namespace_exports.sort_by_key(|(index, _)| *index);
+
+  let mut external_names = IdentifierMap::default();
+  for member in &members {
+    for request in &member.info.requests {
+      if !group.contains(request) && !external_names.contains_key(request) {
+        let name = names.allocate(&format!("_{}", module_stem(compilation, request)));
+        external_names.insert(*request, name);
+      }
+    }
+  }
+  let namespace_names = namespace_exports
+    .iter()
+    .map(|(index, _)| {
+      let stem = module_stem(compilation, &members[*index].module.identifier());
+      (*index, names.allocate(&format!("{stem}_namespace")))
+    })
+    .collect::<HashMap<_, _>>();
+  let export_helper = names.allocate("_export");
+  let render = |binding: &Binding| -> Result<Expr> {
+    Ok(match binding {
+      Binding::Local(name) => Expr::Ident(Ident::new(name.clone(), DUMMY_SP)),
+      Binding::External(module, property) => {
+        let object = Ident::new(
+          external_names
+            .get(module)
+            .ok_or_else(|| internal_error!("Failed to get the name of {module}"))?
+            .clone(),
+          DUMMY_SP,
+        );
+        match property {
+          Some(property) => Expr::Member(MemberExpr {
+            span: DUMMY_SP,
+            obj: Box::new(Expr::Ident(object)),
+            prop: if Ident::verify_symbol(property).is_ok() {
+              MemberProp::Ident(Ident::new(property.clone(), DUMMY_SP))
+            } else {
+              MemberProp::Computed(ComputedPropName {
+                span: DUMMY_SP,
+                expr: Box::new(Expr::Lit(Lit::Str(property.clone().into()))),
+              })
+            },
+          }),
+          None => Expr::Ident(object),
+        }
+      }
+      Binding::Namespace(index) => Expr::Ident(Ident::new(
+        namespace_names
+          .get(index)
+          .ok_or_else(|| internal_error!("Failed to get the namespace object of {index}"))?
+          .clone(),
+        DUMMY_SP,
+      )),
+      Binding::Missing => *undefined(DUMMY_SP),
+    })
+  };
+
+  let mut source = ConcatSource::default();
+  source.add(RawSource::from(
+    "Object.defineProperty(exports, \"__esModule\", { value: true });\n",
+  ));
+  let mut export_calls = vec![(String::from("exports"), &root_exports)];
+  for (index, exports) in &namespace_exports {
+    let name = &namespace_names[index];
+    source.add(RawSource::from(format!(
+      "var {name} = {{}};\nObject.defineProperty({name}, \"__esModule\", {{ value: true }});\n"
+    )));
+    export_calls.push((name.to_string(), exports));
+  }
+  if export_calls.iter().any(|(_, exports)| !exports.is_empty()) {
+    source.add(RawSource::from(format!(
+      "function {export_helper}(target, all) {{\n  for (var name in all) Object.defineProperty(target, name, {{ enumerable: true, get: all[name] }});\n}}\n"
+    )));
+  }
+  for (target, exports) in &export_calls {
+    if exports.is_empty() {
+      continue;
+    }
+    let getters = exports
+      .iter()
+      .map(|(name, binding)| {
+        Ok(format!(
+          "  {}: function() {{\n    return {};\n  }}",
+          serde_json::to_string(name.as_ref()).map_err(|e| internal_error!(e.to_string()))?,
+          stringify_expr(&render(binding)?)
+        ))
+      })
+      .collect::<Result<Vec<_>>>()?;
+    source.add(RawSource::from(format!(
+      "{export_helper}({target}, {{\n{}\n}});\n",
+      getters.join(",\n")
+    )));
+  }
+
+  // `export * from` modules out of the scope are exposed after they are required
+  let mut star_exports: IdentifierMap<Vec<String>> = IdentifierMap::default();
+  for (target, index) in std::iter::once((String::from("exports"), root)).chain(
+    namespace_exports
+      .iter()
+      .map(|(index, _)| (namespace_names[index].to_string(), *index)),
+  ) {
+    for module in linker.external_star_exports(index) {
+      star_exports.entry(module).or_default().push(target.clone());
+    }
+  }
+
+  let interop_externals = linker.interop_externals;
+
+  let mut declared_externals = IdentifierSet::default();
+  for (member, (imports, namespace_properties)) in members.into_iter().zip(member_bindings) {
+    let ConcatenatedMember {
+      module,
+      mut ast,
+      info,
+      names: binding_names,
+    } = member;
+    source.add(RawSource::from(format!(
+      "// CONCATENATED MODULE: {}\n",
+      module.readable_identifier(&compilation.options.context)
+    )));
+    for request in &info.requests {
+      let Some(name) = external_names.get(request) else {
+        continue;
+      };
+      if !declared_externals.insert(*request) {
+        continue;
+      }
+      let id = compilation
+        .chunk_graph
+        .get_module_id(*request)
+        .as_deref()
+        .ok_or_else(|| internal_error!("Failed to get the module id of {request}"))?;
+      let id = serde_json::to_string(id).map_err(|e| internal_error!(e.to_string()))?;
+      generate_context
+        .runtime_requirements
+        .insert(RuntimeGlobals::REQUIRE);
+      if interop_externals.contains(request) {
+        generate_context
+          .runtime_requirements
+          .insert(RuntimeGlobals::INTEROP_REQUIRE);
+        source.add(RawSource::from(format!(
+          "var {name} = __webpack_require__.ir(__webpack_require__({id}));\n"
+        )));
+      } else {
+        source.add(RawSource::from(format!(
+          "var {name} = __webpack_require__({id});\n"
+        )));
+      }
+      for target in star_exports.get(request).into_iter().flatten() {
+        generate_context
+          .runtime_requirements
+          .insert(RuntimeGlobals::EXPORT_STAR);
+        source.add(RawSource::from(format!(
+          "__webpack_require__.es({name}, {target});\n"
+        )));
+      }
+    }
+
+    let imports = imports
+      .iter()
+      .map(|(id, binding)| Ok((id.clone(), render(binding)?)))
+      .collect::<Result<HashMap<_, _>>>()?;
+    let namespace_properties = namespace_properties
+      .iter()
+      .map(|(key, binding)| Ok((key.clone(), render(binding)?)))
+      .collect::<Result<HashMap<_, _>>>()?;
+    ast.transform(|program, _| {
+      program.visit_mut_with(&mut ReferenceRewriter {
+        top_level_ctxt: info.top_level_ctxt,
+        names: &binding_names,
+        imports: &imports,
+        namespace_properties: &namespace_properties,
+      });
+    });
+    run_after_concatenation_pass(&mut ast, module, compilation);
+    source.add(stringify_to_source(&ast, module, compilation)?);
+    source.add(RawSource::from("\n"));
+  }
+
+  Ok(GenerationResult {
+    ast_or_source: AstOrSource::Source(source.boxed()),
+  })
+}
+
+/// Allocates names of the concatenated scope, which don't conflict with names used by any module
+struct NameAllocator {
+  /// How many modules each name is used by
+  usages: HashMap<JsWord, usize>,
+  allocated: HashSet<JsWord>,
+}
+
+impl NameAllocator {
+  fn new(members: &[ConcatenatedMember]) -> Self {
+    let mut usages: HashMap<JsWord, usize> = HashMap::default();
+    for member in members {
+      for name in &member.info.names {
+        *usages.entry(name.clone()).or_default() += 1;
+      }
+    }
+    Self {
+      usages,
+      allocated: RESERVED_NAMES.iter().map(|name| (*name).into()).collect(),
+    }
+  }
+
+  /// Keeps the name of a top level binding if it's only used by one module
+  fn allocate_binding(&mut self, name: &JsWord) -> JsWord {
+    if self.usages.get(name).copied().unwrap_or_default() <= 1
+      && self.allocated.insert(name.clone())
+    {
+      return name.clone();
+    }
+    self.allocate(name)
+  }
+
+  fn allocate(&mut self, name: &str) -> JsWord {
+    let name = JsWord::from(name);
+    if !self.usages.contains_key(&name) && self.allocated.insert(name.clone()) {
+      return name;
+    }
+    let mut index = 1;
+    loop {
+      let name = JsWord::from(format!("{name}_{index}"));
+      if !self.usages.contains_key(&name) && self.allocated.insert(name.clone()) {
+        return name;
+      }
+      index += 1;
+    }
+  }
+}
+
+/// Links imports and exports of concatenated modules to their bindings
+struct Linker<'a, 'b> {
+  members: &'b [ConcatenatedMember<'a>],
+  index_by_module: &'b IdentifierMap<usize>,
+  /// Namespace objects which need to be created
+  used_namespaces: HashSet<usize>,
+  /// Modules out of the scope whose default export or exports object are used
+  interop_externals: IdentifierSet,
+}
+
+impl<'a, 'b> Linker<'a, 'b> {
+  fn resolve_import(
+    &mut self,
+    (reference, name): &ImportedName,
+    visited: &mut HashSet<(usize, JsWord)>,
+  ) -> Binding {
+    match reference {
+      ModuleReference::External(module) => {
+        if name.as_deref().map_or(true, |name| name == "default") {
+          self.interop_externals.insert(*module);
+        }
+        Binding::External(*module, name.clone())
+      }
+      ModuleReference::Concatenated(module) => {
+        let Some(index) = self.index_by_module.get(module).copied() else {
+          return Binding::Missing;
+        };
+        match name {
+          Some(name) => self.resolve_export(index, name, visited),
+          None => Binding::Namespace(index),
+        }
+      }
+    }
+  }
+
+  fn resolve_export(
+    &mut self,
+    index: usize,
+    name: &JsWord,
+    visited: &mut HashSet<(usize, JsWord)>,
+  ) -> Binding {
+    // Circular reexports
+    if !visited.insert((index, name.clone())) {
+      return Binding::Missing;
+    }
+    let info = &self.members[index].info;
+    if let Some((_, source)) = info.exports.iter().find(|(exported, _)| exported == name) {
+      return match source {
+        ExportSource::Local(id) => match info.imports.get(id) {
+          Some(imported) => self.resolve_import(imported, visited),
+          None => self.members[index]
+            .names
+            .get(id)
+            .map_or(Binding::Missing, |name| Binding::Local(name.clone())),
+        },
+        ExportSource::Reexport(imported) => self.resolve_import(imported, visited),
+      };
+    }
+    if &**name == "default" {
+      return Binding::Missing;
+    }
+    let mut external = None;
+    for star in &info.star_exports {
+      match star {
+        ModuleReference::Concatenated(module) => {
+          if let Some(star) = self.index_by_module.get(module).copied() {
+            let binding = self.resolve_export(star, name, visited);
+            if binding != Binding::Missing {
+              return binding;
+            }
+          }
+        }
+        ModuleReference::External(module) => {
+          external.get_or_insert(*module);
+        }
+      }
+    }
+    // Exports of modules out of the scope are unknown, assume the first one has it
+    external.map_or(Binding::Missing, |module| {
+      Binding::External(module, Some(name.clone()))
+    })
+  }
+
+  /// Known exports of a concatenated module, sorted by names
+  fn exports(&mut self, index: usize) -> Vec<(JsWord, Binding)> {
+    let mut names = vec![];
+    self.collect_export_names(index, &mut HashSet::default(), &mut names);
+    names.sort();
+    names.dedup();
+    names
+      .into_iter()
+      .map(|name| {
+        let binding = self.resolve_export(index, &name, &mut HashSet::default());
+        (name, binding)
+      })
+      .filter(|(_, binding)| *binding != Binding::Missing)
+      .collect()
+  }
+
+  fn collect_export_names(
+    &self,
+    index: usize,
+    visited: &mut HashSet<usize>,
+    names: &mut Vec<JsWord>,
+  ) {
+    if !visited.insert(index) {
+      return;
+    }
+    let info = &self.members[index].info;
+    let is_star = visited.len() > 1;
+    names.extend(
+      info
+        .exports
+        .iter()
+        .filter(|(name, _)| !is_star || &**name != "default")
+        .map(|(name, _)| name.clone()),
+    );
+    for star in &info.star_exports {
+      if let ModuleReference::Concatenated(module) = star
+        && let Some(star) = self.index_by_module.get(module)
+      {
+        self.collect_export_names(*star, visited, names);
+      }
+    }
+  }
+
+  /// Modules out of the scope whose exports are reexported by `export *` of the module
+  fn external_star_exports(&self, index: usize) -> Vec<ModuleIdentifier> {
+    let mut modules = vec![];
+    let mut visited = HashSet::default();
+    let mut queue = vec![index];
+    while let Some(index) = queue.pop() {
+      if !visited.insert(index) {
+        continue;
+      }
+      for star in &self.members[index].info.star_exports {
+        match star {
+          ModuleReference::Concatenated(module) => {
+            queue.extend(self.index_by_module.get(module).copied());
+          }
+          ModuleReference::External(module) => {
+            if !modules.contains(module) {
+              modules.push(*module);
+            }
+          }
+        }
+      }
+    }
+    modules
+  }
+
+  /// The binding is used as an object, rather than reading its properties
+  fn use_object(&mut self, binding: &Binding) {
+    match binding {
+      Binding::Namespace(index) => {
+        self.used_namespaces.insert(*index);
+      }
+      Binding::External(module, None) => {
+        self.interop_externals.insert(*module);
+      }
+      _ => {}
+    }
+  }
+}
+
+/// Sanitized file stem of the module, used to name bindings
+fn module_stem(compilation: &Compilation, module: &ModuleIdentifier) -> String {
+  let stem = compilation
+    .module_graph
+    .module_by_identifier(module)
+    .and_then(|module| module.as_normal_module())
+    .and_then(|module| {
+      module
+        .resource_resolved_data()
+        .resource_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+    })
+    .unwrap_or_default();
+  let mut stem = stem
+    .chars()
+    .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+    .collect::<String>();
+  if stem.is_empty() || stem.starts_with(|c: char| c.is_ascii_digit()) {
+    stem.insert_str(0, "module_");
+  }
+  stem
+}
+
+fn stringify_expr(expr: &Expr) -> String {
+  match expr {
+    Expr::Ident(ident) => ident.sym.to_string(),
+    Expr::Member(MemberExpr {
+      obj: box Expr::Ident(obj),
+      prop: MemberProp::Ident(prop),
+      ..
+    }) => format!("{}.{}", obj.sym, prop.sym),
+    Expr::Member(MemberExpr {
+      obj: box Expr::Ident(obj),
+      prop:
+        MemberProp::Computed(ComputedPropName {
+          expr: box Expr::Lit(Lit::Str(prop)),
+          ..
+        }),
+      ..
+    }) => format!(
+      "{}[{}]",
+      obj.sym,
+      serde_json::to_string(prop.value.as_ref()).unwrap_or_default()
+    ),
+    _ => String::from("void 0"),
+  }
+}
+
+/// Renames top level bindings and replaces references to imported bindings
+struct ReferenceRewriter<'a> {
+  top_level_ctxt: SyntaxContext,
+  names: &'a HashMap<Id, JsWord>,
+  imports: &'a HashMap<Id, Expr>,
+  namespace_properties: &'a HashMap<(Id, JsWord), Expr>,
+}
+
+impl<'a> ReferenceRewriter<'a> {
+  fn replacement(&self, expr: &Expr) -> Option<Expr> {
+    match expr {
+      Expr::Ident(ident) => self.imports.get(&ident.to_id()).cloned(),
+      Expr::Member(MemberExpr {
+        obj: box Expr::Ident(obj),
+        prop,
+        ..
+      }) => static_property(prop).and_then(|property| {
+        self
+          .namespace_properties
+          .get(&(obj.to_id(), property))
+          .cloned()
+      }),
+      _ => None,
+    }
+  }
+
+  fn renamed(&self, ident: &Ident) -> Option<Expr> {
+    if let Some(expr) = self.imports.get(&ident.to_id()) {
+      return Some(expr.clone());
+    }
+    if ident.span.ctxt != self.top_level_ctxt {
+      return None;
+    }
+    self
+      .names
+      .get(&ident.to_id())
+      .filter(|name| **name != ident.sym)
+      .map(|name| Expr::Ident(Ident::new(name.clone(), ident.span)))
+  }
+}
+
+impl<'a> VisitMut for ReferenceRewriter<'a> {
+  noop_visit_mut_type!();
+
+  fn visit_mut_ident(&mut self, ident: &mut Ident) {
+    if ident.span.ctxt == self.top_level_ctxt
+      && let Some(name) = self.names.get(&ident.to_id())
+    {
+      ident.sym = name.clone();
+    }
+  }
+
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    if let Some(replacement) = self.replacement(expr) {
+      *expr = replacement;
+      return;
+    }
+    expr.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_callee(&mut self, callee: &mut Callee) {
+    // Calls of imported functions shouldn't bind `this` to the object they belong to
+    if let Callee::Expr(expr) = callee
+      && let Some(replacement) = self.replacement(expr)
+    {
+      **expr = match replacement {
+        Expr::Member(member) => Expr::Paren(ParenExpr {
+          span: DUMMY_SP,
+          expr: Box::new(Expr::Seq(SeqExpr {
+            span: DUMMY_SP,
+            exprs: vec![
+              Box::new(Expr::Lit(Lit::Num(0.into()))),
+              Box::new(Expr::Member(member)),
+            ],
+          })),
+        }),
+        replacement => replacement,
+      };
+      return;
+    }
+    callee.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_prop(&mut self, prop: &mut Prop) {
+    if let Prop::Shorthand(ident) = prop
+      && let Some(value) = self.renamed(ident)
+    {
+      *prop = Prop::KeyValue(KeyValueProp {
+        key: PropName::Ident(Ident::new(ident.sym.clone(), ident.span)),
+        value: Box::new(value),
+      });
+      return;
+    }
+    prop.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_object_pat_prop(&mut self, prop: &mut ObjectPatProp) {
+    if let ObjectPatProp::Assign(assign) = prop
+      && assign.key.span.ctxt == self.top_level_ctxt
+      && self
+        .names
+        .get(&assign.key.to_id())
+        .map_or(false, |name| *name != assign.key.sym)
+    {
+      let key = PropName::Ident(Ident::new(assign.key.sym.clone(), assign.key.span));
+      let mut value = Box::new(Pat::Ident(assign.key.take().into()));
+      if let Some(default) = assign.value.take() {
+        value = Box::new(Pat::Assign(AssignPat {
+          span: DUMMY_SP,
+          left: value,
+          right: default,
+          type_ann: None,
+        }));
+      }
+      let mut key_value = ObjectPatProp::KeyValue(KeyValuePatProp { key, value });
+      key_value.visit_mut_children_with(self);
+      *prop = key_value;
+      return;
+    }
+    prop.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_member_prop(&mut self, prop: &mut MemberProp) {
+    if let MemberProp::Computed(computed) = prop {
+      computed.visit_mut_with(self);
+    }
+  }
+
+  fn visit_mut_super_prop(&mut self, prop: &mut SuperProp) {
+    if let SuperProp::Computed(computed) = prop {
+      computed.visit_mut_with(self);
+    }
+  }
+
+  fn visit_mut_prop_name(&mut self, name: &mut PropName) {
+    if let PropName::Computed(computed) = name {
+      computed.visit_mut_with(self);
+    }
+  }
+}
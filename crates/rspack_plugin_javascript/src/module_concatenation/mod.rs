@@ -0,0 +1,247 @@
+mod analyze;
+mod concatenated_module;
+
+pub(crate) use concatenated_module::generate_concatenated_module;
+use rspack_core::{
+  AstOrSource, CacheOptions, Compilation, DependencyType, ModuleAst, ModuleIdentifier,
+  NormalModuleAstOrSource, OptimizeChunksArgs, Plugin,
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_identifier::IdentifierSet;
+use swc_core::common::SyntaxContext;
+use swc_core::ecma::ast::Ident;
+use swc_core::ecma::visit::{noop_visit_type, Visit};
+
+/// Same as webpack's `ModuleConcatenationPlugin`, also known as scope hoisting.
+///
+/// ESM modules which are only imported by other modules of the same chunks are merged into
+/// the scope of the module importing them, so they don't need a module function and
+/// a `__webpack_require__` call of their own.
+///
+/// A module stays on its own if it's CommonJS, async, uses `eval`, `module` or `exports`,
+/// or is referenced by non-ESM dependencies or by modules in other chunks.
+///
+/// Nothing is concatenated when cache or hot module replacement is enabled, which is reported as a warning.
+#[derive(Debug, Default)]
+pub struct ModuleConcatenationPlugin;
+
+#[async_trait::async_trait]
+impl Plugin for ModuleConcatenationPlugin {
+  fn name(&self) -> &'static str {
+    "ModuleConcatenationPlugin"
+  }
+
+  async fn optimize_chunk_modules(&mut self, args: OptimizeChunksArgs<'_>) -> Result<()> {
+    let compilation = args.compilation;
+    // Code generation results of unchanged modules are reused when cache is enabled,
+    // which can't reflect changes of the modules concatenated into them.
+    if !matches!(compilation.options.cache, CacheOptions::Disabled)
+      || compilation.options.dev_server.hot
+    {
+      compilation.push_diagnostic(Diagnostic::warn(
+        "ModuleConcatenationPlugin".to_string(),
+        "`optimization.concatenateModules` is ignored, because it doesn't work with `cache` or hot module replacement yet".to_string(),
+        0,
+        0,
+      ));
+      return Ok(());
+    }
+
+    let candidates = compilation
+      .module_graph
+      .modules()
+      .keys()
+      .filter(|module| is_candidate(compilation, module))
+      .copied()
+      .collect::<IdentifierSet>();
+    // Modules closer to entries go first, so that they become roots of larger groups.
+    let mut roots = candidates.iter().copied().collect::<Vec<_>>();
+    roots.sort_unstable_by_key(|module| {
+      (
+        compilation
+          .module_graph
+          .get_pre_order_index(module)
+          .unwrap_or(usize::MAX),
+        *module,
+      )
+    });
+
+    let mut concatenated = IdentifierSet::default();
+    for root in roots {
+      if concatenated.contains(&root) {
+        continue;
+      }
+      let modules = collect_concatenated_modules(compilation, root, &candidates, &concatenated)?;
+      if modules.len() < 2 {
+        continue;
+      }
+      concatenated.extend(modules.iter().copied());
+      compilation
+        .concatenated_inner_modules
+        .extend(modules.iter().copied().filter(|module| *module != root));
+      compilation.concatenated_modules.insert(root, modules);
+    }
+    Ok(())
+  }
+}
+
+/// Whether the module is able to be merged with other modules at all
+fn is_candidate(compilation: &Compilation, module: &ModuleIdentifier) -> bool {
+  let module_graph = &compilation.module_graph;
+  let Some(mgm) = module_graph.module_graph_module_by_identifier(module) else {
+    return false;
+  };
+  let is_esm = mgm
+    .build_meta
+    .as_ref()
+    .map_or(false, |build_meta| build_meta.esm && !build_meta.is_async);
+  if !is_esm
+    || !mgm.used
+    || module_graph.is_async(module)
+    || compilation.chunk_graph.get_number_of_module_chunks(*module) == 0
+  {
+    return false;
+  }
+
+  let Some(NormalModuleAstOrSource::BuiltSucceed(AstOrSource::Ast(ModuleAst::JavaScript(ast)))) =
+    module_graph
+      .module_by_identifier(module)
+      .and_then(|module| module.as_normal_module())
+      .map(|module| module.ast_or_source()) else {
+    return false;
+  };
+  ast.visit(|program, context| {
+    let mut visitor = UnsupportedGlobalsVisitor {
+      unresolved_ctxt: SyntaxContext::empty().apply_mark(context.unresolved_mark),
+      found: false,
+    };
+    program.visit_with(&mut visitor);
+    !visitor.found
+  })
+}
+
+/// Modules in the scope of `root`, in the order of evaluation with `root` itself last.
+fn collect_concatenated_modules(
+  compilation: &Compilation,
+  root: ModuleIdentifier,
+  candidates: &IdentifierSet,
+  concatenated: &IdentifierSet,
+) -> Result<Vec<ModuleIdentifier>> {
+  let chunks = compilation.chunk_graph.get_modules_chunks(root);
+  let mut group = IdentifierSet::from_iter([root]);
+  // A module may only be added after all modules importing it are added, so repeat until no more changes.
+  loop {
+    let mut changed = false;
+    for module in group.iter().copied().collect::<Vec<_>>() {
+      for target in esm_import_targets(compilation, &module) {
+        if group.contains(&target)
+          || !candidates.contains(&target)
+          || concatenated.contains(&target)
+          || compilation.entry_module_identifiers.contains(&target)
+          || compilation.chunk_graph.get_modules_chunks(target) != chunks
+        {
+          continue;
+        }
+        let Some(mgm) = compilation.module_graph.module_graph_module_by_identifier(&target) else {
+          continue;
+        };
+        let mut only_imported_by_group = true;
+        for connection in mgm.incoming_connections_unordered(&compilation.module_graph)? {
+          let is_esm_import = compilation
+            .module_graph
+            .dependency_by_id(&connection.dependency_id)
+            .map_or(false, |dependency| {
+              matches!(
+                dependency.dependency_type(),
+                DependencyType::EsmImport | DependencyType::EsmExport
+              )
+            });
+          let is_from_group = connection
+            .original_module_identifier
+            .map_or(false, |module| group.contains(&module));
+          if !is_esm_import || !is_from_group {
+            only_imported_by_group = false;
+            break;
+          }
+        }
+        if only_imported_by_group {
+          group.insert(target);
+          changed = true;
+        }
+      }
+    }
+    if !changed {
+      break;
+    }
+  }
+
+  let mut modules = vec![];
+  let mut visited = IdentifierSet::default();
+  sort_by_evaluation(compilation, root, &group, &mut visited, &mut modules);
+  Ok(modules)
+}
+
+fn sort_by_evaluation(
+  compilation: &Compilation,
+  module: ModuleIdentifier,
+  group: &IdentifierSet,
+  visited: &mut IdentifierSet,
+  modules: &mut Vec<ModuleIdentifier>,
+) {
+  if !visited.insert(module) {
+    return;
+  }
+  for target in esm_import_targets(compilation, &module) {
+    if group.contains(&target) {
+      sort_by_evaluation(compilation, target, group, visited, modules);
+    }
+  }
+  modules.push(module);
+}
+
+/// Modules referenced by import and export declarations, in the order of declarations
+fn esm_import_targets(
+  compilation: &Compilation,
+  module: &ModuleIdentifier,
+) -> Vec<ModuleIdentifier> {
+  let module_graph = &compilation.module_graph;
+  module_graph
+    .module_graph_module_by_identifier(module)
+    .map(|mgm| {
+      mgm
+        .dependencies
+        .iter()
+        .filter(|id| {
+          module_graph
+            .dependency_by_id(id)
+            .map_or(false, |dependency| {
+              matches!(
+                dependency.dependency_type(),
+                DependencyType::EsmImport | DependencyType::EsmExport
+              )
+            })
+        })
+        .filter_map(|id| module_graph.module_identifier_by_dependency_id(id))
+        .copied()
+        .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Finds references to `eval`, `module` and `exports`, which can't be shared by concatenated modules.
+struct UnsupportedGlobalsVisitor {
+  unresolved_ctxt: SyntaxContext,
+  found: bool,
+}
+
+impl Visit for UnsupportedGlobalsVisitor {
+  noop_visit_type!();
+
+  fn visit_ident(&mut self, ident: &Ident) {
+    if ident.span.ctxt == self.unresolved_ctxt
+      && matches!(ident.sym.as_ref(), "eval" | "module" | "exports")
+    {
+      self.found = true;
+    }
+  }
+}
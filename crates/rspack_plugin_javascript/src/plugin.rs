@@ -1,21 +1,24 @@
 use std::collections::HashSet;
 use std::hash::{Hash, Hasher};
+use std::path::Path;
 use std::sync::mpsc;
 
 use async_trait::async_trait;
 use linked_hash_set::LinkedHashSet;
 use rayon::prelude::*;
+use regex::Regex;
 use rspack_core::rspack_sources::{
   BoxSource, ConcatSource, MapOptions, RawSource, Source, SourceExt, SourceMap, SourceMapSource,
   SourceMapSourceOptions,
 };
 use rspack_core::{
-  get_js_chunk_filename_template, AstOrSource, ChunkHashArgs, ChunkKind, ChunkUkey, Compilation,
-  DependencyType, GenerateContext, GenerationResult, JsChunkHashArgs, Module, ModuleAst,
-  ModuleType, ParseContext, ParseResult, ParserAndGenerator, PathData, Plugin,
-  PluginChunkHashHookOutput, PluginContext, PluginJsChunkHashHookOutput, PluginProcessAssetsOutput,
-  PluginRenderManifestHookOutput, ProcessAssetsArgs, RenderArgs, RenderChunkArgs,
-  RenderManifestEntry, RenderStartupArgs, RuntimeGlobals, SourceType,
+  ast::javascript::Ast, get_js_chunk_filename_template, AssetInfo, AstOrSource, ChunkHashArgs,
+  ChunkKind, ChunkUkey, Compilation, CompilationAsset, DependencyType, GenerateContext,
+  GenerationResult, JsChunkHashArgs, Module, ModuleAst, ModuleType, ParseContext, ParseResult,
+  ParserAndGenerator, PathData, Plugin, PluginChunkHashHookOutput, PluginContext,
+  PluginJsChunkHashHookOutput, PluginProcessAssetsOutput, PluginRenderManifestHookOutput,
+  ProcessAssetsArgs, RenderArgs, RenderChunkArgs, RenderManifestEntry, RenderStartupArgs,
+  RuntimeGlobals, SourceType,
 };
 use rspack_error::{
   internal_error, Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray,
@@ -27,7 +30,11 @@ use swc_core::ecma::ast;
 use swc_core::ecma::minifier::option::terser::TerserCompressorOptions;
 use xxhash_rust::xxh3::Xxh3;
 
-use crate::runtime::{generate_chunk_entry_code, render_chunk_modules, render_runtime_modules};
+use crate::module_concatenation::generate_concatenated_module;
+use crate::runtime::{
+  generate_chunk_entry_code, render_chunk_modules, render_external_module_imports,
+  render_runtime_modules,
+};
 use crate::utils::syntax_by_module_type;
 use crate::visitors::{run_after_pass, run_before_pass, scan_dependencies};
 
@@ -190,11 +197,24 @@ impl JsPlugin {
         sources.add(source);
       }
     }
-    let final_source = if compilation.options.output.iife {
+    let mut final_source = if compilation.options.output.iife {
       self.render_iife(sources.boxed())
     } else {
       sources.boxed()
     };
+    if compilation.options.output.module {
+      if let Some(imports) = render_external_module_imports(compilation, &args.chunk_ukey) {
+        final_source = ConcatSource::new([imports, final_source]).boxed();
+      }
+      // Entry chunks without runtime import `__webpack_require__` from the runtime chunk
+      if runtime_requirements.contains(RuntimeGlobals::EXTERNAL_INSTALL_CHUNK) {
+        final_source = ConcatSource::new([
+          final_source,
+          RawSource::from(format!("export default {};\n", RuntimeGlobals::REQUIRE)).boxed(),
+        ])
+        .boxed();
+      }
+    }
     if let Some(source) = compilation.plugin_driver.read().await.render(RenderArgs {
       compilation,
       chunk: &args.chunk_ukey,
@@ -307,7 +327,7 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
       module_type,
       compiler_options.builtins.decorator.is_some(),
     );
-    let (mut ast, diagnostics) = match crate::ast::parse(
+    let (mut ast, mut diagnostics) = match crate::ast::parse(
       source.source().to_string(),
       syntax,
       &resource_data.resource_path.to_string_lossy(),
@@ -334,14 +354,17 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
       module_type,
     )?;
 
-    let (dependencies, presentational_dependencies) = ast.visit(|program, context| {
-      scan_dependencies(
-        program,
-        context.unresolved_mark,
-        resource_data,
-        compiler_options,
-      )
-    });
+    let (dependencies, presentational_dependencies, scan_diagnostics) =
+      ast.visit(|program, context| {
+        scan_dependencies(
+          program,
+          context.unresolved_mark,
+          &context.source_map,
+          resource_data,
+          compiler_options,
+        )
+      });
+    diagnostics.extend(scan_diagnostics);
 
     Ok(
       ParseResult {
@@ -359,7 +382,6 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
     )
   }
 
-  #[allow(clippy::unwrap_in_result)]
   fn generate(
     &self,
     ast_or_source: &AstOrSource,
@@ -370,6 +392,13 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
       generate_context.requested_source_type,
       SourceType::JavaScript
     ) {
+      if let Some(modules) = generate_context
+        .compilation
+        .concatenated_modules
+        .get(&module.identifier())
+      {
+        return generate_concatenated_module(modules, generate_context);
+      }
       // TODO: this should only return AST for javascript only, It's a fast pass, defer to another pr to solve this.
       // Ok(ast_or_source.to_owned().into())
       let mut ast = ast_or_source
@@ -377,40 +406,9 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
         .try_into_ast()?
         .try_into_javascript()?;
       run_after_pass(&mut ast, module, generate_context)?;
-      let output = crate::ast::stringify(&ast, &generate_context.compilation.options.devtool)?;
-      if let Some(map) = output.map {
-        Ok(GenerationResult {
-          ast_or_source: SourceMapSource::new(SourceMapSourceOptions {
-            value: output.code,
-            source_map: SourceMap::from_json(&map).map_err(|e| internal_error!(e.to_string()))?,
-            name: module.try_as_normal_module()?.user_request().to_string(),
-            original_source: {
-              Some(
-                // Safety: you can sure that `build` is called before code generation, so that the `original_source` is exist
-                module
-                  .original_source()
-                  .expect("Failed to get original source, please file an issue.")
-                  .source()
-                  .to_string(),
-              )
-            },
-            inner_source_map: {
-              // Safety: you can sure that `build` is called before code generation, so that the `original_source` is exist
-              module
-                .original_source()
-                .expect("Failed to get original source, please file an issue.")
-                .map(&MapOptions::default())
-            },
-            remove_original_source: false,
-          })
-          .boxed()
-          .into(),
-        })
-      } else {
-        Ok(GenerationResult {
-          ast_or_source: RawSource::from(output.code).boxed().into(),
-        })
-      }
+      Ok(GenerationResult {
+        ast_or_source: stringify_to_source(&ast, module, generate_context.compilation)?.into(),
+      })
     } else {
       Err(internal_error!(
         "Unsupported source type {:?} for plugin JavaScript",
@@ -420,6 +418,50 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
   }
 }
 
+/// Prints the ast of a module, with the source map if enabled by `devtool`.
+#[allow(clippy::unwrap_in_result)]
+pub(crate) fn stringify_to_source(
+  ast: &Ast,
+  module: &dyn Module,
+  compilation: &Compilation,
+) -> Result<BoxSource> {
+  let output = crate::ast::stringify(
+    ast,
+    &compilation.options.devtool,
+    compilation.options.builtins.minify_options.is_some(),
+  )?;
+  if let Some(map) = output.map {
+    Ok(
+      SourceMapSource::new(SourceMapSourceOptions {
+        value: output.code,
+        source_map: SourceMap::from_json(&map).map_err(|e| internal_error!(e.to_string()))?,
+        name: module.try_as_normal_module()?.user_request().to_string(),
+        original_source: {
+          Some(
+            // Safety: you can sure that `build` is called before code generation, so that the `original_source` is exist
+            module
+              .original_source()
+              .expect("Failed to get original source, please file an issue.")
+              .source()
+              .to_string(),
+          )
+        },
+        inner_source_map: {
+          // Safety: you can sure that `build` is called before code generation, so that the `original_source` is exist
+          module
+            .original_source()
+            .expect("Failed to get original source, please file an issue.")
+            .map(&MapOptions::default())
+        },
+        remove_original_source: false,
+      })
+      .boxed(),
+    )
+  } else {
+    Ok(RawSource::from(output.code).boxed())
+  }
+}
+
 #[async_trait]
 impl Plugin for JsPlugin {
   fn name(&self) -> &'static str {
@@ -565,7 +607,13 @@ impl Plugin for JsPlugin {
       &compilation.chunk_group_by_ukey,
     );
 
-    let output_path = filename_template.render_with_chunk(chunk, ".js", &SourceType::JavaScript);
+    let mut asset_info = AssetInfo::default();
+    let output_path = filename_template.render_with_chunk_and_asset_info(
+      chunk,
+      ".js",
+      &SourceType::JavaScript,
+      &mut asset_info,
+    );
 
     let path_options = PathData {
       chunk_ukey: args.chunk_ukey,
@@ -574,6 +622,7 @@ impl Plugin for JsPlugin {
       source,
       output_path,
       path_options,
+      asset_info,
     )])
   }
 
@@ -602,6 +651,13 @@ impl Plugin for JsPlugin {
 
     if let Some(minify_options) = minify_options {
       let (tx, rx) = mpsc::channel::<Vec<Diagnostic>>();
+      let (license_tx, license_rx) = mpsc::channel::<(String, String)>();
+      let extract_comments_condition = minify_options
+        .extract_comments
+        .as_ref()
+        .map(|condition| Regex::new(condition))
+        .transpose()
+        .map_err(|e| internal_error!("Invalid extractComments condition: {e}"))?;
 
       compilation
         .assets
@@ -609,7 +665,7 @@ impl Plugin for JsPlugin {
         .filter(|(filename, _)| {
           filename.ends_with(".js") || filename.ends_with(".cjs") || filename.ends_with(".mjs")
         })
-        .try_for_each_with(tx, |tx, (filename, original)| -> Result<()> {
+        .try_for_each_with((tx, license_tx), |(tx, license_tx), (filename, original)| -> Result<()> {
           // In theory, if a js source is minimized it has high possibility has been tree-shaked.
           if original.get_info().minimized {
             return Ok(());
@@ -618,7 +674,7 @@ impl Plugin for JsPlugin {
           if let Some(original_source) = original.get_source() {
             let input = original_source.source().to_string();
             let input_source_map = original_source.map(&MapOptions::default());
-            let output = match crate::ast::minify(&JsMinifyOptions {
+            let (output, extracted_comments) = match crate::ast::minify(&JsMinifyOptions {
               compress: BoolOrDataConfig::from_obj(TerserCompressorOptions {
                 passes: minify_options.passes,
                 drop_console: minify_options.drop_console,
@@ -629,7 +685,7 @@ impl Plugin for JsPlugin {
               inline_sources_content: true, // Using true so original_source can be None in SourceMapSource
               emit_source_map_columns: !compilation.options.devtool.cheap(),
               ..Default::default()
-            }, input, filename) {
+            }, input, filename, extract_comments_condition.as_ref()) {
               Ok(r) => r,
               Err(e) => {
                 tx.send(e.into()).map_err(|e| internal_error!(e.to_string()))?;
@@ -650,6 +706,22 @@ impl Plugin for JsPlugin {
             } else {
               RawSource::from(output.code).boxed()
             };
+            let source = if extracted_comments.is_empty() {
+              source
+            } else {
+              let license_filename = format!("{filename}.LICENSE.txt");
+              let banner = format!(
+                "/*! For license information please see {} */\n",
+                Path::new(&license_filename)
+                  .file_name()
+                  .map(|name| name.to_string_lossy())
+                  .unwrap_or_default()
+              );
+              license_tx
+                .send((license_filename, format!("{}\n", extracted_comments.join("\n\n"))))
+                .map_err(|e| internal_error!(e.to_string()))?;
+              ConcatSource::new([RawSource::from(banner).boxed(), source]).boxed()
+            };
             original.set_source(Some(source));
             original.get_info_mut().minimized = true;
           }
@@ -657,6 +729,14 @@ impl Plugin for JsPlugin {
         })?;
 
       compilation.push_batch_diagnostic(rx.into_iter().flatten().collect::<Vec<_>>());
+      license_rx
+        .into_iter()
+        .for_each(|(license_filename, comments)| {
+          compilation.emit_asset(
+            license_filename,
+            CompilationAsset::with_source(RawSource::from(comments).boxed()),
+          )
+        });
     }
 
     Ok(())
@@ -2,7 +2,10 @@ use dashmap::DashMap;
 use once_cell::sync::Lazy;
 use rayon::prelude::*;
 use rspack_core::rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt};
-use rspack_core::{ChunkUkey, Compilation, RenderModuleContentArgs, RuntimeGlobals, SourceType};
+use rspack_core::{
+  ChunkUkey, Compilation, RenderModuleContentArgs, RuntimeGlobals, SourceType,
+  EXTERNAL_MODULE_IMPORT_DATA_KEY,
+};
 use rspack_error::Result;
 
 static MODULE_RENDER_CACHE: Lazy<DashMap<BoxSource, BoxSource>> = Lazy::new(DashMap::default);
@@ -29,7 +32,13 @@ pub fn render_chunk_modules(
 
   let mut module_code_array = ordered_modules
     .par_iter()
-    .filter(|mgm| mgm.used)
+    .filter(|mgm| {
+      // modules concatenated into another module are rendered by their root module
+      mgm.used
+        && !compilation
+          .concatenated_inner_modules
+          .contains(&mgm.module_identifier)
+    })
     .map(|mgm| {
       let result = compilation
         .code_generation_results
@@ -86,6 +95,44 @@ pub fn render_chunk_modules(
   Ok(sources.boxed())
 }
 
+/// Static imports of "module" externals, which must be placed at the top level of an esm chunk.
+pub fn render_external_module_imports(
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+) -> Option<BoxSource> {
+  let chunk = compilation
+    .chunk_by_ukey
+    .get(chunk_ukey)
+    .expect("chunk not found");
+  let mut imports = compilation
+    .chunk_graph
+    .get_chunk_modules_by_source_type(
+      chunk_ukey,
+      SourceType::JavaScript,
+      &compilation.module_graph,
+    )
+    .into_iter()
+    .filter_map(|mgm| {
+      compilation
+        .code_generation_results
+        .get(&mgm.module_identifier, Some(&chunk.runtime))
+        .ok()
+        .and_then(|result| result.data.get(EXTERNAL_MODULE_IMPORT_DATA_KEY))
+    })
+    .collect::<Vec<_>>();
+  if imports.is_empty() {
+    return None;
+  }
+  imports.sort_unstable();
+  imports.dedup();
+
+  let mut sources = ConcatSource::default();
+  for import in imports {
+    sources.add(RawSource::from(import.to_string()));
+  }
+  Some(sources.boxed())
+}
+
 fn render_module(source: BoxSource, strict: bool, module_id: &str) -> BoxSource {
   let mut sources = ConcatSource::new([
     RawSource::from("\""),
@@ -0,0 +1,150 @@
+use rspack_core::{ChunkGroupOptions, ContextMode};
+use swc_core::common::comments::Comment;
+
+/// Options of `import()` from magic comments like `/* webpackChunkName: "foo" */`
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ImportMagicComments {
+  pub chunk_name: Option<String>,
+  pub mode: Option<ContextMode>,
+  pub prefetch_order: Option<i32>,
+  pub preload_order: Option<i32>,
+}
+
+impl ImportMagicComments {
+  pub fn from_comments(comments: &[Comment]) -> Self {
+    let mut magic_comments = Self::default();
+    for comment in comments {
+      for (key, value) in parse_magic_comment(&comment.text) {
+        match (key, value) {
+          ("webpackChunkName", MagicCommentValue::Str(name)) => {
+            magic_comments.chunk_name = Some(name.to_string())
+          }
+          ("webpackMode", MagicCommentValue::Str(mode)) => {
+            magic_comments.mode = match mode {
+              "lazy" => Some(ContextMode::Lazy),
+              "lazy-once" => Some(ContextMode::LazyOnce),
+              "eager" => Some(ContextMode::Eager),
+              "weak" => Some(ContextMode::Weak),
+              _ => magic_comments.mode,
+            }
+          }
+          ("webpackPrefetch", value) => {
+            if let Some(order) = value.as_order() {
+              magic_comments.prefetch_order = order;
+            }
+          }
+          ("webpackPreload", value) => {
+            if let Some(order) = value.as_order() {
+              magic_comments.preload_order = order;
+            }
+          }
+          _ => {}
+        }
+      }
+    }
+    magic_comments
+  }
+
+  pub fn group_options(&self) -> ChunkGroupOptions {
+    ChunkGroupOptions {
+      name: self.chunk_name.clone(),
+      prefetch_order: self.prefetch_order,
+      preload_order: self.preload_order,
+      ..Default::default()
+    }
+  }
+}
+
+#[derive(Debug, PartialEq)]
+enum MagicCommentValue<'a> {
+  Str(&'a str),
+  Bool(bool),
+  Num(f64),
+}
+
+impl MagicCommentValue<'_> {
+  /// `true` is order 0, `false` disables it, `None` if the value is invalid
+  fn as_order(&self) -> Option<Option<i32>> {
+    match self {
+      Self::Bool(true) => Some(Some(0)),
+      Self::Bool(false) => Some(None),
+      Self::Num(n) if n.fract() == 0.0 => Some(Some(*n as i32)),
+      _ => None,
+    }
+  }
+}
+
+/// Parses `webpackFoo: "bar", webpackBaz: true` to key value pairs, invalid pairs are skipped
+fn parse_magic_comment(text: &str) -> Vec<(&str, MagicCommentValue<'_>)> {
+  split_pairs(text)
+    .into_iter()
+    .filter_map(|pair| {
+      let (key, value) = pair.split_once(':')?;
+      let key = key.trim();
+      if !key.starts_with("webpack") {
+        return None;
+      }
+      let value = value.trim();
+      let value = match value {
+        "true" => MagicCommentValue::Bool(true),
+        "false" => MagicCommentValue::Bool(false),
+        _ if value.len() >= 2
+          && (value.starts_with('"') && value.ends_with('"')
+            || value.starts_with('\'') && value.ends_with('\'')) =>
+        {
+          MagicCommentValue::Str(&value[1..value.len() - 1])
+        }
+        _ => MagicCommentValue::Num(value.parse().ok()?),
+      };
+      Some((key, value))
+    })
+    .collect()
+}
+
+/// Splits by commas which are not in quotes
+fn split_pairs(text: &str) -> Vec<&str> {
+  let mut pairs = vec![];
+  let mut quote = None;
+  let mut start = 0;
+  for (i, c) in text.char_indices() {
+    match (quote, c) {
+      (None, '"' | '\'') => quote = Some(c),
+      (Some(q), _) if q == c => quote = None,
+      (None, ',') => {
+        pairs.push(&text[start..i]);
+        start = i + 1;
+      }
+      _ => {}
+    }
+  }
+  pairs.push(&text[start..]);
+  pairs
+}
+
+#[test]
+fn test() {
+  use swc_core::common::{comments::CommentKind, DUMMY_SP};
+
+  let comment = |text: &str| Comment {
+    kind: CommentKind::Block,
+    span: DUMMY_SP,
+    text: text.into(),
+  };
+  assert_eq!(
+    ImportMagicComments::from_comments(&[
+      comment(r#" webpackChunkName: "settings-[request]", webpackPrefetch: true "#),
+      comment(" webpackMode: 'lazy-once' "),
+      comment(" webpackPreload: 2, notMagic: 'webpackMode: \"eager\"' "),
+    ]),
+    ImportMagicComments {
+      chunk_name: Some("settings-[request]".to_string()),
+      mode: Some(ContextMode::LazyOnce),
+      prefetch_order: Some(0),
+      preload_order: Some(2),
+    }
+  );
+  assert_eq!(
+    ImportMagicComments::from_comments(&[comment(" webpackChunkName: settings ")]),
+    ImportMagicComments::default()
+  );
+}
@@ -1,5 +1,6 @@
 mod code_generation;
 mod hmr_scanner;
+mod magic_comments;
 mod scanner;
 mod util;
 
@@ -7,28 +8,38 @@ pub use code_generation::*;
 use rspack_core::{
   ast::javascript::Program, CompilerOptions, Dependency, ModuleDependency, ResourceData,
 };
-use swc_core::common::Mark;
+use rspack_error::Diagnostic;
+use swc_core::common::{Mark, SourceMap};
 pub use util::*;
 
 use self::{hmr_scanner::HmrDependencyScanner, scanner::DependencyScanner};
 
-pub type ScanDependenciesResult = (Vec<Box<dyn ModuleDependency>>, Vec<Box<dyn Dependency>>);
+pub type ScanDependenciesResult = (
+  Vec<Box<dyn ModuleDependency>>,
+  Vec<Box<dyn Dependency>>,
+  Vec<Diagnostic>,
+);
 
 pub fn scan_dependencies(
   program: &Program,
   unresolved_mark: Mark,
+  source_map: &SourceMap,
   resource_data: &ResourceData,
   compiler_options: &CompilerOptions,
 ) -> ScanDependenciesResult {
   let mut dependencies: Vec<Box<dyn ModuleDependency>> = vec![];
   let mut presentational_dependencies: Vec<Box<dyn Dependency>> = vec![];
+  let mut diagnostics: Vec<Diagnostic> = vec![];
   program.visit_with_path(
     &mut DependencyScanner::new(
       unresolved_mark,
+      program.comments(),
       resource_data,
       compiler_options,
+      source_map,
       &mut dependencies,
       &mut presentational_dependencies,
+      &mut diagnostics,
     ),
     &mut Default::default(),
   );
@@ -36,5 +47,5 @@ pub fn scan_dependencies(
     &mut HmrDependencyScanner::new(&mut dependencies),
     &mut Default::default(),
   );
-  (dependencies, presentational_dependencies)
+  (dependencies, presentational_dependencies, diagnostics)
 }
@@ -1,25 +1,36 @@
 use rspack_core::{
-  CommonJsRequireContextDependency, CompilerOptions, ConstDependency, ContextMode, ContextOptions,
-  Dependency, DependencyCategory, ImportContextDependency, ModuleDependency,
-  RequireContextDependency, ResourceData, RuntimeGlobals,
+  ChunkGroupOptions, CommonJsRequireContextDependency, CompilerOptions, ConstDependency,
+  ContextMode, ContextOptions, Dependency, DependencyCategory, ErrorSpan, ImportContextDependency,
+  ImportMetaContextDependency, ModuleDependency, RequireContextDependency, ResourceData,
+  RuntimeGlobals,
 };
+use rspack_error::{Diagnostic, DiagnosticKind, Error, Severity, TraceableError};
 use rspack_regex::RspackRegex;
 use sugar_path::SugarPath;
-use swc_core::common::DUMMY_SP;
-use swc_core::common::{pass::AstNodePath, Mark, SyntaxContext};
+use swc_core::base::SwcComments;
+use swc_core::common::comments::Comments;
+use swc_core::common::{
+  pass::AstNodePath, Mark, SourceMap, Span, Spanned, SyntaxContext, DUMMY_SP,
+};
 use swc_core::ecma::ast::{
-  AssignExpr, AssignOp, BinExpr, BinaryOp, CallExpr, Callee, Expr, ExprOrSpread, Ident, Lit,
-  MemberExpr, MemberProp, MetaPropExpr, MetaPropKind, ModuleDecl, NewExpr, Pat, PatOrExpr, Tpl,
+  ArrayLit, ArrowExpr, AssignExpr, AssignOp, BinExpr, BinaryOp, BindingIdent, CallExpr, Callee,
+  Expr, ExprOrSpread, Function, Ident, KeyValueProp, Lit, MemberExpr, MemberProp, MetaPropExpr,
+  MetaPropKind, ModuleDecl, NewExpr, Pat, PatOrExpr, PropName, Regex, Str, Tpl,
 };
 use swc_core::ecma::atoms::js_word;
 use swc_core::ecma::utils::{member_expr, quote_ident, quote_str};
 use swc_core::ecma::visit::{AstParentNodeRef, VisitAstPath, VisitWithPath};
 use swc_core::quote;
 
-use super::{as_parent_path, is_require_context_call, match_member_expr};
+use super::{
+  as_parent_path, is_import_meta_context_call, is_require_context_call,
+  magic_comments::ImportMagicComments, match_member_expr,
+};
 use crate::dependency::{
+  AMDDefineDependency, AMDRequireArrayDependency, AMDRequireItemDependency,
   CommonJSRequireDependency, EsmDynamicImportDependency, EsmExportDependency, EsmImportDependency,
-  URLDependency,
+  RequireEnsureDependency, RequireEnsureItemDependency, RequireResolveDependency, URLDependency,
+  WorkerDependency,
 };
 pub const WEBPACK_HASH: &str = "__webpack_hash__";
 pub const WEBPACK_PUBLIC_PATH: &str = "__webpack_public_path__";
@@ -31,10 +42,26 @@ pub const GLOBAL: &str = "global";
 
 pub struct DependencyScanner<'a> {
   pub unresolved_ctxt: SyntaxContext,
+  pub comments: Option<&'a SwcComments>,
   pub dependencies: &'a mut Vec<Box<dyn ModuleDependency>>,
   pub presentational_dependencies: &'a mut Vec<Box<dyn Dependency>>,
+  pub diagnostics: &'a mut Vec<Diagnostic>,
+  pub source_map: &'a SourceMap,
   pub compiler_options: &'a CompilerOptions,
   pub resource_data: &'a ResourceData,
+  worker_url_span: Option<Span>,
+  /// The arrays of `define([...])` and `require([...])`, the group options are only set for the
+  /// latter as its items are loaded on demand
+  amd_arrays: Vec<(Span, Option<ChunkGroupOptions>)>,
+  /// The length of the ast path of the AMD array being visited
+  amd_array: Option<(usize, Option<ChunkGroupOptions>)>,
+  /// The `require` parameters of AMD factories and `require.ensure` callbacks, e.g.
+  /// `define(function (require) {})`
+  require_ctxts: Vec<SyntaxContext>,
+  /// The spans of `require.ensure` callbacks and the chunk groups of their items
+  require_ensure_callbacks: Vec<(Span, ChunkGroupOptions)>,
+  /// The chunk group of the `require.ensure` callback being visited
+  require_ensure: Option<ChunkGroupOptions>,
 }
 
 impl DependencyScanner<'_> {
@@ -46,6 +73,28 @@ impl DependencyScanner<'_> {
     self.presentational_dependencies.push(dependency);
   }
 
+  fn add_warning(&mut self, span: Span, title: &str, message: String) {
+    let source_file = self.source_map.lookup_char_pos(span.lo).file;
+    let span: ErrorSpan = span.into();
+    let error = TraceableError::from_source_file(
+      &source_file,
+      span.start as usize,
+      span.end as usize,
+      title.to_string(),
+      message,
+    )
+    .with_kind(DiagnosticKind::JavaScript)
+    .with_severity(Severity::Warn);
+    self
+      .diagnostics
+      .extend(Vec::<Diagnostic>::from(Error::TraceableError(error)));
+  }
+
+  fn is_require_ident(&self, ident: &Ident) -> bool {
+    "require".eq(&ident.sym)
+      && (ident.span.ctxt == self.unresolved_ctxt || self.require_ctxts.contains(&ident.span.ctxt))
+  }
+
   fn add_import(&mut self, module_decl: &ModuleDecl, ast_path: &AstNodePath<AstParentNodeRef<'_>>) {
     if let ModuleDecl::Import(import_decl) = module_decl {
       let source = import_decl.src.value.clone();
@@ -59,7 +108,7 @@ impl DependencyScanner<'_> {
   fn add_require(&mut self, call_expr: &CallExpr, ast_path: &AstNodePath<AstParentNodeRef<'_>>) {
     if let Callee::Expr(expr) = &call_expr.callee {
       if let Expr::Ident(ident) = &**expr {
-        if "require".eq(&ident.sym) && ident.span.ctxt == self.unresolved_ctxt {
+        if self.is_require_ident(ident) {
           {
             if call_expr.args.len() != 1 {
               return;
@@ -67,6 +116,16 @@ impl DependencyScanner<'_> {
             if let Some(expr) = call_expr.args.get(0) {
               if expr.spread.is_none() {
                 if let Expr::Lit(Lit::Str(s)) = expr.expr.as_ref() {
+                  // The module is loaded with the chunk of the `require.ensure`
+                  if let Some(group_options) = &self.require_ensure {
+                    self.add_dependency(box RequireEnsureItemDependency::new(
+                      s.value.clone(),
+                      Some(call_expr.span.into()),
+                      as_parent_path(ast_path),
+                      group_options.clone(),
+                    ));
+                    return;
+                  }
                   self.add_dependency(box CommonJSRequireDependency::new(
                     s.value.clone(),
                     Some(call_expr.span.into()),
@@ -85,6 +144,7 @@ impl DependencyScanner<'_> {
                       exclude: None,
                       category: DependencyCategory::CommonJS,
                       request: context,
+                      group_options: None,
                     },
                     Some(call_expr.span.into()),
                     as_parent_path(ast_path),
@@ -97,21 +157,150 @@ impl DependencyScanner<'_> {
       }
     }
   }
+  // require.resolve("./foo");
+  // require.resolveWeak("./foo");
+  fn add_require_resolve(&mut self, expr: &Expr, ast_path: &AstNodePath<AstParentNodeRef<'_>>) {
+    let Expr::Call(CallExpr {
+      callee: Callee::Expr(callee),
+      args,
+      span,
+      ..
+    }) = expr else {
+      return;
+    };
+    let weak = if self.is_require_member(callee, "resolve") {
+      false
+    } else if self.is_require_member(callee, "resolveWeak") {
+      true
+    } else {
+      return;
+    };
+    if let [ExprOrSpread {
+      spread: None,
+      expr: box Expr::Lit(Lit::Str(request)),
+    }] = args.as_slice()
+    {
+      self.add_dependency(box RequireResolveDependency::new(
+        request.value.clone(),
+        weak,
+        Some((*span).into()),
+        as_parent_path(ast_path),
+      ));
+    }
+  }
+
+  // require.ensure(["./foo"], function (require) {}, function (error) {}, "name");
+  fn add_require_ensure(
+    &mut self,
+    call_expr: &CallExpr,
+    ast_path: &AstNodePath<AstParentNodeRef<'_>>,
+  ) {
+    let Callee::Expr(callee) = &call_expr.callee else {
+      return;
+    };
+    if !self.is_require_member(callee, "ensure")
+      || call_expr.args.iter().any(|arg| arg.spread.is_some())
+    {
+      return;
+    }
+    let args = call_expr
+      .args
+      .iter()
+      .map(|arg| &*arg.expr)
+      .collect::<Vec<_>>();
+    let (array, callback, chunk_name) = match args.as_slice() {
+      [Expr::Array(array), callback, Expr::Lit(Lit::Str(name))]
+      | [Expr::Array(array), callback, _, Expr::Lit(Lit::Str(name))] => {
+        (array, callback, Some(name))
+      }
+      [Expr::Array(array), callback] | [Expr::Array(array), callback, _] => (array, callback, None),
+      _ => return,
+    };
+    let group_options = chunk_name
+      .map(|name| ChunkGroupOptions::with_name(&*name.value))
+      .unwrap_or_default();
+
+    if let Some(param) = factory_param(callback, 0) {
+      self.require_ctxts.push(param.span.ctxt);
+    }
+    match callback {
+      Expr::Fn(fn_expr) => self
+        .require_ensure_callbacks
+        .push((fn_expr.function.span, group_options.clone())),
+      Expr::Arrow(arrow_expr) => self
+        .require_ensure_callbacks
+        .push((arrow_expr.span, group_options.clone())),
+      _ => {}
+    }
+    for item in array.elems.iter().flatten() {
+      if let ExprOrSpread {
+        spread: None,
+        expr: box Expr::Lit(Lit::Str(request)),
+      } = item
+      {
+        self.add_dependency(box RequireEnsureItemDependency::new(
+          request.value.clone(),
+          Some(request.span.into()),
+          as_parent_path(ast_path),
+          group_options.clone(),
+        ));
+      }
+    }
+    self.add_presentational_dependency(box RequireEnsureDependency::new(
+      call_expr.span.into(),
+      as_parent_path(ast_path),
+    ));
+  }
+
+  fn find_require_ensure_callback(&self, span: Span) -> Option<ChunkGroupOptions> {
+    self
+      .require_ensure_callbacks
+      .iter()
+      .find(|(callback_span, _)| *callback_span == span)
+      .map(|(_, group_options)| group_options.clone())
+  }
+
+  /// Matches `require.<prop>`
+  fn is_require_member(&self, expr: &Expr, prop: &str) -> bool {
+    matches!(
+      expr,
+      Expr::Member(MemberExpr {
+        obj: box Expr::Ident(obj),
+        prop: MemberProp::Ident(Ident { sym, .. }),
+        ..
+      }) if self.is_require_ident(obj) && sym == prop
+    )
+  }
+
   fn add_dynamic_import(&mut self, node: &CallExpr, ast_path: &AstNodePath<AstParentNodeRef<'_>>) {
     if let Callee::Import(_) = node.callee {
       if let Some(dyn_imported) = node.args.get(0) {
         if dyn_imported.spread.is_none() {
+          // import(/* webpackChunkName: "foo" */ "./foo")
+          let magic_comments = self
+            .comments
+            .and_then(|comments| comments.get_leading(dyn_imported.expr.span().lo))
+            .map(|comments| ImportMagicComments::from_comments(&comments))
+            .unwrap_or_default();
           if let Expr::Lit(Lit::Str(imported)) = dyn_imported.expr.as_ref() {
             self.add_dependency(box EsmDynamicImportDependency::new(
               imported.value.clone(),
               Some(node.span.into()),
               as_parent_path(ast_path),
+              magic_comments.mode.clone().unwrap_or(ContextMode::Lazy),
+              magic_comments.group_options(),
             ));
           }
           if let Some((context, reg)) = scanner_context_module(dyn_imported.expr.as_ref()) {
+            let mode = match &magic_comments.mode {
+              // Modules of the context are still loaded asynchronously
+              Some(ContextMode::Weak) => ContextMode::AsyncWeak,
+              Some(mode) => mode.clone(),
+              None => ContextMode::Lazy,
+            };
             self.add_dependency(box ImportContextDependency::new(
               ContextOptions {
-                mode: ContextMode::Lazy,
+                mode,
                 recursive: true,
                 reg_exp: RspackRegex::new(&reg).expect("reg failed"),
                 reg_str: reg,
@@ -119,6 +308,7 @@ impl DependencyScanner<'_> {
                 exclude: None,
                 category: DependencyCategory::Esm,
                 request: context,
+                group_options: Some(magic_comments.group_options()),
               },
               Some(node.span.into()),
               as_parent_path(ast_path),
@@ -131,47 +321,141 @@ impl DependencyScanner<'_> {
 
   // new URL("./foo.png", import.meta.url);
   fn add_new_url(&mut self, new_expr: &NewExpr, ast_path: &AstNodePath<AstParentNodeRef<'_>>) {
-    if let Expr::Ident(Ident {
-      sym: js_word!("URL"),
-      ..
-    }) = &*new_expr.callee
+    // The url of a worker is bundled as a worker chunk instead
+    if self.worker_url_span == Some(new_expr.span) {
+      return;
+    }
+    if let Some(path) = match_new_url(new_expr) {
+      self.add_dependency(box URLDependency::new(
+        path.value.clone(),
+        Some(new_expr.span.into()),
+        as_parent_path(ast_path),
+      ))
+    }
+  }
+
+  // new Worker(new URL("./foo.js", import.meta.url));
+  fn add_new_worker(&mut self, new_expr: &NewExpr, ast_path: &AstNodePath<AstParentNodeRef<'_>>) {
+    let Expr::Ident(callee) = &*new_expr.callee else { return };
+    if !matches!(&*callee.sym, "Worker" | "SharedWorker")
+      || callee.span.ctxt != self.unresolved_ctxt
     {
-      if let Some(args) = &new_expr.args {
-        if let (Some(first), Some(second)) = (args.first(), args.get(1)) {
-          if let (
-            ExprOrSpread {
-              spread: None,
-              expr: box Expr::Lit(Lit::Str(path)),
-            },
-            // import.meta.url
-            ExprOrSpread {
-              spread: None,
-              expr:
-                box Expr::Member(MemberExpr {
-                  obj:
-                    box Expr::MetaProp(MetaPropExpr {
-                      kind: MetaPropKind::ImportMeta,
-                      ..
-                    }),
-                  prop:
-                    MemberProp::Ident(Ident {
-                      sym: js_word!("url"),
-                      ..
-                    }),
-                  ..
-                }),
-            },
-          ) = (first, second)
-          {
-            self.add_dependency(box URLDependency::new(
-              path.value.clone(),
-              Some(new_expr.span.into()),
-              as_parent_path(ast_path),
-            ))
-          }
-        }
+      return;
+    }
+    if let Some(ExprOrSpread {
+      spread: None,
+      expr: box Expr::New(url),
+    }) = new_expr.args.as_ref().and_then(|args| args.first())
+      && let Some(path) = match_new_url(url)
+    {
+      // new Worker(/* webpackChunkName: "foo" */ new URL("./foo.js", import.meta.url))
+      let magic_comments = self
+        .comments
+        .and_then(|comments| comments.get_leading(url.span.lo))
+        .map(|comments| ImportMagicComments::from_comments(&comments))
+        .unwrap_or_default();
+      self.worker_url_span = Some(url.span);
+      self.add_dependency(box WorkerDependency::new(
+        path.value.clone(),
+        Some(new_expr.span.into()),
+        as_parent_path(ast_path),
+        magic_comments.group_options(),
+      ));
+    }
+  }
+
+  // define("name", ["./foo"], function (foo) {});
+  // require(["./foo"], function (foo) {});
+  fn add_amd(&mut self, expr: &Expr, ast_path: &AstNodePath<AstParentNodeRef<'_>>) {
+    let Expr::Call(CallExpr {
+      callee: Callee::Expr(box Expr::Ident(callee)),
+      args,
+      ..
+    }) = expr else {
+      return;
+    };
+    if args.iter().any(|arg| arg.spread.is_some()) {
+      return;
+    }
+    let args = args.iter().map(|arg| &*arg.expr).collect::<Vec<_>>();
+
+    if "define".eq(&callee.sym) && callee.span.ctxt == self.unresolved_ctxt {
+      let args = match args.as_slice() {
+        [Expr::Lit(Lit::Str(_)), rest @ ..] => rest,
+        rest => rest,
+      };
+      let (array, factory) = match args {
+        [Expr::Array(array), factory] => (Some(array), factory),
+        [factory] => (None, factory),
+        _ => return,
+      };
+      // Without the array, the factory is called with `require, exports, module`
+      let require_index = match array {
+        Some(array) => array
+          .elems
+          .iter()
+          .position(|item| matches!(item, Some(item) if is_amd_request(&item.expr, "require"))),
+        None => Some(0),
+      };
+      if let Some(param) = require_index.and_then(|index| factory_param(factory, index)) {
+        self.require_ctxts.push(param.span.ctxt);
       }
+      if let Some(array) = array {
+        self.amd_arrays.push((array.span, None));
+      }
+      self.add_presentational_dependency(box AMDDefineDependency::new(as_parent_path(ast_path)));
+    } else if self.is_require_ident(callee)
+      && let [Expr::Array(array), rest @ ..] = args.as_slice()
+      && rest.len() <= 2
+    {
+      self
+        .amd_arrays
+        .push((array.span, Some(ChunkGroupOptions::default())));
+      self.add_presentational_dependency(box AMDRequireArrayDependency::new(as_parent_path(
+        ast_path,
+      )));
+    }
+  }
+
+  fn add_amd_require_item(&mut self, expr: &Expr, ast_path: &AstNodePath<AstParentNodeRef<'_>>) {
+    let Some((array_path_len, group_options)) = &self.amd_array else {
+      return;
+    };
+    // ArrayLit -> ExprOrSpread -> Expr
+    if ast_path.len() != array_path_len + 2 {
+      return;
     }
+    let Expr::Lit(Lit::Str(request)) = expr else {
+      return;
+    };
+    let dependency: Box<dyn Dependency> = match &*request.value {
+      "require" => box ConstDependency::new(
+        Expr::Ident(quote_ident!(RuntimeGlobals::REQUIRE)),
+        Some(RuntimeGlobals::REQUIRE),
+        as_parent_path(ast_path),
+      ),
+      "exports" => box ConstDependency::new(
+        Expr::Ident(quote_ident!("exports")),
+        None,
+        as_parent_path(ast_path),
+      ),
+      "module" => box ConstDependency::new(
+        Expr::Ident(quote_ident!("module")),
+        Some(RuntimeGlobals::MODULE),
+        as_parent_path(ast_path),
+      ),
+      _ => {
+        let dependency = box AMDRequireItemDependency::new(
+          request.value.clone(),
+          Some(request.span.into()),
+          as_parent_path(ast_path),
+          group_options.clone(),
+        );
+        self.add_dependency(dependency);
+        return;
+      }
+    };
+    self.add_presentational_dependency(dependency);
   }
 
   fn add_export(
@@ -204,6 +488,7 @@ impl DependencyScanner<'_> {
     Ok(())
   }
 
+  // require.context("./dir", true, /\.js$/, "sync");
   fn scan_require_context(
     &mut self,
     node: &CallExpr,
@@ -220,10 +505,10 @@ impl DependencyScanner<'_> {
 
         let (reg_exp, reg_str) =
           if let Some(Lit::Regex(regex)) = node.args.get(2).and_then(|x| x.expr.as_lit()) {
-            (
-              RspackRegex::try_from(regex).expect("reg failed"),
-              format!("{}|{}", regex.exp, regex.flags),
-            )
+            let Some(reg_exp) = self.parse_context_reg_exp(regex) else {
+              return;
+            };
+            (reg_exp, format!("{}|{}", regex.exp, regex.flags))
           } else {
             (
               RspackRegex::new(r"^\.\/.*$").expect("reg failed"),
@@ -232,15 +517,10 @@ impl DependencyScanner<'_> {
           };
 
         let mode = if let Some(Lit::Str(str)) = node.args.get(3).and_then(|x| x.expr.as_lit()) {
-          match str.value.to_string().as_str() {
-            "sync" => ContextMode::Sync,
-            "eager" => ContextMode::Eager,
-            "weak" => ContextMode::Weak,
-            "lazy" => ContextMode::Lazy,
-            "lazy-once" => ContextMode::LazyOnce,
-            // TODO should give warning
-            _ => unreachable!("unknown context mode"),
-          }
+          let Some(mode) = self.parse_context_mode(str) else {
+            return;
+          };
+          mode
         } else {
           ContextMode::Sync
         };
@@ -254,6 +534,7 @@ impl DependencyScanner<'_> {
             exclude: None,
             category: DependencyCategory::CommonJS,
             request: str.value.to_string(),
+            group_options: None,
           },
           Some(node.span.into()),
           as_parent_path(ast_path),
@@ -261,6 +542,152 @@ impl DependencyScanner<'_> {
       }
     }
   }
+
+  // import.meta.webpackContext("./dir", { recursive: true, regExp: /\.js$/, mode: "sync" });
+  fn scan_import_meta_context(
+    &mut self,
+    node: &CallExpr,
+    ast_path: &AstNodePath<AstParentNodeRef<'_>>,
+  ) {
+    if !is_import_meta_context_call(node) {
+      return;
+    }
+    let Some(Lit::Str(request)) = node.args.get(0).and_then(|x| x.expr.as_lit()) else {
+      return;
+    };
+    let mut recursive = true;
+    let mut reg_exp = None;
+    let mut include = None;
+    let mut exclude = None;
+    let mut mode = ContextMode::Sync;
+    let mut chunk_name = None;
+    if let Some(options) = node.args.get(1) {
+      let Expr::Object(options) = options.expr.as_ref() else {
+        self.add_warning(
+          options.span(),
+          "import.meta.webpackContext",
+          "The options of import.meta.webpackContext should be an object literal".to_string(),
+        );
+        return;
+      };
+      for prop in &options.props {
+        let Some(KeyValueProp { key, value }) = prop.as_prop().and_then(|prop| prop.as_key_value())
+        else {
+          continue;
+        };
+        let key = match key {
+          PropName::Ident(ident) => &ident.sym,
+          PropName::Str(str) => &str.value,
+          _ => continue,
+        };
+        let valid = match (&**key, value.as_lit()) {
+          ("recursive", Some(Lit::Bool(bool))) => {
+            recursive = bool.value;
+            true
+          }
+          ("regExp", Some(Lit::Regex(regex))) => {
+            let Some(compiled) = self.parse_context_reg_exp(regex) else {
+              return;
+            };
+            reg_exp = Some((compiled, format!("{}|{}", regex.exp, regex.flags)));
+            true
+          }
+          ("include", Some(Lit::Regex(regex))) => {
+            let Some(regex) = self.parse_context_reg_exp(regex) else {
+              return;
+            };
+            include = Some(regex);
+            true
+          }
+          ("exclude", Some(Lit::Regex(regex))) => {
+            let Some(regex) = self.parse_context_reg_exp(regex) else {
+              return;
+            };
+            exclude = Some(regex);
+            true
+          }
+          ("mode", Some(Lit::Str(str))) => {
+            let Some(context_mode) = self.parse_context_mode(str) else {
+              return;
+            };
+            mode = context_mode;
+            true
+          }
+          ("chunkName", Some(Lit::Str(str))) => {
+            chunk_name = Some(str.value.to_string());
+            true
+          }
+          ("recursive" | "regExp" | "include" | "exclude" | "mode" | "chunkName", _) => false,
+          // Unsupported options are ignored
+          _ => true,
+        };
+        if !valid {
+          self.add_warning(
+            value.span(),
+            "import.meta.webpackContext",
+            format!("The `{key}` option of import.meta.webpackContext should be a literal of the expected type"),
+          );
+          return;
+        }
+      }
+    }
+
+    let (reg_exp, reg_str) = reg_exp.unwrap_or_else(|| {
+      (
+        RspackRegex::new(r"^\.\/.*$").expect("reg failed"),
+        r"^\.\/.*$".to_string(),
+      )
+    });
+    self.add_dependency(box ImportMetaContextDependency::new(
+      ContextOptions {
+        mode,
+        recursive,
+        reg_exp,
+        reg_str,
+        include,
+        exclude,
+        category: DependencyCategory::Esm,
+        request: request.value.to_string(),
+        group_options: chunk_name.map(ChunkGroupOptions::with_name),
+      },
+      Some(node.span.into()),
+      as_parent_path(ast_path),
+    ));
+  }
+
+  /// Parses the mode of a context, a warning is added for an invalid mode
+  fn parse_context_mode(&mut self, mode: &Str) -> Option<ContextMode> {
+    match &*mode.value {
+      "sync" => Some(ContextMode::Sync),
+      "eager" => Some(ContextMode::Eager),
+      "weak" => Some(ContextMode::Weak),
+      "async-weak" => Some(ContextMode::AsyncWeak),
+      "lazy" => Some(ContextMode::Lazy),
+      "lazy-once" => Some(ContextMode::LazyOnce),
+      _ => {
+        self.add_warning(
+          mode.span,
+          "Invalid context mode",
+          format!(
+            "Unknown context mode \"{}\", expected one of \"sync\", \"eager\", \"weak\", \"async-weak\", \"lazy\" and \"lazy-once\"",
+            mode.value
+          ),
+        );
+        None
+      }
+    }
+  }
+
+  /// Compiles the RegExp of a context, a warning is added if it's not supported
+  fn parse_context_reg_exp(&mut self, regex: &Regex) -> Option<RspackRegex> {
+    match RspackRegex::try_from(regex) {
+      Ok(reg_exp) => Some(reg_exp),
+      Err(err) => {
+        self.add_warning(regex.span, "Invalid context RegExp", err.to_string());
+        None
+      }
+    }
+  }
 }
 
 impl VisitAstPath for DependencyScanner<'_> {
@@ -285,7 +712,33 @@ impl VisitAstPath for DependencyScanner<'_> {
     self.add_dynamic_import(node, &*ast_path);
     self.add_require(node, &*ast_path);
     self.scan_require_context(node, &*ast_path);
+    self.scan_import_meta_context(node, &*ast_path);
+    self.add_require_ensure(node, &*ast_path);
+    node.visit_children_with_path(self, ast_path);
+  }
+
+  fn visit_function<'ast: 'r, 'r>(
+    &mut self,
+    node: &'ast Function,
+    ast_path: &mut AstNodePath<AstParentNodeRef<'r>>,
+  ) {
+    // Only the body of the `require.ensure` callback loads modules with its chunk,
+    // other functions in it may be called anywhere
+    let require_ensure = self.find_require_ensure_callback(node.span);
+    let prev = std::mem::replace(&mut self.require_ensure, require_ensure);
     node.visit_children_with_path(self, ast_path);
+    self.require_ensure = prev;
+  }
+
+  fn visit_arrow_expr<'ast: 'r, 'r>(
+    &mut self,
+    node: &'ast ArrowExpr,
+    ast_path: &mut AstNodePath<AstParentNodeRef<'r>>,
+  ) {
+    let require_ensure = self.find_require_ensure_callback(node.span);
+    let prev = std::mem::replace(&mut self.require_ensure, require_ensure);
+    node.visit_children_with_path(self, ast_path);
+    self.require_ensure = prev;
   }
 
   fn visit_new_expr<'ast: 'r, 'r>(
@@ -293,15 +746,36 @@ impl VisitAstPath for DependencyScanner<'_> {
     node: &'ast NewExpr,
     ast_path: &mut AstNodePath<AstParentNodeRef<'r>>,
   ) {
+    self.add_new_worker(node, &*ast_path);
     self.add_new_url(node, &*ast_path);
     node.visit_children_with_path(self, ast_path);
   }
 
+  fn visit_array_lit<'ast: 'r, 'r>(
+    &mut self,
+    node: &'ast ArrayLit,
+    ast_path: &mut AstNodePath<AstParentNodeRef<'r>>,
+  ) {
+    let amd_array = self
+      .amd_arrays
+      .iter()
+      .find(|(span, _)| *span == node.span)
+      .map(|(_, group_options)| (ast_path.len(), group_options.clone()));
+    // Nested arrays are not AMD arrays
+    let prev = std::mem::replace(&mut self.amd_array, amd_array);
+    node.visit_children_with_path(self, ast_path);
+    self.amd_array = prev;
+  }
+
   fn visit_expr<'ast: 'r, 'r>(
     &mut self,
     expr: &'ast Expr,
     ast_path: &mut AstNodePath<AstParentNodeRef<'r>>,
   ) {
+    self.add_amd(expr, &*ast_path);
+    self.add_amd_require_item(expr, &*ast_path);
+    self.add_require_resolve(expr, &*ast_path);
+
     if let Expr::Assign(AssignExpr {
       op: AssignOp::Assign,
       left: PatOrExpr::Pat(box Pat::Ident(ident)),
@@ -445,21 +919,90 @@ impl VisitAstPath for DependencyScanner<'_> {
 impl<'a> DependencyScanner<'a> {
   pub fn new(
     unresolved_mark: Mark,
+    comments: Option<&'a SwcComments>,
     resource_data: &'a ResourceData,
     compiler_options: &'a CompilerOptions,
+    source_map: &'a SourceMap,
     dependencies: &'a mut Vec<Box<dyn ModuleDependency>>,
     presentational_dependencies: &'a mut Vec<Box<dyn Dependency>>,
+    diagnostics: &'a mut Vec<Diagnostic>,
   ) -> Self {
     Self {
       unresolved_ctxt: SyntaxContext::empty().apply_mark(unresolved_mark),
+      comments,
       dependencies,
       presentational_dependencies,
+      diagnostics,
+      source_map,
       compiler_options,
       resource_data,
+      worker_url_span: None,
+      amd_arrays: Vec::new(),
+      amd_array: None,
+      require_ctxts: Vec::new(),
+      require_ensure_callbacks: Vec::new(),
+      require_ensure: None,
     }
   }
 }
 
+/// Matches `new URL("./foo", import.meta.url)` and returns the request
+fn match_new_url(new_expr: &NewExpr) -> Option<&Str> {
+  let Expr::Ident(Ident {
+    sym: js_word!("URL"),
+    ..
+  }) = &*new_expr.callee else {
+    return None;
+  };
+  let args = new_expr.args.as_ref()?;
+  if let (
+    Some(ExprOrSpread {
+      spread: None,
+      expr: box Expr::Lit(Lit::Str(path)),
+    }),
+    // import.meta.url
+    Some(ExprOrSpread {
+      spread: None,
+      expr:
+        box Expr::Member(MemberExpr {
+          obj:
+            box Expr::MetaProp(MetaPropExpr {
+              kind: MetaPropKind::ImportMeta,
+              ..
+            }),
+          prop:
+            MemberProp::Ident(Ident {
+              sym: js_word!("url"),
+              ..
+            }),
+          ..
+        }),
+    }),
+  ) = (args.first(), args.get(1))
+  {
+    Some(path)
+  } else {
+    None
+  }
+}
+
+fn is_amd_request(expr: &Expr, request: &str) -> bool {
+  matches!(expr, Expr::Lit(Lit::Str(str)) if str.value == *request)
+}
+
+/// Returns the `index`th parameter of `function (a, b) {}` or `(a, b) => {}`
+fn factory_param(factory: &Expr, index: usize) -> Option<&Ident> {
+  let pat = match factory {
+    Expr::Fn(fn_expr) => &fn_expr.function.params.get(index)?.pat,
+    Expr::Arrow(arrow_expr) => arrow_expr.params.get(index)?,
+    _ => return None,
+  };
+  match pat {
+    Pat::Ident(BindingIdent { id, .. }) => Some(id),
+    _ => None,
+  }
+}
+
 #[inline]
 fn split_context_from_prefix(prefix: &str) -> (&str, &str) {
   if let Some(idx) = prefix.rfind('/') {
@@ -83,6 +83,10 @@ pub fn is_import_meta_hot_decline_call(node: &CallExpr) -> bool {
   is_hmr_import_meta_api_call(node, "import.meta.webpackHot.decline")
 }
 
+pub fn is_import_meta_context_call(node: &CallExpr) -> bool {
+  is_hmr_import_meta_api_call(node, "import.meta.webpackContext")
+}
+
 #[test]
 fn test() {
   use swc_core::common::DUMMY_SP;
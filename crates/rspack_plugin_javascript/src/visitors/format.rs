@@ -55,7 +55,12 @@ impl<'a> Fold for RspackModuleFinalizer<'a> {
               .module_graph_module_by_dependency_id(id);
             if let (Some(dependency), Some(module)) = (dependency, module) {
               if DependencyCategory::Esm.eq(dependency.category()) {
-                return Some(module.id(&self.compilation.chunk_graph).to_string());
+                // Weakly imported modules may not have an id
+                return self
+                  .compilation
+                  .chunk_graph
+                  .get_module_id(module.module_identifier)
+                  .clone();
               }
             }
             None
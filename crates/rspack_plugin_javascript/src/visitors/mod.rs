@@ -24,7 +24,9 @@ pub mod relay;
 mod swc_visitor;
 mod tree_shaking;
 use rspack_core::{
-  ast::javascript::Ast, BuildMeta, CompilerOptions, GenerateContext, ResourceData,
+  ast::javascript::{Ast, Program},
+  BuildMeta, CodeGeneratableJavaScriptVisitors, Compilation, CompilerOptions, GenerateContext,
+  ResourceData,
 };
 use rspack_error::{Error, Result};
 use swc_core::base::config::ModuleConfig;
@@ -196,6 +198,7 @@ pub fn run_after_pass(
         .module_graph_module_by_identifier(&module.identifier())
         .expect("should have module graph module");
       let need_tree_shaking = mgm.used;
+      let shake_commonjs_exports = is_commonjs_exports_shakable(module, compilation);
       let build_meta = mgm.build_meta.as_ref().expect("should have build meta");
       let DependencyCodeGenerationVisitors {
         visitors,
@@ -203,23 +206,7 @@ pub fn run_after_pass(
         decl_mappings,
       } = dependency_visitors;
 
-      {
-        if !visitors.is_empty() {
-          program.visit_mut_with_path(
-            &mut DependencyVisitor::new(
-              visitors
-                .iter()
-                .map(|(ast_path, visitor)| (ast_path, &**visitor))
-                .collect(),
-            ),
-            &mut Default::default(),
-          );
-        }
-
-        for (_, root_visitor) in root_visitors {
-          program.visit_mut_with(&mut root_visitor.create());
-        }
-      }
+      apply_dependency_visitors(program, &visitors, &root_visitors);
 
       let mut promises = LinkedList::new();
       if build_meta.is_async {
@@ -241,7 +228,9 @@ pub fn run_after_pass(
             top_level_mark,
             &compilation.side_effects_free_modules,
             &compilation.module_item_map,
-            context.helpers.mark()
+            context.helpers.mark(),
+            unresolved_mark,
+            shake_commonjs_exports,
           ),
           builtin_tree_shaking && need_tree_shaking
         ),
@@ -288,3 +277,117 @@ pub fn run_after_pass(
     })
     .map_err(Error::from)
 }
+
+fn apply_dependency_visitors(
+  program: &mut Program,
+  visitors: &CodeGeneratableJavaScriptVisitors,
+  root_visitors: &CodeGeneratableJavaScriptVisitors,
+) {
+  if !visitors.is_empty() {
+    program.visit_mut_with_path(
+      &mut DependencyVisitor::new(
+        visitors
+          .iter()
+          .map(|(ast_path, visitor)| (ast_path, &**visitor))
+          .collect(),
+      ),
+      &mut Default::default(),
+    );
+  }
+
+  for (_, root_visitor) in root_visitors {
+    program.visit_mut_with(&mut root_visitor.create());
+  }
+}
+
+/// Unused exports of a CommonJS module are removed only if all of its exports are statically known
+fn is_commonjs_exports_shakable(module: &dyn Module, compilation: &Compilation) -> bool {
+  let module_graph = &compilation.module_graph;
+  let is_esm = module_graph
+    .module_graph_module_by_identifier(&module.identifier())
+    .and_then(|mgm| mgm.build_meta.as_ref())
+    .map_or(false, |build_meta| build_meta.esm);
+  !is_esm
+    && module_graph
+      .get_exports_info(&module.identifier())
+      .map_or(false, |exports_info| {
+        exports_info.other_exports_info().provided == Some(false)
+      })
+}
+
+/// Same as [run_after_pass] but keeps import and export declarations,
+/// which are linked to other modules when the module is concatenated into the scope of another module.
+pub fn run_before_concatenation_pass(
+  ast: &mut Ast,
+  module: &dyn Module,
+  generate_context: &mut GenerateContext,
+) -> Result<()> {
+  let cm = ast.get_context().source_map.clone();
+
+  ast
+    .transform_with_handler(cm, |_, program, context| {
+      let unresolved_mark = context.unresolved_mark;
+      let top_level_mark = context.top_level_mark;
+      let compilation = generate_context.compilation;
+      let builtin_tree_shaking = compilation.options.builtins.tree_shaking;
+      let minify_options = &compilation.options.builtins.minify_options;
+      let DependencyCodeGenerationVisitors {
+        visitors,
+        root_visitors,
+        decl_mappings,
+      } = collect_dependency_code_generation_visitors(module, generate_context)?;
+      let need_tree_shaking = compilation
+        .module_graph
+        .module_graph_module_by_identifier(&module.identifier())
+        .map(|mgm| mgm.used)
+        .unwrap_or_default();
+      let shake_commonjs_exports = is_commonjs_exports_shakable(module, compilation);
+
+      apply_dependency_visitors(program, &visitors, &root_visitors);
+
+      let mut pass = chain!(
+        Optional::new(
+          tree_shaking_visitor(
+            &decl_mappings,
+            &compilation.module_graph,
+            module.identifier(),
+            &compilation.used_symbol_ref,
+            top_level_mark,
+            &compilation.side_effects_free_modules,
+            &compilation.module_item_map,
+            context.helpers.mark(),
+            unresolved_mark,
+            shake_commonjs_exports,
+          ),
+          builtin_tree_shaking && need_tree_shaking
+        ),
+        Optional::new(
+          Repeat::new(dce(Config::default(), unresolved_mark)),
+          need_tree_shaking && builtin_tree_shaking && minify_options.is_none()
+        ),
+        Optional::new(
+          dce(Config::default(), unresolved_mark),
+          need_tree_shaking && builtin_tree_shaking && minify_options.is_some()
+        ),
+        inject_runtime_helper(unresolved_mark, generate_context.runtime_requirements),
+      );
+
+      program.fold_with(&mut pass);
+
+      Ok(())
+    })
+    .map_err(Error::from)
+}
+
+/// Finalizes a module concatenated into the scope of another module, whose import and export
+/// declarations are already linked.
+pub fn run_after_concatenation_pass(ast: &mut Ast, module: &dyn Module, compilation: &Compilation) {
+  ast.transform(|program, context| {
+    let mut pass = chain!(
+      finalize(module, compilation, context.unresolved_mark),
+      swc_visitor::hygiene(false, context.top_level_mark),
+      swc_visitor::fixer(None),
+    );
+    program.fold_with(&mut pass);
+  });
+}
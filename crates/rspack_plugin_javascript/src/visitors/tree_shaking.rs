@@ -1,3 +1,7 @@
+use rspack_core::tree_shaking::utils::{
+  create_commonjs_export_symbol, get_commonjs_export, get_commonjs_export_member_name,
+  CommonJsExport,
+};
 use rspack_core::tree_shaking::visitor::SymbolRef;
 use rspack_core::{
   CodeGeneratableDeclMappings, DependencyCategory, DependencyType, ModuleGraph, ModuleIdentifier,
@@ -5,7 +9,7 @@ use rspack_core::{
 use rspack_identifier::{Identifier, IdentifierMap, IdentifierSet};
 use rspack_symbol::{BetterId, IndirectTopLevelSymbol, Symbol, SymbolType};
 use rustc_hash::FxHashSet as HashSet;
-use swc_core::common::{Mark, DUMMY_SP, GLOBALS};
+use swc_core::common::{Mark, SyntaxContext, DUMMY_SP, GLOBALS};
 use swc_core::ecma::ast::*;
 use swc_core::ecma::atoms::JsWord;
 use swc_core::ecma::utils::quote_ident;
@@ -20,6 +24,8 @@ pub fn tree_shaking_visitor<'a>(
   side_effects_free_modules: &'a IdentifierSet,
   module_item_map: &'a IdentifierMap<Vec<ModuleItem>>,
   helper_mark: Mark,
+  unresolved_mark: Mark,
+  shake_commonjs_exports: bool,
 ) -> impl Fold + 'a {
   TreeShaker {
     module_graph,
@@ -33,6 +39,8 @@ pub fn tree_shaking_visitor<'a>(
     last_module_item_index: 0,
     module_item_map,
     helper_mark,
+    unresolved_ctxt: SyntaxContext::empty().apply_mark(unresolved_mark),
+    shake_commonjs_exports,
   }
 }
 
@@ -47,6 +55,15 @@ pub fn tree_shaking_visitor<'a>(
 /// function test() {}
 /// ```
 /// if function `test` is also unused in local module, then it will be removed in DCE phase of `swc`
+///
+/// For a CommonJS module whose exports are all analyzed, the unused export is dropped and only the exported value is kept. e.g.
+/// ```js
+/// exports.test = function () {}
+/// ```
+/// become:
+/// ```js
+/// (function () {})
+/// ```
 struct TreeShaker<'a> {
   module_graph: &'a ModuleGraph,
   decl_mappings: &'a CodeGeneratableDeclMappings,
@@ -60,6 +77,9 @@ struct TreeShaker<'a> {
   last_module_item_index: usize,
   module_item_map: &'a IdentifierMap<Vec<ModuleItem>>,
   helper_mark: Mark,
+  unresolved_ctxt: SyntaxContext,
+  /// `true` if the module is a CommonJS module whose exports are all analyzed
+  shake_commonjs_exports: bool,
 }
 
 impl<'a> Fold for TreeShaker<'a> {
@@ -113,6 +133,7 @@ impl<'a> Fold for TreeShaker<'a> {
         | ModuleDecl::TsExportAssignment(_)
         | ModuleDecl::TsNamespaceExport(_) => ModuleItem::ModuleDecl(module_decl),
       },
+      ModuleItem::Stmt(_) if self.shake_commonjs_exports => self.custom_fold_commonjs_export(node),
       ModuleItem::Stmt(_) => node,
     }
   }
@@ -129,6 +150,17 @@ impl<'a> TreeShaker<'a> {
     )
   }
 
+  fn is_commonjs_export_used(&self, name: &JsWord) -> bool {
+    // `__esModule` is used by the interop of ESM importers at runtime
+    name == "__esModule"
+      || self
+        .used_symbol_set
+        .contains(&SymbolRef::Direct(create_commonjs_export_symbol(
+          self.module_identifier,
+          name.clone(),
+        )))
+  }
+
   fn create_empty_stmt_module_item() -> ModuleItem {
     ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span: DUMMY_SP }))
   }
@@ -504,4 +536,94 @@ impl<'a> TreeShaker<'a> {
       }))))
     }
   }
+
+  fn custom_fold_commonjs_export(&mut self, node: ModuleItem) -> ModuleItem {
+    let (is_all_used, is_object_export) = match get_commonjs_export(&node, self.unresolved_ctxt) {
+      Some(commonjs_export) => (
+        commonjs_export
+          .names()
+          .iter()
+          .all(|name| self.is_commonjs_export_used(name)),
+        matches!(commonjs_export, CommonJsExport::Object(_)),
+      ),
+      None => return node,
+    };
+    if is_all_used {
+      return node;
+    }
+    let ModuleItem::Stmt(Stmt::Expr(ExprStmt { span, expr })) = node else {
+      unreachable!("CommonJS export should be an expression statement")
+    };
+    let expr = match *expr {
+      // `module.exports = { a, b: value }`
+      Expr::Assign(mut assign) if is_object_export => {
+        if let Expr::Object(object) = &mut *assign.right {
+          object.props.retain(|prop| match prop {
+            PropOrSpread::Prop(box Prop::Shorthand(ident)) => {
+              self.is_commonjs_export_used(&ident.sym)
+            }
+            PropOrSpread::Prop(box Prop::Method(MethodProp { key, .. })) => {
+              get_prop_name(key).map_or(true, |name| self.is_commonjs_export_used(&name))
+            }
+            PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp { key, value })) => {
+              // Removing a value which may have side effects changes the semantic
+              !is_side_effect_free_export_value(value)
+                || get_prop_name(key).map_or(true, |name| self.is_commonjs_export_used(&name))
+            }
+            _ => true,
+          });
+        }
+        Box::new(Expr::Assign(assign))
+      }
+      // `exports.a = exports.b = value`
+      expr @ Expr::Assign(_) => self.fold_commonjs_export_assign(Box::new(expr)),
+      // `Object.defineProperty(exports, "a", descriptor)`, only keep the descriptor
+      Expr::Call(mut call) => call.args.pop().expect("should have descriptor").expr,
+      _ => unreachable!("CommonJS export should be an assignment or a call expression"),
+    };
+    ModuleItem::Stmt(Stmt::Expr(ExprStmt { span, expr }))
+  }
+
+  /// Drop the unused part of `exports.a = exports.b = value`
+  fn fold_commonjs_export_assign(&self, expr: Box<Expr>) -> Box<Expr> {
+    match *expr {
+      Expr::Assign(assign) => {
+        let name = match &assign.left {
+          PatOrExpr::Expr(box left) | PatOrExpr::Pat(box Pat::Expr(box left))
+            if assign.op == op!("=") =>
+          {
+            get_commonjs_export_member_name(left, self.unresolved_ctxt)
+          }
+          _ => None,
+        };
+        match name {
+          Some(name) => {
+            let right = self.fold_commonjs_export_assign(assign.right);
+            if self.is_commonjs_export_used(&name) {
+              Box::new(Expr::Assign(AssignExpr { right, ..assign }))
+            } else {
+              right
+            }
+          }
+          None => Box::new(Expr::Assign(assign)),
+        }
+      }
+      expr => Box::new(expr),
+    }
+  }
+}
+
+fn get_prop_name(prop_name: &PropName) -> Option<JsWord> {
+  match prop_name {
+    PropName::Ident(ident) => Some(ident.sym.clone()),
+    PropName::Str(str) => Some(str.value.clone()),
+    PropName::Num(_) | PropName::Computed(_) | PropName::BigInt(_) => None,
+  }
+}
+
+fn is_side_effect_free_export_value(expr: &Expr) -> bool {
+  matches!(
+    expr,
+    Expr::Fn(_) | Expr::Arrow(_) | Expr::Ident(_) | Expr::Lit(_)
+  )
 }
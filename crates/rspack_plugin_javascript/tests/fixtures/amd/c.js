@@ -0,0 +1,3 @@
+define("c", [], function () {
+  return "c";
+});
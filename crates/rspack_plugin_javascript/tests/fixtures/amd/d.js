@@ -0,0 +1 @@
+define(["./a"], (a) => ({ name: "d" + a.name }));
@@ -0,0 +1,15 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["c_js"], {
+"./c.js": function (module, exports, __webpack_require__) {
+(function() {
+    var __WEBPACK_AMD_DEFINE_ARRAY__ = [];
+    var __WEBPACK_AMD_DEFINE_FACTORY__ = function() {
+        return "c";
+    };
+    var __WEBPACK_AMD_DEFINE_RESULT__ = typeof __WEBPACK_AMD_DEFINE_FACTORY__ === 'function' ? __WEBPACK_AMD_DEFINE_FACTORY__.apply(exports, __WEBPACK_AMD_DEFINE_ARRAY__) : __WEBPACK_AMD_DEFINE_FACTORY__;
+    if (__WEBPACK_AMD_DEFINE_RESULT__ !== undefined) {
+        module.exports = __WEBPACK_AMD_DEFINE_RESULT__;
+    }
+})();
+},
+
+}]);
\ No newline at end of file
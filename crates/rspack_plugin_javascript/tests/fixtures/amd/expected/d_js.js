@@ -0,0 +1,17 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["d_js"], {
+"./d.js": function (module, exports, __webpack_require__) {
+(function() {
+    var __WEBPACK_AMD_DEFINE_ARRAY__ = [
+        __webpack_require__("./a.js")
+    ];
+    var __WEBPACK_AMD_DEFINE_FACTORY__ = (a)=>({
+            name: "d" + a.name
+        });
+    var __WEBPACK_AMD_DEFINE_RESULT__ = typeof __WEBPACK_AMD_DEFINE_FACTORY__ === 'function' ? __WEBPACK_AMD_DEFINE_FACTORY__.apply(exports, __WEBPACK_AMD_DEFINE_ARRAY__) : __WEBPACK_AMD_DEFINE_FACTORY__;
+    if (__WEBPACK_AMD_DEFINE_RESULT__ !== undefined) {
+        module.exports = __WEBPACK_AMD_DEFINE_RESULT__;
+    }
+})();
+},
+
+}]);
\ No newline at end of file
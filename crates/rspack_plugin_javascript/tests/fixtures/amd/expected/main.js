@@ -0,0 +1,70 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./a.js": function (module, exports, __webpack_require__) {
+(function() {
+    var __WEBPACK_AMD_DEFINE_ARRAY__ = [
+        __webpack_require__,
+        exports,
+        module
+    ];
+    var __WEBPACK_AMD_DEFINE_FACTORY__ = {
+        name: "a"
+    };
+    var __WEBPACK_AMD_DEFINE_RESULT__ = typeof __WEBPACK_AMD_DEFINE_FACTORY__ === 'function' ? __WEBPACK_AMD_DEFINE_FACTORY__.apply(exports, __WEBPACK_AMD_DEFINE_ARRAY__) : __WEBPACK_AMD_DEFINE_FACTORY__;
+    if (__WEBPACK_AMD_DEFINE_RESULT__ !== undefined) {
+        module.exports = __WEBPACK_AMD_DEFINE_RESULT__;
+    }
+})();
+},
+"./b.js": function (module, exports, __webpack_require__) {
+(function() {
+    var __WEBPACK_AMD_DEFINE_ARRAY__ = [
+        __webpack_require__,
+        exports,
+        module
+    ];
+    var __WEBPACK_AMD_DEFINE_FACTORY__ = function(require, exports, module) {
+        module.exports = "b:" + require("./a.js").name;
+    };
+    var __WEBPACK_AMD_DEFINE_RESULT__ = typeof __WEBPACK_AMD_DEFINE_FACTORY__ === 'function' ? __WEBPACK_AMD_DEFINE_FACTORY__.apply(exports, __WEBPACK_AMD_DEFINE_ARRAY__) : __WEBPACK_AMD_DEFINE_FACTORY__;
+    if (__WEBPACK_AMD_DEFINE_RESULT__ !== undefined) {
+        module.exports = __WEBPACK_AMD_DEFINE_RESULT__;
+    }
+})();
+},
+"./index.js": function (module, exports, __webpack_require__) {
+(function() {
+    var __WEBPACK_AMD_DEFINE_ARRAY__ = [
+        __webpack_require__("./a.js"),
+        __webpack_require__,
+        exports
+    ];
+    var __WEBPACK_AMD_DEFINE_FACTORY__ = function(a, require, exports) {
+        console.log("a", a.name);
+        var b = require("./b.js");
+        console.log("b", b);
+        exports.ready = true;
+        Promise.all([
+            __webpack_require__.el("./c.js"),
+            __webpack_require__.el("./d.js")
+        ]).then(function() {
+            var __WEBPACK_AMD_REQUIRE_ARRAY__ = [
+                __webpack_require__("./c.js"),
+                __webpack_require__("./d.js")
+            ];
+            (function(c, d) {
+                console.log("c", c, "d", d.name);
+            }).apply(null, __WEBPACK_AMD_REQUIRE_ARRAY__);
+        });
+    };
+    var __WEBPACK_AMD_DEFINE_RESULT__ = typeof __WEBPACK_AMD_DEFINE_FACTORY__ === 'function' ? __WEBPACK_AMD_DEFINE_FACTORY__.apply(exports, __WEBPACK_AMD_DEFINE_ARRAY__) : __WEBPACK_AMD_DEFINE_FACTORY__;
+    if (__WEBPACK_AMD_DEFINE_RESULT__ !== undefined) {
+        module.exports = __WEBPACK_AMD_DEFINE_RESULT__;
+    }
+})();
+},
+
+},function(__webpack_require__) {
+var __webpack_exports__ = __webpack_require__('./index.js');
+
+}
+]);
\ No newline at end of file
@@ -0,0 +1,9 @@
+define(["./a", "require", "exports"], function (a, require, exports) {
+  console.log("a", a.name);
+  var b = require("./b");
+  console.log("b", b);
+  exports.ready = true;
+  require(["./c", "./d"], function (c, d) {
+    console.log("c", c, "d", d.name);
+  });
+});
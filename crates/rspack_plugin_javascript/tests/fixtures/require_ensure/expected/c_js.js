@@ -0,0 +1,6 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["c_js"], {
+"./c.js": function (module, exports, __webpack_require__) {
+module.exports = "c";
+},
+
+}]);
\ No newline at end of file
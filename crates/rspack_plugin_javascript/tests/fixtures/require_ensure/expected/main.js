@@ -0,0 +1,33 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./a.js": function (module, exports, __webpack_require__) {
+module.exports = "a";
+},
+"./d.js": function (module, exports, __webpack_require__) {
+module.exports = "d";
+},
+"./index.js": function (module, exports, __webpack_require__) {
+console.log("resolve", "./a.js");
+if (null /* weak dependency, without id */) {
+    console.log("resolveWeak");
+}
+Promise.all([
+    __webpack_require__.el("./a.js"),
+    __webpack_require__.el("./b.js")
+]).then((function(require) {
+    var b = __webpack_require__("./b.js");
+    console.log("ensure", __webpack_require__("./a.js"), b);
+}).bind(null, __webpack_require__));
+Promise.all([
+    __webpack_require__.el("./c.js")
+]).then((function(require) {
+    console.log("ensure c", __webpack_require__("./c.js"));
+}).bind(null, __webpack_require__))['catch'](function(e) {
+    console.log("error", e, __webpack_require__("./d.js"));
+});
+},
+
+},function(__webpack_require__) {
+var __webpack_exports__ = __webpack_require__('./index.js');
+
+}
+]);
\ No newline at end of file
@@ -0,0 +1,6 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["named"], {
+"./b.js": function (module, exports, __webpack_require__) {
+module.exports = "b";
+},
+
+}]);
\ No newline at end of file
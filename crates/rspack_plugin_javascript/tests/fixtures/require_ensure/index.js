@@ -0,0 +1,13 @@
+console.log("resolve", require.resolve("./a"));
+if (require.resolveWeak("./weak")) {
+  console.log("resolveWeak");
+}
+require.ensure(["./a"], function (require) {
+  var b = require("./b");
+  console.log("ensure", require("./a"), b);
+}, "named");
+require.ensure([], function (require) {
+  console.log("ensure c", require("./c"));
+}, function (e) {
+  console.log("error", e, require("./d"));
+});
@@ -0,0 +1,31 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./index.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+var _sharedJs = __webpack_require__("./shared.js");
+const worker = new Worker(new URL(__webpack_require__.p + __webpack_require__.u("worker_js"), __webpack_require__.b));
+const named = new Worker(new URL(__webpack_require__.p + __webpack_require__.u("named-worker"), __webpack_require__.b));
+worker.postMessage(_sharedJs.shared);
+named.postMessage(_sharedJs.shared);
+},
+"./shared.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "shared", {
+    enumerable: true,
+    get: function() {
+        return shared;
+    }
+});
+const shared = "shared";
+},
+
+},function(__webpack_require__) {
+var __webpack_exports__ = __webpack_require__('./index.js');
+
+}
+]);
\ No newline at end of file
@@ -0,0 +1,7 @@
+import { shared } from "./shared";
+const worker = new Worker(new URL("./worker.js", import.meta.url));
+const named = new Worker(
+	/* webpackChunkName: "named-worker" */ new URL("./named.js", import.meta.url)
+);
+worker.postMessage(shared);
+named.postMessage(shared);
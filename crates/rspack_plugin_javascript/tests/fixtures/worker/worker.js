@@ -0,0 +1,2 @@
+import { shared } from "./shared";
+self.onmessage = e => self.postMessage(e.data + shared);
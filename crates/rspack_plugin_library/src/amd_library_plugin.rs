@@ -67,7 +67,7 @@ impl Plugin for AmdLibraryPlugin {
           .and_then(|module| module.as_external_module())
       })
       .collect::<Vec<&ExternalModule>>();
-    let external_deps_array = external_dep_array(&modules);
+    let external_deps_array = external_dep_array(&modules)?;
     let external_arguments = external_arguments(&modules, compilation);
     let mut fn_start = format!("function({external_arguments}){{\n");
     if compilation.options.output.iife || !chunk.has_runtime(&compilation.chunk_group_by_ukey) {
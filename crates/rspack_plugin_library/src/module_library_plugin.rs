@@ -0,0 +1,144 @@
+use std::hash::Hash;
+
+use rspack_core::{
+  json_string,
+  rspack_sources::{ConcatSource, RawSource, SourceExt},
+  to_identifier, Compilation, JsChunkHashArgs, OptimizeChunksArgs, Plugin, PluginContext,
+  PluginJsChunkHashHookOutput, PluginRenderStartupHookOutput, ProvidedExports, RenderStartupArgs,
+};
+use rspack_error::{internal_error, Diagnostic, Result};
+
+/// Renders exports of the entry module as real ES module exports,
+/// should be used together with `output.module`.
+///
+/// Export names are read from the exports info of the entry module provided by tree shaking.
+/// Exports of the entry module are always marked as used, others are dropped as usual.
+/// Only the known exports are rendered if the entry module may provide unknown exports,
+/// and without `builtins.treeShaking` the whole exports object is exported as `default`,
+/// both of which are reported as warnings.
+#[derive(Debug, Default)]
+pub struct ModuleLibraryPlugin;
+
+impl ModuleLibraryPlugin {
+  fn is_enabled(compilation: &Compilation) -> bool {
+    compilation
+      .options
+      .output
+      .library
+      .as_ref()
+      .map_or(false, |library| library.library_type == "module")
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for ModuleLibraryPlugin {
+  fn name(&self) -> &'static str {
+    "ModuleLibraryPlugin"
+  }
+
+  async fn optimize_chunk_modules(&mut self, args: OptimizeChunksArgs<'_>) -> Result<()> {
+    let compilation = args.compilation;
+    if !Self::is_enabled(compilation) {
+      return Ok(());
+    }
+    if !compilation.options.builtins.tree_shaking {
+      compilation.push_diagnostic(Diagnostic::warn(
+        self.name().to_string(),
+        "Exports of the entry module are unknown without `builtins.treeShaking`, the exports object is exported as `default` instead".to_string(),
+        0,
+        0,
+      ));
+      return Ok(());
+    }
+    let mut unknown_modules = compilation
+      .entry_module_identifiers
+      .iter()
+      .filter(|module| {
+        compilation.module_graph.get_provided_exports(module) == ProvidedExports::Unknown
+      })
+      .map(|module| module.to_string())
+      .collect::<Vec<_>>();
+    unknown_modules.sort_unstable();
+    for module in unknown_modules {
+      compilation.push_diagnostic(Diagnostic::warn(
+        self.name().to_string(),
+        format!("Entry module {module} may provide exports which are unknown statically, only the known exports are exported"),
+        0,
+        0,
+      ));
+    }
+    Ok(())
+  }
+
+  fn render_startup(
+    &self,
+    _ctx: PluginContext,
+    args: &RenderStartupArgs,
+  ) -> PluginRenderStartupHookOutput {
+    let compilation = args.compilation;
+    let Some(library) = &compilation.options.output.library else {
+      return Ok(None);
+    };
+    if library.library_type != "module" {
+      return Ok(None);
+    }
+    if library.name.is_some() {
+      return Err(internal_error!(
+        "Library name must be unset. Library type 'module' does not support a name."
+      ));
+    }
+
+    let mut source = ConcatSource::default();
+    let Some(module) = compilation
+      .chunk_graph
+      .get_chunk_entry_modules(args.chunk)
+      .last()
+      .copied() else {
+      return Ok(None);
+    };
+    if compilation.module_graph.is_async(&module) {
+      source.add(RawSource::from(
+        "__webpack_exports__ = await __webpack_exports__;\n",
+      ));
+    }
+
+    if !compilation.options.builtins.tree_shaking {
+      source.add(RawSource::from("export default __webpack_exports__;\n"));
+      return Ok(Some(source.boxed()));
+    }
+
+    let mut exports = vec![];
+    if let Some(exports_info) = compilation.module_graph.get_exports_info(&module) {
+      for name in exports_info.get_known_provided_exports() {
+        let var_name = format!("__webpack_exports__{}", to_identifier(&name));
+        source.add(RawSource::from(format!(
+          "var {var_name} = __webpack_exports__[{}];\n",
+          json_string(&name)
+        )));
+        exports.push(format!("{var_name} as {}", json_string(&name)));
+      }
+    }
+    if !exports.is_empty() {
+      source.add(RawSource::from(format!(
+        "export {{ {} }};\n",
+        exports.join(", ")
+      )));
+    }
+    Ok(Some(source.boxed()))
+  }
+
+  fn js_chunk_hash(
+    &self,
+    _ctx: PluginContext,
+    args: &mut JsChunkHashArgs,
+  ) -> PluginJsChunkHashHookOutput {
+    self.name().hash(&mut args.hasher);
+    args
+      .compilation
+      .options
+      .output
+      .library
+      .hash(&mut args.hasher);
+    Ok(())
+  }
+}
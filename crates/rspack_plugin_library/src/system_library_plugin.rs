@@ -0,0 +1,124 @@
+use std::hash::Hash;
+
+use rspack_core::{
+  rspack_sources::{ConcatSource, RawSource, SourceExt},
+  AdditionalChunkRuntimeRequirementsArgs, ExternalModule, Filename, JsChunkHashArgs, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, PluginJsChunkHashHookOutput,
+  PluginRenderHookOutput, RenderArgs, RuntimeGlobals, SourceType,
+};
+use rspack_error::Result;
+
+use super::utils::{external_request, external_var_names};
+
+const DYNAMIC_EXPORT: &str = "__WEBPACK_DYNAMIC_EXPORT__";
+
+#[derive(Debug, Default)]
+pub struct SystemLibraryPlugin;
+
+impl Plugin for SystemLibraryPlugin {
+  fn name(&self) -> &'static str {
+    "SystemLibraryPlugin"
+  }
+
+  fn additional_chunk_runtime_requirements(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    args
+      .runtime_requirements
+      .insert(RuntimeGlobals::RETURN_EXPORTS_FROM_RUNTIME);
+    Ok(())
+  }
+
+  fn render(&self, _ctx: PluginContext, args: &RenderArgs) -> PluginRenderHookOutput {
+    let compilation = &args.compilation;
+    let chunk = args.chunk();
+    // only externals of "system" type are passed in by `System.register`
+    let modules = compilation
+      .chunk_graph
+      .get_chunk_module_identifiers(args.chunk)
+      .iter()
+      .filter_map(|identifier| {
+        compilation
+          .module_graph
+          .module_by_identifier(identifier)
+          .and_then(|module| module.as_external_module())
+          .filter(|module| module.external_type() == "system")
+      })
+      .collect::<Vec<&ExternalModule>>();
+
+    let name = compilation
+      .options
+      .output
+      .library
+      .as_ref()
+      .and_then(|library| library.name.as_ref())
+      .and_then(|name| name.root.as_ref())
+      .and_then(|root| root.get(0))
+      .map(|name| {
+        let name =
+          Filename::from(name.clone()).render_with_chunk(chunk, ".js", &SourceType::JavaScript);
+        format!("\"{name}\", ")
+      })
+      .unwrap_or_default();
+    let system_dependencies = modules
+      .iter()
+      .map(|m| Ok(format!("\"{}\"", external_request(m, "system")?[0])))
+      .collect::<Result<Vec<_>>>()?
+      .join(", ");
+
+    let external_var_names = external_var_names(&modules, compilation);
+    let external_var_initialization = external_var_names
+      .iter()
+      .map(|name| {
+        format!(
+          "var {name} = {{}};\nObject.defineProperty({name}, \"__esModule\", {{ value: true }});\n"
+        )
+      })
+      .collect::<String>();
+    let setters = if external_var_names.is_empty() {
+      String::new()
+    } else {
+      let setters = external_var_names
+        .iter()
+        .map(|name| {
+          format!(
+            "function(module) {{\n  Object.keys(module).forEach(function(key) {{\n    {name}[key] = module[key];\n  }});\n}}"
+          )
+        })
+        .collect::<Vec<_>>()
+        .join(",\n");
+      format!("setters: [\n{setters}\n],\n")
+    };
+
+    let return_prefix =
+      if compilation.options.output.iife || !chunk.has_runtime(&compilation.chunk_group_by_ukey) {
+        " return "
+      } else {
+        ""
+      };
+    let mut source = ConcatSource::default();
+    source.add(RawSource::from(format!(
+      "System.register({name}[{system_dependencies}], function({DYNAMIC_EXPORT}, __system_context__) {{\n{external_var_initialization}return {{\n{setters}execute: function() {{\n{DYNAMIC_EXPORT}((function() {{\n{return_prefix}"
+    )));
+    source.add(args.source.clone());
+    source.add(RawSource::from("\n})());\n}\n};\n});"));
+    Ok(Some(source.boxed()))
+  }
+
+  fn js_chunk_hash(
+    &self,
+    _ctx: PluginContext,
+    args: &mut JsChunkHashArgs,
+  ) -> PluginJsChunkHashHookOutput {
+    self.name().hash(&mut args.hasher);
+    args
+      .compilation
+      .options
+      .output
+      .library
+      .hash(&mut args.hasher);
+    Ok(())
+  }
+}
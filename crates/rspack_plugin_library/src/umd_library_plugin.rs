@@ -1,13 +1,15 @@
 use std::hash::Hash;
 
 use rspack_core::{
+  json_string, property_access,
   rspack_sources::{ConcatSource, RawSource, SourceExt},
   AdditionalChunkRuntimeRequirementsArgs, Chunk, ExternalModule, Filename, JsChunkHashArgs,
   LibraryAuxiliaryComment, Plugin, PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext,
   PluginJsChunkHashHookOutput, PluginRenderHookOutput, RenderArgs, RuntimeGlobals, SourceType,
 };
+use rspack_error::Result;
 
-use super::utils::{external_arguments, external_dep_array};
+use super::utils::{external_arguments, external_dep_array, external_request};
 
 #[derive(Debug)]
 pub struct UmdLibraryPlugin {
@@ -83,12 +85,12 @@ impl Plugin for UmdLibraryPlugin {
     let define = if let (Some(amd), Some(_)) = &(amd, umd_named_define) {
       format!(
         "define({amd}, {}, {amd_factory});\n",
-        external_dep_array(&required_externals)
+        external_dep_array(&required_externals)?
       )
     } else {
       format!(
         "define({}, {amd_factory});\n",
-        external_dep_array(&required_externals)
+        external_dep_array(&required_externals)?
       )
     };
 
@@ -102,7 +104,7 @@ impl Plugin for UmdLibraryPlugin {
           .map(|commonjs| library_name(&[commonjs], chunk))
           .or_else(|| root.clone().map(|root| library_name(&root, chunk)))
           .unwrap_or_default(),
-        externals_require_array("commonjs", &externals),
+        externals_require_array("commonjs", &externals)?,
       );
       let root_code = format!(
         "{}
@@ -118,7 +120,7 @@ impl Plugin for UmdLibraryPlugin {
           ),
           chunk
         ),
-        external_root_array(&externals)
+        external_root_array(&externals)?
       );
       format!(
         "}} else if(typeof exports === 'object'){{\n
@@ -133,8 +135,8 @@ impl Plugin for UmdLibraryPlugin {
       } else {
         format!(
           "var a = typeof exports === 'object' ? factory({}) : factory({});\n",
-          externals_require_array("commonjs", &externals),
-          external_root_array(&externals)
+          externals_require_array("commonjs", &externals)?,
+          external_root_array(&externals)?
         )
       };
       format!(
@@ -155,7 +157,7 @@ impl Plugin for UmdLibraryPlugin {
             module.exports = factory({});
         }}"#,
       get_auxiliary_comment("commonjs2", auxiliary_comment),
-      externals_require_array("commonjs2", &externals)
+      externals_require_array("commonjs2", &externals)?
     )));
     source.add(RawSource::from(format!(
       "else if(typeof define === 'function' && define.amd) {{
@@ -198,27 +200,36 @@ fn replace_keys(v: String, chunk: &Chunk) -> String {
   Filename::from(v).render_with_chunk(chunk, ".js", &SourceType::JavaScript)
 }
 
-fn externals_require_array(_t: &str, externals: &[&ExternalModule]) -> String {
-  externals
-    .iter()
-    .map(|m| {
-      let request = &m.request;
-      // TODO: check if external module is optional
-      format!("require('{request}')")
-    })
-    .collect::<Vec<_>>()
-    .join(", ")
+fn externals_require_array(t: &str, externals: &[&ExternalModule]) -> Result<String> {
+  Ok(
+    externals
+      .iter()
+      .map(|m| {
+        let request = external_request(m, t)?;
+        let (module, properties) = (&request[0], &request[1..]);
+        // TODO: check if external module is optional
+        Ok(format!(
+          "require({}){}",
+          json_string(module),
+          property_access(properties)
+        ))
+      })
+      .collect::<Result<Vec<_>>>()?
+      .join(", "),
+  )
 }
 
-fn external_root_array(modules: &[&ExternalModule]) -> String {
-  modules
-    .iter()
-    .map(|m| {
-      let request = &m.request;
-      format!("root{}", accessor_to_object_access(&[request.to_owned()]))
-    })
-    .collect::<Vec<_>>()
-    .join(", ")
+fn external_root_array(modules: &[&ExternalModule]) -> Result<String> {
+  Ok(
+    modules
+      .iter()
+      .map(|m| {
+        let request = external_request(m, "root")?;
+        Ok(format!("root{}", property_access(request)))
+      })
+      .collect::<Result<Vec<_>>>()?
+      .join(", "),
+  )
 }
 
 fn accessor_to_object_access(accessor: &[String]) -> String {
@@ -269,3 +280,97 @@ fn get_auxiliary_comment(t: &str, auxiliary_comment: &Option<LibraryAuxiliaryCom
   }
   "".to_string()
 }
+
+#[cfg(test)]
+mod tests {
+  use rspack_core::ExternalRequest;
+  use rustc_hash::FxHashMap as HashMap;
+
+  use super::*;
+
+  fn external(request: ExternalRequest) -> ExternalModule {
+    ExternalModule::new(request, "umd".to_string(), "lodash".to_string())
+  }
+
+  #[test]
+  fn array_request_accesses_properties() {
+    let module = external(ExternalRequest::Single(vec![
+      "lodash".to_string(),
+      "merge".to_string(),
+    ]));
+    assert_eq!(
+      externals_require_array("commonjs", &[&module]).expect("should render"),
+      r#"require("lodash")["merge"]"#
+    );
+    assert_eq!(
+      external_root_array(&[&module]).expect("should render"),
+      r#"root["lodash"]["merge"]"#
+    );
+  }
+
+  #[test]
+  fn object_request_picks_request_of_type() {
+    let module = external(ExternalRequest::Map(HashMap::from_iter([
+      ("root".to_string(), vec!["_".to_string()]),
+      ("commonjs".to_string(), vec!["lodash".to_string()]),
+      (
+        "commonjs2".to_string(),
+        vec!["lodash".to_string(), "default".to_string()],
+      ),
+      ("amd".to_string(), vec!["lodash-amd".to_string()]),
+    ])));
+    assert_eq!(
+      externals_require_array("commonjs", &[&module]).expect("should render"),
+      r#"require("lodash")"#
+    );
+    assert_eq!(
+      externals_require_array("commonjs2", &[&module]).expect("should render"),
+      r#"require("lodash")["default"]"#
+    );
+    assert_eq!(
+      external_root_array(&[&module]).expect("should render"),
+      r#"root["_"]"#
+    );
+    assert_eq!(
+      external_dep_array(&[&module]).expect("should render"),
+      "['lodash-amd']"
+    );
+  }
+
+  #[test]
+  fn requests_are_escaped() {
+    let module = external(ExternalRequest::Single(vec![
+      "it's".to_string(),
+      "a'b".to_string(),
+    ]));
+    assert_eq!(
+      externals_require_array("commonjs", &[&module]).expect("should render"),
+      r#"require("it's")["a'b"]"#
+    );
+    assert_eq!(
+      external_root_array(&[&module]).expect("should render"),
+      r#"root["it's"]["a'b"]"#
+    );
+  }
+
+  #[test]
+  fn object_request_missing_type_is_an_error() {
+    let module = external(ExternalRequest::Map(HashMap::from_iter([(
+      "root".to_string(),
+      vec!["_".to_string()],
+    )])));
+    for (result, r#type) in [
+      (externals_require_array("commonjs", &[&module]), "commonjs"),
+      (external_dep_array(&[&module]), "amd"),
+    ] {
+      let error = result.expect_err("should fail").to_string();
+      assert!(
+        error.contains(&format!(
+          "Missing external configuration for type:{}",
+          r#type
+        )),
+        "{error}"
+      );
+    }
+  }
+}
@@ -1,16 +1,36 @@
 use rspack_core::{to_identifier, Compilation, ExternalModule};
+use rspack_error::{internal_error, Result};
 use rspack_identifier::Identifiable;
 
-pub fn external_dep_array(modules: &[&ExternalModule]) -> String {
+/// The request of the external for the given library type, e.g. `root` of `{ root, amd, commonjs }`
+pub fn external_request<'a>(module: &'a ExternalModule, r#type: &str) -> Result<&'a [String]> {
+  module
+    .request
+    .for_type(r#type)
+    .filter(|request| !request.is_empty())
+    .ok_or_else(|| {
+      internal_error!(
+        "Missing external configuration for type:{} of external {}",
+        r#type,
+        module.request
+      )
+    })
+}
+
+pub fn external_dep_array(modules: &[&ExternalModule]) -> Result<String> {
   let value = modules
     .iter()
-    .map(|m| format!("'{}'", m.request))
-    .collect::<Vec<_>>()
+    .map(|m| Ok(format!("'{}'", external_request(m, "amd")?[0])))
+    .collect::<Result<Vec<_>>>()?
     .join(", ");
-  format!("[{value}]")
+  Ok(format!("[{value}]"))
 }
 
 pub fn external_arguments(modules: &[&ExternalModule], compilation: &Compilation) -> String {
+  external_var_names(modules, compilation).join(", ")
+}
+
+pub fn external_var_names(modules: &[&ExternalModule], compilation: &Compilation) -> Vec<String> {
   modules
     .iter()
     .map(|m| {
@@ -25,6 +45,5 @@ pub fn external_arguments(modules: &[&ExternalModule], compilation: &Compilation
         )
       )
     })
-    .collect::<Vec<_>>()
-    .join(", ")
+    .collect()
 }
@@ -0,0 +1,204 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use rspack_core::{
+  rspack_sources::{RawSource, Source, SourceExt},
+  AstOrSource, BuildContext, BuildResult, CodeGenerationResult, Compilation, Context,
+  GenerationResult, LibIdentOptions, Module, ModuleDependency, ModuleType, RuntimeGlobals,
+  SourceType,
+};
+use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_identifier::{Identifiable, Identifier};
+
+use super::{ContainerExposedDependency, ExposeOptions};
+use crate::utils::{async_module_factory, json_string};
+
+static CONTAINER_ENTRY_MODULE_SOURCE_TYPES: &[SourceType] = &[SourceType::JavaScript];
+
+/// Entry module of a container, which exports `get` and `init` for hosts to consume exposed modules.
+#[derive(Debug)]
+pub struct ContainerEntryModule {
+  id: Identifier,
+  name: String,
+  share_scope: String,
+  exposes: Vec<(String, ExposeOptions)>,
+}
+
+impl ContainerEntryModule {
+  pub fn new(name: String, share_scope: String, exposes: Vec<(String, ExposeOptions)>) -> Self {
+    let exposes_identifier = exposes
+      .iter()
+      .map(|(name, options)| format!("[{name}, {}]", options.import.join(", ")))
+      .collect::<Vec<_>>()
+      .join(", ");
+    Self {
+      id: Identifier::from(format!(
+        "container entry ({share_scope}) [{exposes_identifier}]"
+      )),
+      name,
+      share_scope,
+      exposes,
+    }
+  }
+
+  /// Module ids of each exposed name, in the order of `exposes`
+  fn exposed_module_ids<'a>(&self, compilation: &'a Compilation) -> Vec<(&str, Vec<&'a str>)> {
+    let module_graph = &compilation.module_graph;
+    let dependencies = module_graph
+      .module_graph_module_by_identifier(&self.id)
+      .map(|mgm| mgm.dependencies.as_slice())
+      .unwrap_or_default();
+    self
+      .exposes
+      .iter()
+      .map(|(name, _)| {
+        let ids = dependencies
+          .iter()
+          .filter(|id| {
+            module_graph
+              .dependency_by_id(id)
+              .and_then(|dep| {
+                (**dep)
+                  .as_any()
+                  .downcast_ref::<ContainerExposedDependency>()
+              })
+              .map_or(false, |dep| dep.name() == name)
+          })
+          .filter_map(|id| module_graph.module_identifier_by_dependency_id(id))
+          .filter_map(|module| module_graph.module_graph_module_by_identifier(module))
+          .map(|mgm| mgm.id(&compilation.chunk_graph))
+          .collect();
+        (name.as_str(), ids)
+      })
+      .collect()
+  }
+}
+
+impl Identifiable for ContainerEntryModule {
+  fn identifier(&self) -> Identifier {
+    self.id
+  }
+}
+
+#[async_trait::async_trait]
+impl Module for ContainerEntryModule {
+  fn module_type(&self) -> &ModuleType {
+    &ModuleType::Js
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    CONTAINER_ENTRY_MODULE_SOURCE_TYPES
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    None
+  }
+
+  fn readable_identifier(&self, _context: &Context) -> Cow<str> {
+    Cow::Borrowed("container entry")
+  }
+
+  fn size(&self, _source_type: &SourceType) -> f64 {
+    42.0
+  }
+
+  async fn build(
+    &mut self,
+    _build_context: BuildContext<'_>,
+  ) -> Result<TWithDiagnosticArray<BuildResult>> {
+    let dependencies = self
+      .exposes
+      .iter()
+      .flat_map(|(name, options)| {
+        options.import.iter().map(|request| {
+          Box::new(ContainerExposedDependency::new(
+            name.clone(),
+            request.clone(),
+          )) as Box<dyn ModuleDependency>
+        })
+      })
+      .collect();
+    Ok(
+      BuildResult {
+        dependencies,
+        ..Default::default()
+      }
+      .with_empty_diagnostic(),
+    )
+  }
+
+  fn code_generation(&self, compilation: &Compilation) -> Result<CodeGenerationResult> {
+    let mut cgr = CodeGenerationResult::default();
+    cgr.runtime_requirements.insert(
+      RuntimeGlobals::LOAD_CHUNK_WITH_MODULE
+        | RuntimeGlobals::HAS_OWN_PROPERTY
+        | RuntimeGlobals::SHARE_SCOPE_MAP
+        | RuntimeGlobals::INITIALIZE_SHARING
+        | RuntimeGlobals::CURRENT_REMOTE_GET_SCOPE,
+    );
+
+    let module_map = self
+      .exposed_module_ids(compilation)
+      .into_iter()
+      .map(|(name, ids)| format!("  {}: {}", json_string(name), async_module_factory(&ids)))
+      .collect::<Vec<_>>()
+      .join(",\n");
+    let source = format!(
+      r#"var moduleMap = {{
+{module_map}
+}};
+var get = function(module, getScope) {{
+  {current_remote_get_scope} = getScope;
+  getScope = (
+    {has_own_property}(moduleMap, module)
+      ? moduleMap[module]()
+      : Promise.resolve().then(function() {{
+        throw new Error('Module "' + module + '" does not exist in container.');
+      }})
+  );
+  {current_remote_get_scope} = undefined;
+  return getScope;
+}};
+var init = function(shareScope, initScope) {{
+  if (!{share_scope_map}) return;
+  var name = {share_scope};
+  var oldScope = {share_scope_map}[name];
+  if (oldScope && oldScope !== shareScope) throw new Error("Container initialization failed as it has already been initialized with a different share scope");
+  {share_scope_map}[name] = shareScope;
+  return {initialize_sharing}(name, initScope);
+}};
+// This exports getters to disallow modifications
+Object.defineProperty(exports, "get", {{ enumerable: true, get: function() {{ return get; }} }});
+Object.defineProperty(exports, "init", {{ enumerable: true, get: function() {{ return init; }} }});"#,
+      current_remote_get_scope = RuntimeGlobals::CURRENT_REMOTE_GET_SCOPE,
+      has_own_property = RuntimeGlobals::HAS_OWN_PROPERTY,
+      share_scope_map = RuntimeGlobals::SHARE_SCOPE_MAP,
+      initialize_sharing = RuntimeGlobals::INITIALIZE_SHARING,
+      share_scope = json_string(&self.share_scope),
+    );
+    cgr.add(
+      SourceType::JavaScript,
+      GenerationResult::from(AstOrSource::from(RawSource::from(source).boxed())),
+    );
+    Ok(cgr)
+  }
+
+  fn lib_ident(&self, _options: LibIdentOptions) -> Option<Cow<str>> {
+    Some(Cow::Owned(format!("webpack/container/entry/{}", self.name)))
+  }
+}
+
+impl Hash for ContainerEntryModule {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    "__rspack_internal__ContainerEntryModule".hash(state);
+    self.identifier().hash(state);
+  }
+}
+
+impl PartialEq for ContainerEntryModule {
+  fn eq(&self, other: &Self) -> bool {
+    self.identifier() == other.identifier()
+  }
+}
+
+impl Eq for ContainerEntryModule {}
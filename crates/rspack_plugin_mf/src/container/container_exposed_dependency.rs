@@ -0,0 +1,71 @@
+use rspack_core::{
+  CodeGeneratable, CodeGeneratableContext, CodeGeneratableResult, Dependency, DependencyCategory,
+  DependencyId, DependencyType, ErrorSpan, ModuleDependency, ModuleIdentifier,
+};
+use rspack_error::Result;
+
+/// Dependency from the container entry to a module it exposes,
+/// each exposed module is split into its own chunk.
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub struct ContainerExposedDependency {
+  id: Option<DependencyId>,
+  request: String,
+  /// The exposed name, e.g. `./Button`
+  name: String,
+}
+
+impl ContainerExposedDependency {
+  pub fn new(name: String, request: String) -> Self {
+    Self {
+      id: None,
+      request,
+      name,
+    }
+  }
+
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+}
+
+impl Dependency for ContainerExposedDependency {
+  fn parent_module_identifier(&self) -> Option<&ModuleIdentifier> {
+    None
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::ContainerExposed
+  }
+
+  fn id(&self) -> Option<DependencyId> {
+    self.id
+  }
+
+  fn set_id(&mut self, id: Option<DependencyId>) {
+    self.id = id;
+  }
+}
+
+impl ModuleDependency for ContainerExposedDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    None
+  }
+}
+
+impl CodeGeneratable for ContainerExposedDependency {
+  fn generate(&self, _context: &mut CodeGeneratableContext) -> Result<CodeGeneratableResult> {
+    Ok(CodeGeneratableResult::default())
+  }
+}
@@ -0,0 +1,142 @@
+use std::hash::Hash;
+
+use rspack_core::{
+  rspack_sources::{ConcatSource, RawSource, SourceExt},
+  Chunk, CompilationArgs, DependencyType, EntryItem, FactorizeArgs, JsChunkHashArgs, ModuleExt,
+  ModuleFactoryResult, NormalModuleFactoryContext, Plugin, PluginCompilationHookOutput,
+  PluginContext, PluginFactorizeHookOutput, PluginJsChunkHashHookOutput, PluginRenderHookOutput,
+  PluginRenderStartupHookOutput, RenderArgs, RenderStartupArgs,
+};
+use rspack_error::internal_error;
+
+use super::ContainerEntryModule;
+
+#[derive(Debug, Clone, Hash)]
+pub struct ExposeOptions {
+  /// Requests of the exposed module, the last one is exported
+  pub import: Vec<String>,
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ContainerPluginOptions {
+  /// Name of the container, which is also the name of its entry chunk,
+  /// so the emitted filename follows `output.filename`.
+  pub name: String,
+  pub share_scope: String,
+  /// How the container is exposed, one of `var`, `assign`, `this`, `window`,
+  /// `self`, `global`, `commonjs`, `commonjs2` and `commonjs-module`.
+  pub library_type: String,
+  pub exposes: Vec<(String, ExposeOptions)>,
+}
+
+/// Same as webpack's `ContainerPlugin`, which builds a container entry
+/// exposing modules to other builds at runtime.
+#[derive(Debug)]
+pub struct ContainerPlugin {
+  options: ContainerPluginOptions,
+}
+
+impl ContainerPlugin {
+  pub fn new(options: ContainerPluginOptions) -> Self {
+    Self { options }
+  }
+
+  fn entry_request(&self) -> String {
+    format!("webpack/container/entry/{}", self.options.name)
+  }
+
+  fn is_container_chunk(&self, chunk: &Chunk) -> bool {
+    chunk.name.as_deref() == Some(self.options.name.as_str())
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for ContainerPlugin {
+  fn name(&self) -> &'static str {
+    "ContainerPlugin"
+  }
+
+  async fn compilation(&mut self, args: CompilationArgs<'_>) -> PluginCompilationHookOutput {
+    args.compilation.add_entry(
+      self.options.name.clone(),
+      EntryItem {
+        import: vec![self.entry_request()],
+        runtime: None,
+      },
+    );
+    Ok(())
+  }
+
+  async fn factorize(
+    &self,
+    _ctx: PluginContext,
+    args: FactorizeArgs<'_>,
+    _job_ctx: &mut NormalModuleFactoryContext,
+  ) -> PluginFactorizeHookOutput {
+    if matches!(args.dependency.dependency_type(), DependencyType::Entry)
+      && args.dependency.request() == self.entry_request()
+    {
+      let module = ContainerEntryModule::new(
+        self.options.name.clone(),
+        self.options.share_scope.clone(),
+        self.options.exposes.clone(),
+      );
+      return Ok(Some(ModuleFactoryResult::new(module.boxed())));
+    }
+    Ok(None)
+  }
+
+  fn render(&self, _ctx: PluginContext, args: &RenderArgs) -> PluginRenderHookOutput {
+    if !self.is_container_chunk(args.chunk()) {
+      return Ok(None);
+    }
+    if self.options.library_type == "var" {
+      let mut source = ConcatSource::default();
+      source.add(RawSource::from(format!("var {};\n", self.options.name)));
+      source.add(args.source.clone());
+      return Ok(Some(source.boxed()));
+    }
+    Ok(Some(args.source.clone()))
+  }
+
+  fn render_startup(
+    &self,
+    _ctx: PluginContext,
+    args: &RenderStartupArgs,
+  ) -> PluginRenderStartupHookOutput {
+    if !self.is_container_chunk(args.chunk()) {
+      return Ok(None);
+    }
+    let name = &self.options.name;
+    let target = match self.options.library_type.as_str() {
+      "var" | "assign" => name.clone(),
+      "this" | "window" | "self" => format!(r#"{}["{name}"]"#, self.options.library_type),
+      "global" => format!(
+        r#"{}["{name}"]"#,
+        args.compilation.options.output.global_object
+      ),
+      "commonjs" => format!(r#"exports["{name}"]"#),
+      "commonjs2" | "commonjs-module" => "module.exports".to_string(),
+      r#type => {
+        return Err(internal_error!(
+          "Unsupported library type \"{type}\" of container {name}"
+        ))
+      }
+    };
+    Ok(Some(
+      RawSource::from(format!("{target} = __webpack_exports__;\n")).boxed(),
+    ))
+  }
+
+  fn js_chunk_hash(
+    &self,
+    _ctx: PluginContext,
+    args: &mut JsChunkHashArgs,
+  ) -> PluginJsChunkHashHookOutput {
+    if self.is_container_chunk(args.chunk()) {
+      self.name().hash(&mut args.hasher);
+      self.options.hash(&mut args.hasher);
+    }
+    Ok(())
+  }
+}
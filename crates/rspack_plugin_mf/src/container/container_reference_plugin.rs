@@ -0,0 +1,271 @@
+use rspack_core::{
+  AdditionalChunkRuntimeRequirementsArgs, ApplyContext, ChunkUkey, DependencyType, ExternalItem,
+  ExternalItemValue, ExternalType, FactorizeArgs, ModuleExt, ModuleFactoryResult, ModuleIdentifier,
+  NormalModuleFactoryContext, OptimizeChunksArgs, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, PluginFactorizeHookOutput,
+  RuntimeGlobals, SourceType,
+};
+use rspack_error::{internal_error, Diagnostic, Result};
+use rspack_plugin_externals::ExternalPlugin;
+use rspack_plugin_runtime::RemotesLoadingRuntimeModule;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use super::RemoteModule;
+
+#[derive(Debug, Clone)]
+pub struct RemoteOptions {
+  /// Request of the container external, e.g. `app@http://localhost:3001/remoteEntry.js`
+  pub external: String,
+  pub share_scope: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ContainerReferencePluginOptions {
+  /// Default external type of remotes
+  pub remote_type: ExternalType,
+  pub remotes: Vec<(String, RemoteOptions)>,
+}
+
+/// Same as webpack's `ContainerReferencePlugin`, requests prefixed with a remote key,
+/// e.g. `app/Button` of the remote `app`, are loaded from the container at runtime.
+///
+/// Remote modules are only loaded with async chunks, so they must be imported behind an
+/// `import()` boundary, an error is reported for remote modules in initial chunks.
+#[derive(Debug)]
+pub struct ContainerReferencePlugin {
+  options: ContainerReferencePluginOptions,
+  external_plugin: ExternalPlugin,
+}
+
+impl ContainerReferencePlugin {
+  pub fn new(options: ContainerReferencePluginOptions) -> Self {
+    let externals = options
+      .remotes
+      .iter()
+      .map(|(key, remote)| {
+        (
+          external_request(key),
+          ExternalItemValue::String(remote.external.clone()),
+        )
+      })
+      .collect();
+    let external_plugin = ExternalPlugin::new(
+      options.remote_type.clone(),
+      vec![ExternalItem::Object(externals)],
+    );
+    Self {
+      options,
+      external_plugin,
+    }
+  }
+
+  fn remote_module(&self, request: &str) -> Option<RemoteModule> {
+    self.options.remotes.iter().find_map(|(key, remote)| {
+      let internal_request = if request == key {
+        ".".to_string()
+      } else {
+        format!(
+          "./{}",
+          request.strip_prefix(key.as_str())?.strip_prefix('/')?
+        )
+      };
+      Some(RemoteModule::new(
+        request.to_string(),
+        external_request(key),
+        internal_request,
+        remote.share_scope.clone(),
+      ))
+    })
+  }
+}
+
+fn external_request(key: &str) -> String {
+  format!("webpack/container/reference/{key}")
+}
+
+#[async_trait::async_trait]
+impl Plugin for ContainerReferencePlugin {
+  fn name(&self) -> &'static str {
+    "ContainerReferencePlugin"
+  }
+
+  fn apply(&mut self, _ctx: PluginContext<&mut ApplyContext>) -> Result<()> {
+    Ok(())
+  }
+
+  async fn factorize(
+    &self,
+    ctx: PluginContext,
+    args: FactorizeArgs<'_>,
+    job_ctx: &mut NormalModuleFactoryContext,
+  ) -> PluginFactorizeHookOutput {
+    match args.dependency.dependency_type() {
+      DependencyType::RemoteToExternal => self.external_plugin.factorize(ctx, args, job_ctx).await,
+      DependencyType::Entry | DependencyType::ContainerExposed => Ok(None),
+      _ => Ok(
+        self
+          .remote_module(args.dependency.request())
+          .map(|module| ModuleFactoryResult::new(module.boxed())),
+      ),
+    }
+  }
+
+  /// Container externals are required by `__webpack_require__.f.remotes` before the chunk
+  /// containing the remote module is loaded, so they are moved into runtime chunks.
+  async fn optimize_chunk_modules(&mut self, args: OptimizeChunksArgs<'_>) -> Result<()> {
+    let compilation = args.compilation;
+    let runtime_chunks = compilation
+      .entrypoints
+      .values()
+      .filter_map(|ukey| compilation.chunk_group_by_ukey.get(ukey))
+      .map(|entrypoint| entrypoint.get_runtime_chunk())
+      .collect::<HashSet<_>>();
+
+    let mut external_chunks: HashMap<ModuleIdentifier, HashSet<ChunkUkey>> = HashMap::default();
+    for (identifier, module) in compilation.module_graph.modules() {
+      if module.downcast_ref::<RemoteModule>().is_none() {
+        continue;
+      }
+      let Some(external) = compilation
+        .module_graph
+        .module_graph_module_by_identifier(identifier)
+        .and_then(|mgm| mgm.dependencies.first())
+        .and_then(|dep| compilation.module_graph.module_identifier_by_dependency_id(dep)) else {
+        continue;
+      };
+      let targets = external_chunks.entry(*external).or_default();
+      for chunk in compilation.chunk_graph.get_modules_chunks(*identifier) {
+        let Some(chunk) = compilation.chunk_by_ukey.get(chunk) else {
+          continue;
+        };
+        targets.extend(runtime_chunks.iter().filter(|runtime_chunk| {
+          compilation
+            .chunk_by_ukey
+            .get(runtime_chunk)
+            .map_or(false, |runtime_chunk| {
+              !runtime_chunk.runtime.is_disjoint(&chunk.runtime)
+            })
+        }));
+      }
+    }
+
+    for (external, targets) in external_chunks {
+      let chunks = compilation.chunk_graph.get_modules_chunks(external).clone();
+      for chunk in chunks.difference(&targets) {
+        compilation
+          .chunk_graph
+          .disconnect_chunk_and_module(chunk, external);
+      }
+      for chunk in targets {
+        compilation
+          .chunk_graph
+          .connect_chunk_and_module(chunk, external);
+      }
+    }
+    Ok(())
+  }
+
+  fn additional_tree_runtime_requirements(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let compilation = &mut args.compilation;
+    let chunk = compilation
+      .chunk_by_ukey
+      .get(args.chunk)
+      .ok_or_else(|| internal_error!("chunk not found"))?;
+
+    let mut initial_remotes = chunk
+      .get_all_initial_chunks(&compilation.chunk_group_by_ukey)
+      .iter()
+      .flat_map(|initial_chunk| {
+        compilation.chunk_graph.get_chunk_modules_by_source_type(
+          initial_chunk,
+          SourceType::Remote,
+          &compilation.module_graph,
+        )
+      })
+      .filter_map(|mgm| {
+        compilation
+          .module_graph
+          .module_by_identifier(&mgm.module_identifier)
+          .and_then(|module| module.downcast_ref::<RemoteModule>())
+          .map(|module| module.request().to_string())
+      })
+      .collect::<Vec<_>>();
+    initial_remotes.sort_unstable();
+    initial_remotes.dedup();
+
+    let mut chunk_mapping = vec![];
+    let mut id_to_external_and_name_mapping = vec![];
+    for async_chunk in chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey) {
+      let mut module_ids = vec![];
+      for mgm in compilation.chunk_graph.get_chunk_modules_by_source_type(
+        &async_chunk,
+        SourceType::Remote,
+        &compilation.module_graph,
+      ) {
+        let Some(module) = compilation
+          .module_graph
+          .module_by_identifier(&mgm.module_identifier)
+          .and_then(|module| module.downcast_ref::<RemoteModule>()) else {
+          continue;
+        };
+        let Some(external_id) = mgm
+          .dependencies
+          .first()
+          .and_then(|dep| compilation.module_graph.module_identifier_by_dependency_id(dep))
+          .and_then(|external| compilation.chunk_graph.get_module_id(*external).clone()) else {
+          continue;
+        };
+        let id = mgm.id(&compilation.chunk_graph).to_string();
+        module_ids.push(id.clone());
+        id_to_external_and_name_mapping.push((
+          id,
+          [
+            module.share_scope().to_string(),
+            module.internal_request().to_string(),
+            external_id,
+          ],
+        ));
+      }
+      if !module_ids.is_empty() {
+        module_ids.sort_unstable();
+        let chunk = compilation
+          .chunk_by_ukey
+          .get(&async_chunk)
+          .ok_or_else(|| internal_error!("chunk not found"))?;
+        chunk_mapping.push((chunk.expect_id().to_string(), module_ids));
+      }
+    }
+
+    for request in initial_remotes {
+      compilation.push_diagnostic(Diagnostic::error(
+        "Module Federation".to_string(),
+        format!(
+          "Remote module \"{request}\" can't be loaded by an initial chunk, import it behind an `import()` boundary"
+        ),
+        0,
+        0,
+      ));
+    }
+    if chunk_mapping.is_empty() {
+      return Ok(());
+    }
+    chunk_mapping.sort_unstable();
+    id_to_external_and_name_mapping.sort_unstable();
+    id_to_external_and_name_mapping.dedup();
+    args
+      .runtime_requirements
+      .insert(RuntimeGlobals::MODULE_FACTORIES | RuntimeGlobals::HAS_OWN_PROPERTY);
+    compilation.add_runtime_module(
+      args.chunk,
+      Box::new(RemotesLoadingRuntimeModule::new(
+        chunk_mapping,
+        id_to_external_and_name_mapping,
+      )),
+    );
+    Ok(())
+  }
+}
@@ -0,0 +1,137 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use rspack_core::{
+  rspack_sources::Source, BuildContext, BuildResult, CodeGenerationResult, Compilation, Context,
+  LibIdentOptions, Module, ModuleType, RuntimeGlobals, SourceType,
+};
+use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_identifier::{Identifiable, Identifier};
+
+use super::RemoteToExternalDependency;
+
+static REMOTE_MODULE_SOURCE_TYPES: &[SourceType] = &[SourceType::Remote];
+
+/// A module provided by a remote container, its factory is installed at runtime
+/// by `__webpack_require__.f.remotes` when the chunk containing it is loaded.
+#[derive(Debug)]
+pub struct RemoteModule {
+  id: Identifier,
+  /// Request of the remote module, e.g. `app/Button`
+  request: String,
+  /// Request of the external module of the container
+  external_request: String,
+  /// Request of the module in the container, e.g. `./Button`
+  internal_request: String,
+  share_scope: String,
+}
+
+impl RemoteModule {
+  pub fn new(
+    request: String,
+    external_request: String,
+    internal_request: String,
+    share_scope: String,
+  ) -> Self {
+    Self {
+      id: Identifier::from(format!(
+        "remote ({share_scope}) {external_request} {internal_request}"
+      )),
+      request,
+      external_request,
+      internal_request,
+      share_scope,
+    }
+  }
+
+  pub fn request(&self) -> &str {
+    &self.request
+  }
+
+  pub fn internal_request(&self) -> &str {
+    &self.internal_request
+  }
+
+  pub fn share_scope(&self) -> &str {
+    &self.share_scope
+  }
+}
+
+impl Identifiable for RemoteModule {
+  fn identifier(&self) -> Identifier {
+    self.id
+  }
+}
+
+#[async_trait::async_trait]
+impl Module for RemoteModule {
+  fn module_type(&self) -> &ModuleType {
+    &ModuleType::Js
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    REMOTE_MODULE_SOURCE_TYPES
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    None
+  }
+
+  fn readable_identifier(&self, _context: &Context) -> Cow<str> {
+    Cow::Owned(format!("remote {}", self.request))
+  }
+
+  fn size(&self, _source_type: &SourceType) -> f64 {
+    6.0
+  }
+
+  async fn build(
+    &mut self,
+    _build_context: BuildContext<'_>,
+  ) -> Result<TWithDiagnosticArray<BuildResult>> {
+    Ok(
+      BuildResult {
+        dependencies: vec![Box::new(RemoteToExternalDependency::new(
+          self.external_request.clone(),
+        ))],
+        ..Default::default()
+      }
+      .with_empty_diagnostic(),
+    )
+  }
+
+  fn code_generation(&self, _compilation: &Compilation) -> Result<CodeGenerationResult> {
+    let mut cgr = CodeGenerationResult::default();
+    cgr.runtime_requirements.insert(
+      RuntimeGlobals::MODULE_FACTORIES
+        | RuntimeGlobals::HAS_OWN_PROPERTY
+        | RuntimeGlobals::INITIALIZE_SHARING
+        | RuntimeGlobals::SHARE_SCOPE_MAP
+        | RuntimeGlobals::CURRENT_REMOTE_GET_SCOPE
+        | RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
+    );
+    Ok(cgr)
+  }
+
+  fn lib_ident(&self, _options: LibIdentOptions) -> Option<Cow<str>> {
+    Some(Cow::Owned(format!(
+      "webpack/container/remote/{}",
+      self.request
+    )))
+  }
+}
+
+impl Hash for RemoteModule {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    "__rspack_internal__RemoteModule".hash(state);
+    self.identifier().hash(state);
+  }
+}
+
+impl PartialEq for RemoteModule {
+  fn eq(&self, other: &Self) -> bool {
+    self.identifier() == other.identifier()
+  }
+}
+
+impl Eq for RemoteModule {}
@@ -0,0 +1,60 @@
+use rspack_core::{
+  CodeGeneratable, CodeGeneratableContext, CodeGeneratableResult, Dependency, DependencyCategory,
+  DependencyId, DependencyType, ErrorSpan, ModuleDependency, ModuleIdentifier,
+};
+use rspack_error::Result;
+
+/// Dependency from a remote module to the external module of its container.
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub struct RemoteToExternalDependency {
+  id: Option<DependencyId>,
+  request: String,
+}
+
+impl RemoteToExternalDependency {
+  pub fn new(request: String) -> Self {
+    Self { id: None, request }
+  }
+}
+
+impl Dependency for RemoteToExternalDependency {
+  fn parent_module_identifier(&self) -> Option<&ModuleIdentifier> {
+    None
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::RemoteToExternal
+  }
+
+  fn id(&self) -> Option<DependencyId> {
+    self.id
+  }
+
+  fn set_id(&mut self, id: Option<DependencyId>) {
+    self.id = id;
+  }
+}
+
+impl ModuleDependency for RemoteToExternalDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    None
+  }
+}
+
+impl CodeGeneratable for RemoteToExternalDependency {
+  fn generate(&self, _context: &mut CodeGeneratableContext) -> Result<CodeGeneratableResult> {
+    Ok(CodeGeneratableResult::default())
+  }
+}
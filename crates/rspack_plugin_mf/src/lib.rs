@@ -0,0 +1,5 @@
+mod container;
+pub use container::*;
+mod sharing;
+pub use sharing::*;
+mod utils;
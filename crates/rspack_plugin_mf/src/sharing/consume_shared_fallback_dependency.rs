@@ -0,0 +1,72 @@
+use rspack_core::{
+  ChunkGroupOptions, CodeGeneratable, CodeGeneratableContext, CodeGeneratableResult, Dependency,
+  DependencyCategory, DependencyId, DependencyType, ErrorSpan, ModuleDependency, ModuleIdentifier,
+};
+use rspack_error::Result;
+
+/// Dependency from a consumed shared module to its fallback,
+/// which is used when no satisfying version is found in the share scope.
+///
+/// The fallback is split into an async chunk, unless the shared module is consumed eagerly.
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub struct ConsumeSharedFallbackDependency {
+  id: Option<DependencyId>,
+  request: String,
+  group_options: Option<ChunkGroupOptions>,
+}
+
+impl ConsumeSharedFallbackDependency {
+  pub fn new(request: String, eager: bool) -> Self {
+    Self {
+      id: None,
+      request,
+      group_options: (!eager).then(Default::default),
+    }
+  }
+}
+
+impl Dependency for ConsumeSharedFallbackDependency {
+  fn parent_module_identifier(&self) -> Option<&ModuleIdentifier> {
+    None
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::ConsumeSharedFallback
+  }
+
+  fn id(&self) -> Option<DependencyId> {
+    self.id
+  }
+
+  fn set_id(&mut self, id: Option<DependencyId>) {
+    self.id = id;
+  }
+}
+
+impl ModuleDependency for ConsumeSharedFallbackDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    None
+  }
+
+  fn group_options(&self) -> Option<&ChunkGroupOptions> {
+    self.group_options.as_ref()
+  }
+}
+
+impl CodeGeneratable for ConsumeSharedFallbackDependency {
+  fn generate(&self, _context: &mut CodeGeneratableContext) -> Result<CodeGeneratableResult> {
+    Ok(CodeGeneratableResult::default())
+  }
+}
@@ -0,0 +1,162 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use rspack_core::{
+  rspack_sources::Source, BuildContext, BuildResult, CodeGenerationResult, Compilation, Context,
+  LibIdentOptions, Module, ModuleType, RuntimeGlobals, SourceType,
+};
+use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_identifier::{Identifiable, Identifier};
+
+use super::{ConsumeSharedFallbackDependency, SharedOptions};
+
+static CONSUME_SHARED_MODULE_SOURCE_TYPES: &[SourceType] = &[SourceType::ConsumeShared];
+
+/// A module consumed from the share scope, its factory is installed at runtime
+/// by `__webpack_require__.f.consumes` when the chunk containing it is loaded.
+///
+/// The fallback module is also provided to the share scope by this module.
+#[derive(Debug)]
+pub struct ConsumeSharedModule {
+  id: Identifier,
+  options: SharedOptions,
+}
+
+impl ConsumeSharedModule {
+  pub fn new(options: SharedOptions) -> Self {
+    Self {
+      id: Identifier::from(format!(
+        "consume shared module ({}) {}@{}{}{}{} (fallback: {})",
+        options.share_scope,
+        options.share_key,
+        options.required_version.as_deref().unwrap_or("*"),
+        if options.singleton {
+          " (singleton)"
+        } else {
+          ""
+        },
+        if options.strict_version {
+          " (strict)"
+        } else {
+          ""
+        },
+        if options.eager { " (eager)" } else { "" },
+        options.import
+      )),
+      options,
+    }
+  }
+
+  pub fn options(&self) -> &SharedOptions {
+    &self.options
+  }
+
+  /// Version provided to the share scope, which is the configured `version`
+  /// or the version in the package.json of the fallback module.
+  pub fn provided_version(&self, compilation: &Compilation) -> String {
+    if let Some(version) = &self.options.version {
+      return version.clone();
+    }
+    let module_graph = &compilation.module_graph;
+    module_graph
+      .module_graph_module_by_identifier(&self.id)
+      .and_then(|mgm| mgm.dependencies.first())
+      .and_then(|dep| module_graph.module_identifier_by_dependency_id(dep))
+      .and_then(|fallback| module_graph.module_by_identifier(fallback))
+      .and_then(|fallback| fallback.as_normal_module())
+      .and_then(|fallback| {
+        fallback
+          .resource_resolved_data()
+          .resource_description
+          .as_ref()
+      })
+      .and_then(|description| description.data().raw().get("version"))
+      .and_then(|version| version.as_str())
+      .unwrap_or("0")
+      .to_string()
+  }
+}
+
+impl Identifiable for ConsumeSharedModule {
+  fn identifier(&self) -> Identifier {
+    self.id
+  }
+}
+
+#[async_trait::async_trait]
+impl Module for ConsumeSharedModule {
+  fn module_type(&self) -> &ModuleType {
+    &ModuleType::Js
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    CONSUME_SHARED_MODULE_SOURCE_TYPES
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    None
+  }
+
+  fn readable_identifier(&self, _context: &Context) -> Cow<str> {
+    Cow::Owned(format!(
+      "consume shared module ({}) {}@{}",
+      self.options.share_scope,
+      self.options.share_key,
+      self.options.required_version.as_deref().unwrap_or("*")
+    ))
+  }
+
+  fn size(&self, _source_type: &SourceType) -> f64 {
+    42.0
+  }
+
+  async fn build(
+    &mut self,
+    _build_context: BuildContext<'_>,
+  ) -> Result<TWithDiagnosticArray<BuildResult>> {
+    Ok(
+      BuildResult {
+        dependencies: vec![Box::new(ConsumeSharedFallbackDependency::new(
+          self.options.import.clone(),
+          self.options.eager,
+        ))],
+        ..Default::default()
+      }
+      .with_empty_diagnostic(),
+    )
+  }
+
+  fn code_generation(&self, _compilation: &Compilation) -> Result<CodeGenerationResult> {
+    let mut cgr = CodeGenerationResult::default();
+    cgr.runtime_requirements.insert(
+      RuntimeGlobals::SHARE_SCOPE_MAP
+        | RuntimeGlobals::INITIALIZE_SHARING
+        | RuntimeGlobals::HAS_OWN_PROPERTY
+        | RuntimeGlobals::MODULE_FACTORIES
+        | RuntimeGlobals::LOAD_CHUNK_WITH_MODULE,
+    );
+    Ok(cgr)
+  }
+
+  fn lib_ident(&self, _options: LibIdentOptions) -> Option<Cow<str>> {
+    Some(Cow::Owned(format!(
+      "webpack/sharing/consume/{}/{}",
+      self.options.share_scope, self.options.share_key
+    )))
+  }
+}
+
+impl Hash for ConsumeSharedModule {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    "__rspack_internal__ConsumeSharedModule".hash(state);
+    self.identifier().hash(state);
+  }
+}
+
+impl PartialEq for ConsumeSharedModule {
+  fn eq(&self, other: &Self) -> bool {
+    self.identifier() == other.identifier()
+  }
+}
+
+impl Eq for ConsumeSharedModule {}
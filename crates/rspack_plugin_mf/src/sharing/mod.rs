@@ -0,0 +1,9 @@
+mod consume_shared_fallback_dependency;
+mod consume_shared_module;
+mod share_plugin;
+mod share_runtime_plugin;
+
+pub use consume_shared_fallback_dependency::ConsumeSharedFallbackDependency;
+pub use consume_shared_module::ConsumeSharedModule;
+pub use share_plugin::{SharePlugin, SharedOptions};
+pub use share_runtime_plugin::ShareRuntimePlugin;
@@ -0,0 +1,233 @@
+use std::{collections::BTreeMap, path::Path};
+
+use rspack_core::{
+  AdditionalChunkRuntimeRequirementsArgs, ApplyContext, DependencyType, FactorizeArgs, ModuleExt,
+  ModuleFactoryResult, NormalModuleFactoryContext, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, PluginFactorizeHookOutput,
+  RuntimeGlobals, SourceType,
+};
+use rspack_error::{internal_error, Result};
+use rspack_plugin_runtime::ConsumesLoadingRuntimeModule;
+
+use super::ConsumeSharedModule;
+use crate::utils::{async_module_factory, json_string, sync_module_factory};
+
+#[derive(Debug, Clone, Hash)]
+pub struct SharedOptions {
+  /// Request of the fallback module, which is also provided to the share scope
+  pub import: String,
+  pub share_key: String,
+  pub share_scope: String,
+  /// Semver range of the consumed version, defaults to the range in the nearest package.json
+  /// of the consuming module, versions are not checked if neither is found
+  pub required_version: Option<String>,
+  /// Provided version, defaults to the version in the package.json of the fallback module
+  pub version: Option<String>,
+  pub singleton: bool,
+  pub strict_version: bool,
+  /// The fallback is bundled into the chunk of the consuming module instead of an async chunk,
+  /// so the shared module can be consumed synchronously, e.g. in an entry chunk
+  pub eager: bool,
+}
+
+/// Same as webpack's `SharePlugin`, requests of shared modules are consumed from the share scope
+/// and fall back to the local module, which is provided to the share scope as well.
+///
+/// Keys ending with `/` match all requests with the prefix.
+/// Shared modules which aren't `eager` are loaded asynchronously,
+/// so they can't be consumed by initial chunks without an `import()` boundary.
+#[derive(Debug)]
+pub struct SharePlugin {
+  shared: Vec<(String, SharedOptions)>,
+}
+
+impl SharePlugin {
+  pub fn new(shared: Vec<(String, SharedOptions)>) -> Self {
+    Self { shared }
+  }
+
+  fn resolve_options(&self, request: &str) -> Option<SharedOptions> {
+    if let Some((_, options)) = self.shared.iter().find(|(key, _)| key == request) {
+      return Some(options.clone());
+    }
+    self.shared.iter().find_map(|(key, options)| {
+      if !key.ends_with('/') {
+        return None;
+      }
+      let remainder = request.strip_prefix(key.as_str())?;
+      Some(SharedOptions {
+        import: format!("{}{remainder}", options.import),
+        share_key: format!("{}{remainder}", options.share_key),
+        ..options.clone()
+      })
+    })
+  }
+}
+
+/// Range of the package of `request` in the nearest package.json of `issuer`,
+/// same as `getRequiredVersionFromDescriptionFile` of webpack.
+fn required_version_from_description_file(issuer: &str, request: &str) -> Option<String> {
+  // relative and absolute requests don't belong to a package
+  if request.starts_with('.') || Path::new(request).is_absolute() {
+    return None;
+  }
+  let package_name = match request.split('/').collect::<Vec<_>>()[..] {
+    [scope, name, ..] if scope.starts_with('@') => format!("{scope}/{name}"),
+    [name, ..] => name.to_string(),
+    _ => return None,
+  };
+  let description = Path::new(issuer)
+    .ancestors()
+    .skip(1)
+    .find_map(|dir| std::fs::read_to_string(dir.join("package.json")).ok())?;
+  let description: serde_json::Value = serde_json::from_str(&description).ok()?;
+  [
+    "optionalDependencies",
+    "dependencies",
+    "peerDependencies",
+    "devDependencies",
+  ]
+  .into_iter()
+  .find_map(|field| description.get(field)?.get(&package_name)?.as_str())
+  .map(ToString::to_string)
+}
+
+#[async_trait::async_trait]
+impl Plugin for SharePlugin {
+  fn name(&self) -> &'static str {
+    "SharePlugin"
+  }
+
+  fn apply(&mut self, _ctx: PluginContext<&mut ApplyContext>) -> Result<()> {
+    Ok(())
+  }
+
+  async fn factorize(
+    &self,
+    _ctx: PluginContext,
+    args: FactorizeArgs<'_>,
+    job_ctx: &mut NormalModuleFactoryContext,
+  ) -> PluginFactorizeHookOutput {
+    if matches!(
+      args.dependency.dependency_type(),
+      DependencyType::Entry | DependencyType::ConsumeSharedFallback
+    ) {
+      return Ok(None);
+    }
+    let request = args.dependency.request();
+    let Some(mut options) = self.resolve_options(request) else {
+      return Ok(None);
+    };
+    if options.required_version.is_none() {
+      options.required_version = job_ctx
+        .issuer
+        .as_deref()
+        .and_then(|issuer| required_version_from_description_file(issuer, request));
+    }
+    Ok(Some(ModuleFactoryResult::new(
+      ConsumeSharedModule::new(options).boxed(),
+    )))
+  }
+
+  fn additional_tree_runtime_requirements(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let compilation = &mut args.compilation;
+    let chunk = compilation
+      .chunk_by_ukey
+      .get(args.chunk)
+      .ok_or_else(|| internal_error!("chunk not found"))?;
+    let initial_chunks = chunk.get_all_initial_chunks(&compilation.chunk_group_by_ukey);
+    let async_chunks = chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey);
+
+    let mut module_to_handler_mapping = BTreeMap::new();
+    let mut initial_consumes = vec![];
+    let mut chunk_mapping = vec![];
+    for (chunk_ukey, is_initial) in initial_chunks
+      .iter()
+      .map(|ukey| (ukey, true))
+      .chain(async_chunks.iter().map(|ukey| (ukey, false)))
+    {
+      let mut module_ids = vec![];
+      for mgm in compilation.chunk_graph.get_chunk_modules_by_source_type(
+        chunk_ukey,
+        SourceType::ConsumeShared,
+        &compilation.module_graph,
+      ) {
+        let Some(module) = compilation
+          .module_graph
+          .module_by_identifier(&mgm.module_identifier)
+          .and_then(|module| module.downcast_ref::<ConsumeSharedModule>()) else {
+          continue;
+        };
+        let Some(fallback_id) = mgm
+          .dependencies
+          .first()
+          .and_then(|dep| compilation.module_graph.module_identifier_by_dependency_id(dep))
+          .and_then(|fallback| compilation.chunk_graph.get_module_id(*fallback).as_deref()) else {
+          continue;
+        };
+        let options = module.options();
+        let id = mgm.id(&compilation.chunk_graph).to_string();
+        let fallback = if options.eager {
+          sync_module_factory(fallback_id)
+        } else {
+          async_module_factory(&[fallback_id])
+        };
+        module_to_handler_mapping.insert(
+          id.clone(),
+          format!(
+            "function() {{ return consume({}, {}, {}, {}, {}, {}); }}",
+            json_string(&options.share_scope),
+            json_string(&options.share_key),
+            options
+              .required_version
+              .as_deref()
+              .map_or_else(|| "null".to_string(), json_string),
+            options.singleton,
+            options.strict_version,
+            fallback
+          ),
+        );
+        module_ids.push(id);
+      }
+      if module_ids.is_empty() {
+        continue;
+      }
+      module_ids.sort_unstable();
+      if is_initial {
+        initial_consumes.extend(module_ids);
+      } else {
+        let chunk = compilation
+          .chunk_by_ukey
+          .get(chunk_ukey)
+          .ok_or_else(|| internal_error!("chunk not found"))?;
+        chunk_mapping.push((chunk.expect_id().to_string(), module_ids));
+      }
+    }
+
+    if module_to_handler_mapping.is_empty() {
+      return Ok(());
+    }
+    chunk_mapping.sort_unstable();
+    initial_consumes.sort_unstable();
+    initial_consumes.dedup();
+    args.runtime_requirements.insert(
+      RuntimeGlobals::MODULE_FACTORIES
+        | RuntimeGlobals::HAS_OWN_PROPERTY
+        | RuntimeGlobals::SHARE_SCOPE_MAP
+        | RuntimeGlobals::INITIALIZE_SHARING,
+    );
+    compilation.add_runtime_module(
+      args.chunk,
+      Box::new(ConsumesLoadingRuntimeModule::new(
+        module_to_handler_mapping.into_iter().collect(),
+        chunk_mapping,
+        initial_consumes,
+      )),
+    );
+    Ok(())
+  }
+}
@@ -0,0 +1,121 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use rspack_core::{
+  AdditionalChunkRuntimeRequirementsArgs, ApplyContext, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, RuntimeGlobals, RuntimeModuleExt,
+};
+use rspack_error::{internal_error, Result};
+use rspack_plugin_runtime::SharingRuntimeModule;
+
+use super::ConsumeSharedModule;
+use crate::utils::{async_module_factory, json_string, sync_module_factory};
+use crate::RemoteModule;
+
+/// Adds the runtime of share scopes, which is required by both containers and shared modules.
+#[derive(Debug, Default)]
+pub struct ShareRuntimePlugin {}
+
+#[async_trait::async_trait]
+impl Plugin for ShareRuntimePlugin {
+  fn name(&self) -> &'static str {
+    "ShareRuntimePlugin"
+  }
+
+  fn apply(&mut self, _ctx: PluginContext<&mut ApplyContext>) -> Result<()> {
+    Ok(())
+  }
+
+  fn additional_tree_runtime_requirements(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    if !args
+      .runtime_requirements
+      .contains(RuntimeGlobals::INITIALIZE_SHARING)
+    {
+      return Ok(());
+    }
+    let compilation = &mut args.compilation;
+    let chunk = compilation
+      .chunk_by_ukey
+      .get(args.chunk)
+      .ok_or_else(|| internal_error!("chunk not found"))?;
+
+    // share scope -> (registered shared modules, initialized containers)
+    let mut init_code: BTreeMap<String, (BTreeSet<String>, BTreeSet<String>)> = BTreeMap::new();
+    for chunk_ukey in chunk.get_all_referenced_chunks(&compilation.chunk_group_by_ukey) {
+      for module in compilation
+        .chunk_graph
+        .get_chunk_modules(&chunk_ukey, &compilation.module_graph)
+      {
+        let dependency_module_id = || {
+          compilation
+            .module_graph
+            .module_graph_module_by_identifier(&module.identifier())
+            .and_then(|mgm| mgm.dependencies.first())
+            .and_then(|dep| {
+              compilation
+                .module_graph
+                .module_identifier_by_dependency_id(dep)
+            })
+            .and_then(|module| compilation.chunk_graph.get_module_id(*module).as_deref())
+        };
+        if let Some(module) = module.downcast_ref::<ConsumeSharedModule>() {
+          let Some(fallback_id) = dependency_module_id() else {
+            continue;
+          };
+          let options = module.options();
+          init_code
+            .entry(options.share_scope.clone())
+            .or_default()
+            .0
+            .insert(if options.eager {
+              format!(
+                "register({}, {}, {}, 1);",
+                json_string(&options.share_key),
+                json_string(&module.provided_version(compilation)),
+                sync_module_factory(fallback_id)
+              )
+            } else {
+              format!(
+                "register({}, {}, {});",
+                json_string(&options.share_key),
+                json_string(&module.provided_version(compilation)),
+                async_module_factory(&[fallback_id])
+              )
+            });
+        } else if let Some(module) = module.downcast_ref::<RemoteModule>() {
+          let Some(external_id) = dependency_module_id() else {
+            continue;
+          };
+          init_code
+            .entry(module.share_scope().to_string())
+            .or_default()
+            .1
+            .insert(format!("initExternal({});", json_string(external_id)));
+        }
+      }
+    }
+
+    args
+      .runtime_requirements
+      .insert(RuntimeGlobals::SHARE_SCOPE_MAP | RuntimeGlobals::HAS_OWN_PROPERTY);
+    compilation.add_runtime_module(
+      args.chunk,
+      SharingRuntimeModule::new(
+        init_code
+          .into_iter()
+          .map(|(share_scope, (registers, externals))| {
+            (
+              share_scope,
+              registers.into_iter().chain(externals).collect(),
+            )
+          })
+          .collect(),
+      )
+      .boxed(),
+    );
+    Ok(())
+  }
+}
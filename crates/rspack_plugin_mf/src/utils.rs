@@ -0,0 +1,32 @@
+use rspack_core::RuntimeGlobals;
+
+/// Render a function which loads the chunks of all `module_ids`,
+/// and resolves to the factory of the last one.
+pub(crate) fn async_module_factory(module_ids: &[&str]) -> String {
+  let loads = module_ids
+    .iter()
+    .map(|id| format!("{}(\"{id}\")", RuntimeGlobals::LOAD_CHUNK_WITH_MODULE))
+    .collect::<Vec<_>>()
+    .join(", ");
+  let factory = match module_ids.last() {
+    Some(id) => format!("return {}(\"{id}\");", RuntimeGlobals::REQUIRE),
+    None => String::new(),
+  };
+  format!(
+    "function() {{ return Promise.all([{loads}]).then(function() {{ return function() {{ {factory} }}; }}); }}"
+  )
+}
+
+/// Render a function which returns the factory of the module synchronously,
+/// the module must be in a chunk which is already loaded.
+pub(crate) fn sync_module_factory(module_id: &str) -> String {
+  format!(
+    "function() {{ return function() {{ return {}(\"{module_id}\"); }}; }}",
+    RuntimeGlobals::REQUIRE
+  )
+}
+
+/// Module ids are rendered as string literals in runtime code.
+pub(crate) fn json_string(value: &str) -> String {
+  serde_json::to_string(value).unwrap_or_else(|_| format!("\"{value}\""))
+}
@@ -0,0 +1,249 @@
+use async_trait::async_trait;
+use regex::bytes::{Captures, Regex};
+use rspack_core::{
+  rspack_sources::{RawSource, ReplaceSource, SourceExt},
+  Plugin, PluginContext, PluginProcessAssetsOutput, ProcessAssetsArgs,
+};
+use rspack_error::{internal_error, Result};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use xxhash_rust::xxh3::Xxh3;
+
+#[derive(Debug)]
+struct AssetData {
+  content: Vec<u8>,
+  // Hashes of the asset itself, which appear in the content, e.g. the `sourceMappingURL` comment.
+  own_hashes: HashSet<String>,
+  // Hashes of other assets referenced by the content, e.g. the chunk filename map of the runtime.
+  referenced_hashes: HashSet<String>,
+}
+
+/// Recomputes `[contenthash]` from the final content of assets, after all the assets are processed,
+/// then updates the references between assets and renames the assets.
+#[derive(Debug, Default)]
+pub struct RealContentHashPlugin;
+
+#[async_trait]
+impl Plugin for RealContentHashPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.RealContentHashPlugin"
+  }
+
+  async fn process_assets_stage_optimize_hash(
+    &mut self,
+    _ctx: PluginContext,
+    args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
+    let compilation = args.compilation;
+
+    let mut hash_to_asset_names: HashMap<String, Vec<String>> = HashMap::default();
+    for (name, asset) in &compilation.assets {
+      for hash in &asset.info.content_hash {
+        hash_to_asset_names
+          .entry(hash.clone())
+          .or_default()
+          .push(name.clone());
+      }
+    }
+    if hash_to_asset_names.is_empty() {
+      return Ok(());
+    }
+
+    let mut hashes = hash_to_asset_names.keys().collect::<Vec<_>>();
+    // Match the longer hashes first, in case one hash is the prefix of another one.
+    hashes.sort_unstable_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+    let hash_regex = Regex::new(
+      &hashes
+        .into_iter()
+        .map(|hash| regex::escape(hash))
+        .collect::<Vec<_>>()
+        .join("|"),
+    )
+    .map_err(|e| internal_error!(e.to_string()))?;
+
+    let assets_data = compilation
+      .assets
+      .iter()
+      .filter_map(|(name, asset)| {
+        let content = asset.get_source()?.buffer().to_vec();
+        let mut own_hashes = HashSet::default();
+        let mut referenced_hashes = HashSet::default();
+        for hash in hash_regex.find_iter(&content) {
+          let hash = String::from_utf8_lossy(hash.as_bytes()).to_string();
+          if asset.info.content_hash.contains(&hash) {
+            own_hashes.insert(hash);
+          } else {
+            referenced_hashes.insert(hash);
+          }
+        }
+        Some((
+          name.clone(),
+          AssetData {
+            content,
+            own_hashes,
+            referenced_hashes,
+          },
+        ))
+      })
+      .collect::<HashMap<_, _>>();
+
+    let mut old_hashes = hash_to_asset_names.keys().cloned().collect::<Vec<_>>();
+    old_hashes.sort_unstable();
+    let mut new_hashes = HashMap::default();
+    for hash in &old_hashes {
+      compute_new_hash(
+        hash,
+        &hash_to_asset_names,
+        &assets_data,
+        &hash_regex,
+        &mut new_hashes,
+        &mut vec![],
+      )?;
+    }
+
+    for (name, data) in &assets_data {
+      if data.own_hashes.is_empty() && data.referenced_hashes.is_empty() {
+        continue;
+      }
+      compilation.update_asset(name, |source, _| {
+        *source = if std::str::from_utf8(&data.content).is_ok() {
+          // Replace the hashes in place to keep the source map of the asset
+          let mut replace_source = ReplaceSource::new(source.clone());
+          for hash in hash_regex.find_iter(&data.content) {
+            let old_hash = String::from_utf8_lossy(hash.as_bytes());
+            if let Some(new_hash) = new_hashes.get(old_hash.as_ref()) {
+              replace_source.replace(hash.start() as u32, hash.end() as u32, new_hash, None);
+            }
+          }
+          replace_source.boxed()
+        } else {
+          RawSource::Buffer(replace_hashes(&data.content, &hash_regex, |hash| {
+            new_hashes.get(hash).map(String::as_str)
+          }))
+          .boxed()
+        };
+        Ok(())
+      })?;
+    }
+
+    let replace_name = |name: &str| {
+      String::from_utf8_lossy(&replace_hashes(name.as_bytes(), &hash_regex, |hash| {
+        new_hashes.get(hash).map(String::as_str)
+      }))
+      .to_string()
+    };
+    let mut asset_names = compilation.assets.keys().cloned().collect::<Vec<_>>();
+    asset_names.sort_unstable();
+    for name in asset_names {
+      if let Some(asset) = compilation.assets.get_mut(&name) {
+        let info = &mut asset.info;
+        if !info.content_hash.is_empty() {
+          info.content_hash = info
+            .content_hash
+            .iter()
+            .map(|hash| new_hashes.get(hash).unwrap_or(hash).clone())
+            .collect();
+        }
+        if let Some(source_map) = &info.related.source_map {
+          info.related.source_map = Some(replace_name(source_map));
+        }
+      }
+      let new_name = replace_name(&name);
+      if new_name != name {
+        compilation.rename_asset(&name, new_name);
+      }
+    }
+
+    Ok(())
+  }
+}
+
+fn compute_new_hash(
+  hash: &str,
+  hash_to_asset_names: &HashMap<String, Vec<String>>,
+  assets_data: &HashMap<String, AssetData>,
+  hash_regex: &Regex,
+  new_hashes: &mut HashMap<String, String>,
+  stack: &mut Vec<String>,
+) -> Result<()> {
+  if new_hashes.contains_key(hash) {
+    return Ok(());
+  }
+  if stack.iter().any(|h| h == hash) {
+    return Err(internal_error!(
+      "RealContentHashPlugin\nCircular hash dependency {} -> {hash}",
+      stack.join(" -> ")
+    ));
+  }
+  stack.push(hash.to_string());
+
+  let mut asset_names = hash_to_asset_names
+    .get(hash)
+    .map(|names| names.iter().collect::<Vec<_>>())
+    .unwrap_or_default();
+  asset_names.sort_unstable();
+
+  let assets = asset_names
+    .into_iter()
+    .filter_map(|name| assets_data.get(name))
+    .collect::<Vec<_>>();
+  for asset in &assets {
+    let mut referenced_hashes = asset.referenced_hashes.iter().collect::<Vec<_>>();
+    referenced_hashes.sort_unstable();
+    for referenced_hash in referenced_hashes {
+      compute_new_hash(
+        referenced_hash,
+        hash_to_asset_names,
+        assets_data,
+        hash_regex,
+        new_hashes,
+        stack,
+      )?;
+    }
+  }
+
+  let contents = assets
+    .into_iter()
+    .map(|asset| {
+      // Own hashes are stripped, so the new hash is not affected by the old one.
+      replace_hashes(&asset.content, hash_regex, |hash| {
+        if asset.own_hashes.contains(hash) {
+          Some("")
+        } else {
+          new_hashes.get(hash).map(String::as_str)
+        }
+      })
+    })
+    .collect::<Vec<_>>();
+  // A digest has 16 hex digits, digests with the following seeds are appended for longer hashes,
+  // e.g. `[contenthash:20]` with the content hash provided by plugins.
+  let mut new_hash = String::with_capacity(hash.len());
+  let mut seed = 0;
+  while new_hash.len() < hash.len() {
+    let mut hasher = Xxh3::with_seed(seed);
+    for content in &contents {
+      hasher.update(content);
+    }
+    new_hash.push_str(&format!("{:016x}", hasher.digest()));
+    seed += 1;
+  }
+  new_hash.truncate(hash.len());
+  new_hashes.insert(hash.to_string(), new_hash);
+
+  stack.pop();
+  Ok(())
+}
+
+fn replace_hashes<'a>(
+  content: &[u8],
+  hash_regex: &Regex,
+  get_new_hash: impl Fn(&str) -> Option<&'a str>,
+) -> Vec<u8> {
+  hash_regex
+    .replace_all(content, |caps: &Captures| {
+      let hash = String::from_utf8_lossy(&caps[0]).to_string();
+      get_new_hash(&hash)
+        .map(|new_hash| new_hash.as_bytes().to_vec())
+        .unwrap_or_else(|| caps[0].to_vec())
+    })
+    .into_owned()
+}
@@ -0,0 +1,120 @@
+use async_trait::async_trait;
+use rspack_core::{
+  AdditionalChunkRuntimeRequirementsArgs, ChunkGroupOrderKey, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, RuntimeGlobals, RuntimeModuleExt,
+};
+
+use crate::runtime_module::{
+  ChunkPrefetchPreloadFunctionRuntimeModule, ChunkPrefetchStartupRuntimeModule,
+  ChunkPrefetchTriggerRuntimeModule, ChunkPreloadTriggerRuntimeModule,
+};
+
+/// Prefetch or preload the child chunk groups created by
+/// `import(/* webpackPrefetch: true */ ...)` and `import(/* webpackPreload: true */ ...)`
+#[derive(Debug)]
+pub struct ChunkPrefetchPreloadPlugin {}
+
+#[async_trait]
+impl Plugin for ChunkPrefetchPreloadPlugin {
+  fn name(&self) -> &'static str {
+    "ChunkPrefetchPreloadPlugin"
+  }
+
+  fn additional_chunk_runtime_requirements(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let compilation = &mut args.compilation;
+    let chunk_ukey = args.chunk;
+    let runtime_requirements = &mut args.runtime_requirements;
+
+    if compilation
+      .chunk_graph
+      .get_number_of_entry_modules(chunk_ukey)
+      == 0
+    {
+      return Ok(());
+    }
+
+    let Some(chunk) = compilation.chunk_by_ukey.get(chunk_ukey) else {
+      return Ok(());
+    };
+    if let Some(startup_chunk_ids) = chunk.get_child_ids_by_order(
+      &ChunkGroupOrderKey::Prefetch,
+      &compilation.chunk_group_by_ukey,
+      &compilation.chunk_by_ukey,
+    ) {
+      runtime_requirements.insert(RuntimeGlobals::PREFETCH_CHUNK);
+      compilation.add_runtime_module(
+        chunk_ukey,
+        ChunkPrefetchStartupRuntimeModule::new(startup_chunk_ids).boxed(),
+      );
+    }
+
+    Ok(())
+  }
+
+  fn additional_tree_runtime_requirements(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let compilation = &mut args.compilation;
+    let chunk_ukey = args.chunk;
+    let runtime_requirements = &mut args.runtime_requirements;
+
+    let Some(chunk) = compilation.chunk_by_ukey.get(chunk_ukey) else {
+      return Ok(());
+    };
+    let mut chunk_map = chunk.get_child_ids_by_orders_map(
+      false,
+      &compilation.chunk_group_by_ukey,
+      &compilation.chunk_by_ukey,
+    );
+
+    if let Some(prefetch_map) = chunk_map.remove(&ChunkGroupOrderKey::Prefetch) {
+      runtime_requirements.insert(RuntimeGlobals::PREFETCH_CHUNK);
+      runtime_requirements.insert(RuntimeGlobals::ENSURE_CHUNK_HANDLERS);
+      compilation.add_runtime_module(
+        chunk_ukey,
+        ChunkPrefetchTriggerRuntimeModule::new(prefetch_map).boxed(),
+      );
+    }
+    if let Some(preload_map) = chunk_map.remove(&ChunkGroupOrderKey::Preload) {
+      runtime_requirements.insert(RuntimeGlobals::PRELOAD_CHUNK);
+      runtime_requirements.insert(RuntimeGlobals::ENSURE_CHUNK_HANDLERS);
+      compilation.add_runtime_module(
+        chunk_ukey,
+        ChunkPreloadTriggerRuntimeModule::new(preload_map).boxed(),
+      );
+    }
+
+    if runtime_requirements.contains(RuntimeGlobals::PREFETCH_CHUNK) {
+      runtime_requirements.insert(RuntimeGlobals::PREFETCH_CHUNK_HANDLERS);
+      compilation.add_runtime_module(
+        chunk_ukey,
+        ChunkPrefetchPreloadFunctionRuntimeModule::new(
+          "prefetch",
+          RuntimeGlobals::PREFETCH_CHUNK,
+          RuntimeGlobals::PREFETCH_CHUNK_HANDLERS,
+        )
+        .boxed(),
+      );
+    }
+    if runtime_requirements.contains(RuntimeGlobals::PRELOAD_CHUNK) {
+      runtime_requirements.insert(RuntimeGlobals::PRELOAD_CHUNK_HANDLERS);
+      compilation.add_runtime_module(
+        chunk_ukey,
+        ChunkPrefetchPreloadFunctionRuntimeModule::new(
+          "preload",
+          RuntimeGlobals::PRELOAD_CHUNK,
+          RuntimeGlobals::PRELOAD_CHUNK_HANDLERS,
+        )
+        .boxed(),
+      );
+    }
+
+    Ok(())
+  }
+}
@@ -1,11 +1,11 @@
 use async_trait::async_trait;
 use rspack_core::{
-  AdditionalChunkRuntimeRequirementsArgs, Plugin, PluginAdditionalChunkRuntimeRequirementsOutput,
-  PluginContext, RuntimeGlobals, RuntimeModuleExt,
+  AdditionalChunkRuntimeRequirementsArgs, ChunkLoadingType, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, RuntimeGlobals, RuntimeModuleExt,
 };
 use rspack_error::Result;
 
-use crate::runtime_module::RequireChunkLoadingRuntimeModule;
+use crate::{is_enabled_for_chunk, runtime_module::RequireChunkLoadingRuntimeModule};
 
 #[derive(Debug)]
 pub struct CommonJsChunkLoadingPlugin {}
@@ -28,6 +28,10 @@ impl Plugin for CommonJsChunkLoadingPlugin {
     _ctx: PluginContext,
     args: &mut AdditionalChunkRuntimeRequirementsArgs,
   ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    if !is_enabled_for_chunk(args.chunk, ChunkLoadingType::Require, args.compilation) {
+      return Ok(());
+    }
+
     let compilation = &mut args.compilation;
     let chunk = args.chunk;
     let runtime_requirements = &mut args.runtime_requirements;
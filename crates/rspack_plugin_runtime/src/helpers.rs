@@ -1,6 +1,8 @@
 use std::hash::Hash;
 
-use rspack_core::{ChunkGroupByUkey, ChunkGroupUkey, ChunkUkey, Compilation};
+use rspack_core::{
+  ChunkGroupByUkey, ChunkGroupUkey, ChunkLoadingType, ChunkUkey, Compilation, TargetPlatform,
+};
 use rspack_identifier::IdentifierLinkedMap;
 use rustc_hash::FxHashSet as HashSet;
 use xxhash_rust::xxh3::Xxh3;
@@ -88,3 +90,28 @@ pub fn get_all_chunks(
 
   chunks
 }
+
+/// Whether chunks of the runtime `chunk` are loaded with `chunk_loading`. The entrypoint could
+/// override the chunk loading of the target, e.g. workers use `output.workerChunkLoading`.
+pub fn is_enabled_for_chunk(
+  chunk: &ChunkUkey,
+  chunk_loading: ChunkLoadingType,
+  compilation: &Compilation,
+) -> bool {
+  let specified = compilation
+    .chunk_by_ukey
+    .get(chunk)
+    .and_then(|chunk| chunk.get_chunk_loading(&compilation.chunk_group_by_ukey));
+  let default = if compilation.options.output.module {
+    Some(ChunkLoadingType::Import)
+  } else {
+    match &compilation.options.target.platform {
+      TargetPlatform::Web => Some(ChunkLoadingType::Jsonp),
+      TargetPlatform::WebWorker => Some(ChunkLoadingType::ImportScripts),
+      TargetPlatform::Node(_) => Some(ChunkLoadingType::Require),
+      TargetPlatform::AsyncNode(_) => Some(ChunkLoadingType::AsyncNode),
+      TargetPlatform::None => None,
+    }
+  };
+  specified.or(default) == Some(chunk_loading)
+}
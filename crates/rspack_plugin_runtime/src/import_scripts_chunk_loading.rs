@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use rspack_core::{
+  AdditionalChunkRuntimeRequirementsArgs, ChunkLoadingType, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, RuntimeGlobals, RuntimeModuleExt,
+};
+use rspack_error::Result;
+
+use crate::{is_enabled_for_chunk, runtime_module::ImportScriptsChunkLoadingRuntimeModule};
+
+/// Loads chunks with `importScripts`, for web workers where `document` doesn't exist
+#[derive(Debug)]
+pub struct ImportScriptsChunkLoadingPlugin {}
+
+#[async_trait]
+impl Plugin for ImportScriptsChunkLoadingPlugin {
+  fn name(&self) -> &'static str {
+    "ImportScriptsChunkLoadingPlugin"
+  }
+
+  fn apply(
+    &mut self,
+    _ctx: rspack_core::PluginContext<&mut rspack_core::ApplyContext>,
+  ) -> Result<()> {
+    Ok(())
+  }
+
+  fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    if !is_enabled_for_chunk(
+      args.chunk,
+      ChunkLoadingType::ImportScripts,
+      args.compilation,
+    ) {
+      return Ok(());
+    }
+
+    let compilation = &mut args.compilation;
+    let chunk = args.chunk;
+    let runtime_requirements = &mut args.runtime_requirements;
+
+    let mut has_chunk_loading = false;
+    for runtime_requirement in runtime_requirements.iter() {
+      match runtime_requirement {
+        RuntimeGlobals::ENSURE_CHUNK_HANDLERS => {
+          has_chunk_loading = true;
+          runtime_requirements.insert(RuntimeGlobals::PUBLIC_PATH);
+          runtime_requirements.insert(RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME);
+        }
+        RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS => {
+          has_chunk_loading = true;
+          runtime_requirements.insert(RuntimeGlobals::PUBLIC_PATH);
+          runtime_requirements.insert(RuntimeGlobals::GET_CHUNK_UPDATE_SCRIPT_FILENAME);
+          runtime_requirements.insert(RuntimeGlobals::MODULE_CACHE);
+          runtime_requirements.insert(RuntimeGlobals::HMR_MODULE_DATA);
+          runtime_requirements.insert(RuntimeGlobals::MODULE_FACTORIES_ADD_ONLY);
+        }
+        RuntimeGlobals::HMR_DOWNLOAD_MANIFEST => {
+          has_chunk_loading = true;
+          runtime_requirements.insert(RuntimeGlobals::PUBLIC_PATH);
+          runtime_requirements.insert(RuntimeGlobals::GET_UPDATE_MANIFEST_FILENAME);
+        }
+        RuntimeGlobals::CHUNK_CALLBACK | RuntimeGlobals::BASE_URI => {
+          has_chunk_loading = true;
+        }
+        _ => {}
+      }
+    }
+
+    if has_chunk_loading {
+      runtime_requirements.insert(RuntimeGlobals::MODULE_FACTORIES_ADD_ONLY);
+      runtime_requirements.insert(RuntimeGlobals::HAS_OWN_PROPERTY);
+      compilation.add_runtime_module(
+        chunk,
+        ImportScriptsChunkLoadingRuntimeModule::new(**runtime_requirements).boxed(),
+      );
+    }
+
+    Ok(())
+  }
+}
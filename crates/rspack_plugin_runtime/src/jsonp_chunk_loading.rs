@@ -1,11 +1,11 @@
 use async_trait::async_trait;
 use rspack_core::{
-  AdditionalChunkRuntimeRequirementsArgs, Plugin, PluginAdditionalChunkRuntimeRequirementsOutput,
-  PluginContext, RuntimeGlobals, RuntimeModuleExt,
+  AdditionalChunkRuntimeRequirementsArgs, ChunkLoadingType, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, RuntimeGlobals, RuntimeModuleExt,
 };
 use rspack_error::Result;
 
-use crate::runtime_module::JsonpChunkLoadingRuntimeModule;
+use crate::{is_enabled_for_chunk, runtime_module::JsonpChunkLoadingRuntimeModule};
 
 #[derive(Debug)]
 pub struct JsonpChunkLoadingPlugin {}
@@ -28,6 +28,10 @@ impl Plugin for JsonpChunkLoadingPlugin {
     _ctx: PluginContext,
     args: &mut AdditionalChunkRuntimeRequirementsArgs,
   ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    if !is_enabled_for_chunk(args.chunk, ChunkLoadingType::Jsonp, args.compilation) {
+      return Ok(());
+    }
+
     let compilation = &mut args.compilation;
     let chunk = args.chunk;
     let runtime_requirements = &mut args.runtime_requirements;
@@ -55,6 +59,11 @@ impl Plugin for JsonpChunkLoadingPlugin {
           runtime_requirements.insert(RuntimeGlobals::PUBLIC_PATH);
           runtime_requirements.insert(RuntimeGlobals::GET_UPDATE_MANIFEST_FILENAME);
         }
+        RuntimeGlobals::PREFETCH_CHUNK_HANDLERS | RuntimeGlobals::PRELOAD_CHUNK_HANDLERS => {
+          has_jsonp_chunk_loading = true;
+          runtime_requirements.insert(RuntimeGlobals::PUBLIC_PATH);
+          runtime_requirements.insert(RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME);
+        }
         RuntimeGlobals::ON_CHUNKS_LOADED | RuntimeGlobals::BASE_URI => {
           has_jsonp_chunk_loading = true;
         }
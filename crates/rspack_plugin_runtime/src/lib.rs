@@ -19,15 +19,28 @@ pub use basic_runtime_requirements::BasicRuntimeRequirementPlugin;
 pub use common_js_chunk_format::CommonJsChunkFormatPlugin;
 mod hot_module_replacement;
 pub use hot_module_replacement::HotModuleReplacementPlugin;
+mod chunk_prefetch_preload;
+pub use chunk_prefetch_preload::ChunkPrefetchPreloadPlugin;
 mod css_modules;
 pub use css_modules::CssModulesPlugin;
 mod array_push_callback_chunk_format;
 pub use array_push_callback_chunk_format::ArrayPushCallbackChunkFormatPlugin;
 mod common_js_chunk_loading;
 pub use common_js_chunk_loading::CommonJsChunkLoadingPlugin;
+mod read_file_chunk_loading;
+pub use read_file_chunk_loading::ReadFileChunkLoadingPlugin;
 mod jsonp_chunk_loading;
 pub use jsonp_chunk_loading::JsonpChunkLoadingPlugin;
+mod import_scripts_chunk_loading;
+pub use import_scripts_chunk_loading::ImportScriptsChunkLoadingPlugin;
+mod module_chunk_format;
+pub use module_chunk_format::ModuleChunkFormatPlugin;
+mod module_chunk_loading;
+pub use module_chunk_loading::ModuleChunkLoadingPlugin;
 mod runtime_module;
+pub use runtime_module::{
+  ConsumesLoadingRuntimeModule, RemotesLoadingRuntimeModule, SharingRuntimeModule,
+};
 
 #[derive(Debug)]
 pub struct RuntimePlugin {}
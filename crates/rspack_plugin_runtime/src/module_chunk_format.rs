@@ -0,0 +1,203 @@
+use std::hash::Hash;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use rspack_core::rspack_sources::{ConcatSource, RawSource, SourceExt};
+use rspack_core::{
+  get_js_chunk_filename_template, AdditionalChunkRuntimeRequirementsArgs, JsChunkHashArgs, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, PluginJsChunkHashHookOutput,
+  PluginRenderChunkHookOutput, RenderChunkArgs, RenderStartupArgs, RuntimeGlobals, SourceType,
+};
+use rspack_error::Result;
+use rspack_plugin_javascript::runtime::{
+  generate_chunk_entry_code, render_chunk_modules, render_chunk_runtime_modules,
+  render_external_module_imports,
+};
+
+use super::update_hash_for_entry_startup;
+use crate::runtime_module::get_undo_path;
+
+/// Renders chunks as esm, e.g. `export const ids = ["foo"]; export const modules = {};`
+#[derive(Debug)]
+pub struct ModuleChunkFormatPlugin {}
+
+#[async_trait]
+impl Plugin for ModuleChunkFormatPlugin {
+  fn name(&self) -> &'static str {
+    "ModuleChunkFormatPlugin"
+  }
+
+  fn apply(
+    &mut self,
+    _ctx: rspack_core::PluginContext<&mut rspack_core::ApplyContext>,
+  ) -> Result<()> {
+    Ok(())
+  }
+
+  fn additional_chunk_runtime_requirements(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let compilation = &mut args.compilation;
+    let chunk_ukey = args.chunk;
+    let runtime_requirements = &mut args.runtime_requirements;
+    let chunk = compilation
+      .chunk_by_ukey
+      .get(chunk_ukey)
+      .ok_or_else(|| anyhow!("chunk not found"))?;
+
+    if chunk.has_runtime(&compilation.chunk_group_by_ukey) {
+      return Ok(());
+    }
+
+    if compilation
+      .chunk_graph
+      .get_number_of_entry_modules(chunk_ukey)
+      > 0
+    {
+      runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+      runtime_requirements.insert(RuntimeGlobals::EXTERNAL_INSTALL_CHUNK);
+    }
+
+    Ok(())
+  }
+
+  fn js_chunk_hash(
+    &self,
+    _ctx: PluginContext,
+    args: &mut JsChunkHashArgs,
+  ) -> PluginJsChunkHashHookOutput {
+    if args
+      .chunk()
+      .has_runtime(&args.compilation.chunk_group_by_ukey)
+    {
+      return Ok(());
+    }
+
+    self.name().hash(&mut args.hasher);
+
+    update_hash_for_entry_startup(
+      args.hasher,
+      args.compilation,
+      args
+        .compilation
+        .chunk_graph
+        .get_chunk_entry_modules_with_chunk_group_iterable(args.chunk_ukey),
+      args.chunk_ukey,
+    );
+
+    Ok(())
+  }
+
+  async fn render_chunk(
+    &self,
+    _ctx: PluginContext,
+    args: &RenderChunkArgs,
+  ) -> PluginRenderChunkHookOutput {
+    let chunk = args.chunk();
+    let mut sources = ConcatSource::default();
+    if let Some(imports) = render_external_module_imports(args.compilation, args.chunk_ukey) {
+      sources.add(imports);
+    }
+    let id = serde_json::to_string(chunk.expect_id()).map_err(|e| anyhow!(e))?;
+    sources.add(RawSource::from(format!(
+      "export const id = {id};\nexport const ids = [{id}];\n"
+    )));
+    sources.add(RawSource::from("export const modules = "));
+    sources.add(render_chunk_modules(args.compilation, args.chunk_ukey)?);
+    sources.add(RawSource::from(";\n"));
+    let has_runtime_modules = !args
+      .compilation
+      .chunk_graph
+      .get_chunk_runtime_modules_in_order(args.chunk_ukey)
+      .is_empty();
+    if has_runtime_modules {
+      sources.add(RawSource::from("export const runtime = "));
+      sources.add(render_chunk_runtime_modules(
+        args.compilation,
+        args.chunk_ukey,
+      )?);
+      sources.add(RawSource::from(";\n"));
+    }
+
+    if chunk.has_entry_module(&args.compilation.chunk_graph) {
+      let entry_point = {
+        let entry_points = args
+          .compilation
+          .chunk_graph
+          .get_chunk_entry_modules_with_chunk_group_iterable(&chunk.ukey);
+
+        let (_, entry_point_ukey) = entry_points
+          .iter()
+          .next()
+          .ok_or_else(|| anyhow!("should has entry point ukey"))?;
+
+        args
+          .compilation
+          .chunk_group_by_ukey
+          .get(entry_point_ukey)
+          .ok_or_else(|| anyhow!("should has entry point"))?
+      };
+
+      let runtime_chunk_filename = {
+        let runtime_chunk = args
+          .compilation
+          .chunk_by_ukey
+          .get(&entry_point.get_runtime_chunk())
+          .ok_or_else(|| anyhow!("should has runtime chunk"))?;
+
+        get_js_chunk_filename_template(
+          runtime_chunk,
+          &args.compilation.options.output,
+          &args.compilation.chunk_group_by_ukey,
+        )
+        .render_with_chunk(runtime_chunk, ".js", &SourceType::JavaScript)
+      };
+
+      // The runtime chunk is imported relative to this chunk, both of them could be in directories
+      let chunk_filename = get_js_chunk_filename_template(
+        chunk,
+        &args.compilation.options.output,
+        &args.compilation.chunk_group_by_ukey,
+      )
+      .render_with_chunk(chunk, ".js", &SourceType::JavaScript);
+      let root_output_dir = get_undo_path(
+        &chunk_filename,
+        args.compilation.options.output.path.display().to_string(),
+        true,
+      );
+      // The runtime chunk exports `__webpack_require__` as default
+      sources.add(RawSource::from(format!(
+        "import {} from '{}{}';\n",
+        RuntimeGlobals::REQUIRE,
+        root_output_dir,
+        runtime_chunk_filename
+      )));
+      sources.add(RawSource::from(format!(
+        "{}({{ ids: ids, modules: modules{} }});\n",
+        RuntimeGlobals::EXTERNAL_INSTALL_CHUNK,
+        if has_runtime_modules {
+          ", runtime: runtime"
+        } else {
+          ""
+        }
+      )));
+      sources.add(generate_chunk_entry_code(args.compilation, args.chunk_ukey));
+      if let Some(s) =
+        args
+          .compilation
+          .plugin_driver
+          .read()
+          .await
+          .render_startup(RenderStartupArgs {
+            compilation: args.compilation,
+            chunk: &chunk.ukey,
+          })?
+      {
+        sources.add(s);
+      }
+    }
+    Ok(Some(sources.boxed()))
+  }
+}
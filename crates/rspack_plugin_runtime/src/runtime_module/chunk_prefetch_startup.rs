@@ -0,0 +1,45 @@
+use rspack_core::{
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeGlobals, RuntimeModule, RUNTIME_MODULE_STAGE_TRIGGER,
+};
+use rspack_identifier::Identifier;
+
+use super::utils::stringify_array;
+use crate::impl_runtime_module;
+
+/// Prefetch the ordered children of an entry chunk as soon as it is loaded
+#[derive(Debug, Eq)]
+pub struct ChunkPrefetchStartupRuntimeModule {
+  id: Identifier,
+  startup_chunk_ids: Vec<String>,
+}
+
+impl ChunkPrefetchStartupRuntimeModule {
+  pub fn new(startup_chunk_ids: Vec<String>) -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/chunk_prefetch_startup"),
+      startup_chunk_ids,
+    }
+  }
+}
+
+impl RuntimeModule for ChunkPrefetchStartupRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> BoxSource {
+    RawSource::from(format!(
+      "{}.map({});\n",
+      stringify_array(&self.startup_chunk_ids),
+      RuntimeGlobals::PREFETCH_CHUNK
+    ))
+    .boxed()
+  }
+
+  fn stage(&self) -> u8 {
+    RUNTIME_MODULE_STAGE_TRIGGER
+  }
+}
+
+impl_runtime_module!(ChunkPrefetchStartupRuntimeModule);
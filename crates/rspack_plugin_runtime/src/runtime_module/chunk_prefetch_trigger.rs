@@ -0,0 +1,45 @@
+use std::collections::BTreeMap;
+
+use rspack_core::{
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeModule, RUNTIME_MODULE_STAGE_TRIGGER,
+};
+use rspack_identifier::Identifier;
+
+use super::utils::stringify_chunk_map;
+use crate::impl_runtime_module;
+
+#[derive(Debug, Eq)]
+pub struct ChunkPrefetchTriggerRuntimeModule {
+  id: Identifier,
+  chunk_map: BTreeMap<String, Vec<String>>,
+}
+
+impl ChunkPrefetchTriggerRuntimeModule {
+  pub fn new(chunk_map: BTreeMap<String, Vec<String>>) -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/chunk_prefetch_trigger"),
+      chunk_map,
+    }
+  }
+}
+
+impl RuntimeModule for ChunkPrefetchTriggerRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> BoxSource {
+    RawSource::from(
+      include_str!("runtime/chunk_prefetch_trigger.js")
+        .replace("$CHUNK_MAP$", &stringify_chunk_map(&self.chunk_map)),
+    )
+    .boxed()
+  }
+
+  fn stage(&self) -> u8 {
+    RUNTIME_MODULE_STAGE_TRIGGER
+  }
+}
+
+impl_runtime_module!(ChunkPrefetchTriggerRuntimeModule);
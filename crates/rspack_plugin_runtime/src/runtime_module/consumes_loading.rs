@@ -0,0 +1,75 @@
+use rspack_core::{
+  rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt},
+  Compilation, RuntimeModule, RUNTIME_MODULE_STAGE_ATTACH,
+};
+use rspack_identifier::Identifier;
+
+use super::utils::stringify_array;
+use crate::impl_runtime_module;
+
+/// Resolves consumed shared modules from the share scope before the chunks containing them are loaded.
+#[derive(Debug, Eq)]
+pub struct ConsumesLoadingRuntimeModule {
+  id: Identifier,
+  /// consume shared module id -> function returning the factory of the module, or a promise of it
+  module_to_handler_mapping: Vec<(String, String)>,
+  /// chunk id -> ids of consume shared modules in the chunk
+  chunk_mapping: Vec<(String, Vec<String>)>,
+  /// ids of consume shared modules in initial chunks
+  initial_consumes: Vec<String>,
+}
+
+impl ConsumesLoadingRuntimeModule {
+  pub fn new(
+    module_to_handler_mapping: Vec<(String, String)>,
+    chunk_mapping: Vec<(String, Vec<String>)>,
+    initial_consumes: Vec<String>,
+  ) -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/consumes_loading"),
+      module_to_handler_mapping,
+      chunk_mapping,
+      initial_consumes,
+    }
+  }
+}
+
+impl RuntimeModule for ConsumesLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> BoxSource {
+    let mut source = ConcatSource::default();
+    source.add(RawSource::from(format!(
+      "var moduleToHandlerMapping = {{{}}};\n",
+      self
+        .module_to_handler_mapping
+        .iter()
+        .map(|(module_id, handler)| format!(r#""{module_id}": {handler}"#))
+        .collect::<Vec<_>>()
+        .join(",\n")
+    )));
+    source.add(RawSource::from(format!(
+      "var chunkMapping = {{{}}};\n",
+      self
+        .chunk_mapping
+        .iter()
+        .map(|(chunk_id, module_ids)| format!(r#""{chunk_id}": {}"#, stringify_array(module_ids)))
+        .collect::<Vec<_>>()
+        .join(",\n")
+    )));
+    source.add(RawSource::from(format!(
+      "var initialConsumes = {};\n",
+      stringify_array(&self.initial_consumes)
+    )));
+    source.add(RawSource::from(include_str!("runtime/consumes_loading.js")));
+    source.boxed()
+  }
+
+  fn stage(&self) -> u8 {
+    RUNTIME_MODULE_STAGE_ATTACH
+  }
+}
+
+impl_runtime_module!(ConsumesLoadingRuntimeModule);
@@ -50,10 +50,18 @@ impl RuntimeModule for GetChunkFilenameRuntimeModule {
     let url = match self.chunk {
       Some(chunk) => match compilation.chunk_by_ukey.get(&chunk) {
         Some(chunk) => {
-          let chunks = match self.all_chunks {
+          let mut chunks = match self.all_chunks {
             true => chunk.get_all_referenced_chunks(&compilation.chunk_group_by_ukey),
             false => chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey),
           };
+          // Chunks of entrypoints like workers are loaded by url too
+          for entrypoint_ukey in
+            chunk.get_all_referenced_async_entrypoints(&compilation.chunk_group_by_ukey)
+          {
+            if let Some(entrypoint) = compilation.chunk_group_by_ukey.get(&entrypoint_ukey) {
+              chunks.insert(entrypoint.get_entry_point_chunk());
+            }
+          }
 
           let mut chunks_map = HashMap::default();
           for chunk_ukey in chunks.iter() {
@@ -0,0 +1,117 @@
+use rspack_core::{
+  rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt},
+  ChunkUkey, Compilation, RuntimeGlobals, RuntimeModule, RUNTIME_MODULE_STAGE_ATTACH,
+};
+use rspack_identifier::Identifier;
+
+use super::utils::chunk_has_js;
+use crate::impl_runtime_module;
+use crate::runtime_module::utils::{get_initial_chunk_ids, stringify_chunks};
+
+#[derive(Debug, Default, Eq)]
+pub struct ImportScriptsChunkLoadingRuntimeModule {
+  id: Identifier,
+  chunk: Option<ChunkUkey>,
+  runtime_requirements: RuntimeGlobals,
+}
+
+impl ImportScriptsChunkLoadingRuntimeModule {
+  pub fn new(runtime_requirements: RuntimeGlobals) -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/import_scripts_chunk_loading"),
+      chunk: None,
+      runtime_requirements,
+    }
+  }
+}
+
+impl RuntimeModule for ImportScriptsChunkLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let initial_chunks = get_initial_chunk_ids(self.chunk, compilation, chunk_has_js);
+    let with_hmr = self
+      .runtime_requirements
+      .contains(RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS);
+    let mut source = ConcatSource::default();
+
+    if self.runtime_requirements.contains(RuntimeGlobals::BASE_URI) {
+      source.add(RawSource::from(format!(
+        "{} = self.location + \"\";\n",
+        RuntimeGlobals::BASE_URI
+      )))
+    }
+
+    // object to store loaded chunks
+    // "1" means "already loaded"
+    if with_hmr {
+      source.add(RawSource::from(format!(
+        "var installedChunks = {} = {} || {};\n",
+        RuntimeGlobals::HMR_RUNTIME_STATE_PREFIX,
+        RuntimeGlobals::HMR_RUNTIME_STATE_PREFIX,
+        &stringify_chunks(&initial_chunks, 1)
+      )));
+    } else {
+      source.add(RawSource::from(format!(
+        "var installedChunks = {};\n",
+        &stringify_chunks(&initial_chunks, 1)
+      )));
+    }
+
+    let with_loading = self
+      .runtime_requirements
+      .contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS);
+
+    if with_loading {
+      source.add(RawSource::from(
+        include_str!("runtime/import_scripts_chunk_loading.js")
+          // TODO
+          .replace("JS_MATCHER", "chunkId"),
+      ));
+    }
+
+    if self
+      .runtime_requirements
+      .contains(RuntimeGlobals::CHUNK_CALLBACK)
+      || with_loading
+    {
+      source.add(RawSource::from(include_str!(
+        "runtime/import_scripts_chunk_loading_with_callback.js"
+      )));
+    }
+
+    if with_hmr {
+      source.add(RawSource::from(include_str!(
+        "runtime/import_scripts_chunk_loading_with_hmr.js"
+      )));
+      source.add(RawSource::from(
+        include_str!("runtime/javascript_hot_module_replacement.js")
+          .replace("$key$", "importScripts"),
+      ));
+    }
+
+    if self
+      .runtime_requirements
+      .contains(RuntimeGlobals::HMR_DOWNLOAD_MANIFEST)
+    {
+      // `fetch` is available in workers too
+      source.add(RawSource::from(include_str!(
+        "runtime/jsonp_chunk_loading_with_hmr_manifest.js"
+      )));
+    }
+
+    source.boxed()
+  }
+
+  fn attach(&mut self, chunk: ChunkUkey) {
+    self.chunk = Some(chunk);
+  }
+
+  fn stage(&self) -> u8 {
+    RUNTIME_MODULE_STAGE_ATTACH
+  }
+}
+
+impl_runtime_module!(ImportScriptsChunkLoadingRuntimeModule);
@@ -68,6 +68,28 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
       ));
     }
 
+    if self
+      .runtime_requirements
+      .contains(RuntimeGlobals::PREFETCH_CHUNK_HANDLERS)
+    {
+      source.add(RawSource::from(
+        include_str!("runtime/jsonp_chunk_loading_with_prefetch.js")
+          // TODO
+          .replace("JS_MATCHER", "chunkId"),
+      ));
+    }
+
+    if self
+      .runtime_requirements
+      .contains(RuntimeGlobals::PRELOAD_CHUNK_HANDLERS)
+    {
+      source.add(RawSource::from(
+        include_str!("runtime/jsonp_chunk_loading_with_preload.js")
+          // TODO
+          .replace("JS_MATCHER", "chunkId"),
+      ));
+    }
+
     if self
       .runtime_requirements
       .contains(RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS)
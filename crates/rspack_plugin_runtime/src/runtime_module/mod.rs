@@ -1,4 +1,9 @@
 mod async_module;
+mod chunk_prefetch_preload_function;
+mod chunk_prefetch_startup;
+mod chunk_prefetch_trigger;
+mod chunk_preload_trigger;
+mod consumes_loading;
 mod css_loading;
 mod ensure_chunk;
 mod get_chunk_filename;
@@ -8,14 +13,24 @@ mod get_main_filename;
 mod global;
 mod has_own_property;
 mod hot_module_replacement;
+mod import_scripts_chunk_loading;
 mod jsonp_chunk_loading;
 mod load_chunk_with_module;
 mod load_script;
+mod module_chunk_loading;
 mod on_chunk_loaded;
 mod public_path;
+mod read_file_chunk_loading;
+mod remotes_loading;
 mod require_js_chunk_loading;
+mod sharing;
 mod utils;
 pub use async_module::AsyncRuntimeModule;
+pub use chunk_prefetch_preload_function::ChunkPrefetchPreloadFunctionRuntimeModule;
+pub use chunk_prefetch_startup::ChunkPrefetchStartupRuntimeModule;
+pub use chunk_prefetch_trigger::ChunkPrefetchTriggerRuntimeModule;
+pub use chunk_preload_trigger::ChunkPreloadTriggerRuntimeModule;
+pub use consumes_loading::ConsumesLoadingRuntimeModule;
 pub use css_loading::CssLoadingRuntimeModule;
 pub use ensure_chunk::EnsureChunkRuntimeModule;
 pub use get_chunk_filename::GetChunkFilenameRuntimeModule;
@@ -25,12 +40,18 @@ pub use get_main_filename::GetMainFilenameRuntimeModule;
 pub use global::GlobalRuntimeModule;
 pub use has_own_property::HasOwnPropertyRuntimeModule;
 pub use hot_module_replacement::HotModuleReplacementRuntimeModule;
+pub use import_scripts_chunk_loading::ImportScriptsChunkLoadingRuntimeModule;
 pub use jsonp_chunk_loading::JsonpChunkLoadingRuntimeModule;
 pub use load_chunk_with_module::LoadChunkWithModuleRuntimeModule;
 pub use load_script::LoadScriptRuntimeModule;
+pub use module_chunk_loading::ModuleChunkLoadingRuntimeModule;
 pub use on_chunk_loaded::OnChunkLoadedRuntimeModule;
 pub use public_path::PublicPathRuntimeModule;
+pub use read_file_chunk_loading::ReadFileChunkLoadingRuntimeModule;
+pub use remotes_loading::RemotesLoadingRuntimeModule;
 pub use require_js_chunk_loading::RequireChunkLoadingRuntimeModule;
+pub use sharing::SharingRuntimeModule;
+pub(crate) use utils::get_undo_path;
 mod module_macro;
 mod normal;
 pub use normal::NormalRuntimeModule;
@@ -0,0 +1,67 @@
+use rspack_core::{
+  rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt},
+  Compilation, RuntimeModule, RUNTIME_MODULE_STAGE_ATTACH,
+};
+use rspack_identifier::Identifier;
+
+use super::utils::stringify_array;
+use crate::impl_runtime_module;
+
+/// Installs factories of remote modules when the chunks containing them are loaded.
+#[derive(Debug, Eq)]
+pub struct RemotesLoadingRuntimeModule {
+  id: Identifier,
+  /// chunk id -> ids of remote modules in the chunk
+  chunk_mapping: Vec<(String, Vec<String>)>,
+  /// remote module id -> [share scope, request in the container, id of the container external]
+  id_to_external_and_name_mapping: Vec<(String, [String; 3])>,
+}
+
+impl RemotesLoadingRuntimeModule {
+  pub fn new(
+    chunk_mapping: Vec<(String, Vec<String>)>,
+    id_to_external_and_name_mapping: Vec<(String, [String; 3])>,
+  ) -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/remotes_loading"),
+      chunk_mapping,
+      id_to_external_and_name_mapping,
+    }
+  }
+}
+
+impl RuntimeModule for RemotesLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> BoxSource {
+    let mut source = ConcatSource::default();
+    source.add(RawSource::from(format!(
+      "var chunkMapping = {{{}}};\n",
+      self
+        .chunk_mapping
+        .iter()
+        .map(|(chunk_id, module_ids)| format!(r#""{chunk_id}": {}"#, stringify_array(module_ids)))
+        .collect::<Vec<_>>()
+        .join(",\n")
+    )));
+    source.add(RawSource::from(format!(
+      "var idToExternalAndNameMapping = {{{}}};\n",
+      self
+        .id_to_external_and_name_mapping
+        .iter()
+        .map(|(module_id, data)| format!(r#""{module_id}": {}"#, stringify_array(data)))
+        .collect::<Vec<_>>()
+        .join(",\n")
+    )));
+    source.add(RawSource::from(include_str!("runtime/remotes_loading.js")));
+    source.boxed()
+  }
+
+  fn stage(&self) -> u8 {
+    RUNTIME_MODULE_STAGE_ATTACH
+  }
+}
+
+impl_runtime_module!(RemotesLoadingRuntimeModule);
@@ -0,0 +1,200 @@
+var parseVersion = function (str) {
+	var match =
+		/^[v=\s]*(\d+|[xX*])?(?:\.(\d+|[xX*]))?(?:\.(\d+|[xX*]))?(?:-([0-9A-Za-z.-]+))?/.exec(
+			str
+		);
+	var parts = [];
+	for (var i = 1; i < 4; i++) {
+		if (!match[i] || /[xX*]/.test(match[i])) break;
+		parts.push(+match[i]);
+	}
+	return { parts: parts, pre: match[4] };
+};
+var compareVersion = function (a, b) {
+	for (var i = 0; i < 3; i++) {
+		var x = a.parts[i] || 0;
+		var y = b.parts[i] || 0;
+		if (x !== y) return x < y ? -1 : 1;
+	}
+	if (a.pre === b.pre) return 0;
+	if (!a.pre) return 1;
+	if (!b.pre) return -1;
+	return a.pre < b.pre ? -1 : 1;
+};
+var versionLt = function (a, b) {
+	return compareVersion(parseVersion(a), parseVersion(b)) < 0;
+};
+var bumpVersion = function (parts, index) {
+	var result = parts.slice(0, index + 1);
+	result[index]++;
+	return { parts: result };
+};
+var satisfyComparator = function (comparator, version) {
+	var match = /^(\^|~|>=|<=|>|<|=)?(.*)$/.exec(comparator);
+	var operator = match[1] || "";
+	var target = parseVersion(match[2]);
+	var parts = target.parts;
+	var result = compareVersion(version, target);
+	switch (operator) {
+		case ">=":
+			return result >= 0;
+		case ">":
+			return result > 0;
+		case "<=":
+			return result <= 0;
+		case "<":
+			return result < 0;
+	}
+	// "*", "x" or an empty range
+	if (!parts.length) return true;
+	if (result < 0) return false;
+	if (operator === "^") {
+		var index = 0;
+		while (index < parts.length - 1 && parts[index] === 0) index++;
+		return compareVersion(version, bumpVersion(parts, index)) < 0;
+	}
+	if (operator === "~" && parts.length > 1) {
+		return compareVersion(version, bumpVersion(parts, 1)) < 0;
+	}
+	return compareVersion(version, bumpVersion(parts, parts.length - 1)) < 0;
+};
+var satisfy = function (range, version) {
+	var parsed = parseVersion(version);
+	return range.split("||").some(function (alternative) {
+		return alternative
+			.replace(/(\^|~|>=|<=|>|<|=)\s+/g, "$1")
+			.trim()
+			.split(/\s+/)
+			.every(function (comparator) {
+				return satisfyComparator(comparator, parsed);
+			});
+	});
+};
+var warn = function (msg) {
+	if (typeof console !== "undefined" && console.warn) console.warn(msg);
+};
+var get = function (entry) {
+	entry.loaded = 1;
+	return entry.get();
+};
+var findVersion = function (scope, key) {
+	var versions = scope[key];
+	var version = Object.keys(versions).reduce(function (a, b) {
+		return !a || versionLt(a, b) ? b : a;
+	}, 0);
+	return version && versions[version];
+};
+var findValidVersion = function (scope, key, requiredVersion) {
+	var versions = scope[key];
+	var version = Object.keys(versions).reduce(function (a, b) {
+		if (!satisfy(requiredVersion, b)) return a;
+		return !a || versionLt(a, b) ? b : a;
+	}, 0);
+	return version && versions[version];
+};
+var findSingletonVersionKey = function (scope, key) {
+	var versions = scope[key];
+	return Object.keys(versions).reduce(function (a, b) {
+		return !a || (!versions[a].loaded && versionLt(a, b)) ? b : a;
+	}, 0);
+};
+var getInvalidVersionMessage = function (scope, scopeName, key, requiredVersion) {
+	var versions = scope[key];
+	return (
+		"No satisfying version (" +
+		requiredVersion +
+		") of shared module " +
+		key +
+		" found in shared scope " +
+		scopeName +
+		".\nAvailable versions: " +
+		Object.keys(versions)
+			.map(function (version) {
+				return version + " from " + versions[version].from;
+			})
+			.join(", ")
+	);
+};
+var consume = function (
+	scopeName,
+	key,
+	requiredVersion,
+	singleton,
+	strictVersion,
+	fallback
+) {
+	var resolve = function () {
+		var scope = __webpack_require__.S[scopeName];
+		if (!scope || !__webpack_require__.o(scope, key)) return fallback();
+		if (singleton) {
+			var version = findSingletonVersionKey(scope, key);
+			if (requiredVersion && !satisfy(requiredVersion, version)) {
+				var message =
+					"Unsatisfied version " +
+					version +
+					" from " +
+					scope[key][version].from +
+					" of shared singleton module " +
+					key +
+					" (required " +
+					requiredVersion +
+					")";
+				if (strictVersion) throw new Error(message);
+				warn(message);
+			}
+			return get(scope[key][version]);
+		}
+		if (!requiredVersion) return get(findVersion(scope, key));
+		var entry = findValidVersion(scope, key, requiredVersion);
+		if (entry) return get(entry);
+		if (strictVersion) return fallback();
+		warn(getInvalidVersionMessage(scope, scopeName, key, requiredVersion));
+		return get(findVersion(scope, key));
+	};
+	var promise = __webpack_require__.I(scopeName);
+	if (promise && promise.then) return promise.then(resolve);
+	return resolve();
+};
+var installedModules = {};
+initialConsumes.forEach(function (id) {
+	__webpack_require__.m[id] = function (module) {
+		// Handle case when module is used sync
+		installedModules[id] = 0;
+		var factory = moduleToHandlerMapping[id]();
+		if (typeof factory !== "function")
+			throw new Error(
+				"Shared module is not available for eager consumption: " + id
+			);
+		module.exports = factory();
+	};
+});
+__webpack_require__.f.consumes = function (chunkId, promises) {
+	if (__webpack_require__.o(chunkMapping, chunkId)) {
+		chunkMapping[chunkId].forEach(function (id) {
+			if (__webpack_require__.o(installedModules, id))
+				return promises.push(installedModules[id]);
+			var onFactory = function (factory) {
+				installedModules[id] = 0;
+				__webpack_require__.m[id] = function (module) {
+					module.exports = factory();
+				};
+			};
+			var onError = function (error) {
+				delete installedModules[id];
+				__webpack_require__.m[id] = function () {
+					throw error;
+				};
+			};
+			try {
+				var promise = moduleToHandlerMapping[id]();
+				if (promise.then) {
+					promises.push(
+						(installedModules[id] = promise.then(onFactory)["catch"](onError))
+					);
+				} else onFactory(promise);
+			} catch (e) {
+				onError(e);
+			}
+		});
+	}
+};
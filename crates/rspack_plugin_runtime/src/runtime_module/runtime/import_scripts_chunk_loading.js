@@ -0,0 +1,8 @@
+__webpack_require__.f.i = function (chunkId, promises) {
+	// "1" is the signal for "already loaded"
+	if (!installedChunks[chunkId]) {
+		if (JS_MATCHER) {
+			importScripts(__webpack_require__.p + __webpack_require__.u(chunkId));
+		}
+	}
+};
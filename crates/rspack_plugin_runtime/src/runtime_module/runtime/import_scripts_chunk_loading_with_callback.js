@@ -0,0 +1,17 @@
+// importScripts chunk loading
+var installChunk = function (data) {
+	var [chunkIds, moreModules, runtime] = data;
+	for (var moduleId in moreModules) {
+		if (__webpack_require__.o(moreModules, moduleId)) {
+			__webpack_require__.m[moduleId] = moreModules[moduleId];
+		}
+	}
+	if (runtime) runtime(__webpack_require__);
+	while (chunkIds.length) installedChunks[chunkIds.pop()] = 1;
+	parentChunkLoadingFunction(data);
+};
+
+var chunkLoadingGlobal = (self["webpackChunkwebpack"] =
+	self["webpackChunkwebpack"] || []);
+var parentChunkLoadingFunction = chunkLoadingGlobal.push.bind(chunkLoadingGlobal);
+chunkLoadingGlobal.push = installChunk;
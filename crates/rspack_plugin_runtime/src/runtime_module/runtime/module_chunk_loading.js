@@ -0,0 +1,26 @@
+var installChunk = function (data) {
+	var ids = data.ids,
+		modules = data.modules,
+		runtime = data.runtime;
+	// add "modules" to the modules object,
+	// then flag all "ids" as loaded and fire callback
+	var moduleId,
+		chunkId,
+		i = 0;
+	for (moduleId in modules) {
+		if (__webpack_require__.o(modules, moduleId)) {
+			__webpack_require__.m[moduleId] = modules[moduleId];
+		}
+	}
+	if (runtime) runtime(__webpack_require__);
+	for (; i < ids.length; i++) {
+		chunkId = ids[i];
+		if (
+			__webpack_require__.o(installedChunks, chunkId) &&
+			installedChunks[chunkId]
+		) {
+			installedChunks[chunkId][0]();
+		}
+		installedChunks[ids[i]] = 0;
+	}
+};
@@ -0,0 +1,20 @@
+// object to store loaded chunks
+// "0" means "already loaded", Promise means loading
+
+var installChunk = function (chunk) {
+	var moreModules = chunk.modules,
+		chunkIds = chunk.ids,
+		runtime = chunk.runtime;
+	for (var moduleId in moreModules) {
+		if (__webpack_require__.o(moreModules, moduleId)) {
+			__webpack_require__.m[moduleId] = moreModules[moduleId];
+		}
+	}
+	if (runtime) runtime(__webpack_require__);
+	for (var i = 0; i < chunkIds.length; i++) {
+		if (installedChunks[chunkIds[i]]) {
+			installedChunks[chunkIds[i]][0]();
+		}
+		installedChunks[chunkIds[i]] = 0;
+	}
+};
@@ -0,0 +1,2 @@
+module.exports = __webpack_require__;
+__webpack_require__.C = installChunk;
@@ -0,0 +1,19 @@
+__webpack_require__.hmrM = function () {
+	return new Promise(function (resolve, reject) {
+		var filename = require("path").join(
+			__dirname,
+			"" + __webpack_require__.hmrF()
+		);
+		require("fs").readFile(filename, "utf-8", function (err, content) {
+			if (err) {
+				if (err.code === "ENOENT") return resolve();
+				return reject(err);
+			}
+			try {
+				resolve(JSON.parse(content));
+			} catch (e) {
+				reject(e);
+			}
+		});
+	});
+};
@@ -0,0 +1,3 @@
+__webpack_require__.O.readFileVm = function (chunkId) {
+	return installedChunks[chunkId] === 0;
+};
@@ -0,0 +1,55 @@
+use rspack_core::{
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeModule,
+};
+use rspack_identifier::Identifier;
+
+use crate::impl_runtime_module;
+
+/// Defines the share scopes (`__webpack_require__.S`) and the function initializing them (`__webpack_require__.I`).
+#[derive(Debug, Eq)]
+pub struct SharingRuntimeModule {
+  id: Identifier,
+  /// share scope -> code run when the share scope is initialized, e.g. registering provided modules
+  init_code: Vec<(String, Vec<String>)>,
+}
+
+impl SharingRuntimeModule {
+  pub fn new(init_code: Vec<(String, Vec<String>)>) -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/sharing"),
+      init_code,
+    }
+  }
+}
+
+impl RuntimeModule for SharingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let init_code = self
+      .init_code
+      .iter()
+      .map(|(share_scope, code)| {
+        format!(
+          "case \"{share_scope}\": {{\n{}\n}}\nbreak;",
+          code.join("\n")
+        )
+      })
+      .collect::<Vec<_>>()
+      .join("\n");
+    RawSource::from(
+      include_str!("runtime/sharing.js")
+        .replace(
+          "UNIQUE_NAME",
+          &format!("\"{}\"", compilation.options.output.unique_name),
+        )
+        .replace("INIT_CODE", &init_code),
+    )
+    .boxed()
+  }
+}
+
+impl_runtime_module!(SharingRuntimeModule);
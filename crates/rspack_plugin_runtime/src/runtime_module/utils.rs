@@ -1,26 +1,63 @@
+use std::collections::BTreeMap;
+
 use rspack_core::{ChunkUkey, Compilation, SourceType};
 use rustc_hash::FxHashSet as HashSet;
 
-// pub fn condition_map_to_string(map: &HashMap<String, bool>, _value: String) -> String {
-//   let positive_items = map
-//     .iter()
-//     .filter(|(_, v)| **v)
-//     .map(|(k, _)| k)
-//     .collect::<Vec<_>>();
-//   if positive_items.len() == 0 {
-//     return "false".to_string();
-//   }
-//   let negative_items = map
-//     .iter()
-//     .filter(|(_, v)| !**v)
-//     .map(|(k, _)| k)
-//     .collect::<Vec<_>>();
-//   if negative_items.len() == 0 {
-//     return "true".to_string();
-//   }
-//   // TODO
-//   return "true".to_string();
-// }
+/// Compile a condition of `value` which is true for the keys mapped to `true`, similar to
+/// `compileBooleanMatcher` of webpack
+pub fn compile_boolean_matcher(map: &BTreeMap<String, bool>, value: &str) -> String {
+  let (positive_items, negative_items): (Vec<_>, Vec<_>) = map.iter().partition(|(_, v)| **v);
+  if positive_items.is_empty() {
+    return "false".to_string();
+  }
+  if negative_items.is_empty() {
+    return "true".to_string();
+  }
+  let to_regexp = |items: Vec<(&String, &bool)>| {
+    items
+      .into_iter()
+      .map(|(k, _)| regex_escape(k))
+      .collect::<Vec<_>>()
+      .join("|")
+  };
+  if positive_items.len() <= negative_items.len() {
+    format!("/^({})$/.test({value})", to_regexp(positive_items))
+  } else {
+    format!("!/^({})$/.test({value})", to_regexp(negative_items))
+  }
+}
+
+fn regex_escape(s: &str) -> String {
+  s.chars().fold(String::new(), |mut escaped, c| {
+    if "|{}()[]^$+*?.\\/-".contains(c) {
+      escaped.push('\\');
+    }
+    escaped.push(c);
+    escaped
+  })
+}
+
+/// Condition of `JS_MATCHER` in chunk loading runtimes, which is true for async chunks of the
+/// runtime chunk containing javascript
+pub fn get_js_chunk_matcher(chunk: Option<ChunkUkey>, compilation: &Compilation) -> String {
+  let map = chunk
+    .and_then(|chunk_ukey| compilation.chunk_by_ukey.get(&chunk_ukey))
+    .map(|chunk| {
+      chunk
+        .get_all_async_chunks(&compilation.chunk_group_by_ukey)
+        .iter()
+        .filter_map(|chunk_ukey| {
+          let chunk = compilation.chunk_by_ukey.get(chunk_ukey)?;
+          Some((
+            chunk.expect_id().to_string(),
+            chunk_has_js(chunk_ukey, compilation),
+          ))
+        })
+        .collect::<BTreeMap<_, _>>()
+    })
+    .unwrap_or_default();
+  compile_boolean_matcher(&map, "chunkId")
+}
 
 pub fn get_initial_chunk_ids(
   chunk: Option<ChunkUkey>,
@@ -84,6 +121,17 @@ pub fn stringify_array(vec: &[String]) -> String {
   )
 }
 
+pub fn stringify_chunk_map(map: &BTreeMap<String, Vec<String>>) -> String {
+  format!(
+    r#"{{{}}}"#,
+    map
+      .iter()
+      .fold(String::new(), |prev, (chunk_id, child_ids)| {
+        prev + format!(r#""{chunk_id}": {},"#, stringify_array(child_ids)).as_str()
+      })
+  )
+}
+
 pub fn chunk_has_js(chunk_ukey: &ChunkUkey, compilation: &Compilation) -> bool {
   if compilation
     .chunk_graph
@@ -627,6 +627,7 @@ impl SplitChunksPlugin {
   }
 }
 
+#[async_trait::async_trait]
 impl Plugin for SplitChunksPlugin {
   fn name(&self) -> &'static str {
     "split_chunks"
@@ -636,10 +637,10 @@ impl Plugin for SplitChunksPlugin {
   #[allow(clippy::if_same_then_else)]
   #[allow(clippy::collapsible_else_if)]
   #[allow(unused)]
-  fn optimize_chunks(
+  async fn optimize_chunks(
     &mut self,
     _ctx: rspack_core::PluginContext,
-    args: rspack_core::OptimizeChunksArgs,
+    args: rspack_core::OptimizeChunksArgs<'_>,
   ) -> rspack_core::PluginOptimizeChunksOutput {
     let compilation = args.compilation;
 
@@ -2,7 +2,7 @@ use std::fmt::Debug;
 
 use rayon::prelude::*;
 use rspack_core::{
-  ApplyContext, Module, ModuleType, ParserAndGenerator, PathData, Plugin, PluginContext,
+  ApplyContext, AssetInfo, Module, ModuleType, ParserAndGenerator, PathData, Plugin, PluginContext,
   PluginRenderManifestHookOutput, RenderManifestArgs, RenderManifestEntry, SourceType,
 };
 use rspack_error::Result;
@@ -96,6 +96,7 @@ impl Plugin for AsyncWasmPlugin {
                   .render_with_chunk(chunk, ".wasm", &SourceType::Wasm)
               }),
               path_options,
+              AssetInfo::default(),
             )
           });
 
@@ -4,30 +4,41 @@ use swc_core::ecma::ast::Regex as SwcRegex;
 
 /// Using wrapper because I want to implement the `TryFrom` Trait
 #[derive(Debug, Clone)]
-pub struct RspackRegex(Regex);
+pub struct RspackRegex {
+  regex: Regex,
+  source: String,
+  flags: String,
+}
 
 impl RspackRegex {
   pub fn find(&self, str: &str) -> Option<Match> {
-    self.0.find(str)
+    self.regex.find(str)
   }
   pub fn test(&self, str: &str) -> bool {
     self.find(str).is_some()
   }
 
   pub fn find_iter<'r, 't>(&'r self, text: &'t str) -> Matches<'r, 't> {
-    self.0.find_iter(text)
+    self.regex.find_iter(text)
+  }
+
+  /// The regex in its JavaScript literal form, e.g. `/\.js$/i`
+  pub fn to_source_string(&self) -> String {
+    format!("/{}/{}", self.source, self.flags)
   }
 
   pub fn with_flags(expr: &str, flags: &str) -> Result<Self, Error> {
     Regex::with_flags(expr, flags)
-      .map(RspackRegex)
+      .map(|regex| RspackRegex {
+        regex,
+        source: expr.to_string(),
+        flags: flags.to_string(),
+      })
       .map_err(|_| internal_error!("Can't construct regex `/{expr}/{flags}`"))
   }
 
   pub fn new(expr: &str) -> Result<Self, Error> {
-    Regex::with_flags(expr, "")
-      .map(RspackRegex)
-      .map_err(|_| internal_error!("Can't construct regex `/{}/{}`", expr, ""))
+    Self::with_flags(expr, "")
   }
 }
 
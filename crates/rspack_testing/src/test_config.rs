@@ -6,7 +6,9 @@ use std::{
   sync::Arc,
 };
 
-use rspack_core::{BoxLoader, BoxPlugin, CompilerOptions, ModuleType, PluginExt, TargetPlatform};
+use rspack_core::{
+  BoxLoader, BoxPlugin, ChunkLoadingType, CompilerOptions, ModuleType, PluginExt, TargetPlatform,
+};
 use rspack_plugin_css::pxtorem::options::PxToRemOptions;
 use rspack_plugin_html::config::HtmlPluginConfig;
 use rspack_regex::RspackRegex;
@@ -50,6 +52,10 @@ fn default_chunk_filename() -> String {
   "[name][ext]".to_string()
 }
 
+fn default_optimization_chunk_ids() -> String {
+  "named".to_string()
+}
+
 fn default_optimization_module_ids() -> String {
   "named".to_string()
 }
@@ -85,6 +91,47 @@ pub struct TestConfig {
   pub devtool: String,
   #[serde(default)]
   pub experiments: Experiments,
+  #[serde(default)]
+  pub dev_server: DevServer,
+  #[serde(default)]
+  pub cache: Cache,
+  /// Request of each external, e.g. `"lodash"` or `{ "system": ["lodash"] }`
+  #[serde(default)]
+  pub externals: HashMap<String, External>,
+  /// Falls back to the type of `output.library`, or `var` if there is no library
+  #[serde(default)]
+  pub externals_type: String,
+  /// Relative to the fixture
+  #[serde(default)]
+  pub records_input_path: Option<String>,
+  /// Relative to the fixture
+  #[serde(default)]
+  pub records_output_path: Option<String>,
+}
+
+#[derive(Debug, JsonSchema, Deserialize)]
+#[serde(untagged)]
+pub enum External {
+  String(String),
+  /// Request of each library type
+  Object(HashMap<String, Vec<String>>),
+}
+
+#[derive(Debug, Default, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Cache {
+  /// `memory` or `filesystem`, the cache is disabled by default
+  #[serde(default)]
+  pub r#type: String,
+  #[serde(default)]
+  pub cache_location: String,
+}
+
+#[derive(Debug, Default, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DevServer {
+  #[serde(default)]
+  pub hot: bool,
 }
 
 #[derive(Debug, Default, JsonSchema, Deserialize)]
@@ -101,10 +148,16 @@ pub struct Optimization {
   // True by default to reduce code in snapshots.
   #[serde(default = "true_by_default")]
   pub remove_available_modules: bool,
+  #[serde(default = "default_optimization_chunk_ids")]
+  pub chunk_ids: String,
   #[serde(default = "default_optimization_module_ids")]
   pub module_ids: String,
   #[serde(default = "default_optimization_side_effects")]
   pub side_effects: String,
+  #[serde(default)]
+  pub concatenate_modules: bool,
+  #[serde(default)]
+  pub real_content_hash: bool,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -124,6 +177,8 @@ pub struct Minification {
   pub drop_console: bool,
   #[serde(default)]
   pub pure_funcs: Vec<String>,
+  #[serde(default)]
+  pub extract_comments: Option<String>,
 }
 
 #[derive(Debug, JsonSchema, Deserialize, Default, Clone)]
@@ -228,6 +283,20 @@ pub struct Output {
   pub css_filename: String,
   #[serde(default = "default_chunk_filename")]
   pub css_chunk_filename: String,
+  #[serde(default)]
+  pub module: bool,
+  #[serde(default)]
+  pub library: Option<Library>,
+  /// Falls back to the default of the target, e.g. `require` for node
+  #[serde(default)]
+  pub worker_chunk_loading: Option<String>,
+}
+
+#[derive(Debug, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Library {
+  /// Only library types without options are supported, e.g. `module`, `system`, `umd` or `amd`
+  pub r#type: String,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -379,13 +448,24 @@ impl TestConfig {
         public_path: c::PublicPath::String("/".to_string()),
         unique_name: "__rspack_test__".to_string(),
         path: context.join("dist"),
-        library: None,
+        library: self
+          .output
+          .library
+          .as_ref()
+          .map(|library| c::LibraryOptions {
+            name: None,
+            export: None,
+            library_type: library.r#type.clone(),
+            umd_named_define: None,
+            auxiliary_comment: None,
+          }),
         enabled_library_types: None,
         strict_module_error_handling: false,
         global_object: "self".to_string(),
         import_function_name: "import".to_string(),
-        iife: true,
-        module: false,
+        iife: !self.output.module,
+        module: self.output.module,
+        worker_chunk_loading: self.output.worker_chunk_loading.as_deref().map(Into::into),
       },
       mode: c::Mode::from(self.mode),
       target: c::Target::new(&self.target).expect("Can't construct target"),
@@ -408,6 +488,7 @@ impl TestConfig {
           passes: op.passes,
           drop_console: op.drop_console,
           pure_funcs: op.pure_funcs,
+          extract_comments: op.extract_comments,
         }),
         preset_env: self.builtins.preset_env.map(Into::into),
         ..Default::default()
@@ -419,9 +500,18 @@ impl TestConfig {
       devtool: c::Devtool::from(self.devtool),
       stats: Default::default(),
       snapshot: Default::default(),
-      cache: c::CacheOptions::Disabled,
+      cache: match self.cache.r#type.as_str() {
+        "memory" => c::CacheOptions::Memory(Default::default()),
+        "filesystem" => c::CacheOptions::FileSystem(c::FileSystemCacheOptions {
+          cache_location: self.cache.cache_location,
+          ..Default::default()
+        }),
+        _ => c::CacheOptions::Disabled,
+      },
       experiments: Default::default(),
-      dev_server: Default::default(),
+      dev_server: c::DevServerOptions {
+        hot: self.dev_server.hot,
+      },
       node: c::NodeOption {
         dirname: "mock".to_string(),
         filename: "mock".to_string(),
@@ -431,6 +521,8 @@ impl TestConfig {
         remove_available_modules: self.optimization.remove_available_modules,
         side_effects: c::SideEffectOption::from(self.optimization.side_effects.as_str()),
       },
+      records_input_path: self.records_input_path.map(|path| context.join(path)),
+      records_output_path: self.records_output_path.map(|path| context.join(path)),
     };
     let mut plugins = Vec::new();
     if self.builtins.dev_friendly_split_chunks {
@@ -466,31 +558,107 @@ impl TestConfig {
       .boxed(),
     );
     plugins.push(rspack_plugin_json::JsonPlugin {}.boxed());
-    match &options.target.platform {
-      TargetPlatform::Web => {
-        plugins.push(rspack_plugin_runtime::ArrayPushCallbackChunkFormatPlugin {}.boxed());
-        plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+    if options.output.module {
+      plugins.push(rspack_plugin_runtime::ModuleChunkFormatPlugin {}.boxed());
+      plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+      if options.target.platform.is_web() {
         plugins.push(rspack_plugin_runtime::CssModulesPlugin {}.boxed());
-        plugins.push(rspack_plugin_runtime::JsonpChunkLoadingPlugin {}.boxed());
-      }
-      TargetPlatform::Node(_) => {
-        plugins.push(rspack_plugin_runtime::CommonJsChunkFormatPlugin {}.boxed());
-        plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
-        plugins.push(rspack_plugin_runtime::CommonJsChunkLoadingPlugin {}.boxed());
       }
-      _ => {
-        plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+      plugins.push(rspack_plugin_runtime::ModuleChunkLoadingPlugin {}.boxed());
+    } else {
+      match &options.target.platform {
+        TargetPlatform::Web => {
+          plugins.push(rspack_plugin_runtime::ArrayPushCallbackChunkFormatPlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::CssModulesPlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::JsonpChunkLoadingPlugin {}.boxed());
+        }
+        TargetPlatform::WebWorker => {
+          plugins.push(rspack_plugin_runtime::ArrayPushCallbackChunkFormatPlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::ImportScriptsChunkLoadingPlugin {}.boxed());
+        }
+        platform if platform.is_node() => {
+          plugins.push(rspack_plugin_runtime::CommonJsChunkFormatPlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+          // `require()` for node and `fs.readFile` for async-node, decided by each plugin
+          plugins.push(rspack_plugin_runtime::CommonJsChunkLoadingPlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::ReadFileChunkLoadingPlugin {}.boxed());
+        }
+        _ => {
+          plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+        }
+      };
+      // chunks of workers may be loaded differently from chunks of the target
+      let platform = &options.target.platform;
+      match options
+        .output
+        .worker_chunk_loading
+        .or_else(|| platform.worker_chunk_loading())
+      {
+        Some(ChunkLoadingType::ImportScripts) if !matches!(platform, TargetPlatform::WebWorker) => {
+          plugins.push(rspack_plugin_runtime::ImportScriptsChunkLoadingPlugin {}.boxed());
+        }
+        Some(ChunkLoadingType::Jsonp) if !platform.is_web() => {
+          plugins.push(rspack_plugin_runtime::JsonpChunkLoadingPlugin {}.boxed());
+        }
+        Some(ChunkLoadingType::Require | ChunkLoadingType::AsyncNode) if !platform.is_node() => {
+          plugins.push(rspack_plugin_runtime::CommonJsChunkLoadingPlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::ReadFileChunkLoadingPlugin {}.boxed());
+        }
+        _ => {}
       }
-    };
+    }
     if options.dev_server.hot {
       plugins.push(rspack_plugin_runtime::HotModuleReplacementPlugin {}.boxed());
     }
     plugins.push(rspack_plugin_runtime::BasicRuntimeRequirementPlugin {}.boxed());
+    plugins.push(rspack_plugin_runtime::ChunkPrefetchPreloadPlugin {}.boxed());
     if options.experiments.lazy_compilation {
       plugins.push(rspack_plugin_runtime::LazyCompilationPlugin {}.boxed());
     }
-    // plugins.push(rspack_plugin_externals::ExternalPlugin::default().boxed());
+    if !self.externals.is_empty() {
+      let externals_type = if !self.externals_type.is_empty() {
+        self.externals_type
+      } else if let Some(library) = &self.output.library {
+        library.r#type.clone()
+      } else {
+        "var".to_string()
+      };
+      let externals = self
+        .externals
+        .into_iter()
+        .map(|(request, external)| {
+          let value = match external {
+            External::String(request) => c::ExternalItemValue::String(request),
+            External::Object(requests) => {
+              c::ExternalItemValue::Object(requests.into_iter().collect())
+            }
+          };
+          (request, value)
+        })
+        .collect();
+      plugins.push(
+        rspack_plugin_externals::ExternalPlugin::new(
+          externals_type,
+          vec![c::ExternalItem::Object(externals)],
+        )
+        .boxed(),
+      );
+    }
     plugins.push(rspack_plugin_javascript::JsPlugin::new().boxed());
+    if let Some(library) = &self.output.library {
+      match library.r#type.as_str() {
+        "umd" | "umd2" => plugins
+          .push(rspack_plugin_library::UmdLibraryPlugin::new("umd2".eq(&library.r#type)).boxed()),
+        "amd" | "amd-require" => plugins.push(
+          rspack_plugin_library::AmdLibraryPlugin::new("amd-require".eq(&library.r#type)).boxed(),
+        ),
+        "system" => plugins.push(rspack_plugin_library::SystemLibraryPlugin::default().boxed()),
+        "module" => plugins.push(rspack_plugin_library::ModuleLibraryPlugin::default().boxed()),
+        library_type => panic!("unsupported library type {library_type}"),
+      }
+    }
     plugins.push(
       rspack_plugin_devtool::DevtoolPlugin::new(rspack_plugin_devtool::DevtoolPluginOptions {
         inline: options.devtool.inline(),
@@ -507,11 +675,24 @@ impl TestConfig {
     } else {
       plugins.push(rspack_ids::DeterministicModuleIdsPlugin::default().boxed());
     }
-    plugins.push(rspack_ids::StableNamedChunkIdsPlugin::new(None, None).boxed());
+    match self.optimization.chunk_ids.as_str() {
+      "deterministic" => {
+        plugins.push(rspack_ids::DeterministicChunkIdsPlugin::default().boxed());
+      }
+      "natural" => plugins.push(rspack_ids::NaturalChunkIdsPlugin::default().boxed()),
+      "size" => plugins.push(rspack_ids::OccurrenceChunkIdsPlugin::new(true).boxed()),
+      _ => plugins.push(rspack_ids::StableNamedChunkIdsPlugin::new(None, None).boxed()),
+    }
     // Notice the plugin need to be placed after SplitChunksPlugin
     plugins.push(rspack_plugin_remove_empty_chunks::RemoveEmptyChunksPlugin.boxed());
 
     plugins.push(rspack_plugin_javascript::InferAsyncModulesPlugin {}.boxed());
+    if self.optimization.concatenate_modules {
+      plugins.push(rspack_plugin_javascript::ModuleConcatenationPlugin::default().boxed());
+    }
+    if self.optimization.real_content_hash {
+      plugins.push(rspack_plugin_real_content_hash::RealContentHashPlugin.boxed());
+    }
     if self.experiments.async_web_assembly {
       plugins.push(rspack_plugin_wasm::FetchCompileAsyncWasmPlugin {}.boxed());
       plugins.push(rspack_plugin_wasm::AsyncWasmPlugin::new().boxed());
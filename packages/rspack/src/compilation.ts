@@ -18,6 +18,7 @@ import {
 	JsAsset,
 	JsModule,
 	JsChunk,
+	JsContentHash,
 	JsStatsError,
 	JsStatsWarning
 } from "@rspack/binding";
@@ -29,6 +30,7 @@ import {
 	StatsValue
 } from "./config";
 import { createRawFromSource, createSourceFromRaw } from "./util/createSource";
+import { createHash } from "./util/createHash";
 import { ChunkGroup } from "./chunk_group";
 import { Compiler } from "./compiler";
 import ResolverFactory from "./ResolverFactory";
@@ -47,6 +49,10 @@ const EMPTY_ASSET_INFO = {};
 
 export type AssetInfo = Partial<JsAssetInfo> & Record<string, any>;
 export type Assets = Record<string, Source>;
+export interface RenderManifestEntry {
+	filename: string;
+	source: Source;
+}
 export interface LogEntry {
 	type: string;
 	args: any[];
@@ -80,6 +86,17 @@ export class Compilation {
 			undefined
 		>;
 		finishModules: tapable.AsyncSeriesHook<[Iterable<JsModule>], undefined>;
+		buildModule: tapable.SyncHook<[JsModule]>;
+		succeedModule: tapable.SyncHook<[JsModule]>;
+		optimizeChunks: tapable.AsyncSeriesBailHook<
+			[Iterable<JsChunk>],
+			undefined
+		>;
+		chunkHash: tapable.SyncHook<[JsChunk, ReturnType<typeof createHash>]>;
+		contentHash: tapable.SyncBailHook<[JsChunk], JsContentHash | void>;
+		renderManifest: tapable.SyncWaterfallHook<
+			[RenderManifestEntry[], JsChunk]
+		>;
 	};
 	options: RspackOptionsNormalized;
 	outputOptions: OutputNormalized;
@@ -107,7 +124,13 @@ export class Compilation {
 				"chunks",
 				"modules"
 			]),
-			finishModules: new tapable.AsyncSeriesHook(["modules"])
+			finishModules: new tapable.AsyncSeriesHook(["modules"]),
+			buildModule: new tapable.SyncHook(["module"]),
+			succeedModule: new tapable.SyncHook(["module"]),
+			optimizeChunks: new tapable.AsyncSeriesBailHook(["chunks"]),
+			chunkHash: new tapable.SyncHook(["chunk", "hash"]),
+			contentHash: new tapable.SyncBailHook(["chunk"]),
+			renderManifest: new tapable.SyncWaterfallHook(["result", "chunk"])
 		};
 		this.compiler = compiler;
 		this.resolverFactory = compiler.resolverFactory;
@@ -23,6 +23,8 @@ import ConcurrentCompilationError from "./error/ConcurrentCompilationError";
 import { getRawOptions } from "./config/adapter";
 import { createThreadsafeNodeFSFromRaw } from "./fileSystem";
 import { NormalModuleFactory } from "./normalModuleFactory";
+import { createHash } from "./util/createHash";
+import { createRawFromSource } from "./util/createSource";
 
 class EntryPlugin {
 	apply() {}
@@ -203,7 +205,16 @@ class Compiler {
 					optimizeChunkModule: this.#optimize_chunk_modules.bind(this),
 					finishModules: this.#finish_modules.bind(this),
 					normalModuleFactoryResolveForScheme:
-						this.#normalModuleFactoryResolveForScheme.bind(this)
+						this.#normalModuleFactoryResolveForScheme.bind(this),
+					beforeResolve: this.#beforeResolve.bind(this),
+					afterResolve: this.#afterResolve.bind(this),
+					factorize: this.#factorize.bind(this),
+					buildModule: this.#buildModule.bind(this),
+					succeedModule: this.#succeedModule.bind(this),
+					optimizeChunks: this.#optimizeChunks.bind(this),
+					chunkHash: this.#chunkHash.bind(this),
+					contentHash: this.#contentHash.bind(this),
+					renderManifest: this.#renderManifest.bind(this)
 				},
 				createThreadsafeNodeFSFromRaw(this.outputFileSystem)
 			);
@@ -325,11 +336,23 @@ class Compiler {
 				),
 			compilation: this.hooks.compilation,
 			optimizeChunkModules: this.compilation.hooks.optimizeChunkModules,
-			finishModules: this.compilation.hooks.finishModules
+			finishModules: this.compilation.hooks.finishModules,
 			// normalModuleFactoryResolveForScheme: this.#
+			beforeResolve:
+				this.compilation.normalModuleFactory?.hooks.beforeResolve,
+			afterResolve:
+				this.compilation.normalModuleFactory?.hooks.afterResolve,
+			factorize:
+				this.compilation.normalModuleFactory?.hooks.factorize,
+			buildModule: this.compilation.hooks.buildModule,
+			succeedModule: this.compilation.hooks.succeedModule,
+			optimizeChunks: this.compilation.hooks.optimizeChunks,
+			chunkHash: this.compilation.hooks.chunkHash,
+			contentHash: this.compilation.hooks.contentHash,
+			renderManifest: this.compilation.hooks.renderManifest
 		};
 		for (const [name, hook] of Object.entries(hookMap)) {
-			if (hook.taps.length === 0) {
+			if (!hook || hook.taps.length === 0) {
 				disabledHooks.push(name);
 			}
 		}
@@ -357,6 +380,71 @@ class Compiler {
 		return resourceData.resourceData;
 	}
 
+	async #beforeResolve(resolveData: binding.JsResolveData) {
+		const result =
+			await this.compilation.normalModuleFactory?.hooks.beforeResolve.promise(
+				resolveData
+			);
+		this.#updateDisabledHooks();
+		return { result, resolveData };
+	}
+
+	async #afterResolve(resolveData: binding.JsAfterResolveData) {
+		const result =
+			await this.compilation.normalModuleFactory?.hooks.afterResolve.promise(
+				resolveData
+			);
+		this.#updateDisabledHooks();
+		return result;
+	}
+
+	async #factorize(resolveData: binding.JsResolveData) {
+		await this.compilation.normalModuleFactory?.hooks.factorize.promise(
+			resolveData
+		);
+		this.#updateDisabledHooks();
+	}
+
+	#buildModule(module: binding.JsModule) {
+		this.compilation.hooks.buildModule.call(module);
+		this.#updateDisabledHooks();
+		return module;
+	}
+
+	#succeedModule(module: binding.JsModule) {
+		this.compilation.hooks.succeedModule.call(module);
+		this.#updateDisabledHooks();
+	}
+
+	async #optimizeChunks() {
+		await this.compilation.hooks.optimizeChunks.promise(
+			this.compilation.getChunks()
+		);
+		this.#updateDisabledHooks();
+	}
+
+	#chunkHash(chunk: binding.JsChunk) {
+		const hash = createHash("xxhash64");
+		this.compilation.hooks.chunkHash.call(chunk, hash);
+		this.#updateDisabledHooks();
+		return hash.digest("hex");
+	}
+
+	#contentHash(chunk: binding.JsChunk) {
+		const result = this.compilation.hooks.contentHash.call(chunk);
+		this.#updateDisabledHooks();
+		return result;
+	}
+
+	#renderManifest(chunk: binding.JsChunk) {
+		const result = this.compilation.hooks.renderManifest.call([], chunk);
+		this.#updateDisabledHooks();
+		return result.map(({ filename, source }) => ({
+			filename,
+			source: createRawFromSource(source)
+		}));
+	}
+
 	async #optimize_chunk_modules() {
 		await this.compilation.hooks.optimizeChunkModules.promise(
 			this.compilation.getChunks(),
@@ -11,6 +11,7 @@ import assert from "assert";
 import { normalizeStatsPreset } from "../stats";
 import { isNil } from "../util";
 import {
+	CacheOptions,
 	EntryNormalized,
 	Experiments,
 	ExternalItem,
@@ -72,18 +73,9 @@ export const getRawOptions = (
 			hot: options.devServer?.hot ?? false
 		},
 		snapshot: getRawSnapshotOptions(options.snapshot),
-		cache: {
-			type: options.cache ? "memory" : "disable",
-			// TODO: implement below cache options
-			maxGenerations: 0,
-			maxAge: 0,
-			profile: false,
-			buildDependencies: [],
-			cacheDirectory: "",
-			cacheLocation: "",
-			name: "",
-			version: ""
-		},
+		cache: getRawCacheOptions(options.cache!),
+		recordsInputPath: options.recordsInputPath || undefined,
+		recordsOutputPath: options.recordsOutputPath || undefined,
 		experiments: getRawExperiments(options.experiments),
 		node: getRawNode(options.node),
 		// TODO: refactor builtins
@@ -146,6 +138,7 @@ function getRawResolve(resolve: Resolve): RawOptions["resolve"] {
 
 function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 	const wasmLoading = output.wasmLoading!;
+	const workerChunkLoading = output.workerChunkLoading!;
 	return {
 		path: output.path!,
 		publicPath: output.publicPath!,
@@ -164,7 +157,9 @@ function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 		module: output.module!,
 		wasmLoading: wasmLoading === false ? "false" : wasmLoading,
 		enabledWasmLoadingTypes: output.enabledWasmLoadingTypes!,
-		webassemblyModuleFilename: output.webassemblyModuleFilename!
+		webassemblyModuleFilename: output.webassemblyModuleFilename!,
+		workerChunkLoading:
+			workerChunkLoading === false ? "false" : workerChunkLoading
 	};
 }
 
@@ -268,7 +263,7 @@ const getRawModuleRule = (
 	};
 };
 
-function getRawRuleSetCondition(
+export function getRawRuleSetCondition(
 	condition: RuleSetCondition
 ): RawRuleSetCondition {
 	if (typeof condition === "string") {
@@ -324,6 +319,31 @@ function getRawExternals(externals: Externals): RawOptions["externals"] {
 			return { type: "string", stringPayload: item };
 		} else if (item instanceof RegExp) {
 			return { type: "regexp", regexpPayload: item.source };
+		} else if (typeof item === "function") {
+			return {
+				type: "function",
+				fnPayload: async ctx => {
+					return await new Promise((resolve, reject) => {
+						const promise = item(ctx, (err, result, type) => {
+							if (err) return reject(err);
+							resolve({
+								result: getRawExternalItemValueFormatFnResult(result),
+								externalType: type
+							});
+						});
+						if (promise && promise.then) {
+							promise.then(
+								result =>
+									resolve({
+										result: getRawExternalItemValueFormatFnResult(result),
+										externalType: undefined
+									}),
+								e => reject(e)
+							);
+						}
+					});
+				}
+			};
 		}
 		return {
 			type: "object",
@@ -332,6 +352,11 @@ function getRawExternals(externals: Externals): RawOptions["externals"] {
 			)
 		};
 	}
+	function getRawExternalItemValueFormatFnResult(
+		result?: ExternalItemValue
+	): RawExternalItemValue | undefined {
+		return result === undefined ? undefined : getRawExternalItemValue(result);
+	}
 	function getRawExternalItemValue(
 		value: ExternalItemValue
 	): RawExternalItemValue {
@@ -339,6 +364,18 @@ function getRawExternals(externals: Externals): RawOptions["externals"] {
 			return { type: "string", stringPayload: value };
 		} else if (typeof value === "boolean") {
 			return { type: "bool", boolPayload: value };
+		} else if (Array.isArray(value)) {
+			return { type: "array", arrayPayload: value };
+		} else if (typeof value === "object" && value !== null) {
+			return {
+				type: "object",
+				objectPayload: Object.fromEntries(
+					Object.entries(value).map(([k, v]) => [
+						k,
+						Array.isArray(v) ? v : [v]
+					])
+				)
+			};
 		}
 		throw new Error("unreachable");
 	}
@@ -354,17 +391,23 @@ function getRawOptimization(
 ): RawOptions["optimization"] {
 	assert(
 		!isNil(optimization.moduleIds) &&
+			!isNil(optimization.chunkIds) &&
 			!isNil(optimization.removeAvailableModules) &&
-			!isNil(optimization.sideEffects),
-		"optimization.moduleIds, optimization.removeAvailableModules, optimization.sideEffects should not be nil after defaults"
+			!isNil(optimization.sideEffects) &&
+			!isNil(optimization.concatenateModules) &&
+			!isNil(optimization.realContentHash),
+		"optimization.moduleIds, optimization.chunkIds, optimization.removeAvailableModules, optimization.sideEffects, optimization.concatenateModules, optimization.realContentHash should not be nil after defaults"
 	);
 	return {
 		splitChunks: optimization.splitChunks
 			? getRawSplitChunksOptions(optimization.splitChunks)
 			: undefined,
+		chunkIds: optimization.chunkIds,
 		moduleIds: optimization.moduleIds,
 		removeAvailableModules: optimization.removeAvailableModules,
-		sideEffects: String(optimization.sideEffects)
+		sideEffects: String(optimization.sideEffects),
+		concatenateModules: optimization.concatenateModules,
+		realContentHash: optimization.realContentHash
 	};
 }
 
@@ -396,6 +439,43 @@ function getRawSplitChunksOptions(
 	};
 }
 
+function getRawCacheOptions(cache: CacheOptions): RawOptions["cache"] {
+	const raw: RawOptions["cache"] = {
+		type: "disable",
+		maxGenerations: 0,
+		maxAge: 0,
+		profile: false,
+		buildDependencies: [],
+		cacheDirectory: "",
+		cacheLocation: "",
+		name: "",
+		version: ""
+	};
+	if (cache === false) {
+		return raw;
+	}
+	if (cache === true || cache.type === "memory") {
+		return {
+			...raw,
+			type: "memory",
+			maxGenerations: cache === true ? 0 : cache.maxGenerations ?? 0
+		};
+	}
+	return {
+		...raw,
+		type: "filesystem",
+		maxAge: cache.maxAge ?? 0,
+		profile: cache.profile ?? false,
+		buildDependencies: ([] as string[]).concat(
+			...Object.values(cache.buildDependencies ?? {})
+		),
+		cacheDirectory: cache.cacheDirectory ?? "",
+		cacheLocation: cache.cacheLocation ?? "",
+		name: cache.name ?? "",
+		version: cache.version ?? ""
+	};
+}
+
 function getRawSnapshotOptions(
 	snapshot: SnapshotOptions
 ): RawOptions["snapshot"] {
@@ -452,6 +532,8 @@ function getRawStats(stats: StatsValue): RawOptions["stats"] {
 	const statsOptions = normalizeStatsPreset(stats);
 	return {
 		colors: statsOptions.colors ?? false,
-		reasons: statsOptions.reasons ?? false
+		reasons: statsOptions.reasons ?? false,
+		providedExports: statsOptions.providedExports ?? false,
+		usedExports: statsOptions.usedExports ?? false
 	};
 }
@@ -1,6 +1,7 @@
 import * as path from "path";
 
 import type {
+	RawBannerConfig,
 	RawBuiltins,
 	RawHtmlPluginConfig,
 	RawDecoratorOptions,
@@ -13,10 +14,14 @@ import type {
 	RawPresetEnv,
 	RawPluginImportConfig,
 	RawCssModulesConfig,
-	RawRelayConfig
+	RawRelayConfig,
+	RawModuleFederationConfig,
+	RawSharedConfig
 } from "@rspack/binding";
 import { loadConfig } from "browserslist";
 import { Optimization } from "..";
+import { getRawRuleSetCondition } from "./adapter";
+import { RuleSetCondition } from "./types";
 
 export type BuiltinsHtmlPluginConfig = Omit<RawHtmlPluginConfig, "meta"> & {
 	meta?: Record<string, string | Record<string, string>>;
@@ -54,7 +59,7 @@ export interface Builtins {
 	provide?: Record<string, string | string[]>;
 	html?: Array<BuiltinsHtmlPluginConfig>;
 	decorator?: boolean | Partial<RawDecoratorOptions>;
-	minifyOptions?: Partial<RawMinification>;
+	minifyOptions?: MinifyOptions;
 	emotion?: EmotionConfig;
 	presetEnv?: Partial<RawBuiltins["presetEnv"]>;
 	polyfill?: boolean;
@@ -62,8 +67,20 @@ export interface Builtins {
 	copy?: CopyConfig;
 	pluginImport?: PluginImportConfig[];
 	relay?: RelayConfig;
+	moduleFederation?: ModuleFederationConfig;
+	banner?: BannerConfigs;
 }
 
+export type MinifyOptions = Partial<
+	Omit<RawMinification, "extractComments">
+> & {
+	/**
+	 * Extract comments matching the condition into `<asset>.LICENSE.txt`,
+	 * `true` extracts license comments, and they are kept inline if not set.
+	 */
+	extractComments?: boolean | string;
+};
+
 export type PluginImportConfig = {
 	libraryName: string;
 	libraryDirectory?: string;
@@ -87,6 +104,40 @@ export type CopyConfig = {
 
 export type RelayConfig = boolean | RawRelayConfig;
 
+export type BannerConfig =
+	| string
+	| {
+			banner: string;
+			entryOnly?: boolean;
+			footer?: boolean;
+			raw?: boolean;
+			test?: RuleSetCondition;
+			include?: RuleSetCondition;
+			exclude?: RuleSetCondition;
+	  };
+
+export type BannerConfigs = BannerConfig | BannerConfig[];
+
+export type ModuleFederationConfig = {
+	/** Name of the container, exposed modules are built into a container entry with this name */
+	name?: string;
+	/** Exposed name, e.g. `./Button`, to the request of the exposed module */
+	exposes?: Record<string, string | string[] | { import: string | string[] }>;
+	libraryType?: string;
+	/**
+	 * Remote key to the container external, e.g. `app@http://localhost:3001/remoteEntry.js`,
+	 * remote modules are loaded with async chunks, so they must be imported by `import()`
+	 */
+	remotes?: Record<string, string | { external: string; shareScope?: string }>;
+	remoteType?: string;
+	/**
+	 * A string value is the required version of the shared module, which defaults to the range
+	 * in the package.json of the consumer. Shared modules are loaded asynchronously unless `eager`
+	 */
+	shared?: string[] | Record<string, string | Omit<RawSharedConfig, "key">>;
+	shareScope?: string;
+};
+
 export type ResolvedBuiltins = Omit<RawBuiltins, "html"> & {
 	html?: Array<BuiltinsHtmlPluginConfig>;
 	emotion?: string;
@@ -238,6 +289,43 @@ function resolveEmotion(
 	return JSON.stringify(emotionConfig);
 }
 
+function resolveModuleFederation(
+	moduleFederation?: ModuleFederationConfig
+): RawModuleFederationConfig | undefined {
+	if (!moduleFederation) {
+		return undefined;
+	}
+	const { exposes = {}, remotes = {}, shared = {} } = moduleFederation;
+	return {
+		name: moduleFederation.name,
+		libraryType: moduleFederation.libraryType,
+		remoteType: moduleFederation.remoteType,
+		shareScope: moduleFederation.shareScope,
+		exposes: Object.entries(exposes).map(([name, expose]) => {
+			const request =
+				typeof expose === "object" && !Array.isArray(expose)
+					? expose.import
+					: expose;
+			return {
+				name,
+				import: Array.isArray(request) ? request : [request]
+			};
+		}),
+		remotes: Object.entries(remotes).map(([key, remote]) =>
+			typeof remote === "string"
+				? { key, external: remote }
+				: { key, ...remote }
+		),
+		shared: Array.isArray(shared)
+			? shared.map(key => ({ key }))
+			: Object.entries(shared).map(([key, config]) =>
+					typeof config === "string"
+						? { key, requiredVersion: config }
+						: { key, ...config }
+			  )
+	};
+}
+
 function resolveCopy(copy?: Builtins["copy"]): RawCopyConfig | undefined {
 	if (!copy) {
 		return undefined;
@@ -263,6 +351,37 @@ function resolveCopy(copy?: Builtins["copy"]): RawCopyConfig | undefined {
 	return ret;
 }
 
+function resolveBanner(
+	bannerConfigs?: BannerConfigs
+): RawBannerConfig[] | undefined {
+	if (!bannerConfigs) {
+		return undefined;
+	}
+
+	if (!Array.isArray(bannerConfigs)) {
+		bannerConfigs = [bannerConfigs];
+	}
+
+	return bannerConfigs.map(bannerConfig => {
+		if (typeof bannerConfig === "string") {
+			return { banner: bannerConfig };
+		}
+
+		return {
+			...bannerConfig,
+			test: bannerConfig.test
+				? getRawRuleSetCondition(bannerConfig.test)
+				: undefined,
+			include: bannerConfig.include
+				? getRawRuleSetCondition(bannerConfig.include)
+				: undefined,
+			exclude: bannerConfig.exclude
+				? getRawRuleSetCondition(bannerConfig.exclude)
+				: undefined
+		};
+	});
+}
+
 function resolveRelay(
 	relay: RelayConfig,
 	rootDir: string
@@ -351,10 +470,15 @@ export function resolveBuiltinsOptions(
 		pluginImport: resolvePluginImport(builtins.pluginImport),
 		relay: builtins.relay
 			? resolveRelay(builtins.relay, contextPath)
-			: undefined
+			: undefined,
+		moduleFederation: resolveModuleFederation(builtins.moduleFederation),
+		banner: resolveBanner(builtins.banner)
 	};
 }
 
+// Same as the default condition of terser-webpack-plugin
+const DEFAULT_EXTRACT_COMMENTS_CONDITION = "(?i)^\\**!|@preserve|@lic|@cc_on";
+
 export function resolveMinifyOptions(
 	builtins: Builtins,
 	optimization: Optimization
@@ -367,10 +491,17 @@ export function resolveMinifyOptions(
 		return undefined;
 	}
 
+	const { extractComments, ...minifyOptions } = builtins.minifyOptions ?? {};
 	return {
 		passes: 1,
 		dropConsole: false,
 		pureFuncs: [],
-		...builtins.minifyOptions
+		...minifyOptions,
+		...(extractComments && {
+			extractComments:
+				extractComments === true
+					? DEFAULT_EXTRACT_COMMENTS_CONDITION
+					: extractComments
+		})
 	};
 }
@@ -68,6 +68,8 @@ export const applyRspackOptionsDefaults = (
 	applyExperimentsDefaults(options.experiments);
 
 	F(options, "cache", () => development);
+	D(options, "recordsInputPath", false);
+	D(options, "recordsOutputPath", false);
 
 	applySnapshotDefaults(options.snapshot, { production });
 
@@ -96,7 +98,11 @@ export const applyRspackOptionsDefaults = (
 
 	applyNodeDefaults(options.node, { targetProperties });
 
-	applyOptimizationDefaults(options.optimization, { production, development });
+	applyOptimizationDefaults(options.optimization, {
+		production,
+		development,
+		hot: !!options.devServer?.hot
+	});
 
 	options.resolve = cleverMerge(
 		getResolveDefaults({
@@ -341,6 +347,19 @@ const applyOutputDefaults = (
 		}
 		return false;
 	});
+	F(output, "workerChunkLoading", () => {
+		if (tp) {
+			if (output.module) {
+				if (tp.dynamicImportInWorker) return "import";
+			} else if (tp.nodeBuiltins) {
+				// node `worker_threads` have no `importScripts`
+				return tp.require ? "require" : "async-node";
+			} else if (tp.importScriptsInWorker) {
+				return "import-scripts";
+			}
+		}
+		return false;
+	});
 	A(output, "enabledLibraryTypes", () => {
 		const enabledLibraryTypes = [];
 		if (output.library) {
@@ -357,8 +376,8 @@ const applyOutputDefaults = (
 		return "self";
 	});
 	D(output, "importFunctionName", "import");
-	F(output, "iife", () => !output.module);
 	F(output, "module", () => false); // TODO experiments.outputModule
+	F(output, "iife", () => !output.module);
 
 	A(output, "enabledWasmLoadingTypes", () => {
 		const enabledWasmLoadingTypes = new Set<string>();
@@ -404,14 +423,25 @@ const applyNodeDefaults = (
 
 const applyOptimizationDefaults = (
 	optimization: Optimization,
-	{ production, development }: { production: boolean; development: boolean }
+	{
+		production,
+		development,
+		hot
+	}: { production: boolean; development: boolean; hot: boolean }
 ) => {
 	D(optimization, "removeAvailableModules", true);
 	F(optimization, "moduleIds", () => {
 		if (production) return "deterministic";
 		return "named";
 	});
+	F(optimization, "chunkIds", () => {
+		if (production) return "deterministic";
+		return "named";
+	});
 	F(optimization, "sideEffects", () => (production ? true : "flag"));
+	// concatenated modules can't be replaced by hot module replacement yet
+	F(optimization, "concatenateModules", () => production && !hot);
+	D(optimization, "realContentHash", production);
 	D(optimization, "runtimeChunk", false);
 	D(optimization, "minimize", production);
 	A(optimization, "minimizer", () => []);
@@ -512,7 +542,7 @@ const getResolveDefaults = ({
 			// 	preferRelative: true
 			// },
 			commonjs: cjsDeps(),
-			// amd: cjsDeps(),
+			amd: cjsDeps(),
 			// for backward-compat: loadModule
 			// loader: cjsDeps(),
 			// for backward-compat: Custom Dependency and getResolve without dependencyType
@@ -57,6 +57,7 @@ export const getNormalizedRspackOptions = (
 					? [...output.enabledWasmLoadingTypes]
 					: ["..."],
 				webassemblyModuleFilename: output.webassemblyModuleFilename,
+				workerChunkLoading: output.workerChunkLoading,
 				uniqueName: output.uniqueName,
 				enabledLibraryTypes: output.enabledLibraryTypes
 					? [...output.enabledLibraryTypes]
@@ -122,6 +123,14 @@ export const getNormalizedRspackOptions = (
 			}))
 		})),
 		cache: optionalNestedConfig(config.cache, cache => cache),
+		recordsInputPath:
+			config.recordsInputPath !== undefined
+				? config.recordsInputPath
+				: config.recordsPath,
+		recordsOutputPath:
+			config.recordsOutputPath !== undefined
+				? config.recordsOutputPath
+				: config.recordsPath,
 		stats: nestedConfig(config.stats, stats => {
 			if (stats === false) {
 				return {
@@ -51,7 +51,17 @@ module.exports = {
 		CacheOptions: {
 			description:
 				"Cache generated modules and chunks to improve performance for multiple incremental builds.",
-			type: "boolean"
+			anyOf: [
+				{
+					type: "boolean"
+				},
+				{
+					$ref: "#/definitions/MemoryCacheOptions"
+				},
+				{
+					$ref: "#/definitions/FileCacheOptions"
+				}
+			]
 		},
 		ChunkFilename: {
 			description:
@@ -110,6 +120,30 @@ module.exports = {
 				}
 			]
 		},
+		ChunkLoading: {
+			description:
+				"The method of loading chunks (methods included by default are 'jsonp' (web), 'import' (ESM), 'importScripts' (WebWorker), 'require' (sync node.js), 'async-node' (async node.js), but others might be added by plugins).",
+			anyOf: [
+				{
+					enum: [false]
+				},
+				{
+					$ref: "#/definitions/ChunkLoadingType"
+				}
+			]
+		},
+		ChunkLoadingType: {
+			description:
+				"The method of loading chunks (methods included by default are 'jsonp' (web), 'import' (ESM), 'importScripts' (WebWorker), 'require' (sync node.js), 'async-node' (async node.js), but others might be added by plugins).",
+			anyOf: [
+				{
+					enum: ["jsonp", "import-scripts", "require", "async-node", "import"]
+				},
+				{
+					type: "string"
+				}
+			]
+		},
 		WasmLoadingType: {
 			description:
 				"The method of loading WebAssembly Modules (methods included by default are 'fetch' (web/WebWorker), 'async-node' (node.js), but others might be added by plugins).",
@@ -306,6 +340,11 @@ module.exports = {
 					additionalProperties: {
 						$ref: "#/definitions/ExternalItemValue"
 					}
+				},
+				{
+					description:
+						"The function is called on each dependency (`function(context, request, callback(err, result))`).",
+					instanceof: "Function"
 				}
 			]
 		},
@@ -320,6 +359,31 @@ module.exports = {
 					description:
 						"`true`: The dependency name is used as target of the external.",
 					type: "boolean"
+				},
+				{
+					description: "The target of the external.",
+					type: "array",
+					items: {
+						description: "The target of the external.",
+						type: "string"
+					}
+				},
+				{
+					description: "The target of the external for each library type.",
+					type: "object",
+					additionalProperties: {
+						anyOf: [
+							{
+								type: "array",
+								items: {
+									type: "string"
+								}
+							},
+							{
+								type: "string"
+							}
+						]
+					}
 				}
 			]
 		},
@@ -377,6 +441,64 @@ module.exports = {
 				"node-commonjs"
 			]
 		},
+		FileCacheOptions: {
+			description: "Options object for persistent file-based caching.",
+			type: "object",
+			additionalProperties: false,
+			properties: {
+				buildDependencies: {
+					description:
+						"Dependencies the build depends on (in multiple categories, default categories: 'defaultWebpack').",
+					type: "object",
+					additionalProperties: {
+						description: "List of dependencies the build depends on.",
+						type: "array",
+						items: {
+							description:
+								"Request to a dependency (resolved as directory relative to the context directory).",
+							type: "string",
+							minLength: 1
+						}
+					}
+				},
+				cacheDirectory: {
+					description:
+						"Base directory for the cache (defaults to node_modules/.cache/rspack).",
+					type: "string"
+				},
+				cacheLocation: {
+					description:
+						"Locations for the cache (defaults to cacheDirectory / name).",
+					type: "string"
+				},
+				maxAge: {
+					description:
+						"Time in ms after which unused cache entries are removed from the cache (defaults to one month).",
+					type: "number",
+					minimum: 0
+				},
+				name: {
+					description:
+						"Name for the cache. Different names will lead to different coexisting caches.",
+					type: "string"
+				},
+				profile: {
+					description:
+						"Track and log detailed timing information for individual cache items.",
+					type: "boolean"
+				},
+				type: {
+					description: "Filesystem caching.",
+					enum: ["filesystem"]
+				},
+				version: {
+					description:
+						"Version of the cache data. Different versions won't allow to reuse the cache and override existing content. Update the version when config changed in a way which doesn't allow to reuse cache.",
+					type: "string"
+				}
+			},
+			required: ["type"]
+		},
 		Filename: {
 			description:
 				"Specifies the filename of output files on disk. You must **not** specify an absolute path here, but the path may contain folders separated by '/'! The specified path is joined with the value of the 'output.path' option to determine the location on disk.",
@@ -652,6 +774,24 @@ module.exports = {
 				}
 			]
 		},
+		MemoryCacheOptions: {
+			description: "Options object for in-memory caching.",
+			type: "object",
+			additionalProperties: false,
+			properties: {
+				maxGenerations: {
+					description:
+						"Number of generations unused cache entries stay in memory cache at minimum.",
+					type: "number",
+					minimum: 1
+				},
+				type: {
+					description: "In memory caching.",
+					enum: ["memory"]
+				}
+			},
+			required: ["type"]
+		},
 		Mode: {
 			description: "Enable production optimizations or development hints.",
 			enum: ["development", "production", "none"]
@@ -751,11 +891,26 @@ module.exports = {
 						]
 					}
 				},
+				concatenateModules: {
+					description:
+						"Concatenate modules when possible to generate less modules, more efficient code and enable more optimizations by the minimizer. It's ignored with a warning when cache or hot module replacement is enabled.",
+					type: "boolean"
+				},
+				chunkIds: {
+					description:
+						"Define the algorithm to choose chunk ids (named: readable ids for better debugging, deterministic: numeric hash ids for better long term caching, natural: numeric ids in order of usage, size: numeric ids focused on minimal initial download size).",
+					enum: ["named", "deterministic", "natural", "size"]
+				},
 				moduleIds: {
 					description:
 						"Define the algorithm to choose module ids (natural: numeric ids in order of usage, named: readable ids for better debugging, hashed: (deprecated) short hashes as ids for better long term caching, deterministic: numeric hash ids for better long term caching, size: numeric ids focused on minimal initial download size, false: no algorithm used, as custom one can be provided via plugin).",
 					enum: ["named", "deterministic"]
 				},
+				realContentHash: {
+					description:
+						"Use real [contenthash] based on final content of the assets.",
+					type: "boolean"
+				},
 				removeAvailableModules: {
 					description:
 						"Removes modules from chunks when these modules are already included in all parents.",
@@ -1002,6 +1157,9 @@ module.exports = {
 				webassemblyModuleFilename: {
 					$ref: "#/definitions/WebassemblyModuleFilename"
 				},
+				workerChunkLoading: {
+					$ref: "#/definitions/ChunkLoading"
+				},
 				enabledLibraryTypes: {
 					$ref: "#/definitions/EnabledLibraryTypes"
 				},
@@ -1113,6 +1271,40 @@ module.exports = {
 				}
 			]
 		},
+		RecordsInputPath: {
+			description: "Load compiler state from a json file.",
+			anyOf: [
+				{
+					enum: [false]
+				},
+				{
+					type: "string"
+				}
+			]
+		},
+		RecordsOutputPath: {
+			description: "Store compiler state to a json file.",
+			anyOf: [
+				{
+					enum: [false]
+				},
+				{
+					type: "string"
+				}
+			]
+		},
+		RecordsPath: {
+			description:
+				"Store/Load compiler state from/to a json file. This will result in persistent ids of modules and chunks. An absolute path is expected. `recordsPath` is used for `recordsInputPath` and `recordsOutputPath` if they left undefined.",
+			anyOf: [
+				{
+					enum: [false]
+				},
+				{
+					type: "string"
+				}
+			]
+		},
 		Resolve: {
 			description: "Options for the resolver.",
 			oneOf: [
@@ -1664,6 +1856,14 @@ module.exports = {
 						"Add information about the reasons why modules are included.",
 					type: "boolean"
 				},
+				providedExports: {
+					description: "Show exports provided by modules.",
+					type: "boolean"
+				},
+				usedExports: {
+					description: "Show which exports of a module are used.",
+					type: "boolean"
+				},
 				warnings: {
 					description: "Add warnings.",
 					type: "boolean"
@@ -1885,6 +2085,15 @@ module.exports = {
 		plugins: {
 			$ref: "#/definitions/Plugins"
 		},
+		recordsInputPath: {
+			$ref: "#/definitions/RecordsInputPath"
+		},
+		recordsOutputPath: {
+			$ref: "#/definitions/RecordsOutputPath"
+		},
+		recordsPath: {
+			$ref: "#/definitions/RecordsPath"
+		},
 		resolve: {
 			$ref: "#/definitions/Resolve"
 		},
@@ -43,6 +43,9 @@ export interface RspackOptions {
 	watchOptions?: WatchOptions;
 	devServer?: DevServer;
 	builtins?: Builtins;
+	recordsInputPath?: RecordsInputPath;
+	recordsOutputPath?: RecordsOutputPath;
+	recordsPath?: RecordsPath;
 }
 
 export interface RspackOptionsNormalized {
@@ -71,6 +74,8 @@ export interface RspackOptionsNormalized {
 	watchOptions: WatchOptions;
 	devServer?: DevServer;
 	builtins: Builtins;
+	recordsInputPath?: RecordsInputPath;
+	recordsOutputPath?: RecordsOutputPath;
 }
 
 ///// Name /////
@@ -132,6 +137,7 @@ export interface Output {
 	wasmLoading?: WasmLoading;
 	enabledWasmLoadingTypes?: EnabledWasmLoadingTypes;
 	webassemblyModuleFilename?: WebassemblyModuleFilename;
+	workerChunkLoading?: ChunkLoading;
 }
 export type Path = string;
 export type PublicPath = "auto" | RawPublicPath;
@@ -200,6 +206,10 @@ export type WasmLoadingType =
 	| ("fetch-streaming" | "fetch" | "async-node")
 	| string;
 export type EnabledWasmLoadingTypes = WasmLoadingType[];
+export type ChunkLoading = false | ChunkLoadingType;
+export type ChunkLoadingType =
+	| ("jsonp" | "import-scripts" | "require" | "async-node" | "import")
+	| string;
 export interface OutputNormalized {
 	path?: Path;
 	publicPath?: PublicPath;
@@ -219,6 +229,7 @@ export interface OutputNormalized {
 	wasmLoading?: WasmLoading;
 	enabledWasmLoadingTypes?: EnabledWasmLoadingTypes;
 	webassemblyModuleFilename?: WebassemblyModuleFilename;
+	workerChunkLoading?: ChunkLoading;
 }
 
 ///// Resolve /////
@@ -322,11 +333,32 @@ export type Target = false | string[] | string;
 
 ///// Externals /////
 export type Externals = ExternalItem[] | ExternalItem;
-export type ExternalItem = string | RegExp | ExternalItemObjectUnknown;
+export type ExternalItem =
+	| string
+	| RegExp
+	| ExternalItemObjectUnknown
+	| ExternalItemFunction;
+export interface ExternalItemFunctionData {
+	request: string;
+	context: string;
+	dependencyType: string;
+}
+export type ExternalItemFunction = (
+	data: ExternalItemFunctionData,
+	callback: (
+		err?: Error,
+		result?: ExternalItemValue,
+		type?: ExternalsType
+	) => void
+) => void | Promise<ExternalItemValue | undefined>;
 export interface ExternalItemObjectUnknown {
 	[k: string]: ExternalItemValue;
 }
-export type ExternalItemValue = string | boolean;
+export type ExternalItemValue =
+	| string
+	| boolean
+	| string[]
+	| Record<string, string | string[]>;
 
 ///// ExternalsType /////
 export type ExternalsType =
@@ -419,8 +451,30 @@ export interface SnapshotOptions {
 }
 
 ///// Cache /////
-// TODO: align with webpack
-export type CacheOptions = true | false;
+export type CacheOptions = true | false | MemoryCacheOptions | FileCacheOptions;
+
+export interface MemoryCacheOptions {
+	type: "memory";
+	maxGenerations?: number;
+}
+
+export interface FileCacheOptions {
+	type: "filesystem";
+	buildDependencies?: {
+		[index: string]: string[];
+	};
+	cacheDirectory?: string;
+	cacheLocation?: string;
+	maxAge?: number;
+	name?: string;
+	profile?: boolean;
+	version?: string;
+}
+
+///// Records /////
+export type RecordsInputPath = false | string;
+export type RecordsOutputPath = false | string;
+export type RecordsPath = false | string;
 
 ///// Stats /////
 export type StatsValue =
@@ -442,6 +496,8 @@ export interface StatsOptions {
 	colors?: boolean;
 	hash?: boolean;
 	reasons?: boolean;
+	providedExports?: boolean;
+	usedExports?: boolean;
 	publicPath?: boolean;
 	outputPath?: boolean;
 	chunkModules?: boolean;
@@ -453,12 +509,15 @@ export interface StatsOptions {
 ///// Optimization /////
 export interface Optimization {
 	moduleIds?: "named" | "deterministic";
+	chunkIds?: "named" | "deterministic" | "natural" | "size";
 	minimize?: boolean;
 	minimizer?: ("..." | RspackPluginInstance)[];
 	splitChunks?: OptimizationSplitChunksOptions | false;
 	runtimeChunk?: OptimizationRuntimeChunk;
 	removeAvailableModules?: boolean;
 	sideEffects?: "flag" | boolean;
+	concatenateModules?: boolean;
+	realContentHash?: boolean;
 }
 export interface OptimizationSplitChunksOptions {
 	cacheGroups?: {
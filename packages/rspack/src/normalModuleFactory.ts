@@ -11,12 +11,15 @@ type ResourceData = {
 // resource_query: (!info.query.is_empty()).then_some(info.query),
 // resource_fragment: (!info.fragment.is_empty()).then_some(info.fragment),
 type ResourceDataWithData = ResourceData & { data?: Record<string, any> };
-// type ResolveData = {
-// 	context: string;
-// 	request: string;
-// 	assertions: Record<string, any> | undefined;
-// 	// dependencies: ModuleDependency[];
-// };
+export type ResolveData = {
+	context?: string;
+	request: string;
+	// assertions: Record<string, any> | undefined;
+	// dependencies: ModuleDependency[];
+};
+export type AfterResolveData = ResolveData & {
+	resourceData: ResourceData;
+};
 
 export class NormalModuleFactory {
 	hooks: {
@@ -24,6 +27,10 @@ export class NormalModuleFactory {
 		resolveForScheme: HookMap<
 			AsyncSeriesBailHook<[ResourceDataWithData], true | void>
 		>;
+		beforeResolve: AsyncSeriesBailHook<[ResolveData], false | void>;
+		afterResolve: AsyncSeriesBailHook<[AfterResolveData], false | void>;
+		// TODO: creating modules from JS is not supported yet, the return value is ignored
+		factorize: AsyncSeriesBailHook<[ResolveData], void>;
 	};
 	constructor() {
 		this.hooks = {
@@ -32,17 +39,14 @@ export class NormalModuleFactory {
 			// /** @type {HookMap<AsyncSeriesBailHook<[ResourceDataWithData, ResolveData], true | void>>} */
 			resolveForScheme: new HookMap(
 				() => new AsyncSeriesBailHook(["resourceData"])
-			)
+			),
 			// /** @type {HookMap<AsyncSeriesBailHook<[ResourceDataWithData, ResolveData], true | void>>} */
 			// resolveInScheme: new HookMap(
 			// 	() => new AsyncSeriesBailHook(["resourceData", "resolveData"])
 			// ),
-			// /** @type {AsyncSeriesBailHook<[ResolveData], Module>} */
-			// factorize: new AsyncSeriesBailHook(["resolveData"]),
-			// /** @type {AsyncSeriesBailHook<[ResolveData], false | void>} */
-			// beforeResolve: new AsyncSeriesBailHook(["resolveData"]),
-			// /** @type {AsyncSeriesBailHook<[ResolveData], false | void>} */
-			// afterResolve: new AsyncSeriesBailHook(["resolveData"]),
+			factorize: new AsyncSeriesBailHook(["resolveData"]),
+			beforeResolve: new AsyncSeriesBailHook(["resolveData"]),
+			afterResolve: new AsyncSeriesBailHook(["resolveData"]),
 			// /** @type {AsyncSeriesBailHook<[ResolveData["createData"], ResolveData], Module | void>} */
 			// createModule: new AsyncSeriesBailHook(["createData", "resolveData"]),
 			// /** @type {SyncWaterfallHook<[Module, ResolveData["createData"], ResolveData], Module>} */
@@ -308,6 +308,34 @@ describe("Compiler", () => {
 			done();
 		});
 	});
+	it("should report the error of function externals", done => {
+		compiler = rspack({
+			context: path.join(__dirname, "fixtures"),
+			mode: "production",
+			entry: "./c",
+			externals: [
+				({ request }, callback) => {
+					if (request === "./a") {
+						return callback(new Error("Failed to resolve external ./a"));
+					}
+					callback();
+				}
+			],
+			output: {
+				filename: "bundle.js"
+			}
+		});
+		compiler.outputFileSystem = createFsFromVolume(new Volume());
+		compiler.run((err, stats) => {
+			const messages = err
+				? [err.message]
+				: stats.toJson().errors.map(e => e.message);
+			expect(
+				messages.some(m => m.includes("Failed to resolve external ./a"))
+			).toBeTruthy();
+			done();
+		});
+	});
 	// TODO: support `bail`
 	it.skip("should bubble up errors when wrapped in a promise and bail is true", async () => {
 		try {
@@ -75,6 +75,7 @@ describe("snapshots", () => {
 		expect(baseConfig).toMatchInlineSnapshot(`
 		{
 		  "builtins": {
+		    "banner": undefined,
 		    "copy": undefined,
 		    "css": {
 		      "modules": {
@@ -92,6 +93,7 @@ describe("snapshots", () => {
 		    "emotion": undefined,
 		    "html": [],
 		    "minifyOptions": undefined,
+		    "moduleFederation": undefined,
 		    "noEmitAssets": false,
 		    "pluginImport": undefined,
 		    "postcss": {
@@ -194,9 +196,12 @@ describe("snapshots", () => {
 		    "global": "warn",
 		  },
 		  "optimization": {
+		    "chunkIds": "named",
+		    "concatenateModules": false,
 		    "minimize": false,
 		    "minimizer": [],
 		    "moduleIds": "named",
+		    "realContentHash": false,
 		    "removeAvailableModules": true,
 		    "runtimeChunk": false,
 		    "sideEffects": "flag",
@@ -245,8 +250,11 @@ describe("snapshots", () => {
 		    "uniqueName": "@rspack/core",
 		    "wasmLoading": "fetch",
 		    "webassemblyModuleFilename": "[hash].module.wasm",
+		    "workerChunkLoading": "import-scripts",
 		  },
 		  "plugins": [],
+		  "recordsInputPath": false,
+		  "recordsOutputPath": false,
 		  "resolve": {
 		    "browserField": true,
 		    "byDependency": {
@@ -422,11 +430,17 @@ describe("snapshots", () => {
 		-   "mode": "none",
 		+   "mode": undefined,
 		@@ ... @@
+		-     "chunkIds": "named",
+		-     "concatenateModules": false,
 		-     "minimize": false,
+		+     "chunkIds": "deterministic",
+		+     "concatenateModules": true,
 		+     "minimize": true,
 		@@ ... @@
 		-     "moduleIds": "named",
+		-     "realContentHash": false,
 		+     "moduleIds": "deterministic",
+		+     "realContentHash": true,
 		@@ ... @@
 		-     "sideEffects": "flag",
 		+     "sideEffects": true,
@@ -470,11 +484,17 @@ describe("snapshots", () => {
 		-   "mode": "none",
 		+   "mode": "production",
 		@@ ... @@
+		-     "chunkIds": "named",
+		-     "concatenateModules": false,
 		-     "minimize": false,
+		+     "chunkIds": "deterministic",
+		+     "concatenateModules": true,
 		+     "minimize": true,
 		@@ ... @@
 		-     "moduleIds": "named",
+		-     "realContentHash": false,
 		+     "moduleIds": "deterministic",
+		+     "realContentHash": true,
 		@@ ... @@
 		-     "sideEffects": "flag",
 		+     "sideEffects": true,
@@ -825,6 +845,9 @@ describe("snapshots", () => {
 		-     "wasmLoading": "fetch",
 		+     "wasmLoading": "async-node",
 		@@ ... @@
+		-     "workerChunkLoading": "import-scripts",
+		+     "workerChunkLoading": "require",
+		@@ ... @@
 		-     "browserField": true,
 		+     "browserField": false,
 		@@ ... @@
@@ -895,6 +918,9 @@ describe("snapshots", () => {
 		-     "wasmLoading": "fetch",
 		+     "wasmLoading": "async-node",
 		@@ ... @@
+		-     "workerChunkLoading": "import-scripts",
+		+     "workerChunkLoading": "require",
+		@@ ... @@
 		-     "browserField": true,
 		+     "browserField": false,
 		@@ ... @@
@@ -954,6 +980,9 @@ describe("snapshots", () => {
 		-     "wasmLoading": "fetch",
 		+     "wasmLoading": "async-node",
 		@@ ... @@
+		-     "workerChunkLoading": "import-scripts",
+		+     "workerChunkLoading": "require",
+		@@ ... @@
 		-     "browserField": true,
 		+     "browserField": false,
 		@@ ... @@
@@ -1077,6 +1106,32 @@ describe("snapshots", () => {
 		`)
 	);
 
+	test("records", { recordsPath: "/records.json" }, e =>
+		e.toMatchInlineSnapshot(`
+		- Expected
+		+ Received
+
+		@@ ... @@
+		-   "recordsInputPath": false,
+		-   "recordsOutputPath": false,
+		+   "recordsInputPath": "/records.json",
+		+   "recordsOutputPath": "/records.json",
+	`)
+	);
+	test(
+		"records input",
+		{ recordsPath: "/records.json", recordsInputPath: false },
+		e =>
+			e.toMatchInlineSnapshot(`
+			- Expected
+			+ Received
+
+			@@ ... @@
+			-   "recordsOutputPath": false,
+			+   "recordsOutputPath": "/records.json",
+		`)
+	);
+
 	// TODO: options.node = false
 	// test(
 	// 	"disable",
@@ -26,7 +26,7 @@ describe("Stats", () => {
 		        "main",
 		      ],
 		      "chunks": [
-		        "main",
+		        "179",
 		      ],
 		      "emitted": true,
 		      "info": {
@@ -50,12 +50,12 @@ describe("Stats", () => {
 		      "files": [
 		        "main.js",
 		      ],
-		      "id": "main",
+		      "id": "179",
 		      "initial": true,
 		      "modules": [
 		        {
 		          "chunks": [
-		            "main",
+		            "179",
 		          ],
 		          "id": "777",
 		          "identifier": "javascript/auto|<PROJECT_ROOT>/tests/fixtures/a.js",
@@ -85,7 +85,7 @@ describe("Stats", () => {
 		      ],
 		      "assetsSize": 215,
 		      "chunks": [
-		        "main",
+		        "179",
 		      ],
 		      "name": "main",
 		    },
@@ -96,7 +96,7 @@ describe("Stats", () => {
 		  "modules": [
 		    {
 		      "chunks": [
-		        "main",
+		        "179",
 		      ],
 		      "id": "777",
 		      "identifier": "javascript/auto|<PROJECT_ROOT>/tests/fixtures/a.js",
@@ -117,7 +117,7 @@ describe("Stats", () => {
 		      ],
 		      "assetsSize": 215,
 		      "chunks": [
-		        "main",
+		        "179",
 		      ],
 		      "name": "main",
 		    },
@@ -132,11 +132,11 @@ describe("Stats", () => {
 		"Hash: ff293361e645d785
 		PublicPath: auto
 		  Asset       Size  Chunks             Chunk Names
-		main.js  215 bytes    main  [emitted]  main
+		main.js  215 bytes     179  [emitted]  main
 		Entrypoint main = main.js
-		chunk {main} main.js (main) 55 bytes [entry]
-		 [777] ./fixtures/a.js 55 bytes {main}
-		[777] ./fixtures/a.js 55 bytes {main}"
+		chunk {179} main.js (main) 55 bytes [entry]
+		 [777] ./fixtures/a.js 55 bytes {179}
+		[777] ./fixtures/a.js 55 bytes {179}"
 	`);
 	});
 
@@ -161,12 +161,12 @@ describe("Stats", () => {
 		"Hash: 2168fece27972fed
 		PublicPath: auto
 		  Asset       Size  Chunks             Chunk Names
-		main.js  419 bytes    main  [emitted]  main
+		main.js  419 bytes     179  [emitted]  main
 		Entrypoint main = main.js
-		[777] ./fixtures/a.js 55 bytes {main}
-		[510] ./fixtures/b.js 94 bytes {main}
-		[906] ./fixtures/c.js 72 bytes {main}
-		[492] ./fixtures/abc.js 83 bytes {main}
+		[777] ./fixtures/a.js 55 bytes {179}
+		[510] ./fixtures/b.js 94 bytes {179}
+		[906] ./fixtures/c.js 72 bytes {179}
+		[492] ./fixtures/abc.js 83 bytes {179}
 
 		error[javascript]: JavaScript parsing error
 		  ┌─ tests/fixtures/b.js:6:1
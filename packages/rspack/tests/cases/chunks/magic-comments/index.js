@@ -0,0 +1,43 @@
+it("should put modules with the same webpackChunkName into the same chunk", function () {
+	return Promise.all([
+		import(/* webpackChunkName: "named" */ "./a"),
+		import(/* webpackChunkName: "named" */ "./b")
+	]).then(function ([a, b]) {
+		expect(a.default).toBe("a");
+		expect(b.default).toBe("b");
+	});
+});
+
+it("should include the module into the current chunk with webpackMode eager", function () {
+	const promise = import(/* webpackMode: "eager" */ "./eager");
+	return promise.then(function (eager) {
+		expect(eager.default).toBe("eager");
+	});
+});
+
+it("should reject when the module is not available with webpackMode weak", function () {
+	return import(/* webpackMode: "weak" */ "./weak").then(
+		function () {
+			throw new Error("should not be loaded");
+		},
+		function (err) {
+			expect(err.code).toBe("MODULE_NOT_FOUND");
+		}
+	);
+});
+
+it("should resolve when the module is available with webpackMode weak", function () {
+	require("./shared");
+	return import(/* webpackMode: "weak" */ "./shared").then(function (shared) {
+		expect(shared.default).toBe("shared");
+	});
+});
+
+it("should replace [request] in webpackChunkName of context", function () {
+	const lang = "en";
+	return import(/* webpackChunkName: "locale-[request]" */ `./locales/${lang}`).then(
+		function (locale) {
+			expect(locale.default).toBe("en");
+		}
+	);
+});
@@ -0,0 +1,5 @@
+it("should warn on an invalid context mode", function () {
+	if (Math.random() < 0) {
+		require.context(".", false, /a/, "invalid");
+	}
+});
@@ -0,0 +1 @@
+module.exports = [[/Unknown context mode "invalid"/]];
@@ -0,0 +1,8 @@
+const fs = require("fs");
+
+it("should add banner and footer to the entry chunk", () => {
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(content.startsWith("/*! banner for main */\n")).toBe(true);
+	expect(content).toContain("\n// raw footer");
+	expect(content).not.toContain("/*! " + "excluded banner");
+});
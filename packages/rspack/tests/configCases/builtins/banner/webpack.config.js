@@ -0,0 +1,17 @@
+module.exports = {
+	builtins: {
+		banner: [
+			"banner for [name]",
+			{
+				banner: "// raw footer",
+				raw: true,
+				footer: true,
+				entryOnly: true
+			},
+			{
+				banner: "excluded banner",
+				exclude: /\.js$/
+			}
+		]
+	}
+};
@@ -0,0 +1,14 @@
+/*! @license MIT minify-extract-comments */
+const fs = require("fs");
+
+it("should extract license comments into LICENSE.txt", () => {
+	const content = fs.readFileSync(__filename, "utf-8");
+	const license = fs.readFileSync(__dirname + "/main.js.LICENSE.txt", "utf-8");
+	expect(license).toContain("@license MIT");
+	expect(content).not.toContain(license.trim());
+	expect(
+		content.startsWith(
+			"/*! For license information please see main.js.LICENSE.txt */"
+		)
+	).toBe(true);
+});
@@ -0,0 +1,10 @@
+module.exports = {
+	builtins: {
+		minifyOptions: {
+			extractComments: true
+		}
+	},
+	optimization: {
+		minimize: true
+	}
+};
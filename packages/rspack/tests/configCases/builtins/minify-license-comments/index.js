@@ -0,0 +1,12 @@
+/*! @license MIT minify-license-comments */
+const fs = require("fs");
+const path = require("path");
+
+it("should preserve license comments inline by default", () => {
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(content).toContain("@license MIT minify-" + "license-comments");
+	expect(content).not.toContain("For license information");
+	expect(fs.existsSync(path.resolve(__dirname, "main.js.LICENSE.txt"))).toBe(
+		false
+	);
+});
@@ -0,0 +1,20 @@
+const nodeRequire = require("node-require");
+
+it("should expose modules from the container", async () => {
+	const container = nodeRequire("./container.js");
+	expect(typeof container.get).toBe("function");
+	expect(typeof container.init).toBe("function");
+	await container.init({});
+
+	const exposed = (await container.get("./module"))();
+	expect(exposed.value).toBe("module");
+	const other = (await container.get("./other"))();
+	expect(other.default).toBe("other");
+});
+
+it("should reject modules which are not exposed", async () => {
+	const container = nodeRequire("./container.js");
+	await expect(container.get("./missing")).rejects.toThrow(
+		'Module "./missing" does not exist in container.'
+	);
+});
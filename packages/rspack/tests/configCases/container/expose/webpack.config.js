@@ -0,0 +1,16 @@
+module.exports = {
+	externals: {
+		// `require` of node, which loads the emitted container
+		"node-require": "var require"
+	},
+	builtins: {
+		moduleFederation: {
+			name: "container",
+			libraryType: "commonjs2",
+			exposes: {
+				"./module": "./module.js",
+				"./other": { import: "./other.js" }
+			}
+		}
+	}
+};
@@ -0,0 +1,4 @@
+it("should load modules from the remote container", async () => {
+	const { value } = await import("self/module");
+	expect(value).toBe("module from remote");
+});
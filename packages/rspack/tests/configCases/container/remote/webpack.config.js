@@ -0,0 +1,16 @@
+module.exports = {
+	builtins: {
+		moduleFederation: {
+			name: "container",
+			libraryType: "commonjs2",
+			exposes: {
+				"./module": "./module.js"
+			},
+			// the container built along with the entry is consumed as a remote
+			remotes: {
+				self: "./container.js"
+			},
+			remoteType: "commonjs2"
+		}
+	}
+};
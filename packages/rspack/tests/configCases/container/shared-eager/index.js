@@ -0,0 +1,4 @@
+it("should consume eager shared modules synchronously", () => {
+	// the fallback is bundled into the entry chunk instead of an async chunk
+	expect(require("shared-lib").version).toBe("1.2.3");
+});
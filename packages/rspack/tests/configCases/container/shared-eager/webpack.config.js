@@ -0,0 +1,9 @@
+module.exports = {
+	builtins: {
+		moduleFederation: {
+			shared: {
+				"shared-lib": { eager: true }
+			}
+		}
+	}
+};
@@ -0,0 +1,21 @@
+const nodeRequire = require("node-require");
+
+const provide = version => ({
+	get: () => () => ({ version }),
+	from: "other-build"
+});
+
+it("should infer the required version from package.json of the consumer", async () => {
+	const container = nodeRequire("./container.js");
+	const shareScope = {
+		"shared-lib": {
+			"1.5.0": provide("1.5.0"),
+			"2.0.0": provide("2.0.0")
+		}
+	};
+	await container.init(shareScope);
+
+	// 2.0.0 would be consumed if versions weren't checked
+	const version = (await container.get("./consumer"))();
+	expect(version).toBe("1.5.0");
+});
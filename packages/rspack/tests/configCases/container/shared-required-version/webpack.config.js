@@ -0,0 +1,17 @@
+module.exports = {
+	externals: {
+		// `require` of node, which loads the emitted container
+		"node-require": "var require"
+	},
+	builtins: {
+		moduleFederation: {
+			name: "container",
+			libraryType: "commonjs2",
+			exposes: {
+				"./consumer": "./consumer.js"
+			},
+			// the required version is read from package.json
+			shared: ["shared-lib"]
+		}
+	}
+};
@@ -0,0 +1 @@
+module.exports = require("shared-lib").version;
@@ -0,0 +1,26 @@
+const nodeRequire = require("node-require");
+
+const provide = version => ({
+	get: () => () => ({ version }),
+	from: "other-build"
+});
+
+it("should consume the highest version satisfying the required version", async () => {
+	const container = nodeRequire("./container.js");
+	const shareScope = {
+		"shared-lib": {
+			"1.5.0": provide("1.5.0"),
+			"2.0.0": provide("2.0.0")
+		}
+	};
+	await container.init(shareScope);
+	// the fallback module is provided with the version of its package.json
+	expect(Object.keys(shareScope["shared-lib"]).sort()).toEqual([
+		"1.2.3",
+		"1.5.0",
+		"2.0.0"
+	]);
+
+	const version = (await container.get("./consumer"))();
+	expect(version).toBe("1.5.0");
+});
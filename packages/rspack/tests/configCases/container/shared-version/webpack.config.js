@@ -0,0 +1,18 @@
+module.exports = {
+	externals: {
+		// `require` of node, which loads the emitted container
+		"node-require": "var require"
+	},
+	builtins: {
+		moduleFederation: {
+			name: "container",
+			libraryType: "commonjs2",
+			exposes: {
+				"./consumer": "./consumer.js"
+			},
+			shared: {
+				"shared-lib": "^1.0.0"
+			}
+		}
+	}
+};
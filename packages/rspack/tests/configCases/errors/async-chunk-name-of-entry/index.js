@@ -0,0 +1,8 @@
+it("should put the module into the entry chunk if its chunk name is used by the entry", async () => {
+	const { value } = await import(/* webpackChunkName: "main" */ "./async");
+	expect(value).toBe("async");
+	const files = require("fs")
+		.readdirSync(__dirname)
+		.filter(file => file.endsWith(".js"));
+	expect(files).toEqual(["main.js"]);
+});
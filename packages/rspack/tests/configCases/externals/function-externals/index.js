@@ -0,0 +1,20 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should resolve function externals with a callback", () => {
+	expect(require("callback-external")).toBe(path);
+});
+
+it("should resolve function externals with a promise", () => {
+	expect(require("promise-external")).toBe(path.join);
+});
+
+it("should keep the request as is when the type is given", () => {
+	// never called, "foo bar" doesn't exist
+	// eslint-disable-next-line no-unused-vars
+	function load() {
+		return require("explicit-type");
+	}
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).toContain("module.exports = require('foo" + " bar')");
+});
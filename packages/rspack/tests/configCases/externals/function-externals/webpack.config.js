@@ -0,0 +1,19 @@
+module.exports = {
+	externals: [
+		({ request }, callback) => {
+			if (request === "explicit-type") {
+				// the type is given, so "foo bar" is the request as a whole
+				return callback(null, "foo bar", "commonjs");
+			}
+			if (request === "callback-external") {
+				return callback(null, "commonjs path");
+			}
+			callback();
+		},
+		async ({ request }) => {
+			if (request === "promise-external") {
+				return ["commonjs path", "join"];
+			}
+		}
+	]
+};
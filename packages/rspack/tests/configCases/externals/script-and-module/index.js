@@ -0,0 +1,25 @@
+import "./inject";
+
+const fs = require("fs");
+
+it("should resolve script externals from the global variable", async () => {
+	const lib = await require("script-lib");
+	expect(lib).toEqual({ name: "script-lib" });
+});
+
+it("should escape the url of script externals", () => {
+	const source = fs.readFileSync(__filename, "utf-8");
+	const url = JSON.stringify("https://example.com/it's-a-lib.js");
+	expect(source).toContain("__webpack_require__.l(" + url + ", function");
+});
+
+it("should escape the request of module externals", () => {
+	// never called, the module external is loaded by import() when it's required
+	// eslint-disable-next-line no-unused-vars
+	function load() {
+		return require("module-lib");
+	}
+	const source = fs.readFileSync(__filename, "utf-8");
+	const request = JSON.stringify("./it's-a-module.mjs");
+	expect(source).toContain("module.exports = import(" + request + ")");
+});
@@ -0,0 +1 @@
+global.scriptGlobal = { name: "script-lib" };
@@ -0,0 +1,6 @@
+module.exports = {
+	externals: {
+		"script-lib": "script scriptGlobal@https://example.com/it's-a-lib.js",
+		"module-lib": "module ./it's-a-module.mjs"
+	}
+};
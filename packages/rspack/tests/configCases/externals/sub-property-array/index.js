@@ -0,0 +1,9 @@
+const join = require("path-join");
+const posixJoin = require("posix-join");
+const path = require("path-module");
+
+it("should access the properties of array externals", function () {
+	expect(typeof join).toBe("function");
+	expect(join).toBe(path.join);
+	expect(posixJoin).toBe(path.posix.join);
+});
@@ -0,0 +1,8 @@
+module.exports = {
+	externalsType: "commonjs",
+	externals: {
+		"path-join": ["path", "join"],
+		"posix-join": ["path", "posix", "join"],
+		"path-module": ["path"]
+	}
+};
@@ -0,0 +1,8 @@
+import path from "external-path";
+import join from "external-join";
+
+it("should use the request of the library type of object externals", function () {
+	expect(typeof join).toBe("function");
+	expect(join).toBe(path.join);
+	expect(join("a", "b")).toBe(path.join("a", "b"));
+});
@@ -0,0 +1,23 @@
+/** @type {import("../../../../dist").Configuration} */
+module.exports = {
+	output: {
+		library: {
+			type: "umd",
+			name: "testLibrary"
+		}
+	},
+	externals: {
+		"external-path": {
+			root: "path",
+			commonjs: "path",
+			commonjs2: "path",
+			amd: "path"
+		},
+		"external-join": {
+			root: ["path", "join"],
+			commonjs: ["path", "join"],
+			commonjs2: ["path", "join"],
+			amd: "path"
+		}
+	}
+};
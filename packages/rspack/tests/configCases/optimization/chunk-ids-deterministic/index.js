@@ -0,0 +1,11 @@
+const fs = require("fs");
+
+it("should assign deterministic numeric ids to chunks", async () => {
+	const { value } = await import("./async");
+	expect(value).toBe(42);
+	const chunks = fs
+		.readdirSync(__dirname)
+		.filter(file => file.endsWith(".js") && file !== "main.js");
+	expect(chunks).toHaveLength(1);
+	expect(chunks[0]).toMatch(/^\d+\.js$/);
+});
@@ -0,0 +1,5 @@
+module.exports = {
+	optimization: {
+		chunkIds: "deterministic"
+	}
+};
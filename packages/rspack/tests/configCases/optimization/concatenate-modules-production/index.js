@@ -0,0 +1,11 @@
+import { a } from "./a";
+import { b } from "./b";
+
+it("should concatenate modules by default in production", () => {
+	expect(a + b).toBe("ab");
+	const source = require("fs").readFileSync(__filename, "utf-8");
+	// split the marker so that this file doesn't match it by itself
+	const marker = ["CONCATENATED", "MODULE"].join(" ");
+	expect(source).toContain(`// ${marker}: ./a.js`);
+	expect(source).toContain(`// ${marker}: ./b.js`);
+});
@@ -0,0 +1,7 @@
+module.exports = {
+	mode: "production",
+	optimization: {
+		// keep the comments of concatenated modules
+		minimize: false
+	}
+};
@@ -0,0 +1,2 @@
+// the comment is removed by minification, so the content hash has to be recomputed
+export const value = 42;
@@ -0,0 +1,37 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should load the chunk renamed by the real content hash", async () => {
+	const { value } = await import("./async");
+	expect(value).toBe(42);
+	const chunks = fs
+		.readdirSync(__dirname)
+		.filter(file => file.endsWith(".js") && file !== "main.js");
+	expect(chunks).toHaveLength(1);
+	expect(chunks[0]).toMatch(/^async_js\.[0-9a-f]+\.js$/);
+	expect(fs.readFileSync(__filename, "utf-8")).toContain(
+		chunks[0].split(".")[1]
+	);
+	const content = fs.readFileSync(path.resolve(__dirname, chunks[0]), "utf-8");
+	expect(content).not.toContain("the comment is removed");
+});
+
+it("should rename the source map along with the asset", () => {
+	const chunk = fs
+		.readdirSync(__dirname)
+		.find(file => file.startsWith("async_js.") && file.endsWith(".js"));
+	const content = fs.readFileSync(path.resolve(__dirname, chunk), "utf-8");
+	expect(content.endsWith(`//# sourceMappingURL=${chunk}.map`)).toBe(true);
+	const map = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, `${chunk}.map`), "utf-8")
+	);
+	expect(map.file).toBe(chunk);
+});
+
+it("should replace content hashes longer than a digest", () => {
+	const assets = fs.readdirSync(__dirname).filter(file => file.endsWith(".txt"));
+	expect(assets).toHaveLength(1);
+	const [, hash] = assets[0].split(".");
+	expect(hash).toMatch(/^[0-9a-f]{32}$/);
+	expect(hash).not.toBe("0123456789abcdef0123456789abcdef");
+});
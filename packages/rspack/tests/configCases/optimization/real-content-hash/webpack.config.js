@@ -0,0 +1,31 @@
+const { RawSource } = require("webpack-sources");
+
+const LONG_HASH = "0123456789abcdef0123456789abcdef";
+
+module.exports = {
+	output: {
+		chunkFilename: "[name].[contenthash].js"
+	},
+	devtool: "source-map",
+	optimization: {
+		minimize: true,
+		realContentHash: true
+	},
+	plugins: [
+		{
+			// Emits an asset with a content hash longer than a digest
+			name: "long-content-hash",
+			apply(compiler) {
+				compiler.hooks.compilation.tap("long-content-hash", compilation => {
+					compilation.hooks.processAssets.tap("long-content-hash", () => {
+						compilation.emitAsset(
+							`long.${LONG_HASH}.txt`,
+							new RawSource("long content hash"),
+							{ contentHash: [LONG_HASH] }
+						);
+					});
+				});
+			}
+		}
+	]
+};
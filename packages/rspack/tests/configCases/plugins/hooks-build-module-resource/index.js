@@ -0,0 +1,5 @@
+import lib from "./lib";
+
+it("module should be built from the resource rewritten in buildModule", () => {
+	expect(lib).toBe("redirected");
+});
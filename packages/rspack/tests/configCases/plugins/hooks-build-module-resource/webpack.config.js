@@ -0,0 +1,21 @@
+const path = require("path");
+
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	plugins: [
+		{
+			name: "test",
+			apply(compiler) {
+				compiler.hooks.compilation.tap("test", compilation => {
+					compilation.hooks.buildModule.tap("test", module => {
+						if (module.resource.endsWith("lib.js")) {
+							module.resource = path.resolve(__dirname, "redirected.js");
+						}
+					});
+				});
+			}
+		}
+	]
+};
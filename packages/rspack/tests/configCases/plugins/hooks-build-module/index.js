@@ -0,0 +1,14 @@
+import lib from "./lib";
+const fs = require("fs");
+const path = require("path");
+
+it("module build hooks should be called", () => {
+	expect(lib).toBe("lib");
+	const calls = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "calls.json"), "utf-8")
+	);
+	expect(calls.buildModule.some(resource => resource.endsWith("lib.js"))).toBe(
+		true
+	);
+	expect(calls.succeedModule.sort()).toEqual(calls.buildModule.sort());
+});
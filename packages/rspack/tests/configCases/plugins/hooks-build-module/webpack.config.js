@@ -0,0 +1,29 @@
+const { RawSource } = require("webpack-sources");
+
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	plugins: [
+		{
+			name: "test",
+			apply(compiler) {
+				const calls = { buildModule: [], succeedModule: [] };
+				compiler.hooks.compilation.tap("test", compilation => {
+					compilation.hooks.buildModule.tap("test", module => {
+						calls.buildModule.push(module.resource);
+					});
+					compilation.hooks.succeedModule.tap("test", module => {
+						calls.succeedModule.push(module.resource);
+					});
+					compilation.hooks.processAssets.tap("test", () => {
+						compilation.emitAsset(
+							"calls.json",
+							new RawSource(JSON.stringify(calls))
+						);
+					});
+				});
+			}
+		}
+	]
+};
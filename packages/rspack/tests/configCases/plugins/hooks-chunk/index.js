@@ -0,0 +1,20 @@
+const fs = require("fs");
+const path = require("path");
+
+it("chunk hooks should be called", () => {
+	const calls = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "calls.json"), "utf-8")
+	);
+	expect(calls.optimizeChunks).toContain("main");
+	expect(calls.chunkHash).toContain("main");
+	expect(calls.contentHash).toContain("main");
+	expect(calls.renderManifest).toContain("main");
+});
+
+it("entries of render manifest should be emitted", () => {
+	const manifest = fs.readFileSync(
+		path.resolve(__dirname, "main.manifest.txt"),
+		"utf-8"
+	);
+	expect(manifest).toBe("main");
+});
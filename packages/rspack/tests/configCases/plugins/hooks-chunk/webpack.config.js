@@ -0,0 +1,50 @@
+const { RawSource } = require("webpack-sources");
+
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	plugins: [
+		{
+			name: "test",
+			apply(compiler) {
+				const calls = {
+					optimizeChunks: [],
+					chunkHash: [],
+					contentHash: [],
+					renderManifest: []
+				};
+				compiler.hooks.compilation.tap("test", compilation => {
+					compilation.hooks.optimizeChunks.tap("test", chunks => {
+						for (const chunk of chunks) {
+							calls.optimizeChunks.push(chunk.name);
+						}
+					});
+					compilation.hooks.chunkHash.tap("test", (chunk, hash) => {
+						calls.chunkHash.push(chunk.name);
+						hash.update("test");
+					});
+					compilation.hooks.contentHash.tap("test", chunk => {
+						calls.contentHash.push(chunk.name);
+					});
+					compilation.hooks.renderManifest.tap("test", (result, chunk) => {
+						calls.renderManifest.push(chunk.name);
+						return [
+							...result,
+							{
+								filename: `${chunk.name}.manifest.txt`,
+								source: new RawSource(chunk.name)
+							}
+						];
+					});
+					compilation.hooks.processAssets.tap("test", () => {
+						compilation.emitAsset(
+							"calls.json",
+							new RawSource(JSON.stringify(calls))
+						);
+					});
+				});
+			}
+		}
+	]
+};
@@ -0,0 +1,15 @@
+import lib from "./lib";
+const fs = require("fs");
+const path = require("path");
+
+it("normal module factory hooks should be called", () => {
+	expect(lib).toBe("lib");
+	const calls = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "calls.json"), "utf-8")
+	);
+	expect(calls.beforeResolve).toContain("./lib");
+	expect(calls.factorize).toContain("./lib");
+	expect(
+		calls.afterResolve.some(resource => resource.endsWith("lib.js"))
+	).toBe(true);
+});
@@ -0,0 +1,35 @@
+const { RawSource } = require("webpack-sources");
+
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	plugins: [
+		{
+			name: "test",
+			apply(compiler) {
+				const calls = { beforeResolve: [], afterResolve: [], factorize: [] };
+				compiler.hooks.thisCompilation.tap(
+					"test",
+					(compilation, { normalModuleFactory }) => {
+						normalModuleFactory.hooks.beforeResolve.tap("test", data => {
+							calls.beforeResolve.push(data.request);
+						});
+						normalModuleFactory.hooks.factorize.tap("test", data => {
+							calls.factorize.push(data.request);
+						});
+						normalModuleFactory.hooks.afterResolve.tap("test", data => {
+							calls.afterResolve.push(data.resourceData.resource);
+						});
+						compilation.hooks.processAssets.tap("test", () => {
+							compilation.emitAsset(
+								"calls.json",
+								new RawSource(JSON.stringify(calls))
+							);
+						});
+					}
+				);
+			}
+		}
+	]
+};
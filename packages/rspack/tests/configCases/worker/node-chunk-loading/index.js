@@ -0,0 +1,12 @@
+it("should load chunks of workers with require in node", async () => {
+	// `Worker` isn't a global of node, but workers are only bundled from the global one
+	global.Worker = require("worker_threads").Worker;
+	const worker = new Worker(new URL("./worker.js", import.meta.url));
+	const reply = await new Promise((resolve, reject) => {
+		worker.once("message", resolve);
+		worker.once("error", reject);
+		worker.postMessage("ping");
+	});
+	expect(reply).toBe("ping pong");
+	await worker.terminate();
+});
@@ -0,0 +1 @@
+export const reply = data => data + " pong";
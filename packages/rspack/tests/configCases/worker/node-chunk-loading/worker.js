@@ -0,0 +1,5 @@
+const { parentPort } = require("worker_threads");
+
+parentPort.on("message", data => {
+	import("./lazy").then(({ reply }) => parentPort.postMessage(reply(data)));
+});
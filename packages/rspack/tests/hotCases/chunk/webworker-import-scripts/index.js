@@ -0,0 +1,16 @@
+it("should load chunks and hot updates with importScripts in webworkers", done => {
+	import("./chunk")
+		.then(chunk => {
+			expect(chunk.value).toBe(1);
+			NEXT(require("../../update")(done));
+			module.hot.accept("./chunk", () => {
+				import("./chunk")
+					.then(chunk => {
+						expect(chunk.value).toBe(2);
+						done();
+					})
+					.catch(done);
+			});
+		})
+		.catch(done);
+});